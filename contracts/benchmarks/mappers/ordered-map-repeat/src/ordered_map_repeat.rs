@@ -0,0 +1,57 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+/// Keys are inserted out of order, to also benchmark the rebalancing of the tree.
+/// Since the modulus is prime, the first `KEY_MODULUS` keys generated are unique.
+const KEY_STRIDE: u64 = 7_919;
+const KEY_MODULUS: u64 = 10_007;
+
+#[multiversx_sc::contract]
+pub trait OrderedMapRepeat: benchmark_common::BenchmarkCommon {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn add(&self, num_repeats: usize, value: ManagedBuffer) {
+        let mut bench = self.bench();
+        for i in 0..num_repeats {
+            bench.insert(self.scrambled_key(i), value.clone());
+        }
+    }
+
+    #[endpoint]
+    fn count(&self, value: ManagedBuffer) -> usize {
+        let bench = self.bench();
+        bench.iter().filter(|(_, v)| *v == value).count()
+    }
+
+    #[endpoint]
+    fn count_range(&self, from: u64, to: u64) -> usize {
+        let bench = self.bench();
+        bench.range(from..=to).count()
+    }
+
+    #[endpoint]
+    fn remove(&self, num_repeats: usize) {
+        let mut bench = self.bench();
+        for i in 0..num_repeats {
+            bench.remove(&self.scrambled_key(i));
+        }
+    }
+
+    #[endpoint]
+    fn pop_first(&self, num_repeats: usize) {
+        let mut bench = self.bench();
+        for _ in 0..num_repeats {
+            bench.pop_first();
+        }
+    }
+
+    fn scrambled_key(&self, index: usize) -> u64 {
+        index as u64 * KEY_STRIDE % KEY_MODULUS
+    }
+
+    #[storage_mapper("benchmark")]
+    fn bench(&self) -> OrderedMapMapper<u64, ManagedBuffer>;
+}
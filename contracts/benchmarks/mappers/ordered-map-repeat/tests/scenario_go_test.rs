@@ -0,0 +1,10 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    ScenarioWorld::vm_go()
+}
+
+#[test]
+fn ordered_map_repeat_go() {
+    world().run("scenarios/ordered_map_repeat.scen.json");
+}
@@ -0,0 +1,17 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/benchmarks/mappers/ordered-map-repeat");
+
+    blockchain.register_contract(
+        "file:output/ordered-map-repeat.wasm",
+        ordered_map_repeat::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn ordered_map_repeat_rs() {
+    world().run("scenarios/ordered_map_repeat.scen.json");
+}
@@ -0,0 +1,31 @@
+// Code generated by the multiversx-sc multi-contract system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                            5
+// Async Callback (empty):               1
+// Total number of exported functions:   7
+
+#![no_std]
+#![allow(internal_features)]
+#![feature(lang_items)]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    ordered_map_repeat
+    (
+        init => init
+        add => add
+        count => count
+        count_range => count_range
+        remove => remove
+        pop_first => pop_first
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
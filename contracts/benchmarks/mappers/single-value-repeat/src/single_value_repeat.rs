@@ -30,6 +30,27 @@ pub trait SingleValueRepeat: benchmark_common::BenchmarkCommon {
         }
     }
 
+    #[endpoint]
+    fn increment(&self, num_repeats: usize, key: ManagedBuffer) -> u64 {
+        for _ in 0..num_repeats {
+            self.counter(&key).update(|counter| *counter += 1);
+        }
+        self.counter(&key).get()
+    }
+
+    /// Same as `increment`, but the counter is only loaded and stored once.
+    #[endpoint]
+    #[storage_cache]
+    fn increment_cached(&self, num_repeats: usize, key: ManagedBuffer) -> u64 {
+        for _ in 0..num_repeats {
+            self.counter(&key).update(|counter| *counter += 1);
+        }
+        self.counter(&key).get()
+    }
+
+    #[storage_mapper("counter")]
+    fn counter(&self, key: &ManagedBuffer) -> SingleValueMapper<u64>;
+
     fn item_at(&self, key: &ManagedBuffer, index: usize) -> SingleValueMapper<ManagedBuffer> {
         self.bench(self.append_index(key, index))
     }
@@ -13,3 +13,8 @@ fn single_value_repeat_go() {
 fn single_value_repeat_struct_go() {
     world().run("scenarios/single_value_repeat_struct.scen.json");
 }
+
+#[test]
+fn single_value_repeat_cached_go() {
+    world().run("scenarios/single_value_repeat_cached.scen.json");
+}
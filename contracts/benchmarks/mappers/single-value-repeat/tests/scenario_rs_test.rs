@@ -20,3 +20,8 @@ fn single_value_repeat_rs() {
 fn single_value_repeat_struct_rs() {
     world().run("scenarios/single_value_repeat_struct.scen.json");
 }
+
+#[test]
+fn single_value_repeat_cached_rs() {
+    world().run("scenarios/single_value_repeat_cached.scen.json");
+}
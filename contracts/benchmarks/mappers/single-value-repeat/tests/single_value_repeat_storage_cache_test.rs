@@ -0,0 +1,69 @@
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_chain_vm::tx_mock::StorageAccessStats,
+    scenario_model::{Account, ScCallStep, SetStateStep, TxExpect},
+    ContractInfo, ScenarioWorld,
+};
+use single_value_repeat::ProxyTrait as _;
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const CONTRACT_ADDRESS_EXPR: &str = "sc:contract";
+const CONTRACT_PATH_EXPR: &str = "file:output/single-value-repeat.wasm";
+
+const NUM_REPEATS: usize = 1000;
+
+type SingleValueRepeatContract = ContractInfo<single_value_repeat::Proxy<StaticApi>>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/benchmarks/mappers/single-value-repeat");
+    blockchain.register_contract(CONTRACT_PATH_EXPR, single_value_repeat::ContractBuilder);
+    blockchain
+}
+
+/// The same scenario as `single_value_repeat_cached.scen.json`,
+/// which cannot express the number of storage hooks called.
+#[test]
+fn single_value_repeat_storage_cache_test() {
+    let mut world = world();
+    let mut contract = SingleValueRepeatContract::new(CONTRACT_ADDRESS_EXPR);
+    let code = world.code_expression(CONTRACT_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(CONTRACT_ADDRESS_EXPR, Account::new().code(code)),
+    );
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .call(contract.increment(NUM_REPEATS, ManagedBuffer::from("testing---testing---")))
+            .expect(TxExpect::ok().result("1000")),
+    );
+    // a load and a store for each update, plus the final load
+    assert_eq!(
+        world.last_tx_storage_access_stats(),
+        StorageAccessStats {
+            loads: NUM_REPEATS + 1,
+            stores: NUM_REPEATS,
+        }
+    );
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .call(
+                contract.increment_cached(NUM_REPEATS, ManagedBuffer::from("testing---cached---")),
+            )
+            .expect(TxExpect::ok().result("1000")),
+    );
+    // the first load fills the cache, the flush at the end stores once
+    assert_eq!(
+        world.last_tx_storage_access_stats(),
+        StorageAccessStats {
+            loads: 1,
+            stores: 1,
+        }
+    );
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            6
+// Endpoints:                            8
 // Async Callback (empty):               1
-// Total number of exported functions:   8
+// Total number of exported functions:  10
 
 #![no_std]
 #![allow(internal_features)]
@@ -23,6 +23,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         add => add
         count => count
         remove => remove
+        increment => increment
+        increment_cached => increment_cached
         add_struct => add_struct
         count_struct => count_struct
         remove_struct => remove_struct
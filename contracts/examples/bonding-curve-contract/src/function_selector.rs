@@ -2,7 +2,11 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use crate::bonding_curve::{
-    curves::{curve_function::CurveFunction, linear_function::LinearFunction},
+    curves::{
+        curve_function::CurveFunction, exponential_function::ExponentialFunction,
+        linear_function::LinearFunction, logarithmic_function::LogarithmicFunction,
+        polynomial_function::PolynomialFunction, sigmoid_function::SigmoidFunction,
+    },
     utils::structs::CurveArguments,
 };
 
@@ -14,6 +18,10 @@ pub enum FunctionSelector<M: ManagedTypeApi> {
     CustomExample(BigUint<M>),
     #[default]
     None,
+    Exponential(ExponentialFunction<M>),
+    Polynomial(PolynomialFunction<M>),
+    Logarithmic(LogarithmicFunction<M>),
+    Sigmoid(SigmoidFunction<M>),
 }
 
 impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
@@ -32,6 +40,18 @@ impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
                 let sum = token_start + amount;
                 &(&sum * &sum * sum / 3u32) + &arguments.balance + initial_cost.clone()
             },
+            FunctionSelector::Exponential(exponential_function) => {
+                exponential_function.calculate_price(token_start, amount, arguments)
+            },
+            FunctionSelector::Polynomial(polynomial_function) => {
+                polynomial_function.calculate_price(token_start, amount, arguments)
+            },
+            FunctionSelector::Logarithmic(logarithmic_function) => {
+                logarithmic_function.calculate_price(token_start, amount, arguments)
+            },
+            FunctionSelector::Sigmoid(sigmoid_function) => {
+                sigmoid_function.calculate_price(token_start, amount, arguments)
+            },
             FunctionSelector::None => {
                 M::error_api_impl().signal_error(b"Bonding Curve function is not assiged")
             },
@@ -14,6 +14,11 @@ fn claim_go() {
     world().run("scenarios/claim.scen.json");
 }
 
+#[test]
+fn curves_go() {
+    world().run("scenarios/curves.scen.json");
+}
+
 #[test]
 fn deploy_go() {
     world().run("scenarios/deploy.scen.json");
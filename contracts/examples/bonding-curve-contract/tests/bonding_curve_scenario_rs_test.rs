@@ -19,6 +19,11 @@ fn claim_rs() {
     world().run("scenarios/claim.scen.json");
 }
 
+#[test]
+fn curves_rs() {
+    world().run("scenarios/curves.scen.json");
+}
+
 #[test]
 fn deploy_rs() {
     world().run("scenarios/deploy.scen.json");
@@ -410,6 +410,42 @@ fn test_change_quorum() {
     state.perform(action_id);
 }
 
+#[test]
+fn test_action_storage_freed() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+    state.world.reset_cumulative_storage_report();
+
+    let action_id = state.propose_change_quorum(QUORUM_SIZE);
+    let propose_report = state
+        .world
+        .last_tx_storage_report()
+        .account(&AddressValue::from(MULTISIG_ADDRESS_EXPR).to_vm_address());
+    assert!(propose_report.base_key_delta(b"action_data").bytes_added > 0);
+
+    state.sign(action_id);
+    let sign_report = state.world.account_storage_report(MULTISIG_ADDRESS_EXPR);
+    assert!(sign_report.base_key_delta(b"action_signer_ids").net_bytes() > 0);
+
+    // failed transactions leave no storage footprint
+    let action_id_too_large = state.propose_change_quorum(2);
+    state.sign(action_id_too_large);
+    state.perform_and_expect_err(action_id_too_large, "quorum cannot exceed board size");
+    assert!(state.world.last_tx_storage_report().is_empty());
+
+    // performing an action frees up its storage
+    state.world.reset_cumulative_storage_report();
+    state.perform(action_id);
+    let perform_report = state.world.account_storage_report(MULTISIG_ADDRESS_EXPR);
+    assert!(perform_report.base_key_delta(b"action_data").net_bytes() < 0);
+    assert!(
+        perform_report
+            .base_key_delta(b"action_signer_ids")
+            .net_bytes()
+            < 0
+    );
+}
+
 #[test]
 fn test_transfer_execute_to_user() {
     let mut state = MultisigTestState::new();
@@ -18,9 +18,12 @@ pub mod managed_address_features;
 pub mod managed_buffer_features;
 pub mod managed_vec_features;
 pub mod non_zero_features;
+pub mod storage_cache_features;
 pub mod storage_direct_load;
 pub mod storage_direct_store;
+pub mod storage_mapper_bi_di;
 pub mod storage_mapper_fungible_token;
+pub mod storage_mapper_get_at_address;
 pub mod storage_mapper_linked_list;
 pub mod storage_mapper_map;
 pub mod storage_mapper_map_storage;
@@ -30,6 +33,7 @@ pub mod storage_mapper_set;
 pub mod storage_mapper_single;
 pub mod storage_mapper_token_attributes;
 pub mod storage_mapper_unique_id_mapper;
+pub mod storage_mapper_user;
 pub mod storage_mapper_vec;
 pub mod storage_mapper_whitelist;
 pub mod storage_raw_api_features;
@@ -54,6 +58,7 @@ pub trait BasicFeatures:
     + managed_buffer_features::ManagedBufferFeatures
     + managed_vec_features::ManagedVecFeatures
     + storage_raw_api_features::StorageRawApiFeatures
+    + storage_cache_features::StorageCacheFeatures
     + storage_direct_load::StorageLoadFeatures
     + storage_direct_store::StorageStoreFeatures
     + storage_mapper_linked_list::LinkedListMapperFeatures
@@ -68,6 +73,9 @@ pub trait BasicFeatures:
     + storage_mapper_fungible_token::FungibleTokenMapperFeatures
     + storage_mapper_non_fungible_token::NonFungibleTokenMapperFeatures
     + storage_mapper_unique_id_mapper::UniqueIdMapperFeatures
+    + storage_mapper_user::UserMapperFeatures
+    + storage_mapper_bi_di::BiDiMapperFeatures
+    + storage_mapper_get_at_address::StorageMapperGetAtAddress
     + struct_eq::StructEquals
     + token_identifier_features::TokenIdentifierFeatures
     + non_zero_features::TypeFeatures
@@ -83,4 +83,36 @@ pub trait ManagedVecFeatures {
         let with_explicit_lifetime: ManagedRef<'_, BigUint> = value;
         (*with_explicit_lifetime).clone()
     }
+
+    /// Every iteration leaves one more big integer behind.
+    #[endpoint]
+    fn managed_vec_sum_of_squares(&self, mv: ManagedVec<BigUint>) -> BigUint {
+        let mut sum = BigUint::zero();
+        for item in mv.iter() {
+            sum += &*item * &*item;
+        }
+        sum
+    }
+
+    /// Same as `managed_vec_sum_of_squares`, but releases the temporary values, to save managed memory.
+    #[endpoint]
+    fn managed_vec_sum_of_squares_released(&self, mv: ManagedVec<BigUint>) -> BigUint {
+        let mut sum = BigUint::zero();
+        for item in mv.iter() {
+            let square = &*item * &*item;
+            sum += &square;
+            square.release();
+        }
+        sum
+    }
+
+    /// Releases a value that shares its handle with the first item of the vector.
+    ///
+    /// The item can no longer be read afterwards, in the Rust VM.
+    #[endpoint]
+    fn managed_vec_release_shared_item(&self, mv: ManagedVec<BigUint>) -> BigUint {
+        let shared_handle = mv.get(0).get_raw_handle_of_ref();
+        BigUint::from_handle(shared_handle).release();
+        (*mv.get(0)).clone()
+    }
 }
@@ -0,0 +1,66 @@
+multiversx_sc::imports!();
+
+/// Endpoints running with the write-back storage cache enabled.
+#[multiversx_sc::module]
+pub trait StorageCacheFeatures {
+    #[endpoint]
+    #[storage_cache]
+    fn storage_cache_increment(&self, times: u32) -> u64 {
+        for _ in 0..times {
+            self.cache_counter().update(|counter| *counter += 1);
+        }
+        self.cache_counter().get()
+    }
+
+    #[endpoint]
+    #[storage_cache]
+    fn storage_cache_clear_then_check_empty(&self) -> bool {
+        // makes sure the value is in the cache, before clearing it
+        let _ = self.cache_counter().get();
+        self.cache_counter().clear();
+        self.cache_counter().is_empty()
+    }
+
+    /// The reentrant call must see the increment done before it,
+    /// and this call must see the increment done by the reentrant call.
+    ///
+    /// Returns the counter as seen at the end of the reentrant call, and then at the end of this call.
+    #[endpoint]
+    #[storage_cache]
+    fn storage_cache_increment_then_sync_call_self(&self) -> MultiValue2<u64, u64> {
+        self.cache_counter().update(|counter| *counter += 1);
+
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(1u32);
+        let result = self.send_raw().execute_on_dest_context_raw(
+            self.blockchain().get_gas_left() / 2,
+            &self.blockchain().get_sc_address(),
+            &BigUint::zero(),
+            &ManagedBuffer::from(b"storage_cache_increment"),
+            &arg_buffer,
+        );
+        let reentrant_call_counter = result.get(0).parse_as_u64().unwrap_or_default();
+
+        (reentrant_call_counter, self.cache_counter().get()).into()
+    }
+
+    /// The value set here must be flushed before the async call, otherwise it would be lost.
+    #[endpoint]
+    #[storage_cache]
+    fn storage_cache_set_then_async_call_self(&self, value: u64) {
+        self.cache_counter().set(value);
+
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(1u32);
+        self.send_raw().async_call_raw(
+            &self.blockchain().get_sc_address(),
+            &BigUint::zero(),
+            &ManagedBuffer::from(b"storage_cache_increment"),
+            &arg_buffer,
+        );
+    }
+
+    #[view]
+    #[storage_mapper("cache_counter")]
+    fn cache_counter(&self) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,19 @@
+multiversx_sc::imports!();
+
+/// Storage mapper test.
+#[multiversx_sc::module]
+pub trait BiDiMapperFeatures {
+    #[view]
+    #[storage_mapper("bi_di_mapper")]
+    fn bi_di_mapper(&self) -> BiDiMapper<u32, u32>;
+
+    #[endpoint]
+    fn bi_di_mapper_insert(&self, id: u32, value: u32) -> bool {
+        self.bi_di_mapper().insert(id, value)
+    }
+
+    #[endpoint]
+    fn bi_di_mapper_remove_by_id(&self, id: u32) -> bool {
+        self.bi_di_mapper().remove_by_id(&id)
+    }
+}
@@ -0,0 +1,118 @@
+multiversx_sc::imports!();
+
+/// Storage mapper test, reading the mappers of another contract.
+#[multiversx_sc::module]
+pub trait StorageMapperGetAtAddress {
+    #[storage_mapper_from_address("set_mapper")]
+    fn set_mapper_from_address(&self, address: ManagedAddress) -> SetMapper<u32, ManagedAddress>;
+
+    #[storage_mapper_from_address("map_mapper")]
+    fn map_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> MapMapper<u32, u32, ManagedAddress>;
+
+    #[storage_mapper_from_address("queue_mapper")]
+    fn queue_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> QueueMapper<u32, ManagedAddress>;
+
+    #[storage_mapper_from_address("list_mapper")]
+    fn list_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> LinkedListMapper<u32, ManagedAddress>;
+
+    #[storage_mapper_from_address("user_mapper")]
+    fn user_mapper_from_address(&self, address: ManagedAddress) -> UserMapper<ManagedAddress>;
+
+    #[storage_mapper_from_address("bi_di_mapper")]
+    fn bi_di_mapper_from_address(
+        &self,
+        address: ManagedAddress,
+    ) -> BiDiMapper<u32, u32, ManagedAddress>;
+
+    #[view]
+    fn set_mapper_is_empty_at_address(&self, address: ManagedAddress) -> bool {
+        self.set_mapper_from_address(address).is_empty()
+    }
+
+    #[view]
+    fn set_mapper_contains_at_address(&self, address: ManagedAddress, item: u32) -> bool {
+        self.set_mapper_from_address(address).contains(&item)
+    }
+
+    #[view]
+    fn set_mapper_len_at_address(&self, address: ManagedAddress) -> usize {
+        self.set_mapper_from_address(address).len()
+    }
+
+    #[view]
+    fn map_mapper_get_at_address(&self, address: ManagedAddress, key: u32) -> Option<u32> {
+        self.map_mapper_from_address(address).get(&key)
+    }
+
+    #[view]
+    fn map_mapper_keys_at_address(&self, address: ManagedAddress) -> MultiValueEncoded<u32> {
+        self.map_mapper_from_address(address).keys().collect()
+    }
+
+    #[view]
+    fn queue_mapper_at_address(&self, address: ManagedAddress) -> MultiValueEncoded<u32> {
+        self.queue_mapper_from_address(address).iter().collect()
+    }
+
+    #[view]
+    fn list_mapper_at_address(&self, address: ManagedAddress) -> MultiValueEncoded<u32> {
+        self.list_mapper_from_address(address)
+            .iter()
+            .map(|node| node.into_value())
+            .collect()
+    }
+
+    #[view]
+    fn list_mapper_front_at_address(&self, address: ManagedAddress) -> Option<u32> {
+        self.list_mapper_from_address(address)
+            .front()
+            .map(|node| node.into_value())
+    }
+
+    #[view]
+    fn user_mapper_get_user_id_at_address(
+        &self,
+        address: ManagedAddress,
+        user: ManagedAddress,
+    ) -> usize {
+        self.user_mapper_from_address(address).get_user_id(&user)
+    }
+
+    #[view]
+    fn user_mapper_get_user_address_at_address(
+        &self,
+        address: ManagedAddress,
+        id: usize,
+    ) -> Option<ManagedAddress> {
+        self.user_mapper_from_address(address).get_user_address(id)
+    }
+
+    #[view]
+    fn user_mapper_count_at_address(&self, address: ManagedAddress) -> usize {
+        self.user_mapper_from_address(address).get_user_count()
+    }
+
+    #[view]
+    fn bi_di_mapper_get_value_at_address(&self, address: ManagedAddress, id: u32) -> u32 {
+        self.bi_di_mapper_from_address(address).get_value(&id)
+    }
+
+    #[view]
+    fn bi_di_mapper_get_id_at_address(&self, address: ManagedAddress, value: u32) -> u32 {
+        self.bi_di_mapper_from_address(address).get_id(&value)
+    }
+
+    #[view]
+    fn bi_di_mapper_len_at_address(&self, address: ManagedAddress) -> usize {
+        self.bi_di_mapper_from_address(address).len()
+    }
+}
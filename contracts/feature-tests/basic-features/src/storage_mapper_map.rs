@@ -27,6 +27,16 @@ pub trait MapMapperFeatures {
         result
     }
 
+    #[view]
+    #[paginated]
+    fn map_mapper_page(
+        &self,
+        cursor: usize,
+        limit: usize,
+    ) -> Page<Self::Api, MultiValue2<u32, u32>> {
+        self.map_mapper().page(cursor, limit)
+    }
+
     #[endpoint]
     fn map_mapper_insert(&self, item: u32, value: u32) -> Option<u32> {
         let mut map_mapper = self.map_mapper();
@@ -24,4 +24,10 @@ pub trait SetMapperFeatures {
         let mut set_mapper = self.set_mapper();
         set_mapper.remove(&item)
     }
+
+    #[view]
+    #[paginated]
+    fn set_mapper_page(&self, cursor: usize, limit: usize) -> Page<Self::Api, u32> {
+        self.set_mapper().page(cursor, limit)
+    }
 }
@@ -0,0 +1,14 @@
+multiversx_sc::imports!();
+
+/// Storage mapper test.
+#[multiversx_sc::module]
+pub trait UserMapperFeatures {
+    #[view]
+    #[storage_mapper("user_mapper")]
+    fn user_mapper(&self) -> UserMapper;
+
+    #[endpoint]
+    fn user_mapper_get_or_create_user(&self, address: ManagedAddress) -> usize {
+        self.user_mapper().get_or_create_user(&address)
+    }
+}
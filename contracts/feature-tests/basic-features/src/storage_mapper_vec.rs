@@ -32,4 +32,10 @@ pub trait VecMapperFeatures {
     fn vec_mapper_len_at_address(&self, address: ManagedAddress) -> usize {
         self.vec_mapper().len_at_address(&address)
     }
+
+    #[view]
+    #[paginated]
+    fn vec_mapper_page(&self, cursor: usize, limit: usize) -> Page<Self::Api, u32> {
+        self.vec_mapper().page(cursor, limit)
+    }
 }
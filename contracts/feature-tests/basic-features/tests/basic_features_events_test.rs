@@ -0,0 +1,100 @@
+use multiversx_sc::types::{
+    BigUint, ManagedAddress, ManagedBuffer, ManagedVec, MultiValueManagedVec,
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    scenario_model::{Account, AddressValue, ScCallStep, SetStateStep},
+    ContractInfo, ScenarioWorld,
+};
+
+use basic_features::event_features::{
+    event_types::{EventAEvent, EventBEvent},
+    ProxyTrait as _,
+};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const BASIC_FEATURES_ADDRESS_EXPR: &str = "sc:basic-features";
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+
+type BasicFeaturesContract = ContractInfo<basic_features::Proxy<StaticApi>>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain
+}
+
+fn setup() -> (ScenarioWorld, BasicFeaturesContract) {
+    let mut world = world();
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                BASIC_FEATURES_ADDRESS_EXPR,
+                Account::new().nonce(1).code(basic_features_code),
+            ),
+    );
+    (
+        world,
+        BasicFeaturesContract::new(BASIC_FEATURES_ADDRESS_EXPR),
+    )
+}
+
+#[test]
+fn test_decode_events_data_only() {
+    let (mut world, mut basic_features) = setup();
+
+    world.sc_call_use_raw_response(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .call(basic_features.log_event_a_repeat(3u32)),
+        |response| {
+            let events = response.events::<EventAEvent>();
+            assert_eq!(
+                events,
+                vec![
+                    EventAEvent { data: 0 },
+                    EventAEvent { data: 1 },
+                    EventAEvent { data: 2 },
+                ]
+            );
+
+            // other events are skipped
+            assert!(response.events::<EventBEvent<StaticApi>>().is_empty());
+        },
+    );
+}
+
+#[test]
+fn test_decode_events_with_topics() {
+    let (mut world, mut basic_features) = setup();
+
+    let owner_address = AddressValue::from(OWNER_ADDRESS_EXPR).to_address();
+    let mut data = ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+    data.push(ManagedBuffer::from("first"));
+    data.push(ManagedBuffer::from("second"));
+
+    world.sc_call_use_raw_response(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .call(basic_features.log_event_b(
+                BigUint::from(1234u32),
+                ManagedAddress::from(&owner_address),
+                MultiValueManagedVec::from(data.clone()),
+            )),
+        |response| {
+            let events = response.events::<EventBEvent<StaticApi>>();
+            assert_eq!(
+                events,
+                vec![EventBEvent {
+                    arg1: BigUint::from(1234u32),
+                    arg2: ManagedAddress::from(&owner_address),
+                    data,
+                }]
+            );
+        },
+    );
+}
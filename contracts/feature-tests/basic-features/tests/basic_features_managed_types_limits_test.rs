@@ -0,0 +1,99 @@
+use basic_features::managed_vec_features::ProxyTrait as _;
+use multiversx_sc::types::{BigUint, ManagedVec};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_chain_vm::tx_mock::{ManagedTypesLimits, ManagedTypesStats},
+    scenario_model::{ScCallStep, TxExpect},
+    ContractInfo, ScenarioWorld,
+};
+
+const BASIC_FEATURES_ADDRESS_EXPR: &str = "sc:basic-features";
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+const USER_ADDRESS_EXPR: &str = "address:an_account";
+
+const NUM_ITEMS: u64 = 200;
+const SUM_OF_SQUARES: &str = "2686700";
+
+type BasicFeaturesContract = ContractInfo<basic_features::Proxy<StaticApi>>;
+
+/// Scenarios cannot set the limits, so they are set here, on top of the state of the release scenario.
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain.run("scenarios/managed_vec_release.scen.json");
+    blockchain
+}
+
+fn items() -> ManagedVec<StaticApi, BigUint<StaticApi>> {
+    let mut items = ManagedVec::new();
+    for i in 1..=NUM_ITEMS {
+        items.push(BigUint::from(i));
+    }
+    items
+}
+
+fn sum_of_squares(
+    world: &mut ScenarioWorld,
+    released: bool,
+    expect: TxExpect,
+) -> ManagedTypesStats {
+    let mut basic_features = BasicFeaturesContract::new(BASIC_FEATURES_ADDRESS_EXPR);
+    let step = if released {
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .call(basic_features.managed_vec_sum_of_squares_released(items()))
+    } else {
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .call(basic_features.managed_vec_sum_of_squares(items()))
+    };
+    world.sc_call(step.expect(expect));
+    world.last_tx_managed_types_stats()
+}
+
+#[test]
+fn managed_types_released_handles_test() {
+    let mut world = world();
+
+    let stats_leaked = sum_of_squares(&mut world, false, TxExpect::ok().result(SUM_OF_SQUARES));
+    let stats_released = sum_of_squares(&mut world, true, TxExpect::ok().result(SUM_OF_SQUARES));
+
+    // one square left behind per item, except the one still alive at the end
+    assert!(stats_leaked.peak_handles >= stats_released.peak_handles + NUM_ITEMS as usize - 1);
+    assert!(stats_leaked.peak_bytes > stats_released.peak_bytes);
+}
+
+#[test]
+fn managed_types_handle_limit_test() {
+    let mut world = world();
+
+    let stats_released = sum_of_squares(&mut world, true, TxExpect::ok().result(SUM_OF_SQUARES));
+    world.set_managed_types_limits(
+        ManagedTypesLimits::unlimited().max_handles(stats_released.peak_handles + 10),
+    );
+
+    sum_of_squares(
+        &mut world,
+        false,
+        TxExpect::err(10, "str:too many managed type handles"),
+    );
+    sum_of_squares(&mut world, true, TxExpect::ok().result(SUM_OF_SQUARES));
+}
+
+#[test]
+fn managed_types_memory_limit_test() {
+    let mut world = world();
+
+    let stats_released = sum_of_squares(&mut world, true, TxExpect::ok().result(SUM_OF_SQUARES));
+    world.set_managed_types_limits(
+        ManagedTypesLimits::unlimited().max_bytes(stats_released.peak_bytes + 16),
+    );
+
+    sum_of_squares(
+        &mut world,
+        false,
+        TxExpect::err(10, "str:managed types memory limit exceeded"),
+    );
+    sum_of_squares(&mut world, true, TxExpect::ok().result(SUM_OF_SQUARES));
+}
@@ -0,0 +1,117 @@
+use basic_features::{storage_mapper_map::ProxyTrait as _, storage_mapper_vec::ProxyTrait as _};
+use multiversx_sc::codec::multi_types::MultiValue2;
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    scenario_model::{Account, ScCallStep, ScQueryStep, SetStateStep, TxExpect},
+    ContractInfo, ScenarioWorld,
+};
+
+const BASIC_FEATURES_ADDRESS_EXPR: &str = "sc:basic-features";
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+
+type BasicFeaturesContract = ContractInfo<basic_features::Proxy<StaticApi>>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain
+}
+
+fn setup(world: &mut ScenarioWorld) {
+    let code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    let mut sc_account = Account::new().nonce(1).code(code);
+    sc_account = sc_account.storage("str:vec_mapper.len", "7");
+    for index in 1..=7u32 {
+        sc_account = sc_account.storage(
+            &format!("str:vec_mapper.item|u32:{index}"),
+            &(index * 10).to_string(),
+        );
+    }
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(BASIC_FEATURES_ADDRESS_EXPR, sc_account),
+    );
+}
+
+#[test]
+fn vec_mapper_query_all_pages_test() {
+    let mut world = world();
+    let mut basic_features = BasicFeaturesContract::new(BASIC_FEATURES_ADDRESS_EXPR);
+    setup(&mut world);
+
+    let items: Vec<u32> = world.quick_query_all_pages(3, |cursor, limit| {
+        basic_features.vec_mapper_page(cursor, limit)
+    });
+    assert_eq!(items, vec![10, 20, 30, 40, 50, 60, 70]);
+
+    let items: Vec<u32> = world.quick_query_all_pages(7, |cursor, limit| {
+        basic_features.vec_mapper_page(cursor, limit)
+    });
+    assert_eq!(items.len(), 7);
+}
+
+#[test]
+fn map_mapper_query_all_pages_test() {
+    let mut world = world();
+    let mut basic_features = BasicFeaturesContract::new(BASIC_FEATURES_ADDRESS_EXPR);
+    setup(&mut world);
+
+    for key in 1..=5u32 {
+        world.sc_call(
+            ScCallStep::new()
+                .from(OWNER_ADDRESS_EXPR)
+                .call(basic_features.map_mapper_insert(key, key * 100)),
+        );
+    }
+
+    let entries: Vec<MultiValue2<u32, u32>> = world.quick_query_all_pages(2, |cursor, limit| {
+        basic_features.map_mapper_page(cursor, limit)
+    });
+    let entries: Vec<(u32, u32)> = entries
+        .into_iter()
+        .map(|entry| entry.into_tuple())
+        .collect();
+    assert_eq!(
+        entries,
+        vec![(1, 100), (2, 200), (3, 300), (4, 400), (5, 500)]
+    );
+}
+
+#[test]
+fn page_zero_limit_test() {
+    let mut world = world();
+    let mut basic_features = BasicFeaturesContract::new(BASIC_FEATURES_ADDRESS_EXPR);
+    setup(&mut world);
+
+    // an empty page would point back to its own cursor
+    world.sc_query(
+        ScQueryStep::new()
+            .call(basic_features.vec_mapper_page(3usize, 0usize))
+            .expect(TxExpect::user_error(
+                "str:page limit must be greater than zero",
+            )),
+    );
+    world.sc_query(
+        ScQueryStep::new()
+            .call(basic_features.map_mapper_page(0usize, 0usize))
+            .expect(TxExpect::user_error(
+                "str:page limit must be greater than zero",
+            )),
+    );
+}
+
+#[test]
+#[should_panic(expected = "page limit must be greater than zero")]
+fn query_all_pages_zero_limit_test() {
+    let mut world = world();
+    let mut basic_features = BasicFeaturesContract::new(BASIC_FEATURES_ADDRESS_EXPR);
+    setup(&mut world);
+
+    let _: Vec<u32> = world.quick_query_all_pages(0, |cursor, limit| {
+        basic_features.vec_mapper_page(cursor, limit)
+    });
+}
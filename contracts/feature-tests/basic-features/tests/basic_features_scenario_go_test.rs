@@ -250,6 +250,17 @@ fn managed_vec_biguint_push_go() {
     world().run("scenarios/managed_vec_biguint_push.scen.json");
 }
 
+#[test]
+fn managed_vec_release_go() {
+    world().run("scenarios/managed_vec_release.scen.json");
+}
+
+#[test]
+#[ignore = "only the Rust VM reclaims released handles"]
+fn managed_vec_release_shared_go() {
+    world().run("scenarios/managed_vec_release_shared.scen.json");
+}
+
 #[test]
 fn only_owner_go() {
     world().run("scenarios/only_owner.scen.json");
@@ -295,6 +306,11 @@ fn storage_bool_go() {
     world().run("scenarios/storage_bool.scen.json");
 }
 
+#[test]
+fn storage_cache_go() {
+    world().run("scenarios/storage_cache.scen.json");
+}
+
 #[test]
 fn storage_clear_go() {
     world().run("scenarios/storage_clear.scen.json");
@@ -340,6 +356,11 @@ fn storage_mapper_fungible_token_go() {
     world().run("scenarios/storage_mapper_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_get_at_address_go() {
+    world().run("scenarios/storage_mapper_get_at_address.scen.json");
+}
+
 #[test]
 fn storage_mapper_linked_list_go() {
     world().run("scenarios/storage_mapper_linked_list.scen.json");
@@ -360,6 +381,11 @@ fn storage_mapper_non_fungible_token_go() {
     world().run("scenarios/storage_mapper_non_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_paginated_go() {
+    world().run("scenarios/storage_mapper_paginated.scen.json");
+}
+
 #[test]
 fn storage_mapper_queue_go() {
     world().run("scenarios/storage_mapper_queue.scen.json");
@@ -266,6 +266,16 @@ fn managed_vec_biguint_push_rs() {
     world().run("scenarios/managed_vec_biguint_push.scen.json");
 }
 
+#[test]
+fn managed_vec_release_rs() {
+    world().run("scenarios/managed_vec_release.scen.json");
+}
+
+#[test]
+fn managed_vec_release_shared_rs() {
+    world().run("scenarios/managed_vec_release_shared.scen.json");
+}
+
 #[test]
 fn only_owner_rs() {
     world().run("scenarios/only_owner.scen.json");
@@ -313,6 +323,11 @@ fn storage_bool_rs() {
     world().run("scenarios/storage_bool.scen.json");
 }
 
+#[test]
+fn storage_cache_rs() {
+    world().run("scenarios/storage_cache.scen.json");
+}
+
 #[test]
 fn storage_clear_rs() {
     world().run("scenarios/storage_clear.scen.json");
@@ -359,6 +374,11 @@ fn storage_mapper_fungible_token_rs() {
     world().run("scenarios/storage_mapper_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_get_at_address_rs() {
+    world().run("scenarios/storage_mapper_get_at_address.scen.json");
+}
+
 #[test]
 fn storage_mapper_linked_list_rs() {
     world().run("scenarios/storage_mapper_linked_list.scen.json");
@@ -380,6 +400,11 @@ fn storage_mapper_non_fungible_token_rs() {
     world().run("scenarios/storage_mapper_non_fungible_token.scen.json");
 }
 
+#[test]
+fn storage_mapper_paginated_rs() {
+    world().run("scenarios/storage_mapper_paginated.scen.json");
+}
+
 #[test]
 fn storage_mapper_queue_rs() {
     world().run("scenarios/storage_mapper_queue.scen.json");
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          340
+// Endpoints:                          370
 // Async Callback:                       1
-// Total number of exported functions: 342
+// Total number of exported functions: 372
 
 #![no_std]
 #![allow(internal_features)]
@@ -218,9 +218,17 @@ multiversx_sc_wasm_adapter::endpoints! {
         managed_vec_contains => managed_vec_contains
         managed_vec_array_push => managed_vec_array_push
         managed_ref_explicit => managed_ref_explicit
+        managed_vec_sum_of_squares => managed_vec_sum_of_squares
+        managed_vec_sum_of_squares_released => managed_vec_sum_of_squares_released
+        managed_vec_release_shared_item => managed_vec_release_shared_item
         storage_read_raw => storage_read_raw
         storage_write_raw => storage_write_raw
         storage_read_from_address => storage_read_from_address
+        storage_cache_increment => storage_cache_increment
+        storage_cache_clear_then_check_empty => storage_cache_clear_then_check_empty
+        storage_cache_increment_then_sync_call_self => storage_cache_increment_then_sync_call_self
+        storage_cache_set_then_async_call_self => storage_cache_set_then_async_call_self
+        cache_counter => cache_counter
         load_bytes => load_bytes
         load_big_uint => load_big_uint
         load_big_int => load_big_int
@@ -277,6 +285,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         map_mapper => map_mapper
         map_mapper_keys => map_mapper_keys
         map_mapper_values => map_mapper_values
+        map_mapper_page => map_mapper_page
         map_mapper_insert => map_mapper_insert
         map_mapper_contains_key => map_mapper_contains_key
         map_mapper_get => map_mapper_get
@@ -300,6 +309,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         set_mapper_insert => set_mapper_insert
         set_mapper_contains => set_mapper_contains
         set_mapper_remove => set_mapper_remove
+        set_mapper_page => set_mapper_page
         map_my_single_value_mapper => map_my_single_value_mapper
         my_single_value_mapper_increment_1 => my_single_value_mapper_increment_1
         my_single_value_mapper_increment_2 => my_single_value_mapper_increment_2
@@ -316,6 +326,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         vec_mapper_get_at_address => vec_mapper_get_at_address
         vec_mapper_len => vec_mapper_len
         vec_mapper_len_at_address => vec_mapper_len_at_address
+        vec_mapper_page => vec_mapper_page
         token_attributes_set => token_attributes_set
         token_attributes_update => token_attributes_update
         token_attributes_get_attributes => token_attributes_get_attributes
@@ -354,6 +365,25 @@ multiversx_sc_wasm_adapter::endpoints! {
         unique_id_mapper_swap_remove => unique_id_mapper_swap_remove
         unique_id_mapper_set => unique_id_mapper_set
         unique_id_mapper => unique_id_mapper
+        user_mapper => user_mapper
+        user_mapper_get_or_create_user => user_mapper_get_or_create_user
+        bi_di_mapper => bi_di_mapper
+        bi_di_mapper_insert => bi_di_mapper_insert
+        bi_di_mapper_remove_by_id => bi_di_mapper_remove_by_id
+        set_mapper_is_empty_at_address => set_mapper_is_empty_at_address
+        set_mapper_contains_at_address => set_mapper_contains_at_address
+        set_mapper_len_at_address => set_mapper_len_at_address
+        map_mapper_get_at_address => map_mapper_get_at_address
+        map_mapper_keys_at_address => map_mapper_keys_at_address
+        queue_mapper_at_address => queue_mapper_at_address
+        list_mapper_at_address => list_mapper_at_address
+        list_mapper_front_at_address => list_mapper_front_at_address
+        user_mapper_get_user_id_at_address => user_mapper_get_user_id_at_address
+        user_mapper_get_user_address_at_address => user_mapper_get_user_address_at_address
+        user_mapper_count_at_address => user_mapper_count_at_address
+        bi_di_mapper_get_value_at_address => bi_di_mapper_get_value_at_address
+        bi_di_mapper_get_id_at_address => bi_di_mapper_get_id_at_address
+        bi_di_mapper_len_at_address => bi_di_mapper_len_at_address
         managed_struct_eq => managed_struct_eq
         token_identifier_egld => token_identifier_egld
         token_identifier_is_valid_1 => token_identifier_is_valid_1
@@ -15,6 +15,9 @@ pub trait CallPromisesModule {
     #[proxy]
     fn vault_proxy(&self) -> vault::Proxy<Self::Api>;
 
+    #[proxy]
+    fn promises_features_proxy(&self) -> crate::Proxy<Self::Api>;
+
     #[endpoint]
     #[payable("*")]
     fn forward_promise_accept_funds(&self, to: ManagedAddress) {
@@ -48,6 +51,101 @@ pub trait CallPromisesModule {
             .register_promise()
     }
 
+    /// Registers one promise for each of the amounts, all in the same call.
+    ///
+    /// Only the promises that the vault can no longer cover fail.
+    #[endpoint]
+    fn forward_promises_retrieve_funds_multi(
+        &self,
+        to: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amounts: MultiValueEncoded<BigUint>,
+    ) {
+        let gas_limit = self.blockchain().get_gas_left() / (amounts.len() as u64 + 1);
+        for amount in amounts {
+            self.vault_proxy()
+                .contract(to.clone())
+                .retrieve_funds(token.clone(), token_nonce, amount)
+                .with_gas_limit(gas_limit)
+                .async_call_promise()
+                .with_callback(self.callbacks().retrieve_funds_callback())
+                .with_extra_gas_for_callback(10_000_000)
+                .register_promise();
+        }
+    }
+
+    /// Retrieves `amount` from the vault, then `second_amount` from the callback,
+    /// so the second promise is nested under the callback of the first.
+    #[endpoint]
+    fn forward_promise_retrieve_funds_twice(
+        &self,
+        to: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+        second_amount: BigUint,
+    ) {
+        let gas_limit = self.blockchain().get_gas_left() / 2;
+        self.vault_proxy()
+            .contract(to.clone())
+            .retrieve_funds(token.clone(), token_nonce, amount)
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .with_callback(self.callbacks().retrieve_funds_again_callback(
+                to,
+                token,
+                token_nonce,
+                second_amount,
+            ))
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise()
+    }
+
+    /// Same as `forward_promise_retrieve_funds`, but the callback fails,
+    /// so neither its storage changes nor the promise it registers are kept.
+    #[endpoint]
+    fn forward_promise_retrieve_funds_failing_callback(
+        &self,
+        to: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+    ) {
+        let gas_limit = self.blockchain().get_gas_left() / 2;
+        self.vault_proxy()
+            .contract(to.clone())
+            .retrieve_funds(token.clone(), token_nonce, amount.clone())
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .with_callback(
+                self.callbacks()
+                    .failing_callback(to, token, token_nonce, amount),
+            )
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise()
+    }
+
+    /// Has another promises-features contract retrieve the amounts from the vault,
+    /// so that its promises are nested under the one registered here.
+    #[endpoint]
+    fn forward_promise_retrieve_funds_multi_nested(
+        &self,
+        forwarder: ManagedAddress,
+        vault: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amounts: MultiValueEncoded<BigUint>,
+    ) {
+        let gas_limit = self.blockchain().get_gas_left() / 2;
+        self.promises_features_proxy()
+            .contract(forwarder)
+            .forward_promises_retrieve_funds_multi(vault, token, token_nonce, amounts)
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .register_promise()
+    }
+
     #[promises_callback]
     fn retrieve_funds_callback(&self) {
         let (token, nonce, payment) = self.call_value().egld_or_single_esdt().into_tuple();
@@ -62,6 +160,52 @@ pub trait CallPromisesModule {
         });
     }
 
+    #[promises_callback]
+    fn retrieve_funds_again_callback(
+        &self,
+        to: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+    ) {
+        let gas_limit = self.blockchain().get_gas_left() / 2;
+        self.vault_proxy()
+            .contract(to)
+            .retrieve_funds(token, token_nonce, amount)
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .with_callback(self.callbacks().retrieve_funds_callback())
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise()
+    }
+
+    #[promises_callback]
+    fn failing_callback(
+        &self,
+        to: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+    ) {
+        let _ = self.callback_data().push(&CallbackData {
+            callback_name: ManagedBuffer::from(b"failing_callback"),
+            token_identifier: token.clone(),
+            token_nonce,
+            token_amount: amount.clone(),
+            args: ManagedVec::new(),
+        });
+
+        let gas_limit = self.blockchain().get_gas_left() / 2;
+        self.vault_proxy()
+            .contract(to)
+            .retrieve_funds(token, token_nonce, amount)
+            .with_gas_limit(gas_limit)
+            .async_call_promise()
+            .register_promise();
+
+        sc_panic!("callback failed");
+    }
+
     #[event("retrieve_funds_callback")]
     fn retrieve_funds_callback_event(
         &self,
@@ -1,8 +1,8 @@
 #![no_std]
 #![allow(clippy::type_complexity)]
 
-mod call_promise_direct;
-mod call_promises;
+pub mod call_promise_direct;
+pub mod call_promises;
 
 multiversx_sc::imports!();
 
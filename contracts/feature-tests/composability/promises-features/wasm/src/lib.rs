@@ -5,10 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            7
+// Endpoints:                           11
 // Async Callback (empty):               1
-// Promise callbacks:                    2
-// Total number of exported functions:  11
+// Promise callbacks:                    4
+// Total number of exported functions:  17
 
 #![no_std]
 #![allow(internal_features)]
@@ -23,12 +23,18 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         forward_promise_accept_funds => forward_promise_accept_funds
         forward_promise_retrieve_funds => forward_promise_retrieve_funds
+        forward_promises_retrieve_funds_multi => forward_promises_retrieve_funds_multi
+        forward_promise_retrieve_funds_twice => forward_promise_retrieve_funds_twice
+        forward_promise_retrieve_funds_failing_callback => forward_promise_retrieve_funds_failing_callback
+        forward_promise_retrieve_funds_multi_nested => forward_promise_retrieve_funds_multi_nested
         callback_data => callback_data
         callback_data_at_index => callback_data_at_index
         clear_callback_data => clear_callback_data
         promise_raw_single_token => promise_raw_single_token
         promise_raw_multi_transfer => promise_raw_multi_transfer
         retrieve_funds_callback => retrieve_funds_callback
+        retrieve_funds_again_callback => retrieve_funds_again_callback
+        failing_callback => failing_callback
         the_one_callback => the_one_callback
     )
 }
@@ -0,0 +1,254 @@
+use multiversx_sc::types::{BigUint, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_chain_vm::tx_mock::AsyncCallKind,
+    scenario_model::{Account, CheckAccount, CheckStateStep, ScCallStep, SetStateStep},
+    ContractInfo, ScenarioWorld,
+};
+use promises_features::call_promises::ProxyTrait as _;
+
+const USER_ADDRESS_EXPR: &str = "address:user";
+const FORWARDER_ADDRESS_EXPR: &str = "sc:forwarder";
+const NESTED_FORWARDER_ADDRESS_EXPR: &str = "sc:nested-forwarder";
+const FORWARDER_PATH_EXPR: &str = "file:promises-features/output/promises-features.wasm";
+const VAULT_ADDRESS_EXPR: &str = "sc:vault";
+const VAULT_PATH_EXPR: &str = "file:vault/output/vault.wasm";
+
+type PromisesFeaturesContract = ContractInfo<promises_features::Proxy<StaticApi>>;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(FORWARDER_PATH_EXPR, promises_features::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+    blockchain
+}
+
+/// Two forwarders, and a vault holding 1000 EGLD.
+fn setup(world: &mut ScenarioWorld) {
+    let forwarder_code = world.code_expression(FORWARDER_PATH_EXPR);
+    let vault_code = world.code_expression(VAULT_PATH_EXPR);
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                FORWARDER_ADDRESS_EXPR,
+                Account::new().code(forwarder_code.clone()),
+            )
+            .put_account(
+                NESTED_FORWARDER_ADDRESS_EXPR,
+                Account::new().code(forwarder_code),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                Account::new().balance("1000").code(vault_code),
+            ),
+    );
+}
+
+/// The vault only has enough for the first 2 of them.
+fn amounts() -> MultiValueEncoded<StaticApi, BigUint<StaticApi>> {
+    let mut amounts = MultiValueEncoded::new();
+    for amount in [400u64, 500, 300] {
+        amounts.push(BigUint::from(amount));
+    }
+    amounts
+}
+
+#[test]
+fn promises_multi_call_tree_test() {
+    let mut world = world();
+    let mut forwarder = PromisesFeaturesContract::new(FORWARDER_ADDRESS_EXPR);
+    setup(&mut world);
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .gas_limit(60_000_000u64)
+            .call(forwarder.forward_promises_retrieve_funds_multi(
+                VAULT_ADDRESS_EXPR,
+                EgldOrEsdtTokenIdentifier::egld(),
+                0u64,
+                amounts(),
+            )),
+    );
+
+    let call_tree = world.last_tx_async_call_tree().to_vec();
+    assert_eq!(call_tree.len(), 3);
+    for node in &call_tree {
+        assert_eq!(node.kind, AsyncCallKind::Promise);
+        assert_eq!(node.call.endpoint_name.as_str(), "retrieve_funds");
+        assert_eq!(node.gas_limit, 15_000_000);
+        assert!(node.children.is_empty());
+
+        let callback = node.callback.as_ref().unwrap();
+        assert_eq!(callback.func_name.as_str(), "retrieve_funds_callback");
+        assert_eq!(callback.gas_limit, 25_000_000);
+        assert!(callback.is_success());
+    }
+    assert!(call_tree[0].is_success());
+    assert!(call_tree[1].is_success());
+    assert!(!call_tree[2].is_success());
+
+    // the failed promise was rolled back, the others were not
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("900"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("100")),
+    );
+}
+
+#[test]
+fn promises_call_tree_promise_from_callback_test() {
+    let mut world = world();
+    let mut forwarder = PromisesFeaturesContract::new(FORWARDER_ADDRESS_EXPR);
+    setup(&mut world);
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .gas_limit(60_000_000u64)
+            .call(forwarder.forward_promise_retrieve_funds_twice(
+                VAULT_ADDRESS_EXPR,
+                EgldOrEsdtTokenIdentifier::egld(),
+                0u64,
+                BigUint::from(400u64),
+                BigUint::from(500u64),
+            )),
+    );
+
+    let call_tree = world.last_tx_async_call_tree().to_vec();
+    assert_eq!(call_tree.len(), 1);
+    let node = &call_tree[0];
+    assert_eq!(node.num_calls(), 2);
+    assert_eq!(node.call.endpoint_name.as_str(), "retrieve_funds");
+    assert_eq!(node.gas_limit, 30_000_000);
+    assert!(node.is_success());
+    assert!(node.children.is_empty());
+
+    let callback = node.callback.as_ref().unwrap();
+    assert_eq!(callback.func_name.as_str(), "retrieve_funds_again_callback");
+    assert_eq!(callback.gas_limit, 40_000_000);
+    assert!(callback.is_success());
+
+    // the promise registered by the callback runs right after it
+    assert_eq!(callback.children.len(), 1);
+    let nested_node = &callback.children[0];
+    assert_eq!(nested_node.kind, AsyncCallKind::Promise);
+    assert_eq!(nested_node.call.endpoint_name.as_str(), "retrieve_funds");
+    assert_eq!(nested_node.gas_limit, 20_000_000);
+    assert!(nested_node.is_success());
+
+    let nested_callback = nested_node.callback.as_ref().unwrap();
+    assert_eq!(
+        nested_callback.func_name.as_str(),
+        "retrieve_funds_callback"
+    );
+    assert_eq!(nested_callback.gas_limit, 30_000_000);
+    assert!(nested_callback.is_success());
+
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("900"))
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("100")),
+    );
+}
+
+#[test]
+fn promises_call_tree_failed_callback_test() {
+    let mut world = world();
+    let mut forwarder = PromisesFeaturesContract::new(FORWARDER_ADDRESS_EXPR);
+    setup(&mut world);
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .gas_limit(60_000_000u64)
+            .call(forwarder.forward_promise_retrieve_funds_failing_callback(
+                VAULT_ADDRESS_EXPR,
+                EgldOrEsdtTokenIdentifier::egld(),
+                0u64,
+                BigUint::from(400u64),
+            )),
+    );
+
+    let call_tree = world.last_tx_async_call_tree().to_vec();
+    assert_eq!(call_tree.len(), 1);
+    let node = &call_tree[0];
+    assert_eq!(node.num_calls(), 1);
+    assert!(node.is_success());
+
+    let callback = node.callback.as_ref().unwrap();
+    assert_eq!(callback.func_name.as_str(), "failing_callback");
+    assert!(!callback.is_success());
+    assert_eq!(callback.result_message, "callback failed");
+    // the promise registered before failing was dropped
+    assert!(callback.children.is_empty());
+
+    // the promise itself is kept, but nothing the callback did
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(
+                FORWARDER_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .balance("400")
+                    .check_storage("str:callback_data.len", ""),
+            )
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("600")),
+    );
+}
+
+#[test]
+fn promises_call_tree_nested_rollback_test() {
+    let mut world = world();
+    let mut forwarder = PromisesFeaturesContract::new(FORWARDER_ADDRESS_EXPR);
+    setup(&mut world);
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .gas_limit(100_000_000u64)
+            .call(forwarder.forward_promise_retrieve_funds_multi_nested(
+                NESTED_FORWARDER_ADDRESS_EXPR,
+                VAULT_ADDRESS_EXPR,
+                EgldOrEsdtTokenIdentifier::egld(),
+                0u64,
+                amounts(),
+            )),
+    );
+
+    let call_tree = world.last_tx_async_call_tree().to_vec();
+    assert_eq!(call_tree.len(), 1);
+    let node = &call_tree[0];
+    assert_eq!(node.num_calls(), 4);
+    assert_eq!(
+        node.call.endpoint_name.as_str(),
+        "forward_promises_retrieve_funds_multi"
+    );
+    assert_eq!(node.gas_limit, 50_000_000);
+    assert!(node.is_success());
+    assert!(node.callback.is_none());
+
+    // the promises of the nested forwarder
+    assert_eq!(node.children.len(), 3);
+    for nested_node in &node.children {
+        assert_eq!(nested_node.call.endpoint_name.as_str(), "retrieve_funds");
+        assert_eq!(nested_node.gas_limit, 12_500_000);
+        assert!(nested_node.callback.as_ref().unwrap().is_success());
+    }
+    assert!(node.children[0].is_success());
+    assert!(node.children[1].is_success());
+    assert!(!node.children[2].is_success());
+
+    // only the failed branch was rolled back, the nested forwarder got the rest of the funds
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+            .put_account(
+                NESTED_FORWARDER_ADDRESS_EXPR,
+                CheckAccount::new().balance("900"),
+            )
+            .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("100")),
+    );
+}
@@ -107,4 +107,13 @@ pub trait FormattedMessageFeatures {
         let message = sc_format!("ManagedBuffer hex: {:x}", x);
         message
     }
+
+    #[endpoint]
+    fn format_message_managed_decimal(
+        &self,
+        x: ManagedDecimal<Self::Api, ConstDecimals<2>>,
+    ) -> ManagedBuffer {
+        let message = sc_format!("ManagedDecimal: {}", x);
+        message
+    }
 }
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           16
+// Endpoints:                           17
 // Async Callback (empty):               1
-// Total number of exported functions:  18
+// Total number of exported functions:  19
 
 #![no_std]
 #![allow(internal_features)]
@@ -36,6 +36,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         format_message_i64 => format_message_i64
         format_message_managed_buffer => format_message_managed_buffer
         format_message_managed_buffer_hex => format_message_managed_buffer_hex
+        format_message_managed_decimal => format_message_managed_decimal
     )
 }
 
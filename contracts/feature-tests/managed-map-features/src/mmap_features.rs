@@ -25,6 +25,15 @@ pub trait ManagedMapFeatures {
         map
     }
 
+    fn create_iterable_map(&self) -> IterableManagedMap {
+        let mut map = IterableManagedMap::new();
+        let num_entries = self.get_num_entries();
+        for index in 0..num_entries {
+            map.put(&self.get_key(index), &self.get_value(index));
+        }
+        map
+    }
+
     #[view]
     fn mm_get(&self, key: &ManagedBuffer) -> ManagedBuffer {
         self.create_map().get(key)
@@ -46,4 +55,44 @@ pub trait ManagedMapFeatures {
         let get_value = map.get(get_key);
         (removed_value, get_value).into()
     }
+
+    #[view]
+    fn mm_len(&self) -> usize {
+        self.create_iterable_map().len()
+    }
+
+    #[view]
+    fn mm_keys(&self) -> MultiValueEncoded<ManagedBuffer> {
+        self.create_iterable_map().keys().clone().into()
+    }
+
+    #[view]
+    fn mm_entries(&self) -> MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>> {
+        let mut result = MultiValueEncoded::new();
+        for (key, value) in &self.create_iterable_map() {
+            result.push((key, value).into());
+        }
+        result
+    }
+
+    #[view]
+    fn mm_clear_len(&self) -> usize {
+        let mut map = self.create_iterable_map();
+        map.clear();
+        map.len()
+    }
+
+    #[view]
+    fn mm_from_pairs_get(
+        &self,
+        get_key: &ManagedBuffer,
+        pairs: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>>,
+    ) -> MultiValue2<usize, ManagedBuffer> {
+        let mut entries = ManagedVec::new();
+        for pair in pairs {
+            entries.push(ManagedMapEntry::from(pair.into_tuple()));
+        }
+        let map = IterableManagedMap::from_pairs(&entries);
+        (map.len(), map.get(get_key)).into()
+    }
 }
@@ -10,6 +10,12 @@ fn mmap_get_go() {
     world().run("scenarios/mmap_get.scen.json");
 }
 
+#[test]
+#[ignore]
+fn mmap_iter_go() {
+    world().run("scenarios/mmap_iter.scen.json");
+}
+
 #[test]
 #[ignore]
 fn mmap_remove_go() {
@@ -15,6 +15,11 @@ fn mmap_get_rs() {
     world().run("scenarios/mmap_get.scen.json");
 }
 
+#[test]
+fn mmap_iter_rs() {
+    world().run("scenarios/mmap_iter.scen.json");
+}
+
 #[test]
 fn mmap_remove_rs() {
     world().run("scenarios/mmap_remove.scen.json");
@@ -0,0 +1,70 @@
+use multiversx_sc::{
+    codec::{top_encode_to_vec_u8_or_panic, NestedDecode, NestedEncode, TopDecode},
+    types::{IterableManagedMap, ManagedBuffer, ManagedMapEntry, ManagedVec},
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn buffer(bytes: &[u8]) -> ManagedBuffer<StaticApi> {
+    ManagedBuffer::new_from_bytes(bytes)
+}
+
+fn map_from(pairs: &[(&[u8], &[u8])]) -> IterableManagedMap<StaticApi> {
+    let mut entries = ManagedVec::new();
+    for (key, value) in pairs {
+        entries.push(ManagedMapEntry::new(buffer(key), buffer(value)));
+    }
+    IterableManagedMap::from_pairs(&entries)
+}
+
+#[test]
+fn iterable_managed_map_iteration_order_test() {
+    let mut map = map_from(&[(b"c", b"3"), (b"a", b"1"), (b"b", b"2")]);
+    assert_eq!(map.len(), 3);
+
+    map.remove(&buffer(b"a"));
+    map.put(&buffer(b"a"), &buffer(b"4"));
+    map.put(&buffer(b"c"), &buffer(b"5"));
+
+    let entries: Vec<(Vec<u8>, Vec<u8>)> = map
+        .iter()
+        .map(|(key, value)| (key.to_vec(), value.to_vec()))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (b"c".to_vec(), b"5".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+            (b"a".to_vec(), b"4".to_vec()),
+        ]
+    );
+
+    map.clear();
+    assert!(map.is_empty());
+    assert!(!map.contains(&buffer(b"b")));
+
+    map.put(&buffer(b"b"), &buffer(b"6"));
+    map.put(&buffer(b"a"), &buffer(b""));
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&buffer(b"b")).to_vec(), b"6".to_vec());
+}
+
+#[test]
+fn iterable_managed_map_codec_test() {
+    let map = map_from(&[(b"a", b"1"), (b"bc", b"2")]);
+    let pairs_encoded = [
+        0, 0, 0, 1, b'a', 0, 0, 0, 1, b'1', 0, 0, 0, 2, b'b', b'c', 0, 0, 0, 1, b'2',
+    ];
+
+    let top_encoded = top_encode_to_vec_u8_or_panic(&map);
+    assert_eq!(top_encoded.as_slice(), &pairs_encoded[..]);
+    let top_decoded = IterableManagedMap::<StaticApi>::top_decode(top_encoded).unwrap();
+    assert_eq!(top_decoded.to_pairs(), map.to_pairs());
+
+    let mut dep_encoded = Vec::new();
+    map.dep_encode(&mut dep_encoded).unwrap();
+    assert_eq!(&dep_encoded[..4], &[0, 0, 0, 2]);
+    assert_eq!(&dep_encoded[4..], &pairs_encoded[..]);
+    let dep_decoded =
+        IterableManagedMap::<StaticApi>::dep_decode(&mut dep_encoded.as_slice()).unwrap();
+    assert_eq!(dep_decoded.to_pairs(), map.to_pairs());
+}
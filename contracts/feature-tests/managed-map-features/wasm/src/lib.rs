@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            3
+// Endpoints:                            8
 // Async Callback (empty):               1
-// Total number of exported functions:   5
+// Total number of exported functions:  10
 
 #![no_std]
 #![allow(internal_features)]
@@ -23,6 +23,11 @@ multiversx_sc_wasm_adapter::endpoints! {
         mm_get => mm_get
         mm_contains => mm_contains
         mm_remove_get => mm_remove_get
+        mm_len => mm_len
+        mm_keys => mm_keys
+        mm_entries => mm_entries
+        mm_clear_len => mm_clear_len
+        mm_from_pairs_get => mm_from_pairs_get
     )
 }
 
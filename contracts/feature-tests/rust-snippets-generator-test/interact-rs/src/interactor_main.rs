@@ -24,6 +24,7 @@ const SYSTEM_SC_BECH32: &str = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqq
 const DEFAULT_ADDRESS_EXPR: &str =
     "0x0000000000000000000000000000000000000000000000000000000000000000";
 const TOKEN_ISSUE_COST: u64 = 50_000_000_000_000_000;
+const PAGE_LIMIT: usize = 100;
 
 type ContractType = ContractInfo<rust_snippets_generator_test::Proxy<StaticApi>>;
 
@@ -53,6 +54,7 @@ async fn main() {
         "multi_value_2" => state.multi_value_2().await,
         "multi_value_4" => state.multi_value_4().await,
         "complex_multi_values" => state.complex_multi_values().await,
+        "paginated_view" => state.paginated_view().await,
         _ => panic!("unknown command: {}", &cmd),
     }
 }
@@ -398,4 +400,15 @@ impl State {
         let result = response.result.unwrap();
         println!("Result: {result:?}");
     }
+
+    async fn paginated_view(&mut self) {
+        let _arg = 0u64;
+
+        let result_value: Vec<BigUint<StaticApi>> = self
+            .interactor
+            .quick_query_all_pages(PAGE_LIMIT, |cursor, limit| {
+                self.contract.paginated_view(_arg.clone(), cursor, limit)
+            })
+            .await;
+    }
 }
@@ -117,4 +117,10 @@ pub trait PayableFeatures {
     ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>> {
         arg
     }
+
+    #[view]
+    #[paginated]
+    fn paginated_view(&self, _arg: u64, _cursor: usize, _limit: usize) -> Page<Self::Api, BigUint> {
+        Page::new(MultiValueEncoded::new(), None)
+    }
 }
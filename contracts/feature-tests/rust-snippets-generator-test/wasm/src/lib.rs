@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           16
+// Endpoints:                           17
 // Async Callback (empty):               1
-// Total number of exported functions:  18
+// Total number of exported functions:  19
 
 #![no_std]
 #![allow(internal_features)]
@@ -36,6 +36,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         multi_value_2 => multi_value_2
         multi_value_4 => multi_value_4
         complex_multi_values => complex_multi_values
+        paginated_view => paginated_view
     )
 }
 
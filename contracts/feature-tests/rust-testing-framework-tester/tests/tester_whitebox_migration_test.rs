@@ -0,0 +1,352 @@
+//! Migration guide from the legacy `BlockchainStateWrapper` to `ScenarioWorld`.
+//!
+//! Every scenario is written twice, once with each framework, and the two versions should be kept in sync.
+
+#![allow(deprecated)]
+
+use multiversx_sc::{codec::top_encode_to_vec_u8_or_panic, types::EsdtLocalRole};
+use multiversx_sc_scenario::{
+    managed_biguint, managed_token_id, rust_biguint, scenario_model::*, testing_framework::*, *,
+};
+use rust_testing_framework_tester::*;
+
+const SC_WASM_PATH: &str = "output/rust-testing-framework-tester.wasm";
+const SC_WASM_PATH_EXPR: &str = "file:output/rust-testing-framework-tester.wasm";
+
+const USER_ADDRESS_EXPR: &str = "address:user";
+const SC_ADDRESS_EXPR: &str = "sc:tester";
+
+const TRACE_PATH: &str = "scenarios/trace-world-state.scen.json";
+
+const NFT_TOKEN_ID: &[u8] = b"NFT-123456";
+const NFT_NONCE: u64 = 5;
+const FIRST_ROYALTIES: u64 = 1_000;
+const SECOND_ROYALTIES: u64 = 5_000;
+
+fn nft_attributes() -> NftDummyAttributes {
+    NftDummyAttributes {
+        creation_epoch: 666,
+        cool_factor: 101,
+    }
+}
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/feature-tests/rust-testing-framework-tester");
+    world.register_contract(
+        SC_WASM_PATH_EXPR,
+        rust_testing_framework_tester::ContractBuilder,
+    );
+
+    let code = world.code_expression(SC_WASM_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new())
+            .put_account(SC_ADDRESS_EXPR, Account::new().code(code)),
+    );
+    world
+}
+
+#[test]
+fn nft_balance_legacy() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user = wrapper.create_user_account(&rust_biguint!(0));
+
+    wrapper.set_nft_balance(
+        &user,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+        &rust_biguint!(1_000),
+        &nft_attributes(),
+    );
+    wrapper.check_nft_balance(
+        &user,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+        &rust_biguint!(1_000),
+        Some(&nft_attributes()),
+    );
+
+    let attributes =
+        wrapper.get_nft_attributes::<NftDummyAttributes>(&user, NFT_TOKEN_ID, NFT_NONCE);
+    assert_eq!(attributes, Some(nft_attributes()));
+    wrapper.dump_state_for_account::<NftDummyAttributes>(&user);
+}
+
+#[test]
+fn nft_balance_world() {
+    let mut world = world();
+
+    world
+        .set_nft_balance(
+            USER_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            NFT_NONCE,
+            1_000u64,
+            &nft_attributes(),
+        )
+        .check_nft_balance(
+            USER_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            NFT_NONCE,
+            1_000u64,
+            Some(&nft_attributes()),
+        );
+
+    let attributes = world.get_nft_attributes::<NftDummyAttributes, _>(
+        USER_ADDRESS_EXPR,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+    );
+    assert_eq!(attributes, Some(nft_attributes()));
+    world.dump_state_for_account::<NftDummyAttributes, _>(USER_ADDRESS_EXPR);
+}
+
+#[test]
+#[should_panic]
+fn nft_zero_balance_legacy() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user = wrapper.create_user_account(&rust_biguint!(0));
+
+    wrapper.check_nft_balance(
+        &user,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+        &rust_biguint!(1_000),
+        Some(&nft_attributes()),
+    );
+}
+
+#[test]
+#[should_panic]
+fn nft_zero_balance_world() {
+    let mut world = world();
+
+    world.check_nft_balance(
+        USER_ADDRESS_EXPR,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+        1_000u64,
+        Some(&nft_attributes()),
+    );
+}
+
+#[test]
+fn over_set_nft_legacy() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user = wrapper.create_user_account(&rust_biguint!(0));
+
+    for royalties in [FIRST_ROYALTIES, SECOND_ROYALTIES] {
+        wrapper.set_nft_balance_all_properties(
+            &user,
+            NFT_TOKEN_ID,
+            NFT_NONCE,
+            &rust_biguint!(1),
+            &nft_attributes(),
+            royalties,
+            None,
+            None,
+            None,
+            &[b"uri".to_vec()],
+        );
+    }
+
+    wrapper.check_nft_balance(
+        &user,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+        &rust_biguint!(1),
+        Some(&nft_attributes()),
+    );
+}
+
+#[test]
+fn over_set_nft_world() {
+    let mut world = world();
+
+    for royalties in [FIRST_ROYALTIES, SECOND_ROYALTIES] {
+        world.set_nft_balance_all_properties(
+            USER_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            NFT_NONCE,
+            1u64,
+            &nft_attributes(),
+            royalties,
+            None,
+            None,
+            None,
+            &[b"uri".to_vec()],
+        );
+    }
+
+    world.check_nft_balance(
+        USER_ADDRESS_EXPR,
+        NFT_TOKEN_ID,
+        NFT_NONCE,
+        1u64,
+        Some(&nft_attributes()),
+    );
+}
+
+#[test]
+fn local_roles_legacy() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper.set_esdt_local_roles(
+        sc_wrapper.address_ref(),
+        NFT_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity],
+    );
+    wrapper
+        .execute_tx(&user, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let nonce = sc.create_nft(
+                managed_token_id!(NFT_TOKEN_ID),
+                managed_biguint!(100),
+                nft_attributes(),
+            );
+            sc.mint_esdt(managed_token_id!(NFT_TOKEN_ID), nonce, managed_biguint!(50));
+        })
+        .assert_ok();
+
+    wrapper.check_nft_balance(
+        sc_wrapper.address_ref(),
+        NFT_TOKEN_ID,
+        1,
+        &rust_biguint!(150),
+        Some(&nft_attributes()),
+    );
+}
+
+#[test]
+fn local_roles_world() {
+    let mut world = world();
+    let tester_whitebox =
+        WhiteboxContract::new(SC_ADDRESS_EXPR, rust_testing_framework_tester::contract_obj);
+
+    world
+        .set_esdt_local_roles(
+            SC_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity],
+        )
+        .whitebox_call(
+            &tester_whitebox,
+            ScCallStep::new().from(USER_ADDRESS_EXPR),
+            |sc| {
+                let nonce = sc.create_nft(
+                    managed_token_id!(NFT_TOKEN_ID),
+                    managed_biguint!(100),
+                    nft_attributes(),
+                );
+                sc.mint_esdt(managed_token_id!(NFT_TOKEN_ID), nonce, managed_biguint!(50));
+            },
+        )
+        .check_nft_balance(
+            SC_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            1,
+            150u64,
+            Some(&nft_attributes()),
+        );
+}
+
+#[test]
+fn block_info_legacy() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper.set_block_epoch(10);
+    wrapper.set_block_nonce(20);
+    wrapper.set_block_timestamp(30);
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(sc.get_block_epoch(), 10);
+            assert_eq!(sc.get_block_nonce(), 20);
+            assert_eq!(sc.get_block_timestamp(), 30);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn block_info_world() {
+    let mut world = world();
+    let tester_whitebox =
+        WhiteboxContract::new(SC_ADDRESS_EXPR, rust_testing_framework_tester::contract_obj);
+
+    world
+        .set_block_epoch(10)
+        .set_block_nonce(20)
+        .set_block_timestamp(30)
+        .whitebox_query(&tester_whitebox, |sc| {
+            assert_eq!(sc.get_block_epoch(), 10);
+            assert_eq!(sc.get_block_nonce(), 20);
+            assert_eq!(sc.get_block_timestamp(), 30);
+        });
+}
+
+#[test]
+fn state_setters_recorded_in_trace() {
+    let mut world = ScenarioWorld::new();
+    world
+        .start_trace()
+        .set_state_step(SetStateStep::new().put_account(USER_ADDRESS_EXPR, Account::new()));
+
+    world
+        .set_nft_balance(
+            USER_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            NFT_NONCE,
+            1_000u64,
+            &nft_attributes(),
+        )
+        .check_nft_balance(
+            USER_ADDRESS_EXPR,
+            NFT_TOKEN_ID,
+            NFT_NONCE,
+            1_000u64,
+            Some(&nft_attributes()),
+        )
+        .write_scenario_trace(TRACE_PATH);
+
+    let trace = multiversx_sc_scenario::scenario::parse_scenario(TRACE_PATH);
+    assert_eq!(trace.steps.len(), 3);
+    let user_key = AddressKey::from(USER_ADDRESS_EXPR);
+
+    let Step::SetState(set_nft_step) = &trace.steps[1] else {
+        panic!("expected the NFT balance to be recorded as a setState step");
+    };
+    let Esdt::Full(nft) =
+        &set_nft_step.accounts[&user_key].esdt[&BytesKey::from(NFT_TOKEN_ID.to_vec())]
+    else {
+        panic!("expected the full form of the NFT");
+    };
+    assert_eq!(nft.instances.len(), 1);
+    let instance = &nft.instances[0];
+    assert_eq!(instance.nonce.as_ref().unwrap().value, NFT_NONCE);
+    assert_eq!(
+        instance.balance.as_ref().unwrap().value,
+        rust_biguint!(1_000)
+    );
+    assert_eq!(
+        instance.attributes.as_ref().unwrap().value,
+        top_encode_to_vec_u8_or_panic(&nft_attributes())
+    );
+
+    let Step::CheckState(check_nft_step) = &trace.steps[2] else {
+        panic!("expected the NFT balance check to be recorded as a checkState step");
+    };
+    assert!(check_nft_step.accounts.accounts.contains_key(&user_key));
+}
@@ -0,0 +1,60 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::{
+    ongoing_operation::{CONTINUE_OP, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, STOP_OP},
+    storage_version,
+};
+
+pub const CURRENT_STORAGE_VERSION: u32 = 2;
+pub const SCORE_SCALING_FACTOR: u64 = 100;
+
+/// Example of a module migrating its stored data across storage versions:
+/// - version 0: scores are stored unscaled;
+/// - version 1: scores are stored multiplied by `SCORE_SCALING_FACTOR`;
+/// - version 2: the sum of all scores is also kept in storage.
+#[multiversx_sc::module]
+pub trait StorageVersionModExample: storage_version::StorageVersionModule {
+    /// Resumable, call again until it returns `completed`.
+    /// A contract would normally also call it from `#[init]`, which runs again on upgrade.
+    #[endpoint(migrateScores)]
+    fn migrate_scores(&self) -> OperationCompletionStatus {
+        self.migrate_storage(
+            CURRENT_STORAGE_VERSION,
+            DEFAULT_MIN_GAS_TO_SAVE_PROGRESS,
+            |version, index: &mut usize| {
+                let scores = self.example_scores();
+                if *index == scores.len() {
+                    return STOP_OP;
+                }
+
+                *index += 1;
+                let score = scores.get(*index);
+                match version {
+                    0 => scores.set(*index, &(score * SCORE_SCALING_FACTOR)),
+                    _ => self.example_total_score().update(|total| *total += score),
+                }
+
+                CONTINUE_OP
+            },
+        )
+    }
+
+    #[endpoint(addScore)]
+    fn add_score(&self, score: u64) {
+        self.require_storage_version(CURRENT_STORAGE_VERSION);
+
+        let scaled_score = score * SCORE_SCALING_FACTOR;
+        let mut scores = self.example_scores();
+        scores.push(&scaled_score);
+        self.example_total_score()
+            .update(|total| *total += scaled_score);
+    }
+
+    #[view(getExampleScores)]
+    #[storage_mapper("storageVersionExample:scores")]
+    fn example_scores(&self) -> VecMapper<u64>;
+
+    #[view(getExampleTotalScore)]
+    #[storage_mapper("storageVersionExample:totalScore")]
+    fn example_total_score(&self) -> SingleValueMapper<u64>;
+}
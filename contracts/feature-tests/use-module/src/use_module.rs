@@ -12,6 +12,7 @@ mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+pub mod storage_version_mod_example;
 pub mod token_merge_mod_impl;
 
 multiversx_sc::imports!();
@@ -23,6 +24,9 @@ multiversx_sc::imports!();
 /// - EsdtModule
 /// - GovernanceModule
 /// - PauseModule
+/// - StakingRewardsModule
+/// - StorageVersionModule
+/// - VestingModule
 #[multiversx_sc::contract]
 pub trait UseModule:
     ContractBase
@@ -37,6 +41,7 @@ pub trait UseModule:
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
     + ongoing_operation_mod_example::OngoingOperationModExample
+    + storage_version_mod_example::StorageVersionModExample
     + token_merge_mod_impl::TokenMergeModImpl
     + multiversx_sc_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
     + multiversx_sc_modules::dns::DnsModule
@@ -45,6 +50,7 @@ pub trait UseModule:
     + multiversx_sc_modules::governance::GovernanceModule
     + multiversx_sc_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + multiversx_sc_modules::governance::governance_events::GovernanceEventsModule
+    + multiversx_sc_modules::governance::governance_snapshot::GovernanceSnapshotModule
     + multiversx_sc_modules::pause::PauseModule
     + multiversx_sc_modules::staking::StakingModule
     + multiversx_sc_modules::token_merge::TokenMergeModule
@@ -52,6 +58,11 @@ pub trait UseModule:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::only_admin::OnlyAdminModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+    + multiversx_sc_modules::storage_version::StorageVersionModule
+    + multiversx_sc_modules::vesting::VestingModule
+    + multiversx_sc_modules::vesting::vesting_events::VestingEventsModule
+    + multiversx_sc_modules::staking_rewards::StakingRewardsModule
+    + multiversx_sc_modules::staking_rewards::staking_rewards_events::StakingRewardsEventsModule
 {
     /// Validates that the "featureName" feature is on.
     /// Uses the `feature_guard!` macro.
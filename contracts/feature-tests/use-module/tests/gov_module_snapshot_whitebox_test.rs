@@ -0,0 +1,296 @@
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, ManagedVec, MultiValueEncoded},
+};
+use multiversx_sc_modules::governance::{
+    governance_configurable::GovernanceConfigurablePropertiesModule,
+    governance_proposal::{GovernanceProposalStatus, VoteType},
+    governance_snapshot::GovernanceSnapshotModule,
+    GovernanceModule,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
+    scenario_model::{
+        Account, AddressValue, CheckAccount, CheckStateStep, ScCallStep, SetStateStep,
+    },
+    DebugApi, ScenarioWorld, WhiteboxContract,
+};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const ALICE_ADDRESS_EXPR: &str = "address:alice";
+const BOB_ADDRESS_EXPR: &str = "address:bob";
+const CAROL_ADDRESS_EXPR: &str = "address:carol";
+const DAVE_ADDRESS_EXPR: &str = "address:dave";
+
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const GOV_TOKEN_ID_EXPR: &str = "str:GOV-123456";
+const GOV_TOKEN_ID: &[u8] = b"GOV-123456";
+
+const QUORUM: u64 = 1_500;
+const QUORUM_PERCENTAGE: u64 = 2_500;
+const MIN_BALANCE_PROPOSAL: u64 = 500;
+const VOTING_DELAY_BLOCKS: u64 = 10;
+const VOTING_PERIOD_BLOCKS: u64 = 20;
+const LOCKING_PERIOD_BLOCKS: u64 = 30;
+
+const PROPOSAL_BLOCK: u64 = 11;
+const PROPOSAL_ID: usize = 1;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+fn address_expr_to_address(address_expr: &str) -> Address {
+    AddressValue::from(address_expr).to_address()
+}
+
+fn lock_voting_tokens(
+    world: &mut ScenarioWorld,
+    whitebox: &WhiteboxContract<use_module::ContractObj<DebugApi>>,
+    address_expr: &str,
+    amount: u64,
+) {
+    world.whitebox_call(
+        whitebox,
+        ScCallStep::new()
+            .from(address_expr)
+            .esdt_transfer(GOV_TOKEN_ID, 0, rust_biguint!(amount)),
+        |sc| sc.lock_voting_tokens(),
+    );
+}
+
+fn vote_check_error(
+    world: &mut ScenarioWorld,
+    whitebox: &WhiteboxContract<use_module::ContractObj<DebugApi>>,
+    address_expr: &str,
+    vote: VoteType,
+    err_msg: &str,
+) {
+    world.whitebox_call_check(
+        whitebox,
+        ScCallStep::new().from(address_expr).no_expect(),
+        |sc| sc.vote(PROPOSAL_ID, vote),
+        |r| {
+            r.assert_user_error(err_msg);
+        },
+    );
+}
+
+fn check_proposal_votes(
+    world: &mut ScenarioWorld,
+    whitebox: &WhiteboxContract<use_module::ContractObj<DebugApi>>,
+    up_votes: u64,
+    down_votes: u64,
+) {
+    world.whitebox_query(whitebox, |sc| {
+        let proposal_votes = sc.proposal_votes(PROPOSAL_ID).get();
+        assert_eq!(proposal_votes.up_votes, managed_biguint!(up_votes));
+        assert_eq!(proposal_votes.down_votes, managed_biguint!(down_votes));
+    });
+}
+
+#[test]
+fn gov_module_snapshot_voting_test() {
+    let mut world = world();
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    let mut set_state_step = SetStateStep::new()
+        .put_account(
+            USE_MODULE_ADDRESS_EXPR,
+            Account::new()
+                .nonce(1)
+                .code(use_module_code)
+                .owner(OWNER_ADDRESS_EXPR),
+        )
+        .block_nonce(10);
+    for user in [
+        OWNER_ADDRESS_EXPR,
+        ALICE_ADDRESS_EXPR,
+        BOB_ADDRESS_EXPR,
+        CAROL_ADDRESS_EXPR,
+        DAVE_ADDRESS_EXPR,
+    ] {
+        set_state_step = set_state_step.put_account(
+            user,
+            Account::new()
+                .nonce(1)
+                .esdt_balance(GOV_TOKEN_ID_EXPR, 1_000u64),
+        );
+    }
+    world.set_state_step(set_state_step);
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            sc.init_governance_module(
+                managed_token_id!(GOV_TOKEN_ID),
+                managed_biguint!(QUORUM),
+                managed_biguint!(MIN_BALANCE_PROPOSAL),
+                VOTING_DELAY_BLOCKS,
+                VOTING_PERIOD_BLOCKS,
+                LOCKING_PERIOD_BLOCKS,
+            );
+            sc.init_governance_snapshot_voting(QUORUM_PERCENTAGE);
+        },
+    );
+
+    // carol delegates her voting power to alice
+    lock_voting_tokens(&mut world, &use_module_whitebox, ALICE_ADDRESS_EXPR, 1_000);
+    lock_voting_tokens(&mut world, &use_module_whitebox, BOB_ADDRESS_EXPR, 500);
+    lock_voting_tokens(&mut world, &use_module_whitebox, CAROL_ADDRESS_EXPR, 500);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(CAROL_ADDRESS_EXPR),
+        |sc| {
+            sc.delegate_voting_power(managed_address!(&address_expr_to_address(
+                ALICE_ADDRESS_EXPR
+            )))
+        },
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        let alice = managed_address!(&address_expr_to_address(ALICE_ADDRESS_EXPR));
+        let carol = managed_address!(&address_expr_to_address(CAROL_ADDRESS_EXPR));
+        assert_eq!(sc.get_delegatee(carol.clone()), alice);
+        assert_eq!(sc.get_voting_power(alice), managed_biguint!(1_500));
+        assert_eq!(sc.get_voting_power(carol), managed_biguint!(0));
+    });
+
+    // the snapshot is taken at the end of the proposal block
+    world.set_block_nonce(PROPOSAL_BLOCK);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).esdt_transfer(
+            GOV_TOKEN_ID,
+            0,
+            rust_biguint!(MIN_BALANCE_PROPOSAL),
+        ),
+        |sc| {
+            let mut actions = MultiValueEncoded::new();
+            actions.push(
+                (
+                    1_000_000u64,
+                    managed_address!(&address_expr_to_address(USE_MODULE_ADDRESS_EXPR)),
+                    managed_buffer!(b"changeQuorum"),
+                    ManagedVec::from_single_item(managed_buffer!(&[0x07, 0xd0])),
+                )
+                    .into(),
+            );
+            let proposal_id = sc.propose(managed_buffer!(b"change quorum"), actions);
+            assert_eq!(proposal_id, PROPOSAL_ID);
+        },
+    );
+
+    // changes after the snapshot do not affect the votes on the proposal
+    world.set_block_nonce(PROPOSAL_BLOCK + 1);
+    lock_voting_tokens(&mut world, &use_module_whitebox, DAVE_ADDRESS_EXPR, 1_000);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(BOB_ADDRESS_EXPR),
+        |sc| sc.unlock_voting_tokens(managed_biguint!(500)),
+    );
+    world.check_state_step(CheckStateStep::new().put_account(
+        BOB_ADDRESS_EXPR,
+        CheckAccount::new().esdt_balance(GOV_TOKEN_ID_EXPR, 1_000u64),
+    ));
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(
+            sc.get_past_total_voting_supply(PROPOSAL_BLOCK),
+            managed_biguint!(2_000)
+        );
+        assert_eq!(
+            sc.get_past_total_voting_supply(PROPOSAL_BLOCK + 1),
+            managed_biguint!(2_500)
+        );
+        assert_eq!(
+            sc.get_proposal_quorum(PROPOSAL_ID),
+            managed_biguint!(2_000 * QUORUM_PERCENTAGE / 10_000)
+        );
+    });
+
+    world.set_block_nonce(PROPOSAL_BLOCK + VOTING_DELAY_BLOCKS);
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new()
+            .from(DAVE_ADDRESS_EXPR)
+            .esdt_transfer(GOV_TOKEN_ID, 0, rust_biguint!(0))
+            .no_expect(),
+        |sc| sc.vote(PROPOSAL_ID, VoteType::UpVote),
+        |r| {
+            r.assert_user_error("No payment accepted in snapshot voting mode");
+        },
+    );
+    vote_check_error(
+        &mut world,
+        &use_module_whitebox,
+        DAVE_ADDRESS_EXPR,
+        VoteType::UpVote,
+        "No voting power at proposal creation",
+    );
+    vote_check_error(
+        &mut world,
+        &use_module_whitebox,
+        CAROL_ADDRESS_EXPR,
+        VoteType::UpVote,
+        "No voting power at proposal creation",
+    );
+
+    // alice votes with her own and carol's voting power, then changes her mind
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.vote(PROPOSAL_ID, VoteType::DownVote),
+    );
+    check_proposal_votes(&mut world, &use_module_whitebox, 0, 1_500);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.vote(PROPOSAL_ID, VoteType::UpVote),
+    );
+    check_proposal_votes(&mut world, &use_module_whitebox, 1_500, 0);
+    vote_check_error(
+        &mut world,
+        &use_module_whitebox,
+        ALICE_ADDRESS_EXPR,
+        VoteType::UpVote,
+        "Already voted for this proposal",
+    );
+
+    // bob already unlocked his tokens, but still votes with his snapshot voting power
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(BOB_ADDRESS_EXPR),
+        |sc| sc.vote(PROPOSAL_ID, VoteType::DownVote),
+    );
+    check_proposal_votes(&mut world, &use_module_whitebox, 1_500, 500);
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        let OptionalValue::Some(bob_vote) = sc.get_snapshot_vote(
+            PROPOSAL_ID,
+            managed_address!(&address_expr_to_address(BOB_ADDRESS_EXPR)),
+        ) else {
+            panic!("bob's vote not found");
+        };
+        assert_eq!(bob_vote.vote_type, VoteType::DownVote);
+        assert_eq!(bob_vote.weight, managed_biguint!(500));
+    });
+
+    // votes can no longer be changed after the voting period
+    world.set_block_nonce(PROPOSAL_BLOCK + VOTING_DELAY_BLOCKS + VOTING_PERIOD_BLOCKS);
+    vote_check_error(
+        &mut world,
+        &use_module_whitebox,
+        ALICE_ADDRESS_EXPR,
+        VoteType::DownVote,
+        "Proposal is not active",
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert!(sc.get_proposal_status(PROPOSAL_ID) == GovernanceProposalStatus::Succeeded);
+    });
+}
@@ -0,0 +1,309 @@
+use multiversx_sc::types::{Address, BigUint, EgldOrEsdtTokenIdentifier};
+use multiversx_sc_modules::staking_rewards::{
+    staking_rewards_pool::RewardAccrualMode, StakingRewardsModule,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    scenario_model::{
+        Account, AddressValue, CheckAccount, CheckStateStep, ScCallStep, SetStateStep,
+    },
+    DebugApi, ScenarioWorld, WhiteboxContract,
+};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const ALICE_ADDRESS_EXPR: &str = "address:alice";
+const BOB_ADDRESS_EXPR: &str = "address:bob";
+const CAROL_ADDRESS_EXPR: &str = "address:carol";
+
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const STAKE_TOKEN_ID_EXPR: &str = "str:STAKE-123456";
+const STAKE_TOKEN_ID: &[u8] = b"STAKE-123456";
+const REWARD_TOKEN_ID_EXPR: &str = "str:REWARD-123456";
+const REWARD_TOKEN_ID: &[u8] = b"REWARD-123456";
+
+const UNBONDING_PERIOD: u64 = 10;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+fn token(token_id: &[u8]) -> EgldOrEsdtTokenIdentifier<DebugApi> {
+    EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(token_id))
+}
+
+fn address_expr_to_address(address_expr: &str) -> Address {
+    AddressValue::from(address_expr).to_address()
+}
+
+fn check_balances(world: &mut ScenarioWorld, address_expr: &str, stake: u64, reward: u64) {
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            address_expr,
+            CheckAccount::new()
+                .esdt_balance(STAKE_TOKEN_ID_EXPR, stake)
+                .esdt_balance(REWARD_TOKEN_ID_EXPR, reward),
+        ),
+    );
+}
+
+fn stake(
+    world: &mut ScenarioWorld,
+    whitebox: &WhiteboxContract<use_module::ContractObj<DebugApi>>,
+    address_expr: &str,
+    amount: u64,
+) {
+    world.whitebox_call(
+        whitebox,
+        ScCallStep::new().from(address_expr).esdt_transfer(
+            STAKE_TOKEN_ID,
+            0,
+            rust_biguint!(amount),
+        ),
+        |sc| sc.stake_tokens(),
+    );
+}
+
+fn check_pending_rewards(
+    world: &mut ScenarioWorld,
+    whitebox: &WhiteboxContract<use_module::ContractObj<DebugApi>>,
+    address_expr: &str,
+    reward: u64,
+    stake: u64,
+) {
+    world.whitebox_query(whitebox, |sc| {
+        let pending: Vec<(EgldOrEsdtTokenIdentifier<DebugApi>, BigUint<DebugApi>)> = sc
+            .get_pending_rewards(managed_address!(&address_expr_to_address(address_expr)))
+            .into_iter()
+            .map(|multi_value| multi_value.into_tuple())
+            .collect();
+        assert_eq!(
+            pending,
+            vec![
+                (token(REWARD_TOKEN_ID), managed_biguint!(reward)),
+                (token(STAKE_TOKEN_ID), managed_biguint!(stake)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn staking_rewards_module_test() {
+    let mut world = world();
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    let mut set_state_step = SetStateStep::new()
+        .put_account(
+            OWNER_ADDRESS_EXPR,
+            Account::new()
+                .nonce(1)
+                .esdt_balance(STAKE_TOKEN_ID_EXPR, 10_000u64)
+                .esdt_balance(REWARD_TOKEN_ID_EXPR, 9_000u64),
+        )
+        .put_account(
+            USE_MODULE_ADDRESS_EXPR,
+            Account::new()
+                .nonce(1)
+                .code(use_module_code)
+                .owner(OWNER_ADDRESS_EXPR),
+        )
+        .block_nonce(10);
+    for user in [ALICE_ADDRESS_EXPR, BOB_ADDRESS_EXPR, CAROL_ADDRESS_EXPR] {
+        set_state_step = set_state_step.put_account(
+            user,
+            Account::new()
+                .nonce(1)
+                .esdt_balance(STAKE_TOKEN_ID_EXPR, 1_000u64),
+        );
+    }
+    world.set_state_step(set_state_step);
+
+    // the staking token is also a reward token, so rewards in it can be compounded
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            sc.init_staking_rewards_module(
+                token(STAKE_TOKEN_ID),
+                RewardAccrualMode::PerBlock,
+                UNBONDING_PERIOD,
+            );
+            sc.add_reward_token(token(REWARD_TOKEN_ID), managed_biguint!(100));
+            sc.add_reward_token(token(STAKE_TOKEN_ID), managed_biguint!(10));
+        },
+    );
+    for (token_id, amount) in [(REWARD_TOKEN_ID, 9_000u64), (STAKE_TOKEN_ID, 5_000u64)] {
+        world.whitebox_call(
+            &use_module_whitebox,
+            ScCallStep::new().from(OWNER_ADDRESS_EXPR).esdt_transfer(
+                token_id,
+                0,
+                rust_biguint!(amount),
+            ),
+            |sc| sc.fund_rewards(),
+        );
+    }
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new()
+            .from(ALICE_ADDRESS_EXPR)
+            .esdt_transfer(REWARD_TOKEN_ID, 0, rust_biguint!(0))
+            .no_expect(),
+        |sc| sc.stake_tokens(),
+        |r| {
+            r.assert_user_error("Invalid payment token");
+        },
+    );
+
+    // blocks 10-20: alice alone
+    stake(&mut world, &use_module_whitebox, ALICE_ADDRESS_EXPR, 100);
+    world.set_block_nonce(20);
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        ALICE_ADDRESS_EXPR,
+        1_000,
+        100,
+    );
+
+    // blocks 20-30: alice 1/4, bob 3/4
+    stake(&mut world, &use_module_whitebox, BOB_ADDRESS_EXPR, 300);
+    world.set_block_nonce(30);
+
+    // blocks 30-40: the reward rate doubles, rewards up to now keep the old rate
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.set_reward_rate(token(REWARD_TOKEN_ID), managed_biguint!(200)),
+    );
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        ALICE_ADDRESS_EXPR,
+        1_250,
+        125,
+    );
+    check_pending_rewards(&mut world, &use_module_whitebox, BOB_ADDRESS_EXPR, 750, 75);
+    world.set_block_nonce(40);
+
+    // blocks 40-50: alice 1/8, bob 3/8, carol 1/2, rounding down
+    stake(&mut world, &use_module_whitebox, CAROL_ADDRESS_EXPR, 400);
+    world.set_block_nonce(50);
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        ALICE_ADDRESS_EXPR,
+        2_000,
+        162,
+    );
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        BOB_ADDRESS_EXPR,
+        3_000,
+        187,
+    );
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        CAROL_ADDRESS_EXPR,
+        1_000,
+        50,
+    );
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.claim_rewards(),
+    );
+    check_balances(&mut world, ALICE_ADDRESS_EXPR, 900 + 162, 2_000);
+    check_pending_rewards(&mut world, &use_module_whitebox, ALICE_ADDRESS_EXPR, 0, 0);
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(BOB_ADDRESS_EXPR),
+        |sc| {
+            let compounded = sc.compound_rewards();
+            assert_eq!(compounded, managed_biguint!(187));
+        },
+    );
+    check_pending_rewards(&mut world, &use_module_whitebox, BOB_ADDRESS_EXPR, 3_000, 0);
+
+    // carol leaves, her tokens only become available after the unbonding period
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(CAROL_ADDRESS_EXPR),
+        |sc| sc.unstake_tokens(managed_biguint!(400)),
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.rewards_total_staked().get(), managed_biguint!(587));
+        assert_eq!(
+            sc.rewards_staked_amount(&managed_address!(&address_expr_to_address(
+                BOB_ADDRESS_EXPR
+            )))
+            .get(),
+            managed_biguint!(487)
+        );
+    });
+
+    world.set_block_nonce(50 + UNBONDING_PERIOD - 1);
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(CAROL_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            let _ = sc.unbond_tokens();
+        },
+        |r| {
+            r.assert_user_error("Nothing to unbond");
+        },
+    );
+
+    world.set_block_nonce(50 + UNBONDING_PERIOD);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(CAROL_ADDRESS_EXPR),
+        |sc| {
+            let unbonded = sc.unbond_tokens();
+            assert_eq!(unbonded, managed_biguint!(400));
+            sc.claim_rewards();
+        },
+    );
+    check_balances(&mut world, CAROL_ADDRESS_EXPR, 1_000 + 50, 1_000);
+
+    // blocks 50-65 distribute the last 3000 funded reward tokens, nothing accrues afterwards
+    world.set_block_nonce(80);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.claim_rewards(),
+    );
+    check_balances(&mut world, ALICE_ADDRESS_EXPR, 900 + 162 + 51, 2_000 + 511);
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        let pool = sc.get_reward_pool(token(REWARD_TOKEN_ID));
+        assert_eq!(pool.undistributed_amount, managed_biguint!(0));
+        assert_eq!(pool.last_update, 80);
+    });
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        BOB_ADDRESS_EXPR,
+        5_488,
+        248,
+    );
+
+    world.set_block_nonce(90);
+    check_pending_rewards(
+        &mut world,
+        &use_module_whitebox,
+        BOB_ADDRESS_EXPR,
+        5_488,
+        331,
+    );
+}
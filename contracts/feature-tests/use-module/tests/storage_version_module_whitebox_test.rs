@@ -0,0 +1,144 @@
+use multiversx_sc::types::OperationCompletionStatus;
+use multiversx_sc_modules::{
+    ongoing_operation::DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, storage_version::StorageVersionModule,
+};
+use multiversx_sc_scenario::{
+    scenario_model::{Account, CheckAccount, CheckStateStep, ScCallStep, SetStateStep},
+    ScenarioWorld, WhiteboxContract,
+};
+use use_module::storage_version_mod_example::{StorageVersionModExample, CURRENT_STORAGE_VERSION};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+/// 3 scores and a final check for each of the 2 steps.
+const NUM_BATCHES: usize = 8;
+
+/// Progress of an unrelated operation of the `ongoing_operation` module, must survive the migration.
+const ONGOING_OPERATION_KEY: &str = "str:ongoing_operation:currentOngoingOperation";
+const ONGOING_OPERATION_PROGRESS: &str = "7";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+/// Sets up the contract with its storage as it was laid out in version 0.
+fn setup_v0_snapshot(world: &mut ScenarioWorld) {
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                USE_MODULE_ADDRESS_EXPR,
+                Account::new()
+                    .nonce(1)
+                    .code(use_module_code)
+                    .owner(OWNER_ADDRESS_EXPR)
+                    .storage("str:storageVersionExample:scores.len", "3")
+                    .storage("str:storageVersionExample:scores.item|u32:1", "5")
+                    .storage("str:storageVersionExample:scores.item|u32:2", "10")
+                    .storage("str:storageVersionExample:scores.item|u32:3", "15")
+                    .storage(ONGOING_OPERATION_KEY, ONGOING_OPERATION_PROGRESS),
+            ),
+    );
+}
+
+/// The exact storage expected after migrating the version 0 snapshot.
+fn check_v2_storage(world: &mut ScenarioWorld) {
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            USE_MODULE_ADDRESS_EXPR,
+            CheckAccount::new()
+                .check_storage("str:storage_version:version", "2")
+                .check_storage("str:storageVersionExample:scores.len", "3")
+                .check_storage("str:storageVersionExample:scores.item|u32:1", "500")
+                .check_storage("str:storageVersionExample:scores.item|u32:2", "1000")
+                .check_storage("str:storageVersionExample:scores.item|u32:3", "1500")
+                .check_storage("str:storageVersionExample:totalScore", "3000")
+                .check_storage(ONGOING_OPERATION_KEY, ONGOING_OPERATION_PROGRESS),
+        ),
+    );
+}
+
+#[test]
+fn storage_version_migrate_in_one_call_test() {
+    let mut world = world();
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    setup_v0_snapshot(&mut world);
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            let result = sc.migrate_scores();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        },
+    );
+    check_v2_storage(&mut world);
+
+    // migrating again changes nothing
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            let result = sc.migrate_scores();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        },
+    );
+    check_v2_storage(&mut world);
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.add_score(20),
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+        assert_eq!(sc.example_scores().len(), 4);
+        assert_eq!(sc.example_scores().get(4), 2000);
+        assert_eq!(sc.example_total_score().get(), 5000);
+    });
+}
+
+#[test]
+fn storage_version_migrate_in_batches_test() {
+    let mut world = world();
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    setup_v0_snapshot(&mut world);
+
+    // with this gas limit, a single iteration is performed per call
+    let batch_step = ScCallStep::new()
+        .from(OWNER_ADDRESS_EXPR)
+        .gas_limit(DEFAULT_MIN_GAS_TO_SAVE_PROGRESS);
+
+    world.whitebox_call(&use_module_whitebox, batch_step.clone(), |sc| {
+        let result = sc.migrate_scores();
+        assert_eq!(result, OperationCompletionStatus::InterruptedBeforeOutOfGas);
+    });
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.storage_version().get(), 0);
+        assert_eq!(sc.example_scores().get(1), 500);
+        assert_eq!(sc.example_scores().get(2), 10);
+    });
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.add_score(20),
+        |r| {
+            r.assert_user_error("Storage migration in progress");
+        },
+    );
+
+    world.whitebox_call_until_completed(&use_module_whitebox, batch_step, NUM_BATCHES - 1, |sc| {
+        sc.migrate_scores()
+    });
+    check_v2_storage(&mut world);
+}
@@ -0,0 +1,278 @@
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, EgldOrEsdtTokenIdentifier},
+};
+use multiversx_sc_modules::vesting::{vesting_schedule::VestingSchedule, VestingModule};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    scenario_model::{
+        Account, AddressValue, CheckAccount, CheckStateStep, ScCallStep, SetStateStep,
+    },
+    ScenarioWorld, WhiteboxContract,
+};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const ALICE_ADDRESS_EXPR: &str = "address:alice";
+
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const SFT_TOKEN_ID_EXPR: &str = "str:VEST-123456";
+const SFT_TOKEN_ID: &[u8] = b"VEST-123456";
+const SFT_NONCE: u64 = 5;
+const FUNGIBLE_TOKEN_ID_EXPR: &str = "str:FUNG-123456";
+const FUNGIBLE_TOKEN_ID: &[u8] = b"FUNG-123456";
+
+const START_TIMESTAMP: u64 = 100;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+fn set_block_timestamp(world: &mut ScenarioWorld, timestamp: u64) {
+    world.set_state_step(SetStateStep::new().block_timestamp(timestamp));
+}
+
+fn check_alice_balances(world: &mut ScenarioWorld, sft_balance: u64, fungible_balance: u64) {
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            ALICE_ADDRESS_EXPR,
+            CheckAccount::new()
+                .esdt_nft_balance_and_attributes(
+                    SFT_TOKEN_ID_EXPR,
+                    SFT_NONCE,
+                    sft_balance,
+                    Option::<&[u8]>::None,
+                )
+                .esdt_balance(FUNGIBLE_TOKEN_ID_EXPR, fungible_balance),
+        ),
+    );
+}
+
+#[test]
+fn vesting_module_test() {
+    let mut world = world();
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                OWNER_ADDRESS_EXPR,
+                Account::new()
+                    .nonce(1)
+                    .esdt_nft_balance(
+                        SFT_TOKEN_ID_EXPR,
+                        SFT_NONCE,
+                        1_000u64,
+                        Option::<&[u8]>::None,
+                    )
+                    .esdt_balance(FUNGIBLE_TOKEN_ID_EXPR, 500u64),
+            )
+            .put_account(ALICE_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                USE_MODULE_ADDRESS_EXPR,
+                Account::new()
+                    .nonce(1)
+                    .code(use_module_code)
+                    .owner(OWNER_ADDRESS_EXPR),
+            )
+            .block_timestamp(START_TIMESTAMP - 10),
+    );
+
+    // grant 1: SFT, 50 seconds cliff, 200 seconds linear, revocable
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).esdt_transfer(
+            SFT_TOKEN_ID,
+            SFT_NONCE,
+            rust_biguint!(1_000),
+        ),
+        |sc| {
+            let grant_id = sc.create_vesting_grant_endpoint(
+                managed_address!(&address_expr_to_address(ALICE_ADDRESS_EXPR)),
+                START_TIMESTAMP,
+                50,
+                200,
+                true,
+            );
+            assert_eq!(grant_id, 1);
+        },
+    );
+
+    // grant 2: fungible, no cliff, 100 seconds linear, not revocable
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).esdt_transfer(
+            FUNGIBLE_TOKEN_ID,
+            0,
+            rust_biguint!(500),
+        ),
+        |sc| {
+            let grant_id = sc.create_vesting_grant_endpoint(
+                managed_address!(&address_expr_to_address(ALICE_ADDRESS_EXPR)),
+                START_TIMESTAMP,
+                0,
+                100,
+                false,
+            );
+            assert_eq!(grant_id, 2);
+        },
+    );
+
+    // cliff longer than the vesting duration
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .esdt_transfer(FUNGIBLE_TOKEN_ID, 0, rust_biguint!(0))
+            .no_expect(),
+        |sc| {
+            sc.create_vesting_grant(
+                managed_address!(&address_expr_to_address(ALICE_ADDRESS_EXPR)),
+                VestingSchedule {
+                    token_identifier: EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                        FUNGIBLE_TOKEN_ID
+                    )),
+                    token_nonce: 0,
+                    total_amount: managed_biguint!(10),
+                    start_timestamp: START_TIMESTAMP,
+                    cliff_duration: 20,
+                    vesting_duration: 10,
+                },
+                false,
+            );
+        },
+        |r| {
+            r.assert_user_error("Cliff cannot be longer than the vesting duration");
+        },
+    );
+
+    // end timestamp out of range
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .esdt_transfer(FUNGIBLE_TOKEN_ID, 0, rust_biguint!(0))
+            .no_expect(),
+        |sc| {
+            sc.create_vesting_grant(
+                managed_address!(&address_expr_to_address(ALICE_ADDRESS_EXPR)),
+                VestingSchedule {
+                    token_identifier: EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                        FUNGIBLE_TOKEN_ID
+                    )),
+                    token_nonce: 0,
+                    total_amount: managed_biguint!(10),
+                    start_timestamp: START_TIMESTAMP,
+                    cliff_duration: 0,
+                    vesting_duration: u64::MAX,
+                },
+                false,
+            );
+        },
+        |r| {
+            r.assert_user_error("Vesting schedule ends too late");
+        },
+    );
+
+    // claimable amounts at given timestamps
+    set_block_timestamp(&mut world, START_TIMESTAMP + 20);
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(
+            sc.get_claimable_amount(1, OptionalValue::Some(START_TIMESTAMP + 49)),
+            managed_biguint!(0)
+        );
+        assert_eq!(
+            sc.get_claimable_amount(1, OptionalValue::Some(START_TIMESTAMP + 50)),
+            managed_biguint!(250)
+        );
+        assert_eq!(
+            sc.get_claimable_amount(2, OptionalValue::None),
+            managed_biguint!(100)
+        );
+    });
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.claim_vested(),
+    );
+    check_alice_balances(&mut world, 0, 100);
+
+    // grant 2 fully vested and claimed, so it is removed from the list
+    set_block_timestamp(&mut world, START_TIMESTAMP + 100);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.claim_vested(),
+    );
+    check_alice_balances(&mut world, 500, 500);
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        let grants = sc
+            .get_beneficiary_vesting_grants(managed_address!(&address_expr_to_address(
+                ALICE_ADDRESS_EXPR
+            )))
+            .to_vec();
+        assert_eq!(grants.len(), 1);
+        assert_eq!(grants.get(0), 1);
+
+        let grant = sc.get_existing_vesting_grant(2);
+        assert_eq!(grant.claimed_amount, managed_biguint!(500));
+    });
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| sc.revoke_vesting_grant(2),
+        |r| {
+            r.assert_user_error("Grant is not revocable");
+        },
+    );
+
+    // revoke grant 1: the unvested part returns to the owner
+    set_block_timestamp(&mut world, START_TIMESTAMP + 150);
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.revoke_vesting_grant(1),
+    );
+    world.check_state_step(CheckStateStep::new().put_account(
+        OWNER_ADDRESS_EXPR,
+        CheckAccount::new().esdt_nft_balance_and_attributes(
+            SFT_TOKEN_ID_EXPR,
+            SFT_NONCE,
+            250u64,
+            Option::<&[u8]>::None,
+        ),
+    ));
+
+    // vesting stopped at revocation, the vested part can still be claimed
+    set_block_timestamp(&mut world, START_TIMESTAMP + 300);
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(
+            sc.get_claimable_amount(1, OptionalValue::None),
+            managed_biguint!(250)
+        );
+    });
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(ALICE_ADDRESS_EXPR),
+        |sc| sc.claim_vested(),
+    );
+    check_alice_balances(&mut world, 750, 500);
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        let grants = sc.get_beneficiary_vesting_grants(managed_address!(&address_expr_to_address(
+            ALICE_ADDRESS_EXPR
+        )));
+        assert!(grants.is_empty());
+    });
+}
+
+fn address_expr_to_address(address_expr: &str) -> Address {
+    AddressValue::from(address_expr).to_address()
+}
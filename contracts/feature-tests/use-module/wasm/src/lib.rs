@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           62
+// Endpoints:                          103
 // Async Callback:                       1
-// Total number of exported functions:  64
+// Total number of exported functions: 105
 
 #![no_std]
 #![allow(internal_features)]
@@ -32,6 +32,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         only_admin_mod_endpoint => only_admin_mod_endpoint
         call_derived_not_admin_only => call_derived_not_admin_only
         countTo100 => count_to_100
+        migrateScores => migrate_scores
+        addScore => add_score
+        getExampleScores => example_scores
+        getExampleTotalScore => example_total_score
         mergeTokens => merge_tokens_endpoint
         mergeTokensCustomAttributes => merge_tokens_custom_attributes_endpoint
         splitTokens => split_tokens_endpoint
@@ -48,6 +52,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         execute => execute
         cancel => cancel
         getProposalStatus => get_proposal_status
+        getProposalQuorum => get_proposal_quorum
+        getSnapshotVote => get_snapshot_vote
         getProposer => get_proposer
         getProposalDescription => get_proposal_description
         getProposalActions => get_proposal_actions
@@ -66,6 +72,17 @@ multiversx_sc_wasm_adapter::endpoints! {
         getVotingDelayInBlocks => voting_delay_in_blocks
         getVotingPeriodInBlocks => voting_period_in_blocks
         getLockTimeAfterVotingEndsInBlocks => lock_time_after_voting_ends_in_blocks
+        lockVotingTokens => lock_voting_tokens
+        unlockVotingTokens => unlock_voting_tokens
+        delegateVotingPower => delegate_voting_power
+        changeSnapshotQuorumPercentage => change_snapshot_quorum_percentage
+        getDelegatee => get_delegatee
+        getVotingPower => get_voting_power
+        getPastVotingPower => get_past_voting_power
+        getPastTotalVotingSupply => get_past_total_voting_supply
+        isSnapshotVotingEnabled => snapshot_voting_enabled
+        getSnapshotQuorumPercentage => snapshot_quorum_percentage
+        getLockedVotingTokens => locked_voting_tokens
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
@@ -82,6 +99,30 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin => add_admin
         removeAdmin => remove_admin
         getAdmins => admins
+        getStorageVersion => storage_version
+        createVestingGrant => create_vesting_grant_endpoint
+        revokeVestingGrant => revoke_vesting_grant
+        claimVested => claim_vested
+        getClaimableAmount => get_claimable_amount
+        getVestingGrant => get_existing_vesting_grant
+        getBeneficiaryVestingGrants => get_beneficiary_vesting_grants
+        stakeTokens => stake_tokens
+        unstakeTokens => unstake_tokens
+        unbondTokens => unbond_tokens
+        claimRewards => claim_rewards
+        compoundRewards => compound_rewards
+        addRewardToken => add_reward_token
+        setRewardRate => set_reward_rate
+        fundRewards => fund_rewards
+        getPendingRewards => get_pending_rewards
+        getRewardPool => get_reward_pool
+        getRewardsStakingToken => rewards_staking_token
+        getRewardAccrualMode => reward_accrual_mode
+        getUnbondingPeriod => unbonding_period
+        getRewardsStakedAmount => rewards_staked_amount
+        getRewardsTotalStaked => rewards_total_staked
+        getUnbondingStakes => unbonding_stakes
+        getRewardTokens => reward_tokens
     )
 }
 
@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+/// The price of token `i` is `initial_price * (growth_numerator / growth_denominator)^i`.
+///
+/// The geometric sum is computed exactly and rounded down once, at the end.
+/// Since the powers grow with the supply, it is intended for tokens with a small supply,
+/// e.g. counted in whole units.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct ExponentialFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub growth_numerator: BigUint<M>,
+    pub growth_denominator: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for ExponentialFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        if *amount == 0u32 {
+            return BigUint::zero();
+        }
+        if self.growth_denominator == 0u32 {
+            M::error_api_impl().signal_error(b"Growth denominator cannot be zero");
+        }
+        if self.growth_numerator == self.growth_denominator {
+            return &self.initial_price * amount;
+        }
+
+        let start = to_exponent::<M>(token_start);
+        let count = to_exponent::<M>(amount);
+        let end = start
+            .checked_add(count)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(b"Supply too large for curve"));
+
+        // sum_{i = start}^{end - 1} (p / q)^i
+        //     = p^start * |q^count - p^count| / (q^(end - 1) * |q - p|)
+        let p = &self.growth_numerator;
+        let q = &self.growth_denominator;
+        let (count_diff, base_diff) = if p > q {
+            (p.pow(count) - q.pow(count), p - q)
+        } else {
+            (q.pow(count) - p.pow(count), q - p)
+        };
+
+        let numerator = &self.initial_price * &p.pow(start) * count_diff;
+        let denominator = q.pow(end - 1) * base_diff;
+        numerator / denominator
+    }
+}
+
+fn to_exponent<M: ManagedTypeApi>(value: &BigUint<M>) -> u32 {
+    match value.to_u64() {
+        Some(small) if small <= u32::MAX as u64 => small as u32,
+        _ => M::error_api_impl().signal_error(b"Supply too large for curve"),
+    }
+}
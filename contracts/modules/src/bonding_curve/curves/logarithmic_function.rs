@@ -0,0 +1,42 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+/// The price of token `i` is `initial_price + coefficient * floor(log2(i + offset))`.
+///
+/// The `offset` must be at least 1. The sum over the range is computed exactly, in closed form.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct LogarithmicFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub coefficient: BigUint<M>,
+    pub offset: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for LogarithmicFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        if self.offset == 0u32 {
+            M::error_api_impl().signal_error(b"Offset must be at least 1");
+        }
+
+        let first = token_start + &self.offset;
+        let log_sum = log2_prefix_sum(&(&first + amount - 1u32)) - log2_prefix_sum(&(first - 1u32));
+        &self.initial_price * amount + &self.coefficient * &log_sum
+    }
+}
+
+/// Computes `floor(log2(1)) + floor(log2(2)) + ... + floor(log2(n))`.
+///
+/// With `b = floor(log2(n))`, this equals `(n + 1) * b - 2^(b + 1) + 2`.
+fn log2_prefix_sum<M: ManagedTypeApi>(n: &BigUint<M>) -> BigUint<M> {
+    if *n == 0u32 {
+        return BigUint::zero();
+    }
+    let b = n.log2();
+    (n + 1u32) * b + 2u32 - BigUint::from(2u32).pow(b + 1)
+}
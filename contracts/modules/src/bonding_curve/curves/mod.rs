@@ -1,2 +1,6 @@
 pub mod curve_function;
+pub mod exponential_function;
 pub mod linear_function;
+pub mod logarithmic_function;
+pub mod polynomial_function;
+pub mod sigmoid_function;
@@ -0,0 +1,61 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+/// The price of token `i` is `(c_0 + c_1 * i + c_2 * i^2 + ... + c_d * i^d) / denominator`,
+/// where `c_k` are the `coefficients`, in increasing order of degree.
+///
+/// The sum over the range is computed exactly and rounded down once, at the end.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct PolynomialFunction<M: ManagedTypeApi> {
+    pub coefficients: ManagedVec<M, BigUint<M>>,
+    pub denominator: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PolynomialFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        if self.denominator == 0u32 {
+            M::error_api_impl().signal_error(b"Denominator cannot be zero");
+        }
+        if self.coefficients.is_empty() {
+            return BigUint::zero();
+        }
+
+        let degree = self.coefficients.len() - 1;
+        let token_end = token_start + amount;
+        let sums_to_start = power_sums(token_start, degree);
+        let sums_to_end = power_sums(&token_end, degree);
+
+        let mut total = BigUint::zero();
+        for (k, coefficient) in self.coefficients.iter().enumerate() {
+            let range_sum = sums_to_end.get(k).clone_value() - sums_to_start.get(k).clone_value();
+            total += &*coefficient * &range_sum;
+        }
+        total / &self.denominator
+    }
+}
+
+/// Computes `S_k(n) = 0^k + 1^k + ... + (n - 1)^k`, for all `k` up to `degree`.
+///
+/// Uses the exact recurrence `n^(k + 1) = sum_{j = 0}^{k} C(k + 1, j) * S_j(n)`.
+fn power_sums<M: ManagedTypeApi>(n: &BigUint<M>, degree: usize) -> ManagedVec<M, BigUint<M>> {
+    let mut sums = ManagedVec::<M, BigUint<M>>::new();
+    for k in 0..=degree {
+        let mut lower_terms = BigUint::zero();
+        let mut binomial = BigUint::from(1u32);
+        for j in 0..k {
+            lower_terms += &binomial * &*sums.get(j);
+            // C(k + 1, j + 1) = C(k + 1, j) * (k + 1 - j) / (j + 1)
+            binomial = binomial * (k + 1 - j) as u64 / (j + 1) as u64;
+        }
+        let sum = (n.pow(k as u32 + 1) - lower_terms) / (k + 1) as u64;
+        sums.push(sum);
+    }
+    sums
+}
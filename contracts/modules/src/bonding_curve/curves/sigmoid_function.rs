@@ -0,0 +1,49 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+/// Algebraic sigmoid: the price at supply `s` is
+/// `max_price / 2 * (1 + (s - midpoint) / sqrt(smoothness + (s - midpoint)^2))`.
+///
+/// It rises from 0 towards `max_price`, reaching half of it at `midpoint`.
+/// A larger `smoothness` gives a more gradual transition.
+///
+/// The price over a range is the integral of the function, which has the closed form
+/// `max_price / 2 * (s + sqrt(smoothness + (s - midpoint)^2))`,
+/// evaluated with integer square roots and rounded down.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone)]
+pub struct SigmoidFunction<M: ManagedTypeApi> {
+    pub max_price: BigUint<M>,
+    pub midpoint: BigUint<M>,
+    pub smoothness: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> SigmoidFunction<M> {
+    fn root_term(&self, supply: &BigUint<M>) -> BigUint<M> {
+        let distance = if *supply > self.midpoint {
+            supply - &self.midpoint
+        } else {
+            &self.midpoint - supply
+        };
+        (&distance * &distance + &self.smoothness).sqrt()
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for SigmoidFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        let token_end = token_start + amount;
+        let increasing = amount + &self.root_term(&token_end);
+        let decreasing = self.root_term(token_start);
+        if increasing <= decreasing {
+            // only possible due to rounding, far below the midpoint
+            return BigUint::zero();
+        }
+        &self.max_price * &(increasing - decreasing) / 2u32
+    }
+}
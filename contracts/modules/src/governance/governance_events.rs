@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use super::governance_proposal::GovernanceProposal;
+use super::governance_proposal::{GovernanceProposal, VoteType};
 use crate::governance::ProposalId;
 
 #[multiversx_sc::module]
@@ -70,4 +70,34 @@ pub trait GovernanceEventsModule {
         #[indexed] proposal_id: ProposalId,
         payment: &EsdtTokenPayment<Self::Api>,
     );
+
+    #[event("voteChanged")]
+    fn vote_changed_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] previous_vote: &VoteType,
+        #[indexed] new_vote: &VoteType,
+    );
+
+    #[event("votingTokensLocked")]
+    fn voting_tokens_locked_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("votingTokensUnlocked")]
+    fn voting_tokens_unlocked_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("delegateChanged")]
+    fn delegate_changed_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] previous_delegatee: &ManagedAddress,
+        #[indexed] new_delegatee: &ManagedAddress,
+    );
+
+    #[event("votingPowerChanged")]
+    fn voting_power_changed_event(
+        &self,
+        #[indexed] delegatee: &ManagedAddress,
+        new_voting_power: &BigUint,
+    );
 }
@@ -7,7 +7,9 @@ pub type ProposalId = usize;
 pub type GovernanceActionAsMultiArg<M> =
     MultiValue4<u64, ManagedAddress<M>, ManagedBuffer<M>, ManagedVec<M, ManagedBuffer<M>>>;
 
-#[derive(TypeAbi, TopEncode, TopDecode)]
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug,
+)]
 pub enum VoteType {
     UpVote,
     DownVote,
@@ -66,6 +68,13 @@ pub struct GovernanceProposal<M: ManagedTypeApi> {
     pub fees: ProposalFees<M>,
 }
 
+/// A vote cast in snapshot voting mode, kept so that it can be changed until the voting period ends.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct SnapshotVote<M: ManagedTypeApi> {
+    pub vote_type: VoteType,
+    pub weight: BigUint<M>,
+}
+
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
 pub struct ProposalVotes<M: ManagedTypeApi> {
     pub up_votes: BigUint<M>,
@@ -90,6 +99,23 @@ impl<M: ManagedTypeApi> ProposalVotes<M> {
         }
     }
 
+    pub fn add_votes(&mut self, vote_type: VoteType, amount: &BigUint<M>) {
+        *self.votes_mut(vote_type) += amount;
+    }
+
+    pub fn remove_votes(&mut self, vote_type: VoteType, amount: &BigUint<M>) {
+        *self.votes_mut(vote_type) -= amount;
+    }
+
+    fn votes_mut(&mut self, vote_type: VoteType) -> &mut BigUint<M> {
+        match vote_type {
+            VoteType::UpVote => &mut self.up_votes,
+            VoteType::DownVote => &mut self.down_votes,
+            VoteType::DownVetoVote => &mut self.down_veto_votes,
+            VoteType::AbstainVote => &mut self.abstain_votes,
+        }
+    }
+
     pub fn get_total_votes(&self) -> BigUint<M> {
         &self.up_votes + &self.down_votes + &self.down_veto_votes + &self.abstain_votes
     }
@@ -0,0 +1,281 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::{governance_configurable, governance_events};
+
+/// Quorum percentages are expressed in hundredths of a percent, i.e. `10_000` is 100%.
+pub const QUORUM_PERCENTAGE_DENOMINATOR: u64 = 10_000;
+
+/// The voting power of an account, or the total voting supply, starting from a given block.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug,
+)]
+pub struct VotingPowerCheckpoint<M: ManagedTypeApi> {
+    pub block_nonce: u64,
+    pub amount: BigUint<M>,
+}
+
+/// Optional snapshot voting mode for the governance module.
+///
+/// Instead of depositing tokens with each vote, users lock governance tokens in the contract once.
+/// Locked tokens give voting power, either to their owner or to an address they delegate to.
+/// The voting power history is checkpointed per block, so the weight of a vote is the voting power
+/// the voter had in the block the proposal was created, regardless of later transfers.
+///
+/// In this mode, the quorum is a percentage of the total voting supply at proposal creation.
+#[multiversx_sc::module]
+pub trait GovernanceSnapshotModule:
+    governance_configurable::GovernanceConfigurablePropertiesModule
+    + governance_events::GovernanceEventsModule
+{
+    /// Switches the governance module to snapshot voting.
+    /// Should be called in the contract constructor, right after `init_governance_module`.
+    fn init_governance_snapshot_voting(&self, quorum_percentage: u64) {
+        self.snapshot_voting_enabled().set(true);
+        self.try_change_snapshot_quorum_percentage(quorum_percentage);
+    }
+
+    // endpoints
+
+    /// Locks governance tokens, adding to the voting power of the caller, or of their delegatee.
+    #[payable("*")]
+    #[endpoint(lockVotingTokens)]
+    fn lock_voting_tokens(&self) {
+        self.require_snapshot_voting_enabled();
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.governance_token_id().get(),
+            "Only Governance token accepted as payment"
+        );
+        require!(payment.amount > 0, "Cannot lock zero tokens");
+
+        let caller = self.blockchain().get_caller();
+        self.locked_voting_tokens(&caller)
+            .update(|locked| *locked += &payment.amount);
+        let delegatee = self.get_delegatee(caller.clone());
+        self.move_voting_power(None, Some(&delegatee), &payment.amount);
+        self.update_total_voting_supply(|supply| *supply += &payment.amount);
+
+        self.voting_tokens_locked_event(&caller, &payment.amount);
+    }
+
+    /// Returns locked governance tokens to the caller.
+    /// Votes already cast keep their weight, since they are based on past voting power.
+    #[endpoint(unlockVotingTokens)]
+    fn unlock_voting_tokens(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let locked_mapper = self.locked_voting_tokens(&caller);
+        require!(amount > 0, "Cannot unlock zero tokens");
+        require!(amount <= locked_mapper.get(), "Not enough locked tokens");
+
+        locked_mapper.update(|locked| *locked -= &amount);
+        let delegatee = self.get_delegatee(caller.clone());
+        self.move_voting_power(Some(&delegatee), None, &amount);
+        self.update_total_voting_supply(|supply| *supply -= &amount);
+
+        self.voting_tokens_unlocked_event(&caller, &amount);
+        let governance_token_id = self.governance_token_id().get();
+        self.send()
+            .direct_esdt(&caller, &governance_token_id, 0, &amount);
+    }
+
+    /// Gives the voting power of all tokens locked by the caller, present and future, to another address.
+    /// Delegating to oneself cancels the delegation.
+    #[endpoint(delegateVotingPower)]
+    fn delegate_voting_power(&self, delegatee: ManagedAddress) {
+        self.require_snapshot_voting_enabled();
+        let caller = self.blockchain().get_caller();
+        let previous_delegatee = self.get_delegatee(caller.clone());
+        require!(
+            previous_delegatee != delegatee,
+            "Voting power already delegated to this address"
+        );
+
+        if delegatee == caller {
+            self.voting_power_delegatee(&caller).clear();
+        } else {
+            self.voting_power_delegatee(&caller).set(&delegatee);
+        }
+
+        self.delegate_changed_event(&caller, &previous_delegatee, &delegatee);
+        let locked_amount = self.locked_voting_tokens(&caller).get();
+        self.move_voting_power(Some(&previous_delegatee), Some(&delegatee), &locked_amount);
+    }
+
+    /// Can only be called by the SC itself, through a proposal.
+    #[endpoint(changeSnapshotQuorumPercentage)]
+    fn change_snapshot_quorum_percentage(&self, new_value: u64) {
+        self.require_caller_self();
+
+        self.try_change_snapshot_quorum_percentage(new_value);
+    }
+
+    // views
+
+    /// Defaults to the user itself, if they did not delegate.
+    #[view(getDelegatee)]
+    fn get_delegatee(&self, user: ManagedAddress) -> ManagedAddress {
+        let delegatee_mapper = self.voting_power_delegatee(&user);
+        if delegatee_mapper.is_empty() {
+            user
+        } else {
+            delegatee_mapper.get()
+        }
+    }
+
+    #[view(getVotingPower)]
+    fn get_voting_power(&self, user: ManagedAddress) -> BigUint {
+        self.latest_checkpoint_amount(&self.voting_power_checkpoints(&user))
+    }
+
+    /// Voting power at the end of the given block.
+    #[view(getPastVotingPower)]
+    fn get_past_voting_power(&self, user: ManagedAddress, block_nonce: u64) -> BigUint {
+        self.checkpoint_amount_at(&self.voting_power_checkpoints(&user), block_nonce)
+    }
+
+    /// Total voting supply, i.e. all locked tokens, at the end of the given block.
+    #[view(getPastTotalVotingSupply)]
+    fn get_past_total_voting_supply(&self, block_nonce: u64) -> BigUint {
+        self.checkpoint_amount_at(&self.total_voting_supply_checkpoints(), block_nonce)
+    }
+
+    // private
+
+    fn require_snapshot_voting_enabled(&self) {
+        require!(
+            self.snapshot_voting_enabled().get(),
+            "Snapshot voting is not enabled"
+        );
+    }
+
+    fn try_change_snapshot_quorum_percentage(&self, new_value: u64) {
+        require!(
+            new_value > 0 && new_value <= QUORUM_PERCENTAGE_DENOMINATOR,
+            "Invalid quorum percentage"
+        );
+
+        self.snapshot_quorum_percentage().set(new_value);
+    }
+
+    /// Required quorum for a snapshot of the total voting supply.
+    fn snapshot_quorum(&self, total_voting_supply: &BigUint) -> BigUint {
+        total_voting_supply * self.snapshot_quorum_percentage().get()
+            / QUORUM_PERCENTAGE_DENOMINATOR
+    }
+
+    fn move_voting_power(
+        &self,
+        opt_from: Option<&ManagedAddress>,
+        opt_to: Option<&ManagedAddress>,
+        amount: &BigUint,
+    ) {
+        if opt_from == opt_to || *amount == 0 {
+            return;
+        }
+
+        if let Some(from) = opt_from {
+            let checkpoints = self.voting_power_checkpoints(from);
+            let voting_power = self.latest_checkpoint_amount(&checkpoints);
+            require!(voting_power >= *amount, "Not enough voting power");
+            let new_voting_power = voting_power - amount;
+            self.write_checkpoint(checkpoints, &new_voting_power);
+            self.voting_power_changed_event(from, &new_voting_power);
+        }
+
+        if let Some(to) = opt_to {
+            let checkpoints = self.voting_power_checkpoints(to);
+            let new_voting_power = self.latest_checkpoint_amount(&checkpoints) + amount;
+            self.write_checkpoint(checkpoints, &new_voting_power);
+            self.voting_power_changed_event(to, &new_voting_power);
+        }
+    }
+
+    fn update_total_voting_supply<F: FnOnce(&mut BigUint)>(&self, f: F) {
+        let checkpoints = self.total_voting_supply_checkpoints();
+        let mut supply = self.latest_checkpoint_amount(&checkpoints);
+        f(&mut supply);
+        self.write_checkpoint(checkpoints, &supply);
+    }
+
+    /// Only one checkpoint is kept per block, the last value in the block.
+    fn write_checkpoint(
+        &self,
+        mut checkpoints: VecMapper<VotingPowerCheckpoint<Self::Api>>,
+        amount: &BigUint,
+    ) {
+        let checkpoint = VotingPowerCheckpoint {
+            block_nonce: self.blockchain().get_block_nonce(),
+            amount: amount.clone(),
+        };
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).block_nonce == checkpoint.block_nonce {
+            checkpoints.set(len, &checkpoint);
+        } else {
+            let _ = checkpoints.push(&checkpoint);
+        }
+    }
+
+    fn latest_checkpoint_amount(
+        &self,
+        checkpoints: &VecMapper<VotingPowerCheckpoint<Self::Api>>,
+    ) -> BigUint {
+        let len = checkpoints.len();
+        if len == 0 {
+            BigUint::zero()
+        } else {
+            checkpoints.get(len).amount
+        }
+    }
+
+    /// Binary search for the last checkpoint at or before the given block.
+    fn checkpoint_amount_at(
+        &self,
+        checkpoints: &VecMapper<VotingPowerCheckpoint<Self::Api>>,
+        block_nonce: u64,
+    ) -> BigUint {
+        // invariant: checkpoints before `low` are at or before the block, from `high` on they are after it
+        let mut low = 1;
+        let mut high = checkpoints.len() + 1;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if checkpoints.get(mid).block_nonce <= block_nonce {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 1 {
+            BigUint::zero()
+        } else {
+            checkpoints.get(low - 1).amount
+        }
+    }
+
+    // storage
+
+    #[view(isSnapshotVotingEnabled)]
+    #[storage_mapper("governance:snapshotVotingEnabled")]
+    fn snapshot_voting_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getSnapshotQuorumPercentage)]
+    #[storage_mapper("governance:snapshotQuorumPercentage")]
+    fn snapshot_quorum_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLockedVotingTokens)]
+    #[storage_mapper("governance:lockedVotingTokens")]
+    fn locked_voting_tokens(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("governance:votingPowerDelegatee")]
+    fn voting_power_delegatee(&self, user: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("governance:votingPowerCheckpoints")]
+    fn voting_power_checkpoints(
+        &self,
+        user: &ManagedAddress,
+    ) -> VecMapper<VotingPowerCheckpoint<Self::Api>>;
+
+    #[storage_mapper("governance:totalVotingSupplyCheckpoints")]
+    fn total_voting_supply_checkpoints(&self) -> VecMapper<VotingPowerCheckpoint<Self::Api>>;
+}
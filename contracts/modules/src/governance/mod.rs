@@ -3,6 +3,7 @@ multiversx_sc::imports!();
 pub mod governance_configurable;
 pub mod governance_events;
 pub mod governance_proposal;
+pub mod governance_snapshot;
 
 use governance_proposal::*;
 
@@ -16,6 +17,7 @@ pub const MIN_AMOUNT_NOT_REACHED: &[u8] = b"Minimum amount not reached";
 pub trait GovernanceModule:
     governance_configurable::GovernanceConfigurablePropertiesModule
     + governance_events::GovernanceEventsModule
+    + governance_snapshot::GovernanceSnapshotModule
 {
     // endpoints
 
@@ -178,11 +180,19 @@ pub trait GovernanceModule:
 
     /// Vote on a proposal by depositing any amount of governance tokens
     /// These tokens will be locked until the proposal is executed or cancelled.
+    ///
+    /// In snapshot voting mode, no tokens are deposited. The vote weighs as much as the voting power
+    /// of the voter when the proposal was created, and it can be changed until the voting period ends.
     #[payable("*")]
     #[endpoint]
     fn vote(&self, proposal_id: usize, vote: VoteType) {
         self.require_caller_not_self();
 
+        if self.snapshot_voting_enabled().get() {
+            self.vote_with_snapshot_voting_power(proposal_id, vote);
+            return;
+        }
+
         let payment = self.require_payment_token_governance_token();
         self.require_valid_proposal_id(proposal_id);
         require!(
@@ -194,32 +204,9 @@ pub trait GovernanceModule:
         let new_user = self.user_voted_proposals(&voter).insert(proposal_id);
         require!(new_user, ALREADY_VOTED_ERR_MSG);
 
-        match vote {
-            VoteType::UpVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.up_votes += &payment.amount.clone();
-                });
-                self.up_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-            VoteType::DownVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.down_votes += &payment.amount.clone();
-                });
-                self.down_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-            VoteType::DownVetoVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.down_veto_votes += &payment.amount.clone();
-                });
-                self.down_veto_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-            VoteType::AbstainVote => {
-                self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.abstain_votes += &payment.amount.clone();
-                });
-                self.abstain_vote_cast_event(&voter, proposal_id, &payment.amount);
-            },
-        }
+        self.proposal_votes(proposal_id)
+            .update(|total_votes| total_votes.add_votes(vote, &payment.amount));
+        self.vote_cast_event(&voter, proposal_id, vote, &payment.amount);
     }
 
     /// Queue a proposal for execution.
@@ -366,7 +353,7 @@ pub trait GovernanceModule:
         let total_down_votes = proposal_votes.down_votes;
         let total_down_veto_votes = proposal_votes.down_veto_votes;
         let third_total_votes = &total_votes / 3u64;
-        let quorum = self.quorum().get();
+        let quorum = self.get_proposal_quorum(proposal_id);
 
         sc_print!("Total votes = {} quorum = {}", total_votes, quorum);
         if total_down_veto_votes > third_total_votes {
@@ -376,6 +363,34 @@ pub trait GovernanceModule:
         }
     }
 
+    /// The quorum is fixed in deposit voting mode,
+    /// but depends on the total voting supply at proposal creation in snapshot voting mode.
+    #[view(getProposalQuorum)]
+    fn get_proposal_quorum(&self, proposal_id: ProposalId) -> BigUint {
+        if !self.snapshot_voting_enabled().get() {
+            return self.quorum().get();
+        }
+
+        let snapshot_block = self.proposal_start_block(proposal_id).get();
+        let total_voting_supply = self.get_past_total_voting_supply(snapshot_block);
+        self.snapshot_quorum(&total_voting_supply)
+    }
+
+    /// Snapshot voting mode only.
+    #[view(getSnapshotVote)]
+    fn get_snapshot_vote(
+        &self,
+        proposal_id: ProposalId,
+        voter: ManagedAddress,
+    ) -> OptionalValue<SnapshotVote<Self::Api>> {
+        let vote_mapper = self.snapshot_votes(proposal_id, &voter);
+        if vote_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(vote_mapper.get())
+        }
+    }
+
     #[view(getProposer)]
     fn get_proposer(&self, proposal_id: usize) -> OptionalValue<ManagedAddress> {
         if !self.proposal_exists(proposal_id) {
@@ -415,6 +430,57 @@ pub trait GovernanceModule:
 
     // private
 
+    fn vote_with_snapshot_voting_power(&self, proposal_id: ProposalId, vote: VoteType) {
+        require!(
+            self.call_value().egld_value().clone_value() == 0
+                && self.call_value().all_esdt_transfers().is_empty(),
+            "No payment accepted in snapshot voting mode"
+        );
+        self.require_valid_proposal_id(proposal_id);
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
+            "Proposal is not active"
+        );
+
+        let voter = self.blockchain().get_caller();
+        let snapshot_block = self.proposal_start_block(proposal_id).get();
+        let weight = self.get_past_voting_power(voter.clone(), snapshot_block);
+        require!(weight > 0, "No voting power at proposal creation");
+
+        let vote_mapper = self.snapshot_votes(proposal_id, &voter);
+        let mut proposal_votes = self.proposal_votes(proposal_id).get();
+        if !vote_mapper.is_empty() {
+            let previous_vote = vote_mapper.get();
+            require!(previous_vote.vote_type != vote, ALREADY_VOTED_ERR_MSG);
+
+            proposal_votes.remove_votes(previous_vote.vote_type, &previous_vote.weight);
+            self.vote_changed_event(&voter, proposal_id, &previous_vote.vote_type, &vote);
+        }
+        proposal_votes.add_votes(vote, &weight);
+        self.proposal_votes(proposal_id).set(proposal_votes);
+
+        self.vote_cast_event(&voter, proposal_id, vote, &weight);
+        vote_mapper.set(SnapshotVote {
+            vote_type: vote,
+            weight,
+        });
+    }
+
+    fn vote_cast_event(
+        &self,
+        voter: &ManagedAddress,
+        proposal_id: ProposalId,
+        vote: VoteType,
+        amount: &BigUint,
+    ) {
+        match vote {
+            VoteType::UpVote => self.up_vote_cast_event(voter, proposal_id, amount),
+            VoteType::DownVote => self.down_vote_cast_event(voter, proposal_id, amount),
+            VoteType::DownVetoVote => self.down_veto_vote_cast_event(voter, proposal_id, amount),
+            VoteType::AbstainVote => self.abstain_vote_cast_event(voter, proposal_id, amount),
+        }
+    }
+
     fn refund_payments(&self, proposal_id: ProposalId) {
         let payments = self.proposals().get(proposal_id).fees;
 
@@ -507,6 +573,14 @@ pub trait GovernanceModule:
     #[storage_mapper("governance:userVotedProposals")]
     fn user_voted_proposals(&self, user: &ManagedAddress) -> UnorderedSetMapper<ProposalId>;
 
+    /// Snapshot voting mode only. Not cleared with the proposal, same as the other per-user data.
+    #[storage_mapper("governance:snapshotVotes")]
+    fn snapshot_votes(
+        &self,
+        proposal_id: ProposalId,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<SnapshotVote<Self::Api>>;
+
     #[view(getProposalVotes)]
     #[storage_mapper("proposalVotes")]
     fn proposal_votes(
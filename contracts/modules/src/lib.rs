@@ -12,6 +12,9 @@ pub mod ongoing_operation;
 pub mod only_admin;
 pub mod pause;
 pub mod staking;
+pub mod staking_rewards;
+pub mod storage_version;
 pub mod token_merge;
 pub mod transfer_role_proxy;
 pub mod users;
+pub mod vesting;
@@ -0,0 +1,316 @@
+multiversx_sc::imports!();
+
+pub mod staking_rewards_events;
+pub mod staking_rewards_pool;
+
+use staking_rewards_pool::{RewardAccrualMode, RewardPool, UnbondingStake};
+
+/// Standard smart contract module for staking with rewards.
+///
+/// Users stake a single fungible token and earn any number of reward tokens,
+/// each distributed at its own rate, per block or per second, proportionally to the stake.
+/// Rewards are tracked with the reward-per-share method, so accrual costs the same no matter how many users stake.
+///
+/// It offers:
+/// * endpoints to stake, unstake and, after the unbonding period, withdraw the unstaked tokens
+/// * endpoints to claim rewards, or to compound them, if the staking token is also a reward token
+/// * owner endpoints to register reward tokens, change their rates and fund them
+/// * views for stakes, pending rewards and reward pools
+///
+/// Rewards only accrue as long as the owner has funded them.
+/// The module needs to be initialized with `init_staking_rewards_module` in the contract constructor.
+#[multiversx_sc::module]
+pub trait StakingRewardsModule: staking_rewards_events::StakingRewardsEventsModule {
+    fn init_staking_rewards_module(
+        &self,
+        staking_token: EgldOrEsdtTokenIdentifier,
+        accrual_mode: RewardAccrualMode,
+        unbonding_period: u64,
+    ) {
+        require!(staking_token.is_valid(), "Invalid staking token");
+
+        self.rewards_staking_token().set(staking_token);
+        self.reward_accrual_mode().set(accrual_mode);
+        self.unbonding_period().set(unbonding_period);
+    }
+
+    #[payable("*")]
+    #[endpoint(stakeTokens)]
+    fn stake_tokens(&self) {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            payment_token == self.rewards_staking_token().get(),
+            "Invalid payment token"
+        );
+        require!(payment_amount > 0, "Cannot stake zero tokens");
+
+        let caller = self.blockchain().get_caller();
+        self.increase_stake(&caller, &payment_amount);
+        self.rewards_stake_event(&caller, &payment_amount);
+    }
+
+    /// Stops the stake from earning rewards.
+    /// The tokens can be withdrawn with `unbondTokens` once the unbonding period has passed.
+    #[endpoint(unstakeTokens)]
+    fn unstake_tokens(&self, amount: BigUint) {
+        require!(amount > 0, "Cannot unstake zero tokens");
+        let caller = self.blockchain().get_caller();
+        let staked_amount_mapper = self.rewards_staked_amount(&caller);
+        require!(amount <= staked_amount_mapper.get(), "Not enough stake");
+
+        self.update_user_rewards(&caller);
+        staked_amount_mapper.update(|staked| *staked -= &amount);
+        self.rewards_total_staked()
+            .update(|total| *total -= &amount);
+
+        let unlock_point = self.current_accrual_point() + self.unbonding_period().get();
+        self.unbonding_stakes(&caller).update(|unbonding_stakes| {
+            unbonding_stakes.push(UnbondingStake {
+                amount: amount.clone(),
+                unlock_point,
+            })
+        });
+        self.rewards_unstake_event(&caller, unlock_point, &amount);
+    }
+
+    /// Sends the caller all unstaked tokens that finished unbonding.
+    #[endpoint(unbondTokens)]
+    fn unbond_tokens(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let current_point = self.current_accrual_point();
+
+        let mut unbonded_amount = BigUint::zero();
+        let mut still_unbonding = ManagedVec::<Self::Api, UnbondingStake<Self::Api>>::new();
+        for unbonding_stake in self.unbonding_stakes(&caller).get().iter() {
+            if unbonding_stake.unlock_point <= current_point {
+                unbonded_amount += &unbonding_stake.amount;
+            } else {
+                still_unbonding.push(unbonding_stake);
+            }
+        }
+        require!(unbonded_amount > 0, "Nothing to unbond");
+
+        if still_unbonding.is_empty() {
+            self.unbonding_stakes(&caller).clear();
+        } else {
+            self.unbonding_stakes(&caller).set(still_unbonding);
+        }
+
+        self.rewards_unbond_event(&caller, &unbonded_amount);
+        let staking_token = self.rewards_staking_token().get();
+        self.send()
+            .direct(&caller, &staking_token, 0, &unbonded_amount);
+
+        unbonded_amount
+    }
+
+    /// Sends the caller all their accrued rewards, in all reward tokens.
+    #[endpoint(claimRewards)]
+    fn claim_rewards(&self) {
+        let caller = self.blockchain().get_caller();
+        self.update_user_rewards(&caller);
+
+        for reward_token in self.reward_tokens().iter() {
+            let unclaimed_mapper = self.user_unclaimed_rewards(&caller, &reward_token);
+            let amount = unclaimed_mapper.take();
+            if amount == 0 {
+                continue;
+            }
+
+            self.rewards_claim_event(&caller, &reward_token, &amount);
+            self.send().direct(&caller, &reward_token, 0, &amount);
+        }
+    }
+
+    /// Adds the caller's rewards in the staking token to their stake. Rewards in other tokens are left untouched.
+    #[endpoint(compoundRewards)]
+    fn compound_rewards(&self) -> BigUint {
+        let staking_token = self.rewards_staking_token().get();
+        require!(
+            self.reward_tokens().contains(&staking_token),
+            "Staking token is not a reward token"
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.update_user_rewards(&caller);
+        let amount = self.user_unclaimed_rewards(&caller, &staking_token).take();
+        require!(amount > 0, "No rewards to compound");
+
+        self.increase_stake(&caller, &amount);
+        self.rewards_compound_event(&caller, &amount);
+
+        amount
+    }
+
+    /// Reward rates are given per block or per second, depending on the accrual mode.
+    #[only_owner]
+    #[endpoint(addRewardToken)]
+    fn add_reward_token(&self, reward_token: EgldOrEsdtTokenIdentifier, reward_rate: BigUint) {
+        require!(reward_token.is_valid(), "Invalid reward token");
+        require!(
+            self.reward_tokens().insert(reward_token.clone()),
+            "Reward token already added"
+        );
+
+        self.reward_rate_change_event(&reward_token, &reward_rate);
+        self.reward_pool(&reward_token)
+            .set(RewardPool::new(reward_rate, self.current_accrual_point()));
+    }
+
+    /// Rewards accrued up to the current block use the old rate.
+    #[only_owner]
+    #[endpoint(setRewardRate)]
+    fn set_reward_rate(&self, reward_token: EgldOrEsdtTokenIdentifier, reward_rate: BigUint) {
+        let mut pool = self.get_accrued_reward_pool(&reward_token);
+        pool.reward_rate = reward_rate;
+
+        self.reward_rate_change_event(&reward_token, &pool.reward_rate);
+        self.reward_pool(&reward_token).set(pool);
+    }
+
+    /// Adds the payment to the rewards that can be distributed.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(fundRewards)]
+    fn fund_rewards(&self) {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(payment_amount > 0, "Cannot fund zero rewards");
+
+        let mut pool = self.get_accrued_reward_pool(&payment_token);
+        pool.undistributed_amount += &payment_amount;
+
+        self.rewards_fund_event(&payment_token, &payment_amount);
+        self.reward_pool(&payment_token).set(pool);
+    }
+
+    /// Rewards of a user that can be claimed right now, in all reward tokens.
+    #[view(getPendingRewards)]
+    fn get_pending_rewards(
+        &self,
+        user: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let current_point = self.current_accrual_point();
+        let total_staked = self.rewards_total_staked().get();
+        let staked_amount = self.rewards_staked_amount(&user).get();
+
+        let mut result = MultiValueEncoded::new();
+        for reward_token in self.reward_tokens().iter() {
+            let mut pool = self.reward_pool(&reward_token).get();
+            let _ = pool.accrue(current_point, &total_staked);
+            let paid_reward_per_share = self.user_reward_per_share(&user, &reward_token).get();
+            let pending = self.user_unclaimed_rewards(&user, &reward_token).get()
+                + pool.earned_rewards(&staked_amount, &paid_reward_per_share);
+            result.push((reward_token, pending).into());
+        }
+        result
+    }
+
+    #[view(getRewardPool)]
+    fn get_reward_pool(&self, reward_token: EgldOrEsdtTokenIdentifier) -> RewardPool<Self::Api> {
+        let pool_mapper = self.reward_pool(&reward_token);
+        require!(!pool_mapper.is_empty(), "Unknown reward token");
+        pool_mapper.get()
+    }
+
+    fn get_accrued_reward_pool(
+        &self,
+        reward_token: &EgldOrEsdtTokenIdentifier,
+    ) -> RewardPool<Self::Api> {
+        let mut pool = self.get_reward_pool(reward_token.clone());
+        let _ = pool.accrue(
+            self.current_accrual_point(),
+            &self.rewards_total_staked().get(),
+        );
+        pool
+    }
+
+    /// Accrues all reward pools and moves the user's share into their unclaimed rewards.
+    ///
+    /// Needs to be called before every change of the user's stake.
+    fn update_user_rewards(&self, user: &ManagedAddress) {
+        let current_point = self.current_accrual_point();
+        let total_staked = self.rewards_total_staked().get();
+        let staked_amount = self.rewards_staked_amount(user).get();
+
+        for reward_token in self.reward_tokens().iter() {
+            let pool_mapper = self.reward_pool(&reward_token);
+            let mut pool = pool_mapper.get();
+            let _ = pool.accrue(current_point, &total_staked);
+
+            let paid_reward_per_share_mapper = self.user_reward_per_share(user, &reward_token);
+            let earned = pool.earned_rewards(&staked_amount, &paid_reward_per_share_mapper.get());
+            if earned > 0 {
+                self.user_unclaimed_rewards(user, &reward_token)
+                    .update(|unclaimed| *unclaimed += earned);
+            }
+            paid_reward_per_share_mapper.set(&pool.reward_per_share);
+            pool_mapper.set(pool);
+        }
+    }
+
+    fn increase_stake(&self, user: &ManagedAddress, amount: &BigUint) {
+        self.update_user_rewards(user);
+        self.rewards_staked_amount(user)
+            .update(|staked| *staked += amount);
+        self.rewards_total_staked().update(|total| *total += amount);
+    }
+
+    fn current_accrual_point(&self) -> u64 {
+        match self.reward_accrual_mode().get() {
+            RewardAccrualMode::PerBlock => self.blockchain().get_block_nonce(),
+            RewardAccrualMode::PerSecond => self.blockchain().get_block_timestamp(),
+        }
+    }
+
+    #[view(getRewardsStakingToken)]
+    #[storage_mapper("staking_rewards_module:stakingToken")]
+    fn rewards_staking_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getRewardAccrualMode)]
+    #[storage_mapper("staking_rewards_module:accrualMode")]
+    fn reward_accrual_mode(&self) -> SingleValueMapper<RewardAccrualMode>;
+
+    /// Measured in blocks or seconds, depending on the accrual mode.
+    #[view(getUnbondingPeriod)]
+    #[storage_mapper("staking_rewards_module:unbondingPeriod")]
+    fn unbonding_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getRewardsStakedAmount)]
+    #[storage_mapper("staking_rewards_module:stakedAmount")]
+    fn rewards_staked_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getRewardsTotalStaked)]
+    #[storage_mapper("staking_rewards_module:totalStaked")]
+    fn rewards_total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUnbondingStakes)]
+    #[storage_mapper("staking_rewards_module:unbondingStakes")]
+    fn unbonding_stakes(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<UnbondingStake<Self::Api>>>;
+
+    #[view(getRewardTokens)]
+    #[storage_mapper("staking_rewards_module:rewardTokens")]
+    fn reward_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("staking_rewards_module:rewardPool")]
+    fn reward_pool(
+        &self,
+        reward_token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<RewardPool<Self::Api>>;
+
+    #[storage_mapper("staking_rewards_module:userRewardPerShare")]
+    fn user_reward_per_share(
+        &self,
+        user: &ManagedAddress,
+        reward_token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking_rewards_module:userUnclaimedRewards")]
+    fn user_unclaimed_rewards(
+        &self,
+        user: &ManagedAddress,
+        reward_token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,43 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait StakingRewardsEventsModule {
+    #[event("rewardsStake")]
+    fn rewards_stake_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("rewardsUnstake")]
+    fn rewards_unstake_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] unlock_point: u64,
+        amount: &BigUint,
+    );
+
+    #[event("rewardsUnbond")]
+    fn rewards_unbond_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("rewardsClaim")]
+    fn rewards_claim_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] reward_token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("rewardsCompound")]
+    fn rewards_compound_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("rewardsFund")]
+    fn rewards_fund_event(
+        &self,
+        #[indexed] reward_token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("rewardRateChange")]
+    fn reward_rate_change_event(
+        &self,
+        #[indexed] reward_token: &EgldOrEsdtTokenIdentifier,
+        reward_rate: &BigUint,
+    );
+}
@@ -0,0 +1,97 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Scaling factor for the reward per share, so that small rewards spread over large stakes are not lost.
+pub const REWARD_PER_SHARE_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// Decides what the reward rates and the unbonding period are measured in.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug,
+)]
+pub enum RewardAccrualMode {
+    PerBlock,
+    PerSecond,
+}
+
+/// Reward-per-share accounting for one reward token.
+///
+/// Rewards accrue at `reward_rate` per block/second, shared between stakers proportionally to their stake,
+/// but only as long as there are funded rewards left to distribute.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Debug,
+)]
+pub struct RewardPool<M: ManagedTypeApi> {
+    pub reward_rate: BigUint<M>,
+    /// Total rewards distributed for each staked unit, since the pool was created,
+    /// scaled by `REWARD_PER_SHARE_PRECISION`.
+    pub reward_per_share: BigUint<M>,
+    /// Block nonce or timestamp of the last accrual.
+    pub last_update: u64,
+    /// Funded rewards that have not accrued yet.
+    pub undistributed_amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> RewardPool<M> {
+    pub fn new(reward_rate: BigUint<M>, current_point: u64) -> Self {
+        RewardPool {
+            reward_rate,
+            reward_per_share: BigUint::zero(),
+            last_update: current_point,
+            undistributed_amount: BigUint::zero(),
+        }
+    }
+
+    /// Distributes the rewards accrued since the last update and returns their amount.
+    ///
+    /// Nothing is distributed while nobody is staking, those rewards remain in the pool.
+    pub fn accrue(&mut self, current_point: u64, total_staked: &BigUint<M>) -> BigUint<M> {
+        if current_point <= self.last_update {
+            return BigUint::zero();
+        }
+        let elapsed = current_point - self.last_update;
+        self.last_update = current_point;
+        if *total_staked == 0 {
+            return BigUint::zero();
+        }
+
+        let mut rewards = &self.reward_rate * elapsed;
+        if rewards > self.undistributed_amount {
+            rewards = self.undistributed_amount.clone();
+        }
+        self.undistributed_amount -= &rewards;
+        self.reward_per_share += &rewards * REWARD_PER_SHARE_PRECISION / total_staked;
+        rewards
+    }
+
+    /// Rewards earned by a stake since the reward per share was last recorded for it, rounded down.
+    pub fn earned_rewards(
+        &self,
+        staked_amount: &BigUint<M>,
+        paid_reward_per_share: &BigUint<M>,
+    ) -> BigUint<M> {
+        if self.reward_per_share <= *paid_reward_per_share {
+            return BigUint::zero();
+        }
+        let reward_per_share_delta = &self.reward_per_share - paid_reward_per_share;
+        staked_amount * &reward_per_share_delta / REWARD_PER_SHARE_PRECISION
+    }
+}
+
+/// Unstaked tokens, waiting for the unbonding period to pass.
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+)]
+pub struct UnbondingStake<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    /// Block nonce or timestamp when the tokens can be withdrawn.
+    pub unlock_point: u64,
+}
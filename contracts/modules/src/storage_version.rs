@@ -0,0 +1,141 @@
+multiversx_sc::imports!();
+
+use crate::ongoing_operation::{LoopOp, STOP_OP};
+
+/// Keeps track of the version of the storage layout of a contract
+/// and migrates stored data from one version to the next, one step at a time.
+///
+/// A migration from version `n` to version `n + 1` is described by a single step function,
+/// which is called repeatedly with `n` and the progress saved so far, until it returns `STOP_OP`.
+/// The stored version is only bumped once a step has fully completed,
+/// so running a migration again after it has finished does nothing.
+///
+/// Migrations are gas-bounded: if the contract runs low on gas,
+/// the progress of the current step is saved and the migration can be resumed in a later call.
+/// Because upgrades run the `#[init]` function again, calling `migrate_storage` from there
+/// is enough to have the data migrated automatically on upgrade.
+///
+/// A contract without a stored version is considered to be at version 0.
+/// This covers contracts upgraded from code that predates the module,
+/// but also fresh deployments, which run all the steps over their empty storage.
+/// Steps should therefore be cheap when there is nothing to migrate.
+///
+/// The progress of a step is kept under its own storage key,
+/// so migrations can run alongside the `ongoing_operation` module.
+#[multiversx_sc::module]
+pub trait StorageVersionModule {
+    /// Runs the migration steps from the current storage version up to `target_version`.
+    ///
+    /// `step` is called with the version being migrated from and the progress of that step.
+    /// The progress starts from its default value for each step and is saved between calls
+    /// whenever the operation is interrupted.
+    ///
+    /// # Usage example: Migrating to version 2
+    /// ```
+    /// # use multiversx_sc::types::OperationCompletionStatus;
+    /// # use multiversx_sc_modules::{
+    /// #     ongoing_operation::{CONTINUE_OP, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, STOP_OP},
+    /// #     storage_version,
+    /// # };
+    /// # pub trait ExampleContract: multiversx_sc::contract_base::ContractBase + storage_version::StorageVersionModule
+    /// # {
+    /// fn migrate(&self) -> OperationCompletionStatus {
+    ///     self.migrate_storage(2, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS, |version, index: &mut usize| {
+    ///         match version {
+    ///             0 => {
+    ///                 // migrate item `index`, return STOP_OP after the last one
+    ///                 *index += 1;
+    ///                 if *index == 10 { STOP_OP } else { CONTINUE_OP }
+    ///             },
+    ///             _ => STOP_OP,
+    ///         }
+    ///     })
+    /// }
+    /// # }
+    /// ```
+    fn migrate_storage<P, Step>(
+        &self,
+        target_version: u32,
+        min_gas_to_save_progress: u64,
+        mut step: Step,
+    ) -> OperationCompletionStatus
+    where
+        P: TopEncode + TopDecode + Default,
+        Step: FnMut(u32, &mut P) -> LoopOp,
+    {
+        let mut current_version = self.storage_version().get();
+        require!(
+            current_version <= target_version,
+            "Storage version is newer than the target version"
+        );
+
+        let mut progress = self.load_migration_progress::<P>();
+        let mut gas_per_iteration = 0;
+        let mut gas_before = self.blockchain().get_gas_left();
+        while current_version < target_version {
+            if step(current_version, &mut progress) == STOP_OP {
+                self.storage_migrated_event(current_version, current_version + 1);
+                current_version += 1;
+                self.storage_version().set(current_version);
+                progress = P::default();
+            }
+
+            let gas_after = self.blockchain().get_gas_left();
+            let current_iteration_cost = gas_before - gas_after;
+            if current_iteration_cost > gas_per_iteration {
+                gas_per_iteration = current_iteration_cost;
+            }
+
+            if gas_after <= min_gas_to_save_progress + gas_per_iteration {
+                self.save_migration_progress(&progress);
+                return OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            }
+
+            gas_before = gas_after;
+        }
+
+        self.storage_migration_progress().clear();
+
+        OperationCompletionStatus::Completed
+    }
+
+    /// Guards endpoints that rely on the new storage layout,
+    /// so they cannot be called while a migration is still in progress.
+    fn require_storage_version(&self, version: u32) {
+        require!(
+            self.storage_version().get() == version,
+            "Storage migration in progress"
+        );
+    }
+
+    fn load_migration_progress<P: TopDecode + Default>(&self) -> P {
+        let raw_buffer = self.storage_migration_progress().get();
+        if raw_buffer.is_empty() {
+            return P::default();
+        }
+
+        match P::top_decode(raw_buffer) {
+            Result::Ok(progress) => progress,
+            Result::Err(err) => sc_panic!(err.message_str()),
+        }
+    }
+
+    fn save_migration_progress<P: TopEncode>(&self, progress: &P) {
+        let mut encoded_progress = ManagedBuffer::new();
+        if let Result::Err(err) = progress.top_encode(&mut encoded_progress) {
+            sc_panic!(err.message_str());
+        }
+
+        self.storage_migration_progress().set(&encoded_progress);
+    }
+
+    #[event("storageMigrated")]
+    fn storage_migrated_event(&self, #[indexed] from_version: u32, #[indexed] to_version: u32);
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storage_version:version")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("storage_version:migrationProgress")]
+    fn storage_migration_progress(&self) -> SingleValueMapper<ManagedBuffer>;
+}
@@ -0,0 +1,186 @@
+multiversx_sc::imports!();
+
+pub mod vesting_events;
+pub mod vesting_schedule;
+
+use vesting_schedule::{GrantId, VestingGrant, VestingSchedule};
+
+/// Standard smart contract module for time-based token vesting.
+///
+/// The owner locks tokens in grants, each with its own beneficiary and cliff + linear schedule.
+/// Any token can be vested: EGLD, fungible ESDT, or a specific SFT/meta-ESDT nonce.
+///
+/// It offers:
+/// * endpoints where the owner can create grants and revoke the revocable ones
+/// * an endpoint where beneficiaries claim everything vested so far
+/// * views for grants and for the claimable amount at a given timestamp
+///
+/// Revoking a grant stops its vesting and returns the unvested tokens to the owner.
+/// Tokens already vested remain claimable by the beneficiary.
+#[multiversx_sc::module]
+pub trait VestingModule: vesting_events::VestingEventsModule {
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createVestingGrant)]
+    fn create_vesting_grant_endpoint(
+        &self,
+        beneficiary: ManagedAddress,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        vesting_duration: u64,
+        revocable: bool,
+    ) -> GrantId {
+        let (token_identifier, token_nonce, total_amount) =
+            self.call_value().egld_or_single_esdt().into_tuple();
+        let schedule = VestingSchedule {
+            token_identifier,
+            token_nonce,
+            total_amount,
+            start_timestamp,
+            cliff_duration,
+            vesting_duration,
+        };
+        self.create_vesting_grant(beneficiary, schedule, revocable)
+    }
+
+    /// Registers a new grant. The tokens must already be in the contract.
+    fn create_vesting_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        schedule: VestingSchedule<Self::Api>,
+        revocable: bool,
+    ) -> GrantId {
+        require!(schedule.total_amount > 0, "Cannot vest zero tokens");
+        require!(
+            schedule.cliff_duration <= schedule.vesting_duration,
+            "Cliff cannot be longer than the vesting duration"
+        );
+        require!(
+            schedule
+                .start_timestamp
+                .checked_add(schedule.vesting_duration)
+                .is_some(),
+            "Vesting schedule ends too late"
+        );
+
+        let grant_id = self.last_vesting_grant_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.vesting_grant_created_event(grant_id, &beneficiary, &schedule);
+
+        let _ = self
+            .beneficiary_vesting_grants(&beneficiary)
+            .insert(grant_id);
+        self.vesting_grant(grant_id).set(VestingGrant {
+            beneficiary,
+            schedule,
+            revocable,
+            claimed_amount: BigUint::zero(),
+            revoked_timestamp: None,
+        });
+
+        grant_id
+    }
+
+    #[only_owner]
+    #[endpoint(revokeVestingGrant)]
+    fn revoke_vesting_grant(&self, grant_id: GrantId) {
+        let mut grant = self.get_existing_vesting_grant(grant_id);
+        require!(grant.revocable, "Grant is not revocable");
+        require!(!grant.is_revoked(), "Grant already revoked");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        grant.revoked_timestamp = Some(current_timestamp);
+        let unvested_amount =
+            &grant.schedule.total_amount - &grant.vested_amount(current_timestamp);
+
+        self.vesting_grant_revoked_event(grant_id, &grant.beneficiary, &unvested_amount);
+        if unvested_amount > 0 {
+            let caller = self.blockchain().get_caller();
+            self.send().direct(
+                &caller,
+                &grant.schedule.token_identifier,
+                grant.schedule.token_nonce,
+                &unvested_amount,
+            );
+        }
+
+        self.vesting_grant(grant_id).set(grant);
+    }
+
+    /// Sends the caller everything vested so far, from all their grants.
+    /// Fully settled grants are removed from the list of the caller's grants.
+    #[endpoint(claimVested)]
+    fn claim_vested(&self) {
+        let caller = self.blockchain().get_caller();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        let mut settled_grants = ManagedVec::<Self::Api, GrantId>::new();
+        for grant_id in self.beneficiary_vesting_grants(&caller).iter() {
+            let mut grant = self.vesting_grant(grant_id).get();
+            let claimable_amount = grant.claimable_amount(current_timestamp);
+            if claimable_amount > 0 {
+                grant.claimed_amount += &claimable_amount;
+                self.vesting_claimed_event(grant_id, &caller, &claimable_amount);
+                self.send().direct(
+                    &caller,
+                    &grant.schedule.token_identifier,
+                    grant.schedule.token_nonce,
+                    &claimable_amount,
+                );
+            }
+
+            let fully_vested =
+                grant.is_revoked() || current_timestamp >= grant.schedule.end_timestamp();
+            if fully_vested && grant.claimable_amount(current_timestamp) == 0 {
+                settled_grants.push(grant_id);
+            }
+            self.vesting_grant(grant_id).set(grant);
+        }
+
+        let mut beneficiary_grants = self.beneficiary_vesting_grants(&caller);
+        for grant_id in settled_grants.iter() {
+            let _ = beneficiary_grants.swap_remove(&grant_id);
+        }
+    }
+
+    /// Claimable amount of a grant at the given timestamp. Defaults to the current block timestamp.
+    #[view(getClaimableAmount)]
+    fn get_claimable_amount(&self, grant_id: GrantId, timestamp: OptionalValue<u64>) -> BigUint {
+        let timestamp = timestamp
+            .into_option()
+            .unwrap_or_else(|| self.blockchain().get_block_timestamp());
+        self.get_existing_vesting_grant(grant_id)
+            .claimable_amount(timestamp)
+    }
+
+    #[view(getVestingGrant)]
+    fn get_existing_vesting_grant(&self, grant_id: GrantId) -> VestingGrant<Self::Api> {
+        let grant_mapper = self.vesting_grant(grant_id);
+        require!(!grant_mapper.is_empty(), "Vesting grant not found");
+        grant_mapper.get()
+    }
+
+    #[view(getBeneficiaryVestingGrants)]
+    fn get_beneficiary_vesting_grants(
+        &self,
+        beneficiary: ManagedAddress,
+    ) -> MultiValueEncoded<GrantId> {
+        self.beneficiary_vesting_grants(&beneficiary)
+            .iter()
+            .collect()
+    }
+
+    #[storage_mapper("vesting_module:last_grant_id")]
+    fn last_vesting_grant_id(&self) -> SingleValueMapper<GrantId>;
+
+    #[storage_mapper("vesting_module:grant")]
+    fn vesting_grant(&self, grant_id: GrantId) -> SingleValueMapper<VestingGrant<Self::Api>>;
+
+    #[storage_mapper("vesting_module:beneficiary_grants")]
+    fn beneficiary_vesting_grants(
+        &self,
+        beneficiary: &ManagedAddress,
+    ) -> UnorderedSetMapper<GrantId>;
+}
@@ -0,0 +1,30 @@
+multiversx_sc::imports!();
+
+use super::vesting_schedule::{GrantId, VestingSchedule};
+
+#[multiversx_sc::module]
+pub trait VestingEventsModule {
+    #[event("vestingGrantCreated")]
+    fn vesting_grant_created_event(
+        &self,
+        #[indexed] grant_id: GrantId,
+        #[indexed] beneficiary: &ManagedAddress,
+        schedule: &VestingSchedule<Self::Api>,
+    );
+
+    #[event("vestingClaimed")]
+    fn vesting_claimed_event(
+        &self,
+        #[indexed] grant_id: GrantId,
+        #[indexed] beneficiary: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("vestingGrantRevoked")]
+    fn vesting_grant_revoked_event(
+        &self,
+        #[indexed] grant_id: GrantId,
+        #[indexed] beneficiary: &ManagedAddress,
+        unvested_amount: &BigUint,
+    );
+}
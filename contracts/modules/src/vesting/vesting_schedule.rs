@@ -0,0 +1,84 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type GrantId = u64;
+
+/// Cliff + linear vesting schedule.
+///
+/// Nothing is vested before `start_timestamp + cliff_duration`.
+/// After the cliff, the vested amount grows linearly from `start_timestamp`,
+/// until everything is vested at `start_timestamp + vesting_duration`.
+///
+/// The end timestamp must fit in a `u64`, `VestingModule` checks it when creating grants.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Debug,
+)]
+pub struct VestingSchedule<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub total_amount: BigUint<M>,
+    pub start_timestamp: u64,
+    pub cliff_duration: u64,
+    pub vesting_duration: u64,
+}
+
+impl<M: ManagedTypeApi> VestingSchedule<M> {
+    pub fn cliff_timestamp(&self) -> u64 {
+        self.start_timestamp + self.cliff_duration
+    }
+
+    pub fn end_timestamp(&self) -> u64 {
+        self.start_timestamp + self.vesting_duration
+    }
+
+    /// Amount vested at the given timestamp, rounded down.
+    pub fn vested_amount(&self, timestamp: u64) -> BigUint<M> {
+        if timestamp < self.cliff_timestamp() {
+            return BigUint::zero();
+        }
+        if timestamp >= self.end_timestamp() {
+            return self.total_amount.clone();
+        }
+
+        let elapsed = timestamp - self.start_timestamp;
+        &self.total_amount * elapsed / self.vesting_duration
+    }
+}
+
+/// A vesting schedule assigned to a beneficiary, together with its claim accounting.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Debug,
+)]
+pub struct VestingGrant<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub schedule: VestingSchedule<M>,
+    pub revocable: bool,
+    pub claimed_amount: BigUint<M>,
+    /// Vesting stops at this timestamp, if the grant was revoked.
+    pub revoked_timestamp: Option<u64>,
+}
+
+impl<M: ManagedTypeApi> VestingGrant<M> {
+    /// Amount vested at the given timestamp, taking revocation into account.
+    pub fn vested_amount(&self, timestamp: u64) -> BigUint<M> {
+        let effective_timestamp = match self.revoked_timestamp {
+            Some(revoked_timestamp) => core::cmp::min(timestamp, revoked_timestamp),
+            None => timestamp,
+        };
+        self.schedule.vested_amount(effective_timestamp)
+    }
+
+    /// Amount vested at the given timestamp that has not been claimed yet.
+    pub fn claimable_amount(&self, timestamp: u64) -> BigUint<M> {
+        let vested = self.vested_amount(timestamp);
+        if vested > self.claimed_amount {
+            vested - &self.claimed_amount
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_timestamp.is_some()
+    }
+}
@@ -41,6 +41,7 @@ pub struct EndpointAbi {
     pub rust_method_name: &'static str,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub paginated: bool,
     pub labels: &'static [&'static str],
     pub endpoint_type: EndpointTypeAbi,
     pub mutability: EndpointMutabilityAbi,
@@ -14,3 +14,12 @@ pub const SET_USERNAME_FUNC_NAME: &str = "SetUserName";
 pub const MIGRATE_USERNAME_FUNC_NAME: &str = "migrateUserName";
 pub const DELETE_USERNAME_FUNC_NAME: &str = "DeleteUserName";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &str = "upgradeContract";
+pub const SAVE_KEY_VALUE_FUNC_NAME: &str = "SaveKeyValue";
+pub const ESDT_FREEZE_FUNC_NAME: &str = "ESDTFreeze";
+pub const ESDT_UNFREEZE_FUNC_NAME: &str = "ESDTUnFreeze";
+pub const ESDT_WIPE_FUNC_NAME: &str = "ESDTWipe";
+pub const ESDT_SET_LIMITED_TRANSFER_FUNC_NAME: &str = "ESDTSetLimitedTransfer";
+pub const ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME: &str = "ESDTUnSetLimitedTransfer";
+pub const SET_GUARDIAN_FUNC_NAME: &str = "SetGuardian";
+pub const GUARD_ACCOUNT_FUNC_NAME: &str = "GuardAccount";
+pub const UNGUARD_ACCOUNT_FUNC_NAME: &str = "UnGuardAccount";
@@ -2,7 +2,9 @@ use super::{ErrorApi, ManagedTypeApi, SendApi, StorageReadApi, StorageWriteApi};
 
 /// Provided for convenience.
 /// Designed to be used in any types that send tokens or calls.
-pub trait CallTypeApi: SendApi + ManagedTypeApi + ErrorApi {}
+///
+/// Also requires storage writes, to flush the storage cache before any outgoing call.
+pub trait CallTypeApi: SendApi + StorageWriteApi + ManagedTypeApi + ErrorApi {}
 
 /// Provided for convenience.
 /// Designed to be used in storage mappers.
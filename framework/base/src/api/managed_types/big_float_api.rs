@@ -40,4 +40,9 @@ pub trait BigFloatApiImpl: HandleTypeInfo {
 
     fn bf_get_const_pi(&self, dest: Self::BigFloatHandle);
     fn bf_get_const_e(&self, dest: Self::BigFloatHandle);
+
+    /// Releases the handle, the big float can no longer be used afterwards.
+    ///
+    /// Only the Rust VM reclaims handles, so by default it does nothing.
+    fn bf_drop(&self, _handle: Self::BigFloatHandle) {}
 }
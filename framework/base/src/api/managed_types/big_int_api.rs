@@ -61,4 +61,9 @@ pub trait BigIntApiImpl: HandleTypeInfo + ErrorApi {
     fn bi_shl(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, bits: usize);
 
     fn bi_to_string(&self, bi_handle: Self::BigIntHandle, str_handle: Self::ManagedBufferHandle);
+
+    /// Releases the handle, the big integer can no longer be used afterwards.
+    ///
+    /// Only the Rust VM reclaims handles, so by default it does nothing.
+    fn bi_drop(&self, _handle: Self::BigIntHandle) {}
 }
@@ -26,3 +26,8 @@ pub const NEW_HANDLE_START_FROM: RawHandle = -100; // > -100 reserved for APIs
 
 /// Used as a flag. Do not use as a regular handle.
 pub const MANAGED_OPTION_NONE: RawHandle = i32::MAX - 1;
+
+/// Handles reserved for the APIs, as well as the flag values, are never released.
+pub fn is_reserved_handle(handle: RawHandle) -> bool {
+    (NEW_HANDLE_START_FROM < handle && handle < 0) || handle >= MANAGED_OPTION_NONE
+}
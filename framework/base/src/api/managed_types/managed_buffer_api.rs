@@ -63,4 +63,9 @@ pub trait ManagedBufferApiImpl: HandleTypeInfo {
         source_handle: Self::ManagedBufferHandle,
         dest_handle: Self::ManagedBufferHandle,
     );
+
+    /// Releases the handle, the buffer can no longer be used afterwards.
+    ///
+    /// Only the Rust VM reclaims handles, so by default it does nothing.
+    fn mb_drop(&self, _handle: Self::ManagedBufferHandle) {}
 }
@@ -31,4 +31,9 @@ pub trait ManagedMapApiImpl: HandleTypeInfo {
         map_handle: Self::ManagedMapHandle,
         key_handle: Self::ManagedBufferHandle,
     ) -> bool;
+
+    /// Releases the handle, the map can no longer be used afterwards.
+    ///
+    /// Only the Rust VM reclaims handles, so by default it does nothing.
+    fn mm_drop(&self, _map_handle: Self::ManagedMapHandle) {}
 }
@@ -31,4 +31,12 @@ pub trait StaticVarApiImpl {
     fn set_call_value_multi_esdt_handle(&self, handle: RawHandle);
 
     fn get_call_value_multi_esdt_handle(&self) -> RawHandle;
+
+    fn set_storage_cache_map_handle(&self, handle: RawHandle);
+
+    fn get_storage_cache_map_handle(&self) -> RawHandle;
+
+    fn set_storage_cache_dirty_keys_handle(&self, handle: RawHandle);
+
+    fn get_storage_cache_dirty_keys_handle(&self) -> RawHandle;
 }
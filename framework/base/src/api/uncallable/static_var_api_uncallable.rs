@@ -56,4 +56,20 @@ impl StaticVarApiImpl for UncallableApi {
     fn get_call_value_multi_esdt_handle(&self) -> RawHandle {
         unreachable!()
     }
+
+    fn set_storage_cache_map_handle(&self, _handle: RawHandle) {
+        unreachable!()
+    }
+
+    fn get_storage_cache_map_handle(&self) -> RawHandle {
+        unreachable!()
+    }
+
+    fn set_storage_cache_dirty_keys_handle(&self, _handle: RawHandle) {
+        unreachable!()
+    }
+
+    fn get_storage_cache_dirty_keys_handle(&self) -> RawHandle {
+        unreachable!()
+    }
 }
@@ -5,6 +5,7 @@ use crate::{
         const_handles, use_raw_handle, BigIntApiImpl, BlockchainApiImpl, CallTypeApi,
         HandleConstraints, ManagedBufferApiImpl, RawHandle, SendApiImpl, StaticVarApiImpl,
     },
+    storage::storage_cache_flush,
     types::{
         BigUint, CodeMetadata, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer,
         ManagedType, ManagedVec, TokenIdentifier,
@@ -45,6 +46,7 @@ where
     where
         D: Into<ManagedBuffer<A>>,
     {
+        storage_cache_flush::<A>();
         let empty_mb_handle: A::ManagedBufferHandle =
             use_raw_handle(const_handles::MBUF_TEMPORARY_1);
         A::managed_type_impl().mb_overwrite(empty_mb_handle.clone(), &[]);
@@ -66,6 +68,7 @@ where
         endpoint_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> Result<(), &'static [u8]> {
+        storage_cache_flush::<A>();
         A::send_api_impl().transfer_value_execute(
             to.get_handle().get_raw_handle(),
             egld_value.get_handle().get_raw_handle(),
@@ -123,6 +126,7 @@ where
         endpoint_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> Result<(), &'static [u8]> {
+        storage_cache_flush::<A>();
         A::send_api_impl().multi_transfer_esdt_nft_execute(
             to.get_handle().get_raw_handle(),
             payments.get_handle().get_raw_handle(),
@@ -139,6 +143,7 @@ where
         endpoint_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> ! {
+        storage_cache_flush::<A>();
         A::send_api_impl().async_call_raw(
             to.get_handle().get_raw_handle(),
             egld_value.get_handle().get_raw_handle(),
@@ -160,6 +165,7 @@ where
         extra_gas_for_callback: u64,
         serialized_callback_closure_args: &ManagedBuffer<A>,
     ) {
+        storage_cache_flush::<A>();
         A::send_api_impl().create_async_call_raw(
             to.get_handle().get_raw_handle(),
             egld_value.get_handle().get_raw_handle(),
@@ -187,6 +193,7 @@ where
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> (ManagedAddress<A>, ManagedVec<A, ManagedBuffer<A>>) {
+        storage_cache_flush::<A>();
         let code_metadata_handle = const_handles::MBUF_TEMPORARY_1;
         self.load_code_metadata_to_mb(code_metadata, code_metadata_handle);
         let new_address_handle = A::static_var_api_impl().next_handle();
@@ -217,6 +224,7 @@ where
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> (ManagedAddress<A>, ManagedVec<A, ManagedBuffer<A>>) {
+        storage_cache_flush::<A>();
         let code_metadata_handle = const_handles::MBUF_TEMPORARY_1;
         self.load_code_metadata_to_mb(code_metadata, code_metadata_handle);
         let new_address_handle = A::static_var_api_impl().next_handle();
@@ -245,6 +253,7 @@ where
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<A>,
     ) {
+        storage_cache_flush::<A>();
         let code_metadata_handle = const_handles::MBUF_TEMPORARY_1;
         self.load_code_metadata_to_mb(code_metadata, code_metadata_handle);
         A::send_api_impl().upgrade_from_source_contract(
@@ -269,6 +278,7 @@ where
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<A>,
     ) {
+        storage_cache_flush::<A>();
         let code_metadata_handle = const_handles::MBUF_TEMPORARY_1;
         self.load_code_metadata_to_mb(code_metadata, code_metadata_handle);
         A::send_api_impl().upgrade_contract(
@@ -290,6 +300,7 @@ where
         endpoint_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> ManagedVec<A, ManagedBuffer<A>> {
+        storage_cache_flush::<A>();
         let result_handle = A::static_var_api_impl().next_handle();
         A::send_api_impl().execute_on_dest_context_raw(
             gas,
@@ -310,6 +321,7 @@ where
         endpoint_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> ManagedVec<A, ManagedBuffer<A>> {
+        storage_cache_flush::<A>();
         let result_handle = A::static_var_api_impl().next_handle();
         A::send_api_impl().execute_on_same_context_raw(
             gas,
@@ -330,6 +342,7 @@ where
         endpoint_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> ManagedVec<A, ManagedBuffer<A>> {
+        storage_cache_flush::<A>();
         let result_handle = A::static_var_api_impl().next_handle();
         A::send_api_impl().execute_on_dest_context_readonly_raw(
             gas,
@@ -348,6 +361,7 @@ where
         function_name: &ManagedBuffer<A>,
         arg_buffer: &ManagedArgBuffer<A>,
     ) -> ManagedVec<A, ManagedBuffer<A>> {
+        storage_cache_flush::<A>();
         // account-level built-in function, so the destination address is the contract itself
         let own_address_handle: A::ManagedBufferHandle =
             use_raw_handle(const_handles::MBUF_TEMPORARY_1);
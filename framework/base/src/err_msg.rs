@@ -38,6 +38,14 @@ pub const CONTRACT_CALL_ENCODE_ERROR: &[u8] = b"contract call encode error: ";
 pub const VALUE_EXCEEDS_SLICE: &[u8] = b"value exceeds target slice";
 pub const CAST_TO_I64_ERROR: &[u8] = b"cast to i64 error";
 pub const BIG_UINT_EXCEEDS_SLICE: &[u8] = b"big uint as_bytes exceed target slice";
+pub const MANAGED_DECIMAL_SCALE_MISMATCH: &[u8] = b"managed decimal scale mismatch";
+pub const MANAGED_DECIMAL_NEGATIVE: &[u8] = b"managed decimal cannot be negative";
+pub const BIG_FLOAT_LOG_NON_POSITIVE: &[u8] = b"logarithm of non-positive big float";
+pub const BIG_FLOAT_EXP_OUT_OF_RANGE: &[u8] = b"big float exponential out of range";
+pub const BIG_FLOAT_POW_NEGATIVE_BASE: &[u8] = b"fractional power of negative big float";
+pub const BIG_FLOAT_ZERO_ROOT: &[u8] = b"root degree must be positive";
+pub const BIG_FLOAT_EVEN_ROOT_NEGATIVE: &[u8] = b"even root of negative big float";
+pub const BIG_FLOAT_DIVISION_BY_ZERO: &[u8] = b"big float division by zero";
 pub const BIG_UINT_SUB_NEGATIVE: &[u8] = b"cannot subtract because result would be negative";
 
 pub const DESERIALIZATION_INVALID_BYTE: &str = "call data deserialization error: not a valid byte";
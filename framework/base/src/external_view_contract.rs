@@ -39,6 +39,7 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
         rust_method_name: EXTERNAL_VIEW_CONSTRUCTOR_FLAG,
         only_owner: false,
         only_admin: false,
+        paginated: false,
         labels: &[],
         mutability: EndpointMutabilityAbi::Mutable,
         endpoint_type: EndpointTypeAbi::Init,
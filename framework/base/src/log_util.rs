@@ -1,4 +1,6 @@
-use crate::codec::{TopEncode, TopEncodeMulti};
+use crate::codec::{
+    DecodeErrorHandler, TopDecodeInput, TopDecodeMultiInput, TopEncode, TopEncodeMulti,
+};
 
 use crate::{
     api::{ErrorApi, LogApi, LogApiImpl, ManagedTypeApi},
@@ -46,3 +48,17 @@ where
 {
     A::log_api_impl().managed_write_log(topics.get_handle(), data.get_handle());
 }
+
+/// Implemented by the event structs generated for each `#[event]` method,
+/// so that logs emitted by contracts can be decoded back into typed values.
+pub trait ContractEvent: Sized {
+    /// The event identifier, always the first topic of the log.
+    const IDENTIFIER: &'static [u8];
+
+    /// Decodes the event from its topics, without the identifier, and its data.
+    fn decode_event<I, D, H>(topics: I, data: D, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeMultiInput,
+        D: TopDecodeInput,
+        H: DecodeErrorHandler;
+}
@@ -1,6 +1,10 @@
 use core::marker::PhantomData;
 
-use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
+use super::{
+    set_mapper,
+    source::{CurrentStorage, StorageAddress},
+    SetMapper, StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
@@ -8,26 +12,28 @@ use crate::{
         multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
         NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
     },
-    storage::{storage_clear, storage_get, storage_set, StorageKey},
-    types::{ManagedType, MultiValueEncoded},
+    storage::{storage_clear, storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, Page},
 };
 
 const MAPPED_VALUE_IDENTIFIER: &[u8] = b".mapped";
-type Keys<'a, SA, T> = set_mapper::Iter<'a, SA, T>;
+type Keys<'a, SA, T, A> = set_mapper::Iter<'a, SA, T, A>;
 
-pub struct MapMapper<SA, K, V>
+pub struct MapMapper<SA, K, V, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
-    keys_set: SetMapper<SA, K>,
+    keys_set: SetMapper<SA, K, A>,
     _phantom_value: PhantomData<V>,
 }
 
-impl<SA, K, V> StorageMapper<SA> for MapMapper<SA, K, V>
+impl<SA, K, V> StorageMapper<SA> for MapMapper<SA, K, V, CurrentStorage>
 where
     SA: StorageMapperApi,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
@@ -36,6 +42,7 @@ where
     fn new(base_key: StorageKey<SA>) -> Self {
         MapMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
             base_key: base_key.clone(),
             keys_set: SetMapper::<SA, K>::new(base_key),
             _phantom_value: PhantomData,
@@ -43,7 +50,24 @@ where
     }
 }
 
-impl<SA, K, V> StorageClearable for MapMapper<SA, K, V>
+impl<SA, K, V> StorageMapperFromAddress<SA> for MapMapper<SA, K, V, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    V: TopEncode + TopDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        MapMapper {
+            _phantom_api: PhantomData,
+            address: address.clone(),
+            base_key: base_key.clone(),
+            keys_set: SetMapper::new_from_address(address, base_key),
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<SA, K, V> StorageClearable for MapMapper<SA, K, V, CurrentStorage>
 where
     SA: StorageMapperApi,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
@@ -57,9 +81,10 @@ where
     }
 }
 
-impl<SA, K, V> MapMapper<SA, K, V>
+impl<SA, K, V, A> MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
     V: TopEncode + TopDecode,
 {
@@ -71,18 +96,8 @@ where
     }
 
     fn get_mapped_value(&self, key: &K) -> V {
-        storage_get(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref())
-    }
-
-    fn set_mapped_value(&self, key: &K, value: &V) {
-        storage_set(
-            self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref(),
-            &value,
-        );
-    }
-
-    fn clear_mapped_value(&self, key: &K) {
-        storage_clear(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref());
+        self.address
+            .address_storage_get(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref())
     }
 
     /// Returns `true` if the map contains no elements.
@@ -100,6 +115,59 @@ where
         self.keys_set.contains(k)
     }
 
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self, k: &K) -> Option<V> {
+        if self.keys_set.contains(k) {
+            return Some(self.get_mapped_value(k));
+        }
+        None
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    /// The iterator element type is `&'a K`.
+    pub fn keys(&self) -> Keys<SA, K, A> {
+        self.keys_set.iter()
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    /// The iterator element type is `&'a V`.
+    pub fn values(&self) -> Values<SA, K, V, A> {
+        Values::new(self)
+    }
+
+    /// Loads at most `limit` entries, in insertion order.
+    /// The cursor of the first page is `0`, see `QueueMapper::page` for details.
+    pub fn page(&self, cursor: usize, limit: usize) -> Page<SA, MultiValue2<K, V>> {
+        self.keys_set.page_map(cursor, limit, |key| {
+            let value = self.get_mapped_value(&key);
+            MultiValue2::from((key, value))
+        })
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// The iterator element type is `(&'a K, &'a V)`.
+    pub fn iter(&self) -> Iter<SA, K, V, A> {
+        Iter::new(self)
+    }
+}
+
+impl<SA, K, V> MapMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    V: TopEncode + TopDecode,
+{
+    fn set_mapped_value(&self, key: &K, value: &V) {
+        storage_set(
+            self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref(),
+            &value,
+        );
+    }
+
+    fn clear_mapped_value(&self, key: &K) {
+        storage_clear(self.build_named_key(MAPPED_VALUE_IDENTIFIER, key).as_ref());
+    }
+
     /// Gets the given key's corresponding entry in the map for in-place manipulation.
     pub fn entry(&mut self, key: K) -> Entry<'_, SA, K, V> {
         if self.contains_key(&key) {
@@ -117,14 +185,6 @@ where
         }
     }
 
-    /// Gets a reference to the value in the entry.
-    pub fn get(&self, k: &K) -> Option<V> {
-        if self.keys_set.contains(k) {
-            return Some(self.get_mapped_value(k));
-        }
-        None
-    }
-
     /// Sets the value of the entry, and returns the entry's old value.
     pub fn insert(&mut self, k: K, v: V) -> Option<V> {
         let old_value = self.get(&k);
@@ -142,58 +202,43 @@ where
         }
         None
     }
-
-    /// An iterator visiting all keys in arbitrary order.
-    /// The iterator element type is `&'a K`.
-    pub fn keys(&self) -> Keys<SA, K> {
-        self.keys_set.iter()
-    }
-
-    /// An iterator visiting all values in arbitrary order.
-    /// The iterator element type is `&'a V`.
-    pub fn values(&self) -> Values<SA, K, V> {
-        Values::new(self)
-    }
-
-    /// An iterator visiting all key-value pairs in arbitrary order.
-    /// The iterator element type is `(&'a K, &'a V)`.
-    pub fn iter(&self) -> Iter<SA, K, V> {
-        Iter::new(self)
-    }
 }
 
-impl<'a, SA, K, V> IntoIterator for &'a MapMapper<SA, K, V>
+impl<'a, SA, K, V, A> IntoIterator for &'a MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
     V: TopEncode + TopDecode,
 {
     type Item = (K, V);
 
-    type IntoIter = Iter<'a, SA, K, V>;
+    type IntoIter = Iter<'a, SA, K, V, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-pub struct Iter<'a, SA, K, V>
+pub struct Iter<'a, SA, K, V, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    key_iter: Keys<'a, SA, K>,
-    hash_map: &'a MapMapper<SA, K, V>,
+    key_iter: Keys<'a, SA, K, A>,
+    hash_map: &'a MapMapper<SA, K, V, A>,
 }
 
-impl<'a, SA, K, V> Iter<'a, SA, K, V>
+impl<'a, SA, K, V, A> Iter<'a, SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    fn new(hash_map: &'a MapMapper<SA, K, V>) -> Iter<'a, SA, K, V> {
+    fn new(hash_map: &'a MapMapper<SA, K, V, A>) -> Iter<'a, SA, K, V, A> {
         Iter {
             key_iter: hash_map.keys(),
             hash_map,
@@ -201,9 +246,10 @@ where
     }
 }
 
-impl<'a, SA, K, V> Iterator for Iter<'a, SA, K, V>
+impl<'a, SA, K, V, A> Iterator for Iter<'a, SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
@@ -219,23 +265,25 @@ where
     }
 }
 
-pub struct Values<'a, SA, K, V>
+pub struct Values<'a, SA, K, V, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    key_iter: Keys<'a, SA, K>,
-    hash_map: &'a MapMapper<SA, K, V>,
+    key_iter: Keys<'a, SA, K, A>,
+    hash_map: &'a MapMapper<SA, K, V, A>,
 }
 
-impl<'a, SA, K, V> Values<'a, SA, K, V>
+impl<'a, SA, K, V, A> Values<'a, SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
-    fn new(hash_map: &'a MapMapper<SA, K, V>) -> Values<'a, SA, K, V> {
+    fn new(hash_map: &'a MapMapper<SA, K, V, A>) -> Values<'a, SA, K, V, A> {
         Values {
             key_iter: hash_map.keys(),
             hash_map,
@@ -243,9 +291,10 @@ where
     }
 }
 
-impl<'a, SA, K, V> Iterator for Values<'a, SA, K, V>
+impl<'a, SA, K, V, A> Iterator for Values<'a, SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
@@ -453,9 +502,10 @@ where
 }
 
 /// Behaves like a MultiResultVec<MultiValue2<K, V>> when an endpoint result.
-impl<SA, K, V> TopEncodeMulti for MapMapper<SA, K, V>
+impl<SA, K, V, A> TopEncodeMulti for MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
@@ -469,18 +519,20 @@ where
     }
 }
 
-impl<SA, K, V> CodecFrom<MapMapper<SA, K, V>> for MultiValueEncoded<SA, MultiValue2<K, V>>
+impl<SA, K, V, A> CodecFrom<MapMapper<SA, K, V, A>> for MultiValueEncoded<SA, MultiValue2<K, V>>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
     V: TopEncode + TopDecode + 'static,
 {
 }
 
 /// Behaves like a MultiResultVec<MultiValue<K, V>> when an endpoint result.
-impl<SA, K, V> TypeAbi for MapMapper<SA, K, V>
+impl<SA, K, V, A> TypeAbi for MapMapper<SA, K, V, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi + 'static,
     V: TopEncode + TopDecode + TypeAbi + 'static,
 {
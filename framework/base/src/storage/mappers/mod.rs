@@ -3,9 +3,11 @@ mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
+mod ordered_map_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
+mod source;
 mod token;
 mod unique_id_mapper;
 mod unordered_set_mapper;
@@ -17,10 +19,12 @@ pub use bi_di_mapper::BiDiMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
-pub use mapper::{StorageClearable, StorageMapper};
+pub use mapper::{StorageClearable, StorageMapper, StorageMapperFromAddress};
+pub use ordered_map_mapper::OrderedMapMapper;
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::{SingleValue, SingleValueMapper};
+pub use source::{CurrentStorage, StorageAddress};
 pub use token::*;
 pub use unique_id_mapper::{UniqueId, UniqueIdMapper};
 pub use unordered_set_mapper::UnorderedSetMapper;
@@ -0,0 +1,928 @@
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use super::{
+    source::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    codec::{
+        self,
+        derive::{TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault},
+        multi_encode_iter_or_handle_err,
+        multi_types::MultiValue2,
+        CodecFrom, DecodeDefault, EncodeDefault, EncodeErrorHandler, NestedDecode, NestedEncode,
+        TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+    },
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded},
+};
+use alloc::vec::Vec;
+
+const NULL_ENTRY: u32 = 0;
+const INFO_IDENTIFIER: &[u8] = b".info";
+const NODE_LINKS_IDENTIFIER: &[u8] = b".node_links";
+const NODE_KEY_IDENTIFIER: &[u8] = b".node_key";
+const VALUE_IDENTIFIER: &[u8] = b".value";
+
+/// The position of a node in the tree.
+///
+/// Kept separately from the key and the value, so that rebalancing does not rewrite them.
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Copy)]
+pub struct OrderedMapNodeLinks {
+    pub left: u32,
+    pub right: u32,
+    pub height: u32,
+}
+
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, PartialEq, Eq, Clone, Copy)]
+pub struct OrderedMapMapperInfo {
+    pub len: u32,
+    pub root: u32,
+    pub new: u32,
+}
+
+impl EncodeDefault for OrderedMapMapperInfo {
+    fn is_default(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl DecodeDefault for OrderedMapMapperInfo {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            root: 0,
+            new: 0,
+        }
+    }
+}
+
+impl OrderedMapMapperInfo {
+    pub fn generate_new_node_id(&mut self) -> u32 {
+        self.new += 1;
+        self.new
+    }
+}
+
+/// A map that keeps its keys sorted.
+///
+/// The entries are stored as an AVL tree, so lookups, insertions, removals,
+/// as well as finding the first, last or closest key all take *O*(log n) storage reads.
+/// Iterating over a range of entries costs *O*(log n) to find its start, then amortized *O*(1) per entry.
+pub struct OrderedMapMapper<SA, K, V, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    address: A,
+    base_key: StorageKey<SA>,
+    _phantom_key: PhantomData<K>,
+    _phantom_value: PhantomData<V>,
+}
+
+impl<SA, K, V> StorageMapper<SA> for OrderedMapMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        OrderedMapMapper {
+            _phantom_api: PhantomData,
+            address: CurrentStorage,
+            base_key,
+            _phantom_key: PhantomData,
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<SA, K, V> StorageMapperFromAddress<SA> for OrderedMapMapper<SA, K, V, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        OrderedMapMapper {
+            _phantom_api: PhantomData,
+            address,
+            base_key,
+            _phantom_key: PhantomData,
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<SA, K, V> StorageClearable for OrderedMapMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn clear(&mut self) {
+        let node_ids: Vec<u32> = NodeIter::new(self, Bound::Unbounded, Bound::Unbounded)
+            .map(|(node_id, _)| node_id)
+            .collect();
+        for node_id in node_ids {
+            self.clear_node(node_id);
+        }
+        self.set_info(OrderedMapMapperInfo::default());
+    }
+}
+
+impl<SA, K, V, A> OrderedMapMapper<SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn build_node_id_named_key(&self, name: &[u8], node_id: u32) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(&node_id);
+        named_key
+    }
+
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn get_info(&self) -> OrderedMapMapperInfo {
+        self.address
+            .address_storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
+    }
+
+    fn get_links(&self, node_id: u32) -> OrderedMapNodeLinks {
+        self.address.address_storage_get(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn get_key(&self, node_id: u32) -> K {
+        self.address.address_storage_get(
+            self.build_node_id_named_key(NODE_KEY_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn get_value(&self, node_id: u32) -> V {
+        self.address.address_storage_get(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn get_entry(&self, node_id: u32) -> Option<(K, V)> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some((self.get_key(node_id), self.get_value(node_id)))
+    }
+
+    fn height(&self, node_id: u32) -> u32 {
+        if node_id == NULL_ENTRY {
+            return 0;
+        }
+        self.get_links(node_id).height
+    }
+
+    fn find_node_id(&self, key: &K) -> u32 {
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let links = self.get_links(node_id);
+            node_id = match key.cmp(&self.get_key(node_id)) {
+                Ordering::Equal => return node_id,
+                Ordering::Less => links.left,
+                Ordering::Greater => links.right,
+            };
+        }
+        NULL_ENTRY
+    }
+
+    /// The node with the smallest key that is either greater than the given key,
+    /// or equal to it, if `or_equal` is set.
+    fn find_closest_above(&self, key: &K, or_equal: bool) -> u32 {
+        let mut result = NULL_ENTRY;
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let links = self.get_links(node_id);
+            let node_key = self.get_key(node_id);
+            if node_key > *key || (or_equal && node_key == *key) {
+                result = node_id;
+                node_id = links.left;
+            } else {
+                node_id = links.right;
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if the map contains no elements.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find_node_id(key) != NULL_ENTRY
+    }
+
+    /// Gets the value associated with the key, if any.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let node_id = self.find_node_id(key);
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some(self.get_value(node_id))
+    }
+
+    /// The entry with the smallest key, or `None` if the map is empty.
+    pub fn first(&self) -> Option<(K, V)> {
+        let mut node_id = self.get_info().root;
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        loop {
+            let left = self.get_links(node_id).left;
+            if left == NULL_ENTRY {
+                return self.get_entry(node_id);
+            }
+            node_id = left;
+        }
+    }
+
+    /// The entry with the largest key, or `None` if the map is empty.
+    pub fn last(&self) -> Option<(K, V)> {
+        let mut node_id = self.get_info().root;
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        loop {
+            let right = self.get_links(node_id).right;
+            if right == NULL_ENTRY {
+                return self.get_entry(node_id);
+            }
+            node_id = right;
+        }
+    }
+
+    /// The first entry with a key greater than or equal to the given key.
+    pub fn lower_bound(&self, key: &K) -> Option<(K, V)> {
+        self.get_entry(self.find_closest_above(key, true))
+    }
+
+    /// The first entry with a key strictly greater than the given key.
+    pub fn upper_bound(&self, key: &K) -> Option<(K, V)> {
+        self.get_entry(self.find_closest_above(key, false))
+    }
+
+    /// An iterator over the entries with keys in the given range, in ascending key order.
+    ///
+    /// ```ignore
+    /// for (price, order_id) in self.orders_by_price().range(min_price..=max_price) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn range<R>(&self, range: R) -> Iter<SA, K, V, A>
+    where
+        R: RangeBounds<K>,
+        K: Clone,
+    {
+        Iter {
+            node_iter: NodeIter::new(
+                self,
+                range.start_bound().cloned(),
+                range.end_bound().cloned(),
+            ),
+        }
+    }
+
+    /// An iterator visiting all keys in ascending order.
+    pub fn keys(&self) -> Keys<SA, K, V, A> {
+        Keys {
+            node_iter: NodeIter::new(self, Bound::Unbounded, Bound::Unbounded),
+        }
+    }
+
+    /// An iterator visiting all values, in the ascending order of their keys.
+    pub fn values(&self) -> Values<SA, K, V, A> {
+        Values {
+            node_iter: NodeIter::new(self, Bound::Unbounded, Bound::Unbounded),
+        }
+    }
+
+    /// An iterator visiting all key-value pairs in ascending key order.
+    pub fn iter(&self) -> Iter<SA, K, V, A> {
+        Iter {
+            node_iter: NodeIter::new(self, Bound::Unbounded, Bound::Unbounded),
+        }
+    }
+
+    /// Runs several checks in order to verify that the keys are sorted,
+    /// the tree is balanced, the stored heights are correct
+    /// and the number of items in the map is correct.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        let mut node_ids = Vec::new();
+        if self
+            .check_subtree(info.root, None, None, &mut node_ids)
+            .is_none()
+        {
+            return false;
+        }
+        if node_ids.len() != info.len as usize {
+            return false;
+        }
+
+        // node IDs should be unique and already generated
+        node_ids.sort_unstable();
+        node_ids.dedup();
+        if node_ids.len() != info.len as usize {
+            return false;
+        }
+        node_ids.iter().all(|node_id| *node_id <= info.new)
+    }
+
+    /// Returns the height of a valid subtree, whose keys are all strictly between the given bounds.
+    fn check_subtree(
+        &self,
+        node_id: u32,
+        lower: Option<&K>,
+        upper: Option<&K>,
+        node_ids: &mut Vec<u32>,
+    ) -> Option<u32> {
+        if node_id == NULL_ENTRY {
+            return Some(0);
+        }
+
+        let key = self.get_key(node_id);
+        if let Some(lower) = lower {
+            if key <= *lower {
+                return None;
+            }
+        }
+        if let Some(upper) = upper {
+            if key >= *upper {
+                return None;
+            }
+        }
+        node_ids.push(node_id);
+
+        let links = self.get_links(node_id);
+        let left_height = self.check_subtree(links.left, lower, Some(&key), node_ids)?;
+        let right_height = self.check_subtree(links.right, Some(&key), upper, node_ids)?;
+        if left_height.abs_diff(right_height) > 1 {
+            return None;
+        }
+        let height = 1 + left_height.max(right_height);
+        if links.height != height {
+            return None;
+        }
+        Some(height)
+    }
+}
+
+impl<SA, K, V> OrderedMapMapper<SA, K, V, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn set_info(&mut self, value: OrderedMapMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+    }
+
+    fn set_links(&mut self, node_id: u32, links: OrderedMapNodeLinks) {
+        storage_set(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+            &links,
+        );
+    }
+
+    fn set_key(&mut self, node_id: u32, key: &K) {
+        storage_set(
+            self.build_node_id_named_key(NODE_KEY_IDENTIFIER, node_id)
+                .as_ref(),
+            key,
+        );
+    }
+
+    fn set_value(&mut self, node_id: u32, value: &V) {
+        storage_set(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+            value,
+        );
+    }
+
+    fn clear_node(&mut self, node_id: u32) {
+        for name in [NODE_LINKS_IDENTIFIER, NODE_KEY_IDENTIFIER, VALUE_IDENTIFIER] {
+            storage_set(
+                self.build_node_id_named_key(name, node_id).as_ref(),
+                &codec::Empty,
+            );
+        }
+    }
+
+    fn rotate_left(&mut self, node_id: u32) -> u32 {
+        let mut links = self.get_links(node_id);
+        let pivot_id = links.right;
+        let mut pivot_links = self.get_links(pivot_id);
+
+        links.right = pivot_links.left;
+        links.height = 1 + self.height(links.left).max(self.height(links.right));
+        self.set_links(node_id, links);
+
+        pivot_links.left = node_id;
+        pivot_links.height = 1 + links.height.max(self.height(pivot_links.right));
+        self.set_links(pivot_id, pivot_links);
+        pivot_id
+    }
+
+    fn rotate_right(&mut self, node_id: u32) -> u32 {
+        let mut links = self.get_links(node_id);
+        let pivot_id = links.left;
+        let mut pivot_links = self.get_links(pivot_id);
+
+        links.left = pivot_links.right;
+        links.height = 1 + self.height(links.left).max(self.height(links.right));
+        self.set_links(node_id, links);
+
+        pivot_links.right = node_id;
+        pivot_links.height = 1 + links.height.max(self.height(pivot_links.left));
+        self.set_links(pivot_id, pivot_links);
+        pivot_id
+    }
+
+    /// Restores the AVL property of a subtree whose children are balanced,
+    /// but might differ in height by 2. Returns the new root of the subtree.
+    fn rebalance(&mut self, node_id: u32) -> u32 {
+        let mut links = self.get_links(node_id);
+        let left_height = self.height(links.left);
+        let right_height = self.height(links.right);
+
+        if left_height > right_height + 1 {
+            let left_links = self.get_links(links.left);
+            if self.height(left_links.left) < self.height(left_links.right) {
+                links.left = self.rotate_left(links.left);
+                self.set_links(node_id, links);
+            }
+            return self.rotate_right(node_id);
+        }
+
+        if right_height > left_height + 1 {
+            let right_links = self.get_links(links.right);
+            if self.height(right_links.right) < self.height(right_links.left) {
+                links.right = self.rotate_right(links.right);
+                self.set_links(node_id, links);
+            }
+            return self.rotate_left(node_id);
+        }
+
+        let height = 1 + left_height.max(right_height);
+        if links.height != height {
+            links.height = height;
+            self.set_links(node_id, links);
+        }
+        node_id
+    }
+
+    /// Returns the new root of the subtree and the replaced value, if the key was already present.
+    fn insert_into_subtree(
+        &mut self,
+        info: &mut OrderedMapMapperInfo,
+        node_id: u32,
+        key: K,
+        value: &V,
+    ) -> (u32, Option<V>) {
+        if node_id == NULL_ENTRY {
+            let new_node_id = info.generate_new_node_id();
+            self.set_links(
+                new_node_id,
+                OrderedMapNodeLinks {
+                    left: NULL_ENTRY,
+                    right: NULL_ENTRY,
+                    height: 1,
+                },
+            );
+            self.set_key(new_node_id, &key);
+            self.set_value(new_node_id, value);
+            info.len += 1;
+            return (new_node_id, None);
+        }
+
+        let mut links = self.get_links(node_id);
+        match key.cmp(&self.get_key(node_id)) {
+            Ordering::Equal => {
+                let old_value = self.get_value(node_id);
+                self.set_value(node_id, value);
+                (node_id, Some(old_value))
+            },
+            Ordering::Less => {
+                let (new_left, old_value) = self.insert_into_subtree(info, links.left, key, value);
+                if old_value.is_some() {
+                    return (node_id, old_value);
+                }
+                links.left = new_left;
+                self.set_links(node_id, links);
+                (self.rebalance(node_id), None)
+            },
+            Ordering::Greater => {
+                let (new_right, old_value) =
+                    self.insert_into_subtree(info, links.right, key, value);
+                if old_value.is_some() {
+                    return (node_id, old_value);
+                }
+                links.right = new_right;
+                self.set_links(node_id, links);
+                (self.rebalance(node_id), None)
+            },
+        }
+    }
+
+    /// Detaches the node with the smallest key from the subtree.
+    /// Returns the new root of the subtree and the detached node id.
+    fn detach_min(&mut self, node_id: u32) -> (u32, u32) {
+        let mut links = self.get_links(node_id);
+        if links.left == NULL_ENTRY {
+            return (links.right, node_id);
+        }
+
+        let (new_left, min_node_id) = self.detach_min(links.left);
+        links.left = new_left;
+        self.set_links(node_id, links);
+        (self.rebalance(node_id), min_node_id)
+    }
+
+    /// Detaches the node with the largest key from the subtree.
+    /// Returns the new root of the subtree and the detached node id.
+    fn detach_max(&mut self, node_id: u32) -> (u32, u32) {
+        let mut links = self.get_links(node_id);
+        if links.right == NULL_ENTRY {
+            return (links.left, node_id);
+        }
+
+        let (new_right, max_node_id) = self.detach_max(links.right);
+        links.right = new_right;
+        self.set_links(node_id, links);
+        (self.rebalance(node_id), max_node_id)
+    }
+
+    /// Detaches the node with the given key from the subtree, if present.
+    /// Returns the new root of the subtree and the detached node id.
+    fn detach_key(&mut self, node_id: u32, key: &K) -> (u32, u32) {
+        if node_id == NULL_ENTRY {
+            return (NULL_ENTRY, NULL_ENTRY);
+        }
+
+        let mut links = self.get_links(node_id);
+        match key.cmp(&self.get_key(node_id)) {
+            Ordering::Equal => {
+                if links.left == NULL_ENTRY {
+                    return (links.right, node_id);
+                }
+                if links.right == NULL_ENTRY {
+                    return (links.left, node_id);
+                }
+
+                // the successor takes the place of the detached node
+                let (new_right, successor_id) = self.detach_min(links.right);
+                let mut successor_links = self.get_links(successor_id);
+                successor_links.left = links.left;
+                successor_links.right = new_right;
+                self.set_links(successor_id, successor_links);
+                (self.rebalance(successor_id), node_id)
+            },
+            Ordering::Less => {
+                let (new_left, detached_id) = self.detach_key(links.left, key);
+                if detached_id == NULL_ENTRY {
+                    return (node_id, NULL_ENTRY);
+                }
+                links.left = new_left;
+                self.set_links(node_id, links);
+                (self.rebalance(node_id), detached_id)
+            },
+            Ordering::Greater => {
+                let (new_right, detached_id) = self.detach_key(links.right, key);
+                if detached_id == NULL_ENTRY {
+                    return (node_id, NULL_ENTRY);
+                }
+                links.right = new_right;
+                self.set_links(node_id, links);
+                (self.rebalance(node_id), detached_id)
+            },
+        }
+    }
+
+    /// Completes a removal, after the node was detached from the tree.
+    fn remove_detached(
+        &mut self,
+        mut info: OrderedMapMapperInfo,
+        new_root: u32,
+        node_id: u32,
+    ) -> Option<(K, V)> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+
+        let entry = self.get_entry(node_id);
+        self.clear_node(node_id);
+        info.root = new_root;
+        info.len -= 1;
+        self.set_info(info);
+        entry
+    }
+
+    /// Sets the value associated with the key, and returns the old value, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut info = self.get_info();
+        let root = info.root;
+        let (new_root, old_value) = self.insert_into_subtree(&mut info, root, key, &value);
+        if old_value.is_none() {
+            info.root = new_root;
+            self.set_info(info);
+        }
+        old_value
+    }
+
+    /// Removes a key from the map, and returns its value, if it was present.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let info = self.get_info();
+        let (new_root, node_id) = self.detach_key(info.root, key);
+        self.remove_detached(info, new_root, node_id)
+            .map(|(_, value)| value)
+    }
+
+    /// Removes the entry with the smallest key and returns it, or `None` if the map is empty.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let info = self.get_info();
+        if info.root == NULL_ENTRY {
+            return None;
+        }
+        let (new_root, node_id) = self.detach_min(info.root);
+        self.remove_detached(info, new_root, node_id)
+    }
+
+    /// Removes the entry with the largest key and returns it, or `None` if the map is empty.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let info = self.get_info();
+        if info.root == NULL_ENTRY {
+            return None;
+        }
+        let (new_root, node_id) = self.detach_max(info.root);
+        self.remove_detached(info, new_root, node_id)
+    }
+}
+
+impl<'a, SA, K, V, A> IntoIterator for &'a OrderedMapMapper<SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    type Item = (K, V);
+
+    type IntoIter = Iter<'a, SA, K, V, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// In-order traversal, yielding node ids together with their keys.
+///
+/// Keeps the path to the next node on a stack, so it does not need parent links.
+struct NodeIter<'a, SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    map: &'a OrderedMapMapper<SA, K, V, A>,
+    stack: Vec<u32>,
+    end: Bound<K>,
+}
+
+impl<'a, SA, K, V, A> NodeIter<'a, SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(map: &'a OrderedMapMapper<SA, K, V, A>, start: Bound<K>, end: Bound<K>) -> Self {
+        let mut stack = Vec::new();
+        let mut node_id = map.get_info().root;
+        while node_id != NULL_ENTRY {
+            let links = map.get_links(node_id);
+            let in_range = match &start {
+                Bound::Included(start_key) => map.get_key(node_id) >= *start_key,
+                Bound::Excluded(start_key) => map.get_key(node_id) > *start_key,
+                Bound::Unbounded => true,
+            };
+            if in_range {
+                stack.push(node_id);
+                node_id = links.left;
+            } else {
+                node_id = links.right;
+            }
+        }
+
+        NodeIter { map, stack, end }
+    }
+
+    fn push_left_spine(&mut self, mut node_id: u32) {
+        while node_id != NULL_ENTRY {
+            self.stack.push(node_id);
+            node_id = self.map.get_links(node_id).left;
+        }
+    }
+}
+
+impl<'a, SA, K, V, A> Iterator for NodeIter<'a, SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = (u32, K);
+
+    fn next(&mut self) -> Option<(u32, K)> {
+        let node_id = self.stack.pop()?;
+        let key = self.map.get_key(node_id);
+        let past_end = match &self.end {
+            Bound::Included(end_key) => key > *end_key,
+            Bound::Excluded(end_key) => key >= *end_key,
+            Bound::Unbounded => false,
+        };
+        if past_end {
+            self.stack.clear();
+            return None;
+        }
+
+        self.push_left_spine(self.map.get_links(node_id).right);
+        Some((node_id, key))
+    }
+}
+
+/// An iterator over the entries of an `OrderedMapMapper`, in ascending key order.
+///
+/// This `struct` is created by [`OrderedMapMapper::iter()`] or [`OrderedMapMapper::range()`].
+pub struct Iter<'a, SA, K, V, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    node_iter: NodeIter<'a, SA, K, V, A>,
+}
+
+impl<'a, SA, K, V, A> Iterator for Iter<'a, SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        let (node_id, key) = self.node_iter.next()?;
+        Some((key, self.node_iter.map.get_value(node_id)))
+    }
+}
+
+/// An iterator over the keys of an `OrderedMapMapper`, in ascending order.
+pub struct Keys<'a, SA, K, V, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    node_iter: NodeIter<'a, SA, K, V, A>,
+}
+
+impl<'a, SA, K, V, A> Iterator for Keys<'a, SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<K> {
+        self.node_iter.next().map(|(_, key)| key)
+    }
+}
+
+/// An iterator over the values of an `OrderedMapMapper`, in the ascending order of their keys.
+pub struct Values<'a, SA, K, V, A = CurrentStorage>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    node_iter: NodeIter<'a, SA, K, V, A>,
+}
+
+impl<'a, SA, K, V, A> Iterator for Values<'a, SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<V> {
+        let (node_id, _) = self.node_iter.next()?;
+        Some(self.node_iter.map.get_value(node_id))
+    }
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<K, V>> when an endpoint result.
+impl<SA, K, V, A> TopEncodeMulti for OrderedMapMapper<SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        let iter = self.iter().map(MultiValue2::<K, V>::from);
+        multi_encode_iter_or_handle_err(iter, output, h)
+    }
+}
+
+impl<SA, K, V, A> CodecFrom<OrderedMapMapper<SA, K, V, A>>
+    for MultiValueEncoded<SA, MultiValue2<K, V>>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec<MultiValue<K, V>> when an endpoint result.
+impl<SA, K, V, A> TypeAbi for OrderedMapMapper<SA, K, V, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + TypeAbi + 'static,
+    V: TopEncode + TopDecode + TypeAbi + 'static,
+{
+    fn type_name() -> TypeName {
+        MultiValueEncoded::<SA, MultiValue2<K, V>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
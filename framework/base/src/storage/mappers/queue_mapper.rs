@@ -1,17 +1,20 @@
 use core::marker::PhantomData;
 
-use super::{StorageClearable, StorageMapper};
+use super::{
+    source::{CurrentStorage, StorageAddress},
+    StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
-    api::StorageMapperApi,
+    api::{ErrorApiImpl, StorageMapperApi},
     codec::{
         self,
         derive::{TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault},
         multi_encode_iter_or_handle_err, CodecFrom, DecodeDefault, EncodeDefault,
         EncodeErrorHandler, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
     },
-    storage::{storage_get, storage_set, StorageKey},
-    types::{ManagedType, MultiValueEncoded},
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, Page},
 };
 use alloc::vec::Vec;
 
@@ -20,6 +23,8 @@ const INFO_IDENTIFIER: &[u8] = b".info";
 const NODE_IDENTIFIER: &[u8] = b".node_links";
 const VALUE_IDENTIFIER: &[u8] = b".value";
 
+static INVALID_PAGE_CURSOR_ERR_MSG: &[u8] = b"invalid page cursor";
+
 #[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Copy)]
 pub struct Node {
     pub previous: u32,
@@ -62,17 +67,19 @@ impl QueueMapperInfo {
 ///
 /// The `QueueMapper` allows pushing and popping elements at either end
 /// in constant time.
-pub struct QueueMapper<SA, T>
+pub struct QueueMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
     _phantom_item: PhantomData<T>,
 }
 
-impl<SA, T> StorageMapper<SA> for QueueMapper<SA, T>
+impl<SA, T> StorageMapper<SA> for QueueMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
@@ -80,13 +87,29 @@ where
     fn new(base_key: StorageKey<SA>) -> Self {
         QueueMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
             base_key,
             _phantom_item: PhantomData,
         }
     }
 }
 
-impl<SA, T> StorageClearable for QueueMapper<SA, T>
+impl<SA, T> StorageMapperFromAddress<SA> for QueueMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        QueueMapper {
+            _phantom_api: PhantomData,
+            address,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for QueueMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
@@ -104,9 +127,10 @@ where
     }
 }
 
-impl<SA, T> QueueMapper<SA, T>
+impl<SA, T, A> QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn build_node_id_named_key(&self, name: &[u8], node_id: u32) -> StorageKey<SA> {
@@ -123,20 +147,190 @@ where
     }
 
     fn get_info(&self) -> QueueMapperInfo {
-        storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
-    }
-
-    fn set_info(&mut self, value: QueueMapperInfo) {
-        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+        self.address
+            .address_storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
     }
 
     fn get_node(&self, node_id: u32) -> Node {
-        storage_get(
+        self.address.address_storage_get(
             self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
                 .as_ref(),
         )
     }
 
+    fn get_value(&self, node_id: u32) -> T {
+        self.address.address_storage_get(
+            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn get_value_option(&self, node_id: u32) -> Option<T> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some(self.get_value(node_id))
+    }
+
+    /// Returns `true` if the `Queue` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the length of the `Queue`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// Provides a copy to the front element, or `None` if the queue is
+    /// empty.
+    pub fn front(&self) -> Option<T> {
+        self.get_value_option(self.get_info().front)
+    }
+
+    /// Provides a copy to the back element, or `None` if the queue is
+    /// empty.
+    pub fn back(&self) -> Option<T> {
+        self.get_value_option(self.get_info().back)
+    }
+
+    /// Provides a forward iterator.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        Iter::new(self)
+    }
+
+    /// Loads at most `limit` elements, starting from the front if `cursor` is `0`.
+    /// Following cursors are node ids, so pages stay consistent while elements are added or removed,
+    /// unless the very element a cursor points to is removed, in which case an error is signalled.
+    pub fn page(&self, cursor: usize, limit: usize) -> Page<SA, T> {
+        self.page_map(cursor, limit, |value| value)
+    }
+
+    pub(crate) fn page_map<R, F>(&self, cursor: usize, limit: usize, f: F) -> Page<SA, R>
+    where
+        R: TopEncodeMulti,
+        F: Fn(T) -> R,
+    {
+        Page::<SA, R>::check_limit(limit);
+        let mut node_id = if cursor == 0 {
+            self.get_info().front
+        } else {
+            self.check_page_cursor(cursor)
+        };
+
+        let mut items = MultiValueEncoded::new();
+        for _ in 0..limit {
+            if node_id == NULL_ENTRY {
+                break;
+            }
+            items.push(f(self.get_value(node_id)));
+            node_id = self.get_node(node_id).next;
+        }
+
+        let next_cursor = if node_id == NULL_ENTRY {
+            None
+        } else {
+            Some(node_id as usize)
+        };
+        Page::new(items, next_cursor)
+    }
+
+    fn check_page_cursor(&self, cursor: usize) -> u32 {
+        let node_id = cursor as u32;
+        let node_key = self.build_node_id_named_key(NODE_IDENTIFIER, node_id);
+        if node_id as usize != cursor
+            || self.address.address_storage_get_len(node_key.as_ref()) == 0
+        {
+            SA::error_api_impl().signal_error(INVALID_PAGE_CURSOR_ERR_MSG);
+        }
+        node_id
+    }
+
+    /// Runs several checks in order to verify that both forwards and backwards iteration
+    /// yields the same node entries and that the number of items in the queue is correct.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        let mut front = info.front;
+        let mut back = info.back;
+        if info.len == 0 {
+            // if the queue is empty, both ends should point to null entries
+            if front != NULL_ENTRY {
+                return false;
+            }
+            if back != NULL_ENTRY {
+                return false;
+            }
+            true
+        } else {
+            // if the queue is non-empty, both ends should point to non-null entries
+            if front == NULL_ENTRY {
+                return false;
+            }
+            if back == NULL_ENTRY {
+                return false;
+            }
+
+            // the node before the first and the one after the last should both be null
+            if self.get_node(front).previous != NULL_ENTRY {
+                return false;
+            }
+            if self.get_node(back).next != NULL_ENTRY {
+                return false;
+            }
+
+            // iterate forwards
+            let mut forwards = Vec::new();
+            while front != NULL_ENTRY {
+                forwards.push(front);
+                front = self.get_node(front).next;
+            }
+            if forwards.len() != info.len as usize {
+                return false;
+            }
+
+            // iterate backwards
+            let mut backwards = Vec::new();
+            while back != NULL_ENTRY {
+                backwards.push(back);
+                back = self.get_node(back).previous;
+            }
+            if backwards.len() != info.len as usize {
+                return false;
+            }
+
+            // check that both iterations match element-wise
+            let backwards_reversed: Vec<u32> = backwards.iter().rev().cloned().collect();
+            if forwards != backwards_reversed {
+                return false;
+            }
+
+            // check that the node IDs are unique
+            forwards.sort_unstable();
+            forwards.dedup();
+            if forwards.len() != info.len as usize {
+                return false;
+            }
+            true
+        }
+    }
+}
+
+impl<SA, T> QueueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn set_info(&mut self, value: QueueMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+    }
+
     fn set_node(&mut self, node_id: u32, item: Node) {
         storage_set(
             self.build_node_id_named_key(NODE_IDENTIFIER, node_id)
@@ -153,20 +347,6 @@ where
         );
     }
 
-    fn get_value(&self, node_id: u32) -> T {
-        storage_get(
-            self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
-                .as_ref(),
-        )
-    }
-
-    fn get_value_option(&self, node_id: u32) -> Option<T> {
-        if node_id == NULL_ENTRY {
-            return None;
-        }
-        Some(self.get_value(node_id))
-    }
-
     fn set_value(&mut self, node_id: u32, value: &T) {
         storage_set(
             self.build_node_id_named_key(VALUE_IDENTIFIER, node_id)
@@ -183,20 +363,6 @@ where
         )
     }
 
-    /// Returns `true` if the `Queue` is empty.
-    ///
-    /// This operation should compute in *O*(1) time.
-    pub fn is_empty(&self) -> bool {
-        self.get_info().len == 0
-    }
-
-    /// Returns the length of the `Queue`.
-    ///
-    /// This operation should compute in *O*(1) time.
-    pub fn len(&self) -> usize {
-        self.get_info().len as usize
-    }
-
     /// Appends an element to the back of a queue
     /// and returns the node id of the newly added node.
     ///
@@ -264,18 +430,6 @@ where
         self.set_info(info);
     }
 
-    /// Provides a copy to the front element, or `None` if the queue is
-    /// empty.
-    pub fn front(&self) -> Option<T> {
-        self.get_value_option(self.get_info().front)
-    }
-
-    /// Provides a copy to the back element, or `None` if the queue is
-    /// empty.
-    pub fn back(&self) -> Option<T> {
-        self.get_value_option(self.get_info().back)
-    }
-
     /// Removes the last element from a queue and returns it, or `None` if
     /// it is empty.
     ///
@@ -327,92 +481,17 @@ where
         self.set_info(info);
         Some(removed_value)
     }
-
-    /// Provides a forward iterator.
-    pub fn iter(&self) -> Iter<SA, T> {
-        Iter::new(self)
-    }
-
-    /// Runs several checks in order to verify that both forwards and backwards iteration
-    /// yields the same node entries and that the number of items in the queue is correct.
-    /// Used for unit testing.
-    ///
-    /// This operation should compute in *O*(n) time.
-    pub fn check_internal_consistency(&self) -> bool {
-        let info = self.get_info();
-        let mut front = info.front;
-        let mut back = info.back;
-        if info.len == 0 {
-            // if the queue is empty, both ends should point to null entries
-            if front != NULL_ENTRY {
-                return false;
-            }
-            if back != NULL_ENTRY {
-                return false;
-            }
-            true
-        } else {
-            // if the queue is non-empty, both ends should point to non-null entries
-            if front == NULL_ENTRY {
-                return false;
-            }
-            if back == NULL_ENTRY {
-                return false;
-            }
-
-            // the node before the first and the one after the last should both be null
-            if self.get_node(front).previous != NULL_ENTRY {
-                return false;
-            }
-            if self.get_node(back).next != NULL_ENTRY {
-                return false;
-            }
-
-            // iterate forwards
-            let mut forwards = Vec::new();
-            while front != NULL_ENTRY {
-                forwards.push(front);
-                front = self.get_node(front).next;
-            }
-            if forwards.len() != info.len as usize {
-                return false;
-            }
-
-            // iterate backwards
-            let mut backwards = Vec::new();
-            while back != NULL_ENTRY {
-                backwards.push(back);
-                back = self.get_node(back).previous;
-            }
-            if backwards.len() != info.len as usize {
-                return false;
-            }
-
-            // check that both iterations match element-wise
-            let backwards_reversed: Vec<u32> = backwards.iter().rev().cloned().collect();
-            if forwards != backwards_reversed {
-                return false;
-            }
-
-            // check that the node IDs are unique
-            forwards.sort_unstable();
-            forwards.dedup();
-            if forwards.len() != info.len as usize {
-                return false;
-            }
-            true
-        }
-    }
 }
 
-impl<'a, SA, T> IntoIterator for &'a QueueMapper<SA, T>
+impl<'a, SA, T, A> IntoIterator for &'a QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     type Item = T;
 
-    type IntoIter = Iter<'a, SA, T>;
+    type IntoIter = Iter<'a, SA, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -423,21 +502,23 @@ where
 ///
 /// This `struct` is created by [`QueueMapper::iter()`]. See its
 /// documentation for more.
-pub struct Iter<'a, SA, T>
+pub struct Iter<'a, SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     node_id: u32,
-    queue: &'a QueueMapper<SA, T>,
+    queue: &'a QueueMapper<SA, T, A>,
 }
 
-impl<'a, SA, T> Iter<'a, SA, T>
+impl<'a, SA, T, A> Iter<'a, SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
-    fn new(queue: &'a QueueMapper<SA, T>) -> Iter<'a, SA, T> {
+    fn new(queue: &'a QueueMapper<SA, T, A>) -> Iter<'a, SA, T, A> {
         Iter {
             node_id: queue.get_info().front,
             queue,
@@ -445,9 +526,10 @@ where
     }
 }
 
-impl<'a, SA, T> Iterator for Iter<'a, SA, T>
+impl<'a, SA, T, A> Iterator for Iter<'a, SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
     type Item = T;
@@ -464,9 +546,10 @@ where
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TopEncodeMulti for QueueMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -478,17 +561,19 @@ where
     }
 }
 
-impl<SA, T> CodecFrom<QueueMapper<SA, T>> for MultiValueEncoded<SA, T>
+impl<SA, T, A> CodecFrom<QueueMapper<SA, T, A>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TypeAbi for QueueMapper<SA, T>
+impl<SA, T, A> TypeAbi for QueueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
@@ -1,7 +1,10 @@
 use core::marker::PhantomData;
 
 pub use super::queue_mapper::Iter;
-use super::{QueueMapper, StorageClearable, StorageMapper};
+use super::{
+    source::{CurrentStorage, StorageAddress},
+    QueueMapper, StorageClearable, StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
@@ -9,24 +12,26 @@ use crate::{
         self, multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode,
         NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
     },
-    storage::{storage_get, storage_set, StorageKey},
-    types::{ManagedType, MultiValueEncoded},
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, MultiValueEncoded, Page},
 };
 
 const NULL_ENTRY: u32 = 0;
 const NODE_ID_IDENTIFIER: &[u8] = b".node_id";
 
-pub struct SetMapper<SA, T>
+pub struct SetMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
-    queue_mapper: QueueMapper<SA, T>,
+    queue_mapper: QueueMapper<SA, T, A>,
 }
 
-impl<SA, T> StorageMapper<SA> for SetMapper<SA, T>
+impl<SA, T> StorageMapper<SA> for SetMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode,
@@ -34,13 +39,29 @@ where
     fn new(base_key: StorageKey<SA>) -> Self {
         SetMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
             base_key: base_key.clone(),
             queue_mapper: QueueMapper::<SA, T>::new(base_key),
         }
     }
 }
 
-impl<SA, T> StorageClearable for SetMapper<SA, T>
+impl<SA, T> StorageMapperFromAddress<SA> for SetMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        SetMapper {
+            _phantom_api: PhantomData,
+            address: address.clone(),
+            base_key: base_key.clone(),
+            queue_mapper: QueueMapper::new_from_address(address, base_key),
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for SetMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode,
@@ -53,9 +74,10 @@ where
     }
 }
 
-impl<SA, T> SetMapper<SA, T>
+impl<SA, T, A> SetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode,
 {
     fn build_named_value_key(&self, name: &[u8], value: &T) -> StorageKey<SA> {
@@ -66,12 +88,58 @@ where
     }
 
     fn get_node_id(&self, value: &T) -> u32 {
-        storage_get(
+        self.address.address_storage_get(
             self.build_named_value_key(NODE_ID_IDENTIFIER, value)
                 .as_ref(),
         )
     }
 
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.queue_mapper.is_empty()
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.queue_mapper.len()
+    }
+
+    /// Returns `true` if the set contains a value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.get_node_id(value) != NULL_ENTRY
+    }
+
+    /// An iterator visiting all elements in arbitrary order.
+    /// The iterator element type is `&'a T`.
+    pub fn iter(&self) -> Iter<SA, T, A> {
+        self.queue_mapper.iter()
+    }
+
+    /// Loads at most `limit` elements, in insertion order.
+    /// The cursor of the first page is `0`, see `QueueMapper::page` for details.
+    pub fn page(&self, cursor: usize, limit: usize) -> Page<SA, T> {
+        self.queue_mapper.page(cursor, limit)
+    }
+
+    pub(crate) fn page_map<R, F>(&self, cursor: usize, limit: usize, f: F) -> Page<SA, R>
+    where
+        R: TopEncodeMulti,
+        F: Fn(T) -> R,
+    {
+        self.queue_mapper.page_map(cursor, limit, f)
+    }
+
+    /// Checks the internal consistency of the collection. Used for unit tests.
+    pub fn check_internal_consistency(&self) -> bool {
+        self.queue_mapper.check_internal_consistency()
+    }
+}
+
+impl<SA, T> SetMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
     fn set_node_id(&self, value: &T, node_id: u32) {
         storage_set(
             self.build_named_value_key(NODE_ID_IDENTIFIER, value)
@@ -88,21 +156,6 @@ where
         );
     }
 
-    /// Returns `true` if the set contains no elements.
-    pub fn is_empty(&self) -> bool {
-        self.queue_mapper.is_empty()
-    }
-
-    /// Returns the number of elements in the set.
-    pub fn len(&self) -> usize {
-        self.queue_mapper.len()
-    }
-
-    /// Returns `true` if the set contains a value.
-    pub fn contains(&self, value: &T) -> bool {
-        self.get_node_id(value) != NULL_ENTRY
-    }
-
     /// Adds a value to the set.
     ///
     /// If the set did not have this value present, `true` is returned.
@@ -137,34 +190,24 @@ where
             self.remove(&item);
         }
     }
-
-    /// An iterator visiting all elements in arbitrary order.
-    /// The iterator element type is `&'a T`.
-    pub fn iter(&self) -> Iter<SA, T> {
-        self.queue_mapper.iter()
-    }
-
-    /// Checks the internal consistency of the collection. Used for unit tests.
-    pub fn check_internal_consistency(&self) -> bool {
-        self.queue_mapper.check_internal_consistency()
-    }
 }
 
-impl<'a, SA, T> IntoIterator for &'a SetMapper<SA, T>
+impl<'a, SA, T, A> IntoIterator for &'a SetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
     type Item = T;
 
-    type IntoIter = Iter<'a, SA, T>;
+    type IntoIter = Iter<'a, SA, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<SA, T> Extend<T> for SetMapper<SA, T>
+impl<SA, T> Extend<T> for SetMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
@@ -180,9 +223,10 @@ where
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TopEncodeMulti for SetMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for SetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -194,17 +238,19 @@ where
     }
 }
 
-impl<SA, T> CodecFrom<SetMapper<SA, T>> for MultiValueEncoded<SA, T>
+impl<SA, T, A> CodecFrom<SetMapper<SA, T, A>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
 {
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA, T> TypeAbi for SetMapper<SA, T>
+impl<SA, T, A> TypeAbi for SetMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
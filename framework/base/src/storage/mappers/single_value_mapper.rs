@@ -1,6 +1,9 @@
 use core::{borrow::Borrow, marker::PhantomData};
 
-use super::StorageMapper;
+use super::{
+    source::{CurrentStorage, StorageAddress},
+    StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
@@ -9,26 +12,25 @@ use crate::{
         EncodeErrorHandler, TopDecode, TopDecodeInput, TopEncode, TopEncodeMulti,
         TopEncodeMultiOutput, TopEncodeOutput,
     },
-    storage::{
-        storage_clear, storage_get, storage_get_from_address, storage_get_len, storage_set,
-        StorageKey,
-    },
+    storage::{storage_clear, storage_get_from_address, storage_set, StorageKey},
     types::{ManagedAddress, ManagedType},
 };
 use storage_get_from_address::storage_get_len_from_address;
 
 /// Manages a single serializable item in storage.
-pub struct SingleValueMapper<SA, T>
+pub struct SingleValueMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + 'static,
 {
+    address: A,
     key: StorageKey<SA>,
     _phantom_api: PhantomData<SA>,
     _phantom_item: PhantomData<T>,
 }
 
-impl<SA, T> StorageMapper<SA> for SingleValueMapper<SA, T>
+impl<SA, T> StorageMapper<SA> for SingleValueMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
@@ -36,6 +38,7 @@ where
     #[inline]
     fn new(base_key: StorageKey<SA>) -> Self {
         SingleValueMapper {
+            address: CurrentStorage,
             key: base_key,
             _phantom_api: PhantomData,
             _phantom_item: PhantomData,
@@ -43,19 +46,31 @@ where
     }
 }
 
-impl<SA, T> SingleValueMapper<SA, T>
+impl<SA, T> StorageMapperFromAddress<SA> for SingleValueMapper<SA, T, ManagedAddress<SA>>
 where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
 {
-    /// Retrieves current value from storage.
-    pub fn get(&self) -> T {
-        storage_get(self.key.as_ref())
+    #[inline]
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        SingleValueMapper {
+            address,
+            key: base_key,
+            _phantom_api: PhantomData,
+            _phantom_item: PhantomData,
+        }
     }
+}
 
-    /// Gets the value from the given address. Both adresses have to be in the same shard.
-    pub fn get_from_address(&self, address: &ManagedAddress<SA>) -> T {
-        storage_get_from_address(address.as_ref(), self.key.as_ref())
+impl<SA, T, A> SingleValueMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: TopEncode + TopDecode,
+{
+    /// Retrieves current value from storage.
+    pub fn get(&self) -> T {
+        self.address.address_storage_get(self.key.as_ref())
     }
 
     /// Returns whether the storage managed by this mapper is empty.
@@ -63,6 +78,21 @@ where
         self.raw_byte_length() == 0
     }
 
+    pub fn raw_byte_length(&self) -> usize {
+        self.address.address_storage_get_len(self.key.as_ref())
+    }
+}
+
+impl<SA, T> SingleValueMapper<SA, T, CurrentStorage>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    /// Gets the value from the given address. Both adresses have to be in the same shard.
+    pub fn get_from_address(&self, address: &ManagedAddress<SA>) -> T {
+        storage_get_from_address(address.as_ref(), self.key.as_ref())
+    }
+
     /// Returns whether the storage at the given key is empty at the given address.
     /// Both adresses have to be in the same shard.
     pub fn is_empty_at_address(&self, address: &ManagedAddress<SA>) -> bool {
@@ -107,10 +137,6 @@ where
         result
     }
 
-    pub fn raw_byte_length(&self) -> usize {
-        storage_get_len(self.key.as_ref())
-    }
-
     /// Takes the value out of the storage, clearing it in the process.
     pub fn take(&self) -> T {
         let value = self.get();
@@ -129,9 +155,10 @@ where
     }
 }
 
-impl<SA, T> TopEncodeMulti for SingleValueMapper<SA, T>
+impl<SA, T, A> TopEncodeMulti for SingleValueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -181,31 +208,35 @@ impl<T: TopDecode> SingleValue<T> {
     }
 }
 
-impl<SA, T> !CodecFromSelf for SingleValueMapper<SA, T>
+impl<SA, T, A> !CodecFromSelf for SingleValueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
-impl<SA, T, R> CodecFrom<SingleValueMapper<SA, T>> for SingleValue<R>
+impl<SA, T, A, R> CodecFrom<SingleValueMapper<SA, T, A>> for SingleValue<R>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
     R: TopDecode + CodecFrom<T>,
 {
 }
 
-impl<SA, T> CodecFrom<SingleValueMapper<SA, T>> for PlaceholderOutput
+impl<SA, T, A> CodecFrom<SingleValueMapper<SA, T, A>> for PlaceholderOutput
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode,
 {
 }
 
-impl<SA, T> TypeAbi for SingleValueMapper<SA, T>
+impl<SA, T, A> TypeAbi for SingleValueMapper<SA, T, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: TopEncode + TopDecode + TypeAbi,
 {
     fn type_name() -> TypeName {
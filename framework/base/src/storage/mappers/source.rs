@@ -0,0 +1,94 @@
+use crate::{
+    api::StorageMapperApi,
+    codec::TopDecode,
+    storage::{
+        storage_get, storage_get_from_address, storage_get_len, storage_get_len_from_address,
+        StorageKey,
+    },
+    types::{ManagedAddress, ManagedRef, ManagedType},
+};
+
+/// Where a storage mapper reads its data from.
+///
+/// Mappers that read from another address are read-only:
+/// all the methods that write to storage are only available for [`CurrentStorage`].
+///
+/// Reading from a mapper of another contract:
+///
+/// ```
+/// # use multiversx_sc::{api::StorageMapperApi, storage::mappers::SetMapper, types::ManagedAddress};
+/// fn contains_remote<SA: StorageMapperApi>(mapper: &SetMapper<SA, u32, ManagedAddress<SA>>) -> bool {
+///     mapper.contains(&5)
+/// }
+/// ```
+///
+/// Writing to it does not compile:
+///
+/// ```compile_fail,E0599
+/// # use multiversx_sc::{api::StorageMapperApi, storage::mappers::SetMapper, types::ManagedAddress};
+/// fn insert_remote<SA: StorageMapperApi>(mapper: &mut SetMapper<SA, u32, ManagedAddress<SA>>) {
+///     mapper.insert(5);
+/// }
+/// ```
+///
+/// ```compile_fail,E0599
+/// # use multiversx_sc::{api::StorageMapperApi, storage::mappers::UserMapper, types::ManagedAddress};
+/// fn create_user_remote<SA: StorageMapperApi>(
+///     mapper: &UserMapper<SA, ManagedAddress<SA>>,
+///     address: &ManagedAddress<SA>,
+/// ) -> usize {
+///     mapper.get_or_create_user(address)
+/// }
+/// ```
+///
+/// Neither does clearing it, only [`CurrentStorage`] mappers are
+/// [`StorageClearable`](super::StorageClearable):
+///
+/// ```compile_fail,E0599
+/// # use multiversx_sc::{
+/// #     api::StorageMapperApi,
+/// #     storage::mappers::{LinkedListMapper, StorageClearable},
+/// #     types::ManagedAddress,
+/// # };
+/// fn clear_remote<SA: StorageMapperApi>(mapper: &mut LinkedListMapper<SA, u32, ManagedAddress<SA>>) {
+///     mapper.clear();
+/// }
+/// ```
+pub trait StorageAddress<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn address_storage_get<T: TopDecode>(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> T;
+
+    fn address_storage_get_len(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> usize;
+}
+
+/// The storage of the current contract. This is the default for all storage mappers.
+pub struct CurrentStorage;
+
+impl<SA> StorageAddress<SA> for CurrentStorage
+where
+    SA: StorageMapperApi,
+{
+    fn address_storage_get<T: TopDecode>(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> T {
+        storage_get(key)
+    }
+
+    fn address_storage_get_len(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> usize {
+        storage_get_len(key)
+    }
+}
+
+/// The storage of another contract. Both addresses have to be in the same shard.
+impl<SA> StorageAddress<SA> for ManagedAddress<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn address_storage_get<T: TopDecode>(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> T {
+        storage_get_from_address(self.as_ref(), key)
+    }
+
+    fn address_storage_get_len(&self, key: ManagedRef<'_, SA, StorageKey<SA>>) -> usize {
+        storage_get_len_from_address(self.as_ref(), key)
+    }
+}
@@ -1,16 +1,19 @@
-use core::marker::PhantomData;
+use core::{cmp::min, marker::PhantomData};
 
 use crate::codec::{
     multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, TopEncodeMulti,
     TopEncodeMultiOutput,
 };
 
-use super::StorageMapper;
+use super::{
+    source::{CurrentStorage, StorageAddress},
+    StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     abi::{TypeAbi, TypeName},
     api::StorageMapperApi,
-    storage::{storage_get, storage_get_len, storage_set, StorageKey},
-    types::{ManagedAddress, ManagedType, ManagedVec, MultiValueEncoded},
+    storage::{storage_set, StorageKey},
+    types::{ManagedAddress, ManagedType, ManagedVec, MultiValueEncoded, Page},
 };
 
 const ADDRESS_TO_ID_SUFFIX: &[u8] = b"_address_to_id";
@@ -26,29 +29,46 @@ const COUNT_SUFFIX: &[u8] = b"_count";
 /// user data other than address/id.
 ///
 /// It also doesn't allow removing users. Once in, their ids are reserved forever.
-pub struct UserMapper<SA>
+pub struct UserMapper<SA, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
 {
     _phantom_api: PhantomData<SA>,
+    address: A,
     base_key: StorageKey<SA>,
 }
 
-impl<SA> StorageMapper<SA> for UserMapper<SA>
+impl<SA> StorageMapper<SA> for UserMapper<SA, CurrentStorage>
 where
     SA: StorageMapperApi,
 {
     fn new(base_key: StorageKey<SA>) -> Self {
         UserMapper {
             _phantom_api: PhantomData,
+            address: CurrentStorage,
             base_key,
         }
     }
 }
 
-impl<SA> UserMapper<SA>
+impl<SA> StorageMapperFromAddress<SA> for UserMapper<SA, ManagedAddress<SA>>
 where
     SA: StorageMapperApi,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        UserMapper {
+            _phantom_api: PhantomData,
+            address,
+            base_key,
+        }
+    }
+}
+
+impl<SA, A> UserMapper<SA, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
 {
     fn get_user_id_key(&self, address: &ManagedAddress<SA>) -> StorageKey<SA> {
         let mut user_id_key = self.base_key.clone();
@@ -73,11 +93,8 @@ where
     /// Yields the user id for a given address.
     /// Will return 0 if the address is not known to the contract.
     pub fn get_user_id(&self, address: &ManagedAddress<SA>) -> usize {
-        storage_get(self.get_user_id_key(address).as_ref())
-    }
-
-    fn set_user_id(&self, address: &ManagedAddress<SA>, id: usize) {
-        storage_set(self.get_user_id_key(address).as_ref(), &id);
+        self.address
+            .address_storage_get(self.get_user_id_key(address).as_ref())
     }
 
     /// Yields the user address for a given id, if the id is valid.
@@ -85,8 +102,8 @@ where
         let key = self.get_user_address_key(id);
         // TODO: optimize, storage_load_managed_buffer_len is currently called twice
 
-        if storage_get_len(key.as_ref()) > 0 {
-            Some(storage_get(key.as_ref()))
+        if self.address.address_storage_get_len(key.as_ref()) > 0 {
+            Some(self.address.address_storage_get(key.as_ref()))
         } else {
             None
         }
@@ -95,7 +112,8 @@ where
     /// Yields the user address for a given id.
     /// Will cause a deserialization error if the id is invalid.
     pub fn get_user_address_unchecked(&self, id: usize) -> ManagedAddress<SA> {
-        storage_get(self.get_user_address_key(id).as_ref())
+        self.address
+            .address_storage_get(self.get_user_address_key(id).as_ref())
     }
 
     /// Yields the user address for a given id, if the id is valid.
@@ -103,20 +121,54 @@ where
     pub fn get_user_address_or_zero(&self, id: usize) -> ManagedAddress<SA> {
         let key = self.get_user_address_key(id);
         // TODO: optimize, storage_load_managed_buffer_len is currently called twice
-        if storage_get_len(key.as_ref()) > 0 {
-            storage_get(key.as_ref())
+        if self.address.address_storage_get_len(key.as_ref()) > 0 {
+            self.address.address_storage_get(key.as_ref())
         } else {
             ManagedAddress::zero()
         }
     }
 
-    fn set_user_address(&self, id: usize, address: &ManagedAddress<SA>) {
-        storage_set(self.get_user_address_key(id).as_ref(), address);
-    }
-
     /// Number of users.
     pub fn get_user_count(&self) -> usize {
-        storage_get(self.get_user_count_key().as_ref())
+        self.address
+            .address_storage_get(self.get_user_count_key().as_ref())
+    }
+
+    /// Loads all addresses from storage and places them in a ManagedVec.
+    /// Can easily consume a lot of gas.
+    pub fn get_all_addresses(&self) -> ManagedVec<SA, ManagedAddress<SA>> {
+        let user_count = self.get_user_count();
+        let mut result = ManagedVec::new();
+        for i in 1..=user_count {
+            result.push(self.get_user_address_or_zero(i));
+        }
+        result
+    }
+
+    /// Loads at most `limit` addresses, skipping the first `cursor` users.
+    /// The paginated alternative to `get_all_addresses`.
+    pub fn get_addresses_page(&self, cursor: usize, limit: usize) -> Page<SA, ManagedAddress<SA>> {
+        Page::<SA, ManagedAddress<SA>>::check_limit(limit);
+        let user_count = self.get_user_count();
+        let end = min(cursor.saturating_add(limit), user_count);
+        let items = (cursor.saturating_add(1)..=end)
+            .map(|id| self.get_user_address_or_zero(id))
+            .collect();
+        let next_cursor = if end < user_count { Some(end) } else { None };
+        Page::new(items, next_cursor)
+    }
+}
+
+impl<SA> UserMapper<SA, CurrentStorage>
+where
+    SA: StorageMapperApi,
+{
+    fn set_user_id(&self, address: &ManagedAddress<SA>, id: usize) {
+        storage_set(self.get_user_id_key(address).as_ref(), &id);
+    }
+
+    fn set_user_address(&self, id: usize, address: &ManagedAddress<SA>) {
+        storage_set(self.get_user_address_key(id).as_ref(), address);
     }
 
     fn set_user_count(&self, user_count: usize) {
@@ -162,24 +214,14 @@ where
         }
         self.set_user_count(user_count);
     }
-
-    /// Loads all addresses from storage and places them in a ManagedVec.
-    /// Can easily consume a lot of gas.
-    pub fn get_all_addresses(&self) -> ManagedVec<SA, ManagedAddress<SA>> {
-        let user_count = self.get_user_count();
-        let mut result = ManagedVec::new();
-        for i in 1..=user_count {
-            result.push(self.get_user_address_or_zero(i));
-        }
-        result
-    }
 }
 
 /// Behaves like a MultiResultVec<Address> when an endpoint result,
 /// and lists all users addresses.
-impl<SA> TopEncodeMulti for UserMapper<SA>
+impl<SA, A> TopEncodeMulti for UserMapper<SA, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
 {
     fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
     where
@@ -191,15 +233,18 @@ where
     }
 }
 
-impl<SA> CodecFrom<UserMapper<SA>> for MultiValueEncoded<SA, ManagedAddress<SA>> where
-    SA: StorageMapperApi
+impl<SA, A> CodecFrom<UserMapper<SA, A>> for MultiValueEncoded<SA, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
 {
 }
 
 /// Behaves like a MultiResultVec when an endpoint result.
-impl<SA> TypeAbi for UserMapper<SA>
+impl<SA, A> TypeAbi for UserMapper<SA, A>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
 {
     fn type_name() -> TypeName {
         crate::abi::type_name_variadic::<ManagedAddress<SA>>()
@@ -1,11 +1,14 @@
 use core::marker::PhantomData;
 
-use super::{SingleValueMapper, StorageMapper};
+use super::{
+    source::{CurrentStorage, StorageAddress},
+    SingleValueMapper, StorageMapper, StorageMapperFromAddress,
+};
 use crate::{
     api::{ErrorApiImpl, StorageMapperApi},
     codec::NestedEncode,
     storage::StorageKey,
-    types::ManagedAddress,
+    types::{ManagedAddress, ManagedType},
 };
 
 type FlagMapper<SA> = SingleValueMapper<SA, bool>;
@@ -15,29 +18,71 @@ static ITEM_NOT_WHITELISTED_ERR_MSG: &[u8] = b"Item not whitelisted";
 /// A non-iterable whitelist mapper.
 /// Very efficient for storing a whitelist, as each item requires only one storage key.
 /// If you need to iterate over the keys, use UnorderedSetMapper or SetMapper instead.
-pub struct WhitelistMapper<SA, T>
+pub struct WhitelistMapper<SA, T, A = CurrentStorage>
 where
     SA: StorageMapperApi,
+    A: StorageAddress<SA>,
     T: NestedEncode + 'static,
 {
+    address: A,
     base_key: StorageKey<SA>,
     _phantom: PhantomData<T>,
 }
 
-impl<SA, T> StorageMapper<SA> for WhitelistMapper<SA, T>
+impl<SA, T> StorageMapper<SA> for WhitelistMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: NestedEncode + 'static,
 {
     fn new(base_key: StorageKey<SA>) -> Self {
         Self {
+            address: CurrentStorage,
+            base_key,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageMapperFromAddress<SA> for WhitelistMapper<SA, T, ManagedAddress<SA>>
+where
+    SA: StorageMapperApi,
+    T: NestedEncode + 'static,
+{
+    fn new_from_address(address: ManagedAddress<SA>, base_key: StorageKey<SA>) -> Self {
+        Self {
+            address,
             base_key,
             _phantom: PhantomData,
         }
     }
 }
 
-impl<SA, T> WhitelistMapper<SA, T>
+impl<SA, T, A> WhitelistMapper<SA, T, A>
+where
+    SA: StorageMapperApi,
+    A: StorageAddress<SA>,
+    T: NestedEncode + 'static,
+{
+    pub fn contains(&self, item: &T) -> bool {
+        self.address
+            .address_storage_get_len(self.build_item_key(item).as_ref())
+            != 0
+    }
+
+    pub fn require_whitelisted(&self, item: &T) {
+        if !self.contains(item) {
+            SA::error_api_impl().signal_error(ITEM_NOT_WHITELISTED_ERR_MSG);
+        }
+    }
+
+    fn build_item_key(&self, item: &T) -> StorageKey<SA> {
+        let mut key = self.base_key.clone();
+        key.append_item(item);
+        key
+    }
+}
+
+impl<SA, T> WhitelistMapper<SA, T, CurrentStorage>
 where
     SA: StorageMapperApi,
     T: NestedEncode + 'static,
@@ -52,22 +97,11 @@ where
         mapper.clear();
     }
 
-    pub fn contains(&self, item: &T) -> bool {
-        let mapper = self.build_mapper_for_item(item);
-        !mapper.is_empty()
-    }
-
     pub fn contains_at_address(&self, address: &ManagedAddress<SA>, item: &T) -> bool {
         let mapper = self.build_mapper_for_item(item);
         !mapper.is_empty_at_address(address)
     }
 
-    pub fn require_whitelisted(&self, item: &T) {
-        if !self.contains(item) {
-            SA::error_api_impl().signal_error(ITEM_NOT_WHITELISTED_ERR_MSG);
-        }
-    }
-
     pub fn require_whitelisted_at_address(&self, address: &ManagedAddress<SA>, item: &T) {
         if !self.contains_at_address(address, item) {
             SA::error_api_impl().signal_error(ITEM_NOT_WHITELISTED_ERR_MSG);
@@ -75,9 +109,6 @@ where
     }
 
     fn build_mapper_for_item(&self, item: &T) -> FlagMapper<SA> {
-        let mut key = self.base_key.clone();
-        key.append_item(item);
-
-        FlagMapper::<SA>::new(key)
+        FlagMapper::<SA>::new(self.build_item_key(item))
     }
 }
@@ -1,10 +1,18 @@
 pub mod mappers;
 pub mod protected_keys;
+#[cfg(feature = "managed-map")]
+pub mod storage_cache;
+#[cfg(not(feature = "managed-map"))]
+mod storage_cache_disabled;
 pub mod storage_get;
 pub mod storage_get_from_address;
 pub mod storage_key;
 pub mod storage_set;
 
+#[cfg(feature = "managed-map")]
+pub use storage_cache::*;
+#[cfg(not(feature = "managed-map"))]
+pub(crate) use storage_cache_disabled::*;
 pub use storage_get::*;
 pub use storage_get_from_address::*;
 pub use storage_key::*;
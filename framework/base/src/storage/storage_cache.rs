@@ -0,0 +1,176 @@
+//! Write-back storage cache, enabled by `#[storage_cache]` endpoints.
+//!
+//! The cache is kept in a managed map, so it requires the `managed-map` feature.
+//! The VM cannot list the entries of a managed map, so the keys of the dirty entries
+//! are also kept in a separate buffer, nested-encoded one after the other.
+
+use crate::{
+    api::{
+        const_handles, use_raw_handle, HandleConstraints, ManagedBufferApiImpl, ManagedMapApiImpl,
+        ManagedTypeApi, StaticVarApiImpl, StorageReadApi, StorageReadApiImpl, StorageWriteApi,
+        StorageWriteApiImpl,
+    },
+    codec::NestedDecode,
+    types::{ManagedBuffer, ManagedBufferNestedDecodeInput, ManagedType},
+};
+
+/// Cache entries are prefixed by a flag, so that they are never empty,
+/// and so that values that were only read are not written back.
+const CLEAN_ENTRY_FLAG: u8 = 0;
+const DIRTY_ENTRY_FLAG: u8 = 1;
+
+fn storage_cache_map_handle<A>() -> Option<A::ManagedMapHandle>
+where
+    A: ManagedTypeApi,
+{
+    let raw_handle = A::static_var_api_impl().get_storage_cache_map_handle();
+    if raw_handle == const_handles::UNINITIALIZED_HANDLE {
+        None
+    } else {
+        Some(use_raw_handle(raw_handle))
+    }
+}
+
+/// Enables the write-back storage cache for the rest of the current call.
+///
+/// While enabled, storage reads of the current contract are memoized
+/// and storage writes are buffered, until the next call to `storage_cache_flush`.
+///
+/// Generated at the start of endpoints annotated with `#[storage_cache]`.
+pub fn storage_cache_enable<A>()
+where
+    A: ManagedTypeApi,
+{
+    if storage_cache_map_handle::<A>().is_none() {
+        let api = A::managed_type_impl();
+        let map_handle = api.mm_new();
+        let dirty_keys_handle = api.mb_new_empty();
+        A::static_var_api_impl().set_storage_cache_map_handle(map_handle.get_raw_handle());
+        A::static_var_api_impl()
+            .set_storage_cache_dirty_keys_handle(dirty_keys_handle.get_raw_handle());
+    }
+}
+
+pub fn storage_cache_is_enabled<A>() -> bool
+where
+    A: ManagedTypeApi,
+{
+    storage_cache_map_handle::<A>().is_some()
+}
+
+/// Writes all buffered values to storage and empties the cache, which stays enabled.
+///
+/// Generated at the end of endpoints annotated with `#[storage_cache]`.
+/// Also called before every outgoing call, so that the callee, as well as any reentrant call,
+/// sees the up-to-date storage. The cache being emptied means that values changed by such calls
+/// are loaded again afterwards.
+///
+/// Does nothing if the cache is not enabled.
+pub fn storage_cache_flush<A>()
+where
+    A: StorageWriteApi + ManagedTypeApi,
+{
+    if let Some(map_handle) = storage_cache_map_handle::<A>() {
+        let api = A::managed_type_impl();
+        let dirty_keys_handle: A::ManagedBufferHandle =
+            use_raw_handle(A::static_var_api_impl().get_storage_cache_dirty_keys_handle());
+        let mut dirty_keys_input = ManagedBufferNestedDecodeInput::new(
+            ManagedBuffer::<A>::from_handle(dirty_keys_handle.clone()),
+        );
+        while !dirty_keys_input.is_depleted() {
+            let key = match ManagedBuffer::<A>::dep_decode(&mut dirty_keys_input) {
+                Ok(key) => key,
+                Err(_) => break,
+            };
+            let entry = ManagedBuffer::<A>::from_handle(use_raw_handle(
+                A::static_var_api_impl().next_handle(),
+            ));
+            api.mm_get(map_handle.clone(), key.get_handle(), entry.get_handle());
+            let value = entry.copy_slice(1, entry.len() - 1).unwrap_or_default();
+            A::storage_write_api_impl()
+                .storage_store_managed_buffer_raw(key.get_handle(), value.get_handle());
+        }
+
+        api.mm_drop(map_handle);
+        let new_map_handle = api.mm_new();
+        A::static_var_api_impl().set_storage_cache_map_handle(new_map_handle.get_raw_handle());
+        api.mb_overwrite(dirty_keys_handle, &[]);
+    }
+}
+
+/// Loads a value from the storage of the current contract, going through the cache if enabled.
+pub(crate) fn storage_cache_load_raw<A>(
+    key_handle: A::ManagedBufferHandle,
+    dest_handle: A::ManagedBufferHandle,
+) where
+    A: StorageReadApi + ManagedTypeApi,
+{
+    let map_handle = if let Some(map_handle) = storage_cache_map_handle::<A>() {
+        map_handle
+    } else {
+        A::storage_read_api_impl().storage_load_managed_buffer_raw(key_handle, dest_handle);
+        return;
+    };
+
+    let api = A::managed_type_impl();
+    let entry_handle: A::ManagedBufferHandle =
+        use_raw_handle(A::static_var_api_impl().next_handle());
+    api.mm_get(map_handle.clone(), key_handle.clone(), entry_handle.clone());
+    let entry_len = api.mb_len(entry_handle.clone());
+    if entry_len > 0 {
+        let _ = api.mb_copy_slice(entry_handle.clone(), 1, entry_len - 1, dest_handle);
+        api.mb_drop(entry_handle);
+        return;
+    }
+
+    A::storage_read_api_impl()
+        .storage_load_managed_buffer_raw(key_handle.clone(), dest_handle.clone());
+    api.mb_overwrite(entry_handle.clone(), &[CLEAN_ENTRY_FLAG]);
+    api.mb_append(entry_handle.clone(), dest_handle);
+    api.mm_put(map_handle, key_handle, entry_handle.clone());
+    api.mb_drop(entry_handle);
+}
+
+/// Saves a value to the storage of the current contract, or only to the cache if enabled.
+pub(crate) fn storage_cache_store_raw<A>(
+    key_handle: A::ManagedBufferHandle,
+    value_handle: A::ManagedBufferHandle,
+) where
+    A: StorageWriteApi + ManagedTypeApi,
+{
+    let map_handle = if let Some(map_handle) = storage_cache_map_handle::<A>() {
+        map_handle
+    } else {
+        A::storage_write_api_impl().storage_store_managed_buffer_raw(key_handle, value_handle);
+        return;
+    };
+
+    let api = A::managed_type_impl();
+    if !is_dirty_entry::<A>(map_handle.clone(), key_handle.clone()) {
+        let dirty_keys_handle: A::ManagedBufferHandle =
+            use_raw_handle(A::static_var_api_impl().get_storage_cache_dirty_keys_handle());
+        let key_len = api.mb_len(key_handle.clone()) as u32;
+        api.mb_append_bytes(dirty_keys_handle.clone(), &key_len.to_be_bytes());
+        api.mb_append(dirty_keys_handle, key_handle.clone());
+    }
+
+    let entry_handle = api.mb_new_from_bytes(&[DIRTY_ENTRY_FLAG]);
+    api.mb_append(entry_handle.clone(), value_handle);
+    api.mm_put(map_handle, key_handle, entry_handle.clone());
+    api.mb_drop(entry_handle);
+}
+
+/// Dirty entries are written back on flush, so their keys are already in the dirty key list.
+fn is_dirty_entry<A>(map_handle: A::ManagedMapHandle, key_handle: A::ManagedBufferHandle) -> bool
+where
+    A: ManagedTypeApi,
+{
+    let api = A::managed_type_impl();
+    let entry_handle: A::ManagedBufferHandle =
+        use_raw_handle(A::static_var_api_impl().next_handle());
+    api.mm_get(map_handle, key_handle, entry_handle.clone());
+    let mut flag = [CLEAN_ENTRY_FLAG; 1];
+    let _ = api.mb_load_slice(entry_handle.clone(), 0, &mut flag);
+    api.mb_drop(entry_handle);
+    flag[0] == DIRTY_ENTRY_FLAG
+}
@@ -0,0 +1,36 @@
+//! Storage access without the cache, when the `managed-map` feature is off.
+//!
+//! `#[storage_cache]` endpoints do not compile in this case,
+//! since `storage_cache_enable` is not available.
+
+use crate::api::{
+    ManagedTypeApi, StorageReadApi, StorageReadApiImpl, StorageWriteApi, StorageWriteApiImpl,
+};
+
+/// No cache, so there is never anything to write back.
+#[inline]
+pub(crate) fn storage_cache_flush<A>()
+where
+    A: StorageWriteApi + ManagedTypeApi,
+{
+}
+
+#[inline]
+pub(crate) fn storage_cache_load_raw<A>(
+    key_handle: A::ManagedBufferHandle,
+    dest_handle: A::ManagedBufferHandle,
+) where
+    A: StorageReadApi + ManagedTypeApi,
+{
+    A::storage_read_api_impl().storage_load_managed_buffer_raw(key_handle, dest_handle);
+}
+
+#[inline]
+pub(crate) fn storage_cache_store_raw<A>(
+    key_handle: A::ManagedBufferHandle,
+    value_handle: A::ManagedBufferHandle,
+) where
+    A: StorageWriteApi + ManagedTypeApi,
+{
+    A::storage_write_api_impl().storage_store_managed_buffer_raw(key_handle, value_handle);
+}
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 use crate::{
     api::{
         const_handles, use_raw_handle, ErrorApi, ErrorApiImpl, ManagedBufferApiImpl,
-        ManagedTypeApi, StaticVarApiImpl, StorageReadApi, StorageReadApiImpl,
+        ManagedTypeApi, StaticVarApiImpl, StorageReadApi,
     },
     codec::*,
     err_msg,
@@ -13,7 +13,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 
-use super::StorageKey;
+use super::{storage_cache_load_raw, StorageKey};
 
 struct StorageGetInput<'k, A>
 where
@@ -34,8 +34,7 @@ where
     fn to_managed_buffer(&self) -> ManagedBuffer<A> {
         let mbuf_handle: A::ManagedBufferHandle =
             use_raw_handle(A::static_var_api_impl().next_handle());
-        A::storage_read_api_impl()
-            .storage_load_managed_buffer_raw(self.key.buffer.get_handle(), mbuf_handle.clone());
+        storage_cache_load_raw::<A>(self.key.buffer.get_handle(), mbuf_handle.clone());
         ManagedBuffer::from_handle(mbuf_handle)
     }
 
@@ -49,8 +48,7 @@ where
 
     fn load_len_managed_buffer(&self) -> usize {
         let value_handle: A::ManagedBufferHandle = use_raw_handle(const_handles::MBUF_TEMPORARY_1);
-        A::storage_read_api_impl()
-            .storage_load_managed_buffer_raw(self.key.buffer.get_handle(), value_handle.clone());
+        storage_cache_load_raw::<A>(self.key.buffer.get_handle(), value_handle.clone());
         A::managed_type_impl().mb_len(value_handle)
     }
 }
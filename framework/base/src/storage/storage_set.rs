@@ -1,7 +1,7 @@
 use crate::{
     api::{
         const_handles, use_raw_handle, ErrorApi, ManagedBufferApiImpl, ManagedTypeApi,
-        StorageWriteApi, StorageWriteApiImpl,
+        StorageWriteApi,
     },
     codec::*,
     contract_base::ExitCodecErrorHandler,
@@ -9,7 +9,7 @@ use crate::{
     types::{BigInt, BigUint, ManagedBuffer, ManagedBufferCachedBuilder, ManagedRef, ManagedType},
 };
 
-use super::StorageKey;
+use super::{storage_cache_store_raw, StorageKey};
 
 struct StorageSetOutput<'k, A>
 where
@@ -28,10 +28,7 @@ where
     }
 
     fn set_managed_buffer(&self, managed_buffer: &ManagedBuffer<A>) {
-        A::storage_write_api_impl().storage_store_managed_buffer_raw(
-            self.key.buffer.get_handle(),
-            managed_buffer.handle.clone(),
-        );
+        storage_cache_store_raw::<A>(self.key.buffer.get_handle(), managed_buffer.handle.clone());
     }
 }
 
@@ -100,5 +97,5 @@ where
     let value_handle: A::ManagedBufferHandle = use_raw_handle(const_handles::MBUF_CONST_EMPTY);
     A::managed_type_impl().mb_overwrite(value_handle.clone(), &[]);
 
-    A::storage_write_api_impl().storage_store_managed_buffer_raw(key.get_handle(), value_handle);
+    storage_cache_store_raw::<A>(key.get_handle(), value_handle);
 }
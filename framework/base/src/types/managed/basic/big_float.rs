@@ -2,7 +2,8 @@ use super::ManagedBuffer;
 
 use crate::{
     api::{
-        use_raw_handle, BigFloatApiImpl, ManagedTypeApi, ManagedTypeApiImpl, Sign, StaticVarApiImpl,
+        const_handles, use_raw_handle, BigFloatApiImpl, HandleConstraints, ManagedTypeApi,
+        ManagedTypeApiImpl, Sign, StaticVarApiImpl,
     },
     types::{BigInt, BigUint, ManagedType},
 };
@@ -185,6 +186,14 @@ impl<M: ManagedTypeApi> BigFloat<M> {
         BigFloat::from_handle(new_bf_handle)
     }
 
+    /// Releases the handle of the big float. Works the same as `ManagedBuffer::release`,
+    /// so it must not be called on a handle shared with another value.
+    pub fn release(self) {
+        if !const_handles::is_reserved_handle(self.handle.get_raw_handle_unchecked()) {
+            M::managed_type_impl().bf_drop(self.handle);
+        }
+    }
+
     pub fn to_buffer(&self) -> ManagedBuffer<M> {
         let new_man_buf_handle: M::ManagedBufferHandle =
             use_raw_handle(M::static_var_api_impl().next_handle());
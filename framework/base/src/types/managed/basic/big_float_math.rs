@@ -0,0 +1,224 @@
+use core::cmp::Ordering;
+
+use crate::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+};
+
+use super::BigFloat;
+
+/// Default precision of the transcendental functions, in decimals.
+pub const BIG_FLOAT_DEFAULT_PRECISION: u32 = 18;
+
+/// Hard limit on the number of series terms, regardless of precision.
+///
+/// Keeps gas consumption bounded. All series used here converge long before reaching it.
+const MAX_SERIES_TERMS: u32 = 200;
+
+/// Newton iterations applied on top of the logarithm-based estimate in `nth_root`.
+const NTH_ROOT_NEWTON_STEPS: u32 = 2;
+
+// All functions below only use the basic BigFloat operations, evaluated in a fixed order,
+// and stop the series expansions at a threshold derived from the precision alone.
+// Results are therefore the same on every run, for the same input and precision.
+impl<M: ManagedTypeApi> BigFloat<M> {
+    /// Natural logarithm. Signals an error if the value is not strictly positive.
+    pub fn ln(&self) -> Self {
+        self.ln_with_precision(BIG_FLOAT_DEFAULT_PRECISION)
+    }
+
+    /// Natural logarithm, with series expansion stopped at `10^-precision`.
+    pub fn ln_with_precision(&self, precision: u32) -> Self {
+        let (mantissa, exponent) = self.log_reduce();
+        let ln_2 = ln_2_with_precision::<M>(precision);
+        let ln_mantissa = ln_reduced(&mantissa, precision);
+        &(&BigFloat::from(exponent) * &ln_2) + &ln_mantissa
+    }
+
+    /// Base 2 logarithm. Signals an error if the value is not strictly positive.
+    pub fn log2(&self) -> Self {
+        self.log2_with_precision(BIG_FLOAT_DEFAULT_PRECISION)
+    }
+
+    /// Base 2 logarithm, with series expansion stopped at `10^-precision`.
+    pub fn log2_with_precision(&self, precision: u32) -> Self {
+        let (mantissa, exponent) = self.log_reduce();
+        let ln_2 = ln_2_with_precision::<M>(precision);
+        let ln_mantissa = ln_reduced(&mantissa, precision);
+        &BigFloat::from(exponent) + &(&ln_mantissa / &ln_2)
+    }
+
+    /// The exponential function, `e^self`.
+    pub fn exp(&self) -> Self {
+        self.exp_with_precision(BIG_FLOAT_DEFAULT_PRECISION)
+    }
+
+    /// The exponential function, with series expansion stopped at `10^-precision`.
+    pub fn exp_with_precision(&self, precision: u32) -> Self {
+        // self = n * ln(2) + r, with |r| <= ln(2) / 2
+        // e^self = 2^n * e^r
+        let ln_2 = ln_2_with_precision::<M>(precision);
+        let n_float = &(self / &ln_2) + &BigFloat::from_frac(1, 2);
+        let n = match n_float.floor().to_i64() {
+            Some(n) if n > i32::MIN as i64 && n <= i32::MAX as i64 => n as i32,
+            _ => M::error_api_impl().signal_error(err_msg::BIG_FLOAT_EXP_OUT_OF_RANGE),
+        };
+        let r = self - &(&BigFloat::from(n) * &ln_2);
+
+        let epsilon = epsilon::<M>(precision);
+        let mut sum = BigFloat::from(1);
+        let mut term = BigFloat::from(1);
+        for k in 1..=MAX_SERIES_TERMS {
+            term = &(&term * &r) / &BigFloat::from(k as i64);
+            if term.magnitude() < epsilon {
+                break;
+            }
+            sum += &term;
+        }
+
+        &sum * &pow_2::<M>(n)
+    }
+
+    /// Raises the value to a fractional power, `self^exp`.
+    ///
+    /// Signals an error for negative bases, and for zero raised to a negative power.
+    pub fn pow_frac(&self, exp: &BigFloat<M>) -> Self {
+        self.pow_frac_with_precision(exp, BIG_FLOAT_DEFAULT_PRECISION)
+    }
+
+    /// Raises the value to a fractional power, with series expansions stopped at `10^-precision`.
+    pub fn pow_frac_with_precision(&self, exp: &BigFloat<M>, precision: u32) -> Self {
+        if cmp_zero(self).is_lt() {
+            M::error_api_impl().signal_error(err_msg::BIG_FLOAT_POW_NEGATIVE_BASE);
+        }
+        if cmp_zero(self).is_eq() {
+            if cmp_zero(exp).is_lt() {
+                M::error_api_impl().signal_error(err_msg::BIG_FLOAT_DIVISION_BY_ZERO);
+            }
+            if cmp_zero(exp).is_eq() {
+                return BigFloat::from(1);
+            }
+            return BigFloat::zero();
+        }
+
+        (exp * &self.ln_with_precision(precision)).exp_with_precision(precision)
+    }
+
+    /// The n-th root of the value.
+    ///
+    /// Odd roots of negative values are negative. Signals an error for even roots
+    /// of negative values, and for `n == 0`.
+    pub fn nth_root(&self, n: u32) -> Self {
+        self.nth_root_with_precision(n, BIG_FLOAT_DEFAULT_PRECISION)
+    }
+
+    /// The n-th root of the value, with series expansions stopped at `10^-precision`.
+    pub fn nth_root_with_precision(&self, n: u32, precision: u32) -> Self {
+        if n == 0 {
+            M::error_api_impl().signal_error(err_msg::BIG_FLOAT_ZERO_ROOT);
+        }
+        if cmp_zero(self).is_eq() {
+            return BigFloat::zero();
+        }
+        let negative = cmp_zero(self).is_lt();
+        if negative && n % 2 == 0 {
+            M::error_api_impl().signal_error(err_msg::BIG_FLOAT_EVEN_ROOT_NEGATIVE);
+        }
+        if n == 1 {
+            return self.clone();
+        }
+
+        let x = self.magnitude();
+        let n_float = BigFloat::from(n as i64);
+        let n_minus_one = BigFloat::from(n as i64 - 1);
+        let mut root = (&x.ln_with_precision(precision) / &n_float).exp_with_precision(precision);
+
+        // Newton's method: root = ((n - 1) * root + x / root^(n - 1)) / n
+        for _ in 0..NTH_ROOT_NEWTON_STEPS {
+            let correction = &x / &root.pow(n as i32 - 1);
+            root = &(&(&n_minus_one * &root) + &correction) / &n_float;
+        }
+
+        if negative {
+            root.neg()
+        } else {
+            root
+        }
+    }
+
+    /// Splits a strictly positive value into `mantissa * 2^exponent`, with `mantissa` in `[1, 2)`.
+    ///
+    /// The exponent comes from the bit length of the integer part (of the inverse, for values below 1),
+    /// so the cost does not depend on the magnitude of the value.
+    fn log_reduce(&self) -> (Self, i64) {
+        if cmp_zero(self).is_le() {
+            M::error_api_impl().signal_error(err_msg::BIG_FLOAT_LOG_NON_POSITIVE);
+        }
+
+        let mut exponent = if *self >= 1 {
+            self.floor().magnitude().log2() as i64
+        } else {
+            let inverse = &BigFloat::from(1) / self;
+            -(inverse.floor().magnitude().log2() as i64) - 1
+        };
+        let mut mantissa = self * &pow_2::<M>(-exponent as i32);
+
+        // the division above can be off by one ulp, at the interval edges
+        if mantissa >= 2 {
+            mantissa = &mantissa / &BigFloat::from(2);
+            exponent += 1;
+        } else if mantissa < 1 {
+            mantissa = &mantissa * &BigFloat::from(2);
+            exponent -= 1;
+        }
+        (mantissa, exponent)
+    }
+}
+
+/// Compares with zero via `bf_cmp`, since `bf_sign` rejects zero.
+fn cmp_zero<M: ManagedTypeApi>(x: &BigFloat<M>) -> Ordering {
+    x.cmp(&BigFloat::zero())
+}
+
+/// `10^-precision`.
+fn epsilon<M: ManagedTypeApi>(precision: u32) -> BigFloat<M> {
+    &BigFloat::from(1) / &BigFloat::from(10).pow(precision as i32)
+}
+
+/// `2^n`, also for negative `n`.
+fn pow_2<M: ManagedTypeApi>(n: i32) -> BigFloat<M> {
+    let power = BigFloat::from(2).pow(n.unsigned_abs() as i32);
+    if n < 0 {
+        &BigFloat::from(1) / &power
+    } else {
+        power
+    }
+}
+
+fn ln_2_with_precision<M: ManagedTypeApi>(precision: u32) -> BigFloat<M> {
+    ln_reduced(&BigFloat::from(2), precision)
+}
+
+/// Natural logarithm of a value in `[1, 2]`.
+///
+/// Uses `ln(x) = 2 * atanh(z)`, with `z = (x - 1) / (x + 1)`, so `z` is in `[0, 1/3]`
+/// and the series `z + z^3/3 + z^5/5 + ...` converges quickly.
+fn ln_reduced<M: ManagedTypeApi>(x: &BigFloat<M>, precision: u32) -> BigFloat<M> {
+    let one = BigFloat::from(1);
+    let z = &(x - &one) / &(x + &one);
+    let z_squared = &z * &z;
+    let epsilon = epsilon::<M>(precision);
+
+    let mut sum = z.clone();
+    let mut power = z;
+    for k in 1..MAX_SERIES_TERMS {
+        power *= &z_squared;
+        let term = &power / &BigFloat::from(2 * k as i64 + 1);
+        if term < epsilon {
+            break;
+        }
+        sum += &term;
+    }
+
+    &sum * &BigFloat::from(2)
+}
@@ -79,6 +79,14 @@ impl<M: ManagedTypeApi> BigInt<M> {
         Self::set_value(temp.clone(), value);
         temp
     }
+
+    /// Releases the handle of the big integer. Works the same as `ManagedBuffer::release`,
+    /// so it must not be called on a handle shared with another value.
+    pub fn release(self) {
+        if !const_handles::is_reserved_handle(self.handle.get_raw_handle_unchecked()) {
+            M::managed_type_impl().bi_drop(self.handle);
+        }
+    }
 }
 
 impl<M: ManagedTypeApi> From<BigUint<M>> for BigInt<M> {
@@ -188,6 +188,14 @@ impl<M: ManagedTypeApi> BigUint<M> {
         M::managed_type_impl().mb_from_big_int_unsigned(self.handle.clone(), mb_handle.clone());
         ManagedBuffer::from_handle(mb_handle)
     }
+
+    /// Releases the handle of the big integer. Works the same as `ManagedBuffer::release`,
+    /// so it must not be called on a handle shared with another value.
+    pub fn release(self) {
+        if !const_handles::is_reserved_handle(self.handle.get_raw_handle_unchecked()) {
+            M::managed_type_impl().bi_drop(self.handle);
+        }
+    }
 }
 
 impl<M: ManagedTypeApi> BigUint<M> {
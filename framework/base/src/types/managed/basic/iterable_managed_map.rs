@@ -0,0 +1,231 @@
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::ManagedTypeApi,
+    codec::{
+        DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
+        NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput, TryStaticCast,
+    },
+    types::{ManagedBufferNestedDecodeInput, ManagedVec, ManagedVecRefIterator},
+};
+
+use super::{ManagedBuffer, ManagedMap, ManagedMapEntry};
+
+/// A `ManagedMap` that also keeps a list of its keys, so that it can be iterated.
+///
+/// Keys are iterated in insertion order.
+/// Entries with empty values are considered absent.
+///
+/// Keeping the key list up to date costs extra VM calls on every `put`,
+/// and removing a key has to search the list.
+/// Plain `ManagedMap` should be preferred when the entries do not need to be listed.
+pub struct IterableManagedMap<M: ManagedTypeApi> {
+    map: ManagedMap<M>,
+    keys: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+impl<M: ManagedTypeApi> IterableManagedMap<M> {
+    pub fn new() -> Self {
+        IterableManagedMap {
+            map: ManagedMap::new(),
+            keys: ManagedVec::new(),
+        }
+    }
+
+    /// Bulk-loads the map from a list of key-value pairs.
+    ///
+    /// Later pairs overwrite earlier ones with the same key.
+    pub fn from_pairs(pairs: &ManagedVec<M, ManagedMapEntry<M>>) -> Self {
+        let mut map = IterableManagedMap::new();
+        for entry in pairs {
+            map.put(&entry.key, &entry.value);
+        }
+        map
+    }
+}
+
+impl<M: ManagedTypeApi> Default for IterableManagedMap<M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: ManagedTypeApi> IterableManagedMap<M> {
+    pub fn get(&self, key: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+        self.map.get(key)
+    }
+
+    /// Putting an empty value removes the key.
+    pub fn put(&mut self, key: &ManagedBuffer<M>, value: &ManagedBuffer<M>) {
+        if value.is_empty() {
+            let _ = self.remove(key);
+            return;
+        }
+
+        if !self.map.contains(key) {
+            self.keys.push(key.clone());
+        }
+        self.map.put(key, value);
+    }
+
+    pub fn remove(&mut self, key: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+        let removed_value = self.map.remove(key);
+        if !removed_value.is_empty() {
+            if let Some(index) = self.keys.find(key) {
+                self.keys.remove(index);
+            }
+        }
+        removed_value
+    }
+
+    pub fn contains(&self, key: &ManagedBuffer<M>) -> bool {
+        self.map.contains(key)
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Removes all entries.
+    ///
+    /// The underlying map is replaced by a new one, so it costs the same regardless of the number of entries.
+    pub fn clear(&mut self) {
+        core::mem::take(&mut self.map).release();
+        self.keys.clear();
+    }
+
+    /// All keys, in insertion order.
+    pub fn keys(&self) -> &ManagedVec<M, ManagedBuffer<M>> {
+        &self.keys
+    }
+
+    /// All values, in the insertion order of their keys.
+    pub fn values(&self) -> ManagedVec<M, ManagedBuffer<M>> {
+        self.iter().map(|(_, value)| value).collect()
+    }
+
+    /// All entries, as a list of key-value pairs, in insertion order.
+    pub fn to_pairs(&self) -> ManagedVec<M, ManagedMapEntry<M>> {
+        self.iter().map(ManagedMapEntry::from).collect()
+    }
+
+    /// Iterates over all key-value pairs, in insertion order.
+    ///
+    /// The values are retrieved as the iteration progresses.
+    pub fn iter(&self) -> IterableManagedMapIterator<'_, M> {
+        IterableManagedMapIterator {
+            map: &self.map,
+            keys_iter: self.keys.iter(),
+        }
+    }
+}
+
+impl<'a, M: ManagedTypeApi> IntoIterator for &'a IterableManagedMap<M> {
+    type Item = (ManagedBuffer<M>, ManagedBuffer<M>);
+
+    type IntoIter = IterableManagedMapIterator<'a, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct IterableManagedMapIterator<'a, M: ManagedTypeApi> {
+    map: &'a ManagedMap<M>,
+    keys_iter: ManagedVecRefIterator<'a, M, ManagedBuffer<M>>,
+}
+
+impl<'a, M: ManagedTypeApi> Iterator for IterableManagedMapIterator<'a, M> {
+    type Item = (ManagedBuffer<M>, ManagedBuffer<M>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys_iter.next()?.clone_value();
+        let value = self.map.get(&key);
+        Some((key, value))
+    }
+}
+
+impl<M: ManagedTypeApi> TryStaticCast for IterableManagedMap<M> {}
+
+/// Encoded the same as a list of `ManagedMapEntry`.
+impl<M: ManagedTypeApi> TopEncode for IterableManagedMap<M> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let mut nested_buffer = output.start_nested_encode();
+        for (key, value) in self {
+            key.dep_encode_or_handle_err(&mut nested_buffer, h)?;
+            value.dep_encode_or_handle_err(&mut nested_buffer, h)?;
+        }
+        output.finalize_nested_encode(nested_buffer);
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> NestedEncode for IterableManagedMap<M> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.len().dep_encode_or_handle_err(dest, h)?;
+        for (key, value) in self {
+            key.dep_encode_or_handle_err(dest, h)?;
+            value.dep_encode_or_handle_err(dest, h)?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for IterableManagedMap<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let buffer = ManagedBuffer::<M>::top_decode_or_handle_err(input, h)?;
+        let mut nested_de_input = ManagedBufferNestedDecodeInput::new(buffer);
+        let mut map = IterableManagedMap::new();
+        while !nested_de_input.is_depleted() {
+            let key = ManagedBuffer::dep_decode_or_handle_err(&mut nested_de_input, h)?;
+            let value = ManagedBuffer::dep_decode_or_handle_err(&mut nested_de_input, h)?;
+            map.put(&key, &value);
+        }
+        Ok(map)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for IterableManagedMap<M> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let num_entries = usize::dep_decode_or_handle_err(input, h)?;
+        let mut map = IterableManagedMap::new();
+        for _ in 0..num_entries {
+            let key = ManagedBuffer::dep_decode_or_handle_err(input, h)?;
+            let value = ManagedBuffer::dep_decode_or_handle_err(input, h)?;
+            map.put(&key, &value);
+        }
+        Ok(map)
+    }
+}
+
+impl<M: ManagedTypeApi> TypeAbi for IterableManagedMap<M> {
+    /// It is semantically equivalent to a list of `ManagedMapEntry`.
+    fn type_name() -> TypeName {
+        <&[ManagedMapEntry<M>] as TypeAbi>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        ManagedMapEntry::<M>::provide_type_descriptions(accumulator);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     abi::TypeName,
     api::{
-        use_raw_handle, ErrorApiImpl, HandleConstraints, InvalidSliceError, ManagedBufferApiImpl,
-        ManagedTypeApi, StaticVarApiImpl,
+        const_handles, use_raw_handle, ErrorApiImpl, HandleConstraints, InvalidSliceError,
+        ManagedBufferApiImpl, ManagedTypeApi, StaticVarApiImpl,
     },
     codec::{
         CodecFrom, CodecFromSelf, DecodeErrorHandler, Empty, EncodeErrorHandler, NestedDecode,
@@ -64,6 +64,28 @@ impl<M: ManagedTypeApi> ManagedBuffer<M> {
         ManagedBuffer::from_handle(new_handle)
     }
 
+    /// Releases the underlying handle, after which the VM can reclaim its memory.
+    ///
+    /// Only the Rust VM reclaims handles, on the blockchain this does nothing,
+    /// but it can help keep long loops within the managed memory limits of the debugger.
+    /// Handles reserved for the APIs (call values, temporaries) are never released.
+    ///
+    /// Only explicit releases are reclaimed: managed types have no `Drop` implementation,
+    /// so values that simply go out of scope stay allocated until the end of the call.
+    /// This includes the temporary values created in every iteration of a loop over a `ManagedVec`,
+    /// which need to be released one by one to keep the loop within the limits.
+    ///
+    /// Only values that own their handle should be released.
+    /// A value created with `from_handle` out of the handle of another value,
+    /// for instance from `ManagedRef::get_raw_handle_of_ref` on an item of a `ManagedVec`,
+    /// shares that handle, and the other value becomes unusable once it is released.
+    /// The blockchain ignores releases, so such mistakes only surface in the Rust VM, as failed calls.
+    pub fn release(self) {
+        if !const_handles::is_reserved_handle(self.handle.get_raw_handle_unchecked()) {
+            M::managed_type_impl().mb_drop(self.handle);
+        }
+    }
+
     fn load_static_cache(&self) -> StaticBufferRef<M>
     where
         M: ManagedTypeApi,
@@ -448,8 +470,7 @@ impl<M: ManagedTypeApi> SCDisplay for ManagedBuffer<M> {
 
 impl<M: ManagedTypeApi> SCLowerHex for ManagedBuffer<M> {
     fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
-        let hex_handle: M::ManagedBufferHandle =
-            use_raw_handle(crate::api::const_handles::MBUF_TEMPORARY_1);
+        let hex_handle: M::ManagedBufferHandle = use_raw_handle(const_handles::MBUF_TEMPORARY_1);
         M::managed_type_impl().mb_to_hex(self.handle.clone(), hex_handle.clone());
         f.append_managed_buffer(&ManagedBuffer::from_handle(
             hex_handle.cast_or_signal_error::<M, _>(),
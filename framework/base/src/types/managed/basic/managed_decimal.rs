@@ -0,0 +1,537 @@
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::{
+        const_handles, use_raw_handle, BigIntApiImpl, ErrorApiImpl, HandleConstraints,
+        ManagedTypeApi,
+    },
+    codec::{
+        CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+        NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    },
+    err_msg,
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::{BigFloat, BigUint, ManagedBuffer, ManagedType},
+};
+use alloc::format;
+use core::cmp::Ordering;
+
+/// Number of decimals, decided at runtime.
+pub type NumDecimals = usize;
+
+/// Describes the scale of a `ManagedDecimal`.
+///
+/// Implemented by `NumDecimals`, for a scale known only at runtime,
+/// and by `ConstDecimals`, for a scale fixed at compile time.
+pub trait Decimals: Clone {
+    fn num_decimals(&self) -> NumDecimals;
+
+    /// `10^num_decimals`, i.e. the number of raw units in one unit.
+    fn scaling_factor<M: ManagedTypeApi>(&self) -> BigUint<M> {
+        scaling_factor(self.num_decimals())
+    }
+}
+
+impl Decimals for NumDecimals {
+    #[inline]
+    fn num_decimals(&self) -> NumDecimals {
+        *self
+    }
+}
+
+/// Zero-sized scale, known at compile time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstDecimals<const DECIMALS: NumDecimals>;
+
+impl<const DECIMALS: NumDecimals> Decimals for ConstDecimals<DECIMALS> {
+    #[inline]
+    fn num_decimals(&self) -> NumDecimals {
+        DECIMALS
+    }
+}
+
+fn scaling_factor<M: ManagedTypeApi>(num_decimals: NumDecimals) -> BigUint<M> {
+    BigUint::from(10u32).pow(num_decimals as u32)
+}
+
+/// Specifies what to do with the digits that get discarded
+/// when reducing the scale of a value or when dividing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Discard the extra digits.
+    #[default]
+    Down,
+
+    /// Round up whenever any of the discarded digits is non-zero.
+    Up,
+
+    /// Round to the nearest value, ties go up.
+    HalfUp,
+
+    /// Round to the nearest value, ties go to the even neighbour ("banker's rounding").
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Divides `numerator` by `denominator`, rounding the result according to the mode.
+    ///
+    /// Division by zero signals an error, same as for `BigUint`.
+    pub fn div<M: ManagedTypeApi>(
+        self,
+        numerator: &BigUint<M>,
+        denominator: &BigUint<M>,
+    ) -> BigUint<M> {
+        let quotient = numerator / denominator;
+        if self == RoundingMode::Down {
+            return quotient;
+        }
+
+        let remainder = numerator % denominator;
+        if remainder == 0u32 {
+            return quotient;
+        }
+
+        let round_up = match self {
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            RoundingMode::HalfUp | RoundingMode::HalfEven => {
+                let double_remainder = remainder * 2u32;
+                match double_remainder.cmp(denominator) {
+                    Ordering::Less => false,
+                    Ordering::Greater => true,
+                    Ordering::Equal => self == RoundingMode::HalfUp || &quotient % 2u32 == 1u32,
+                }
+            },
+        };
+
+        if round_up {
+            quotient + 1u32
+        } else {
+            quotient
+        }
+    }
+}
+
+/// Unsigned fixed-point decimal number.
+///
+/// Holds an integer amount of raw units, plus a scale, i.e. the number of decimals.
+/// For instance, `1.25` with 2 decimals is stored as `125` raw units.
+///
+/// The scale is either a compile time constant (`ConstDecimals<N>`), or a runtime value (`NumDecimals`).
+/// Arithmetic operations require both operands to have the same scale,
+/// use `rescale` to convert between scales.
+#[derive(Debug, Clone)]
+pub struct ManagedDecimal<M: ManagedTypeApi, D: Decimals> {
+    data: BigUint<M>,
+    decimals: D,
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    /// Builds a decimal directly from raw units, no scaling is performed.
+    #[inline]
+    pub fn from_raw_units(data: BigUint<M>, decimals: D) -> Self {
+        ManagedDecimal { data, decimals }
+    }
+
+    /// Builds a decimal representing the integer `value`, i.e. `value * 10^decimals` raw units.
+    pub fn from_big_uint(value: BigUint<M>, decimals: D) -> Self {
+        let data = value * decimals.scaling_factor();
+        ManagedDecimal { data, decimals }
+    }
+
+    /// Converts a `BigFloat` to a decimal with the given scale, truncating the extra digits.
+    ///
+    /// Negative values signal an error.
+    pub fn from_big_float(value: &BigFloat<M>, decimals: D) -> Self {
+        Self::from_big_float_with_rounding(value, decimals, RoundingMode::Down)
+    }
+
+    /// Converts a `BigFloat` to a decimal with the given scale, rounding according to the given mode.
+    ///
+    /// Negative values signal an error.
+    pub fn from_big_float_with_rounding(
+        value: &BigFloat<M>,
+        decimals: D,
+        rounding: RoundingMode,
+    ) -> Self {
+        if *value < 0i64 {
+            M::error_api_impl().signal_error(err_msg::MANAGED_DECIMAL_NEGATIVE);
+        }
+
+        let scaled = value * &BigFloat::from_big_uint(&decimals.scaling_factor());
+        let data = match rounding {
+            RoundingMode::Down => scaled.trunc().magnitude(),
+            RoundingMode::Up => scaled.ceil().magnitude(),
+            RoundingMode::HalfUp | RoundingMode::HalfEven => {
+                let floor = scaled.floor().magnitude();
+                let fraction = &scaled - &BigFloat::from_big_uint(&floor);
+                match fraction.cmp(&BigFloat::from_frac(1, 2)) {
+                    Ordering::Less => floor,
+                    Ordering::Greater => floor + 1u32,
+                    Ordering::Equal => {
+                        if rounding == RoundingMode::HalfEven && &floor % 2u32 == 0u32 {
+                            floor
+                        } else {
+                            floor + 1u32
+                        }
+                    },
+                }
+            },
+        };
+        ManagedDecimal { data, decimals }
+    }
+
+    #[inline]
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.data
+    }
+
+    #[inline]
+    pub fn into_raw_units(self) -> BigUint<M> {
+        self.data
+    }
+
+    #[inline]
+    pub fn decimals(&self) -> &D {
+        &self.decimals
+    }
+
+    #[inline]
+    pub fn scale(&self) -> NumDecimals {
+        self.decimals.num_decimals()
+    }
+
+    #[inline]
+    pub fn scaling_factor(&self) -> BigUint<M> {
+        self.decimals.scaling_factor()
+    }
+
+    /// The integer part, the fractional part is discarded.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.data / &self.scaling_factor()
+    }
+
+    /// Rounds to an integer, according to the given mode.
+    pub fn round(&self, rounding: RoundingMode) -> BigUint<M> {
+        rounding.div(&self.data, &self.scaling_factor())
+    }
+
+    pub fn to_big_float(&self) -> BigFloat<M> {
+        BigFloat::from_big_uint(&self.data) / BigFloat::from_big_uint(&self.scaling_factor())
+    }
+
+    /// Converts to another scale. Extra digits are truncated when the scale is reduced.
+    pub fn rescale<T: Decimals>(&self, scale_to: T) -> ManagedDecimal<M, T> {
+        self.rescale_with_rounding(scale_to, RoundingMode::Down)
+    }
+
+    /// Converts to another scale, rounding according to the given mode when the scale is reduced.
+    ///
+    /// Increasing the scale is always exact.
+    pub fn rescale_with_rounding<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let from_num_decimals = self.scale();
+        let to_num_decimals = scale_to.num_decimals();
+        let data = match from_num_decimals.cmp(&to_num_decimals) {
+            Ordering::Equal => self.data.clone(),
+            Ordering::Less => &self.data * &scaling_factor(to_num_decimals - from_num_decimals),
+            Ordering::Greater => rounding.div(
+                &self.data,
+                &scaling_factor(from_num_decimals - to_num_decimals),
+            ),
+        };
+        ManagedDecimal::from_raw_units(data, scale_to)
+    }
+
+    /// Converts the scale to one only known at runtime.
+    pub fn into_var_decimals(self) -> ManagedDecimal<M, NumDecimals> {
+        let num_decimals = self.scale();
+        ManagedDecimal::from_raw_units(self.data, num_decimals)
+    }
+
+    fn same_scale<D2: Decimals>(&self, other: &ManagedDecimal<M, D2>) -> bool {
+        self.scale() == other.scale()
+    }
+
+    pub(super) fn require_same_scale<D2: Decimals>(&self, other: &ManagedDecimal<M, D2>) {
+        if !self.same_scale(other) {
+            M::error_api_impl().signal_error(err_msg::MANAGED_DECIMAL_SCALE_MISMATCH);
+        }
+    }
+
+    /// Returns `None` if the scales differ.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if !self.same_scale(other) {
+            return None;
+        }
+
+        Some(ManagedDecimal::from_raw_units(
+            &self.data + &other.data,
+            self.decimals.clone(),
+        ))
+    }
+
+    /// Returns `None` if the scales differ, or if the result would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if !self.same_scale(other) || self.data < other.data {
+            return None;
+        }
+
+        Some(ManagedDecimal::from_raw_units(
+            &self.data - &other.data,
+            self.decimals.clone(),
+        ))
+    }
+
+    /// Product, truncated to the common scale. Returns `None` if the scales differ.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        self.checked_mul_with_rounding(other, RoundingMode::Down)
+    }
+
+    /// Product, rounded to the common scale according to the given mode.
+    /// Returns `None` if the scales differ.
+    pub fn checked_mul_with_rounding(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        if !self.same_scale(other) {
+            return None;
+        }
+
+        Some(self.mul_rounded(other, rounding))
+    }
+
+    /// Quotient, truncated to the common scale.
+    /// Returns `None` if the scales differ, or if `other` is zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_div_with_rounding(other, RoundingMode::Down)
+    }
+
+    /// Quotient, rounded to the common scale according to the given mode.
+    /// Returns `None` if the scales differ, or if `other` is zero.
+    pub fn checked_div_with_rounding(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        if !self.same_scale(other) || other.data == 0u32 {
+            return None;
+        }
+
+        Some(self.div_rounded(other, rounding))
+    }
+
+    /// Assumes equal scales.
+    pub(super) fn mul_rounded(&self, other: &Self, rounding: RoundingMode) -> Self {
+        let product = &self.data * &other.data;
+        ManagedDecimal::from_raw_units(
+            rounding.div(&product, &self.scaling_factor()),
+            self.decimals.clone(),
+        )
+    }
+
+    /// Assumes equal scales. Division by zero signals an error.
+    pub(super) fn div_rounded(&self, other: &Self, rounding: RoundingMode) -> Self {
+        let numerator = &self.data * &self.scaling_factor();
+        ManagedDecimal::from_raw_units(rounding.div(&numerator, &other.data), self.decimals.clone())
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> ManagedDecimal<M, ConstDecimals<DECIMALS>> {
+    #[inline]
+    pub fn zero() -> Self {
+        ManagedDecimal::from_raw_units(BigUint::zero(), ConstDecimals)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> From<BigUint<M>>
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn from(value: BigUint<M>) -> Self {
+        ManagedDecimal::from_big_uint(value, ConstDecimals)
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> From<&ManagedDecimal<M, D>> for BigFloat<M> {
+    fn from(item: &ManagedDecimal<M, D>) -> Self {
+        item.to_big_float()
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> From<ManagedDecimal<M, D>> for BigFloat<M> {
+    fn from(item: ManagedDecimal<M, D>) -> Self {
+        item.to_big_float()
+    }
+}
+
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> PartialEq<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    fn eq(&self, other: &ManagedDecimal<M, D2>) -> bool {
+        cmp_decimals(self, other).is_eq()
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Eq for ManagedDecimal<M, D> {}
+
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> PartialOrd<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    fn partial_cmp(&self, other: &ManagedDecimal<M, D2>) -> Option<Ordering> {
+        Some(cmp_decimals(self, other))
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Ord for ManagedDecimal<M, D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_decimals(self, other)
+    }
+}
+
+/// Values with different scales are compared exactly, by bringing both to the larger scale.
+fn cmp_decimals<M: ManagedTypeApi, D1: Decimals, D2: Decimals>(
+    first: &ManagedDecimal<M, D1>,
+    second: &ManagedDecimal<M, D2>,
+) -> Ordering {
+    match first.scale().cmp(&second.scale()) {
+        Ordering::Equal => first.data.cmp(&second.data),
+        Ordering::Less => first.rescale(second.scale()).data.cmp(&second.data),
+        Ordering::Greater => first.data.cmp(&second.rescale(first.scale()).data),
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> CodecFromSelf for ManagedDecimal<M, D> {}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::top_decode_or_handle_err(input, h)?,
+            ConstDecimals,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::from_raw_units(
+            BigUint::dep_decode_or_handle_err(input, h)?,
+            ConstDecimals,
+        ))
+    }
+}
+
+/// The runtime scale is serialized after the raw units.
+impl<M: ManagedTypeApi> TopEncode for ManagedDecimal<M, NumDecimals> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        crate::codec::top_encode_from_nested(self, output, h)
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for ManagedDecimal<M, NumDecimals> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        crate::codec::top_decode_from_nested_or_handle_err(input, h)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedEncode for ManagedDecimal<M, NumDecimals> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)?;
+        self.decimals.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for ManagedDecimal<M, NumDecimals> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let data = BigUint::dep_decode_or_handle_err(input, h)?;
+        let decimals = NumDecimals::dep_decode_or_handle_err(input, h)?;
+        Ok(ManagedDecimal::from_raw_units(data, decimals))
+    }
+}
+
+impl<M: ManagedTypeApi> TypeAbi for ManagedDecimal<M, NumDecimals> {
+    fn type_name() -> TypeName {
+        TypeName::from("ManagedDecimal<usize>")
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TypeAbi
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn type_name() -> TypeName {
+        TypeName::from(format!("ManagedDecimal<{DECIMALS}>"))
+    }
+}
+
+/// Displayed as a decimal number, with all the fractional digits, e.g. `12.050` for 3 decimals.
+impl<M: ManagedTypeApi, D: Decimals> SCDisplay for ManagedDecimal<M, D> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        let scaling_factor = self.scaling_factor();
+        let integer_part = &self.data / &scaling_factor;
+        SCDisplay::fmt(&integer_part, f);
+
+        let num_decimals = self.scale();
+        if num_decimals == 0 {
+            return;
+        }
+
+        f.append_bytes(b".");
+        let fractional_part = &self.data % &scaling_factor;
+        let str_handle: M::ManagedBufferHandle = use_raw_handle(const_handles::MBUF_TEMPORARY_1);
+        M::managed_type_impl().bi_to_string(fractional_part.handle.clone(), str_handle.clone());
+        let fractional_str: ManagedBuffer<F::Api> =
+            ManagedBuffer::from_handle(str_handle.cast_or_signal_error::<M, _>());
+        for _ in fractional_str.len()..num_decimals {
+            f.append_bytes(b"0");
+        }
+        f.append_managed_buffer(&fractional_str);
+    }
+}
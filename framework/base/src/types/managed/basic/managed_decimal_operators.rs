@@ -0,0 +1,94 @@
+use super::{Decimals, ManagedDecimal, RoundingMode};
+use crate::api::ManagedTypeApi;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+// Operators signal an error if the scales of the operands differ.
+// Multiplication and division truncate the result to the common scale.
+// For other behaviors, see the `checked_*` methods.
+
+impl<M: ManagedTypeApi, D: Decimals> Add<&ManagedDecimal<M, D>> for &ManagedDecimal<M, D> {
+    type Output = ManagedDecimal<M, D>;
+
+    fn add(self, other: &ManagedDecimal<M, D>) -> ManagedDecimal<M, D> {
+        self.require_same_scale(other);
+        ManagedDecimal::from_raw_units(
+            self.raw_units() + other.raw_units(),
+            self.decimals().clone(),
+        )
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Sub<&ManagedDecimal<M, D>> for &ManagedDecimal<M, D> {
+    type Output = ManagedDecimal<M, D>;
+
+    fn sub(self, other: &ManagedDecimal<M, D>) -> ManagedDecimal<M, D> {
+        self.require_same_scale(other);
+        ManagedDecimal::from_raw_units(
+            self.raw_units() - other.raw_units(),
+            self.decimals().clone(),
+        )
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Mul<&ManagedDecimal<M, D>> for &ManagedDecimal<M, D> {
+    type Output = ManagedDecimal<M, D>;
+
+    fn mul(self, other: &ManagedDecimal<M, D>) -> ManagedDecimal<M, D> {
+        self.require_same_scale(other);
+        self.mul_rounded(other, RoundingMode::Down)
+    }
+}
+
+impl<M: ManagedTypeApi, D: Decimals> Div<&ManagedDecimal<M, D>> for &ManagedDecimal<M, D> {
+    type Output = ManagedDecimal<M, D>;
+
+    fn div(self, other: &ManagedDecimal<M, D>) -> ManagedDecimal<M, D> {
+        self.require_same_scale(other);
+        self.div_rounded(other, RoundingMode::Down)
+    }
+}
+
+macro_rules! owned_operators {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<M: ManagedTypeApi, D: Decimals> $trait for ManagedDecimal<M, D> {
+            type Output = ManagedDecimal<M, D>;
+
+            #[inline]
+            fn $method(self, other: ManagedDecimal<M, D>) -> ManagedDecimal<M, D> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<'b, M: ManagedTypeApi, D: Decimals> $trait<&'b ManagedDecimal<M, D>>
+            for ManagedDecimal<M, D>
+        {
+            type Output = ManagedDecimal<M, D>;
+
+            #[inline]
+            fn $method(self, other: &ManagedDecimal<M, D>) -> ManagedDecimal<M, D> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<M: ManagedTypeApi, D: Decimals> $assign_trait for ManagedDecimal<M, D> {
+            #[inline]
+            fn $assign_method(&mut self, other: ManagedDecimal<M, D>) {
+                *self = (&*self).$method(&other);
+            }
+        }
+
+        impl<M: ManagedTypeApi, D: Decimals> $assign_trait<&ManagedDecimal<M, D>>
+            for ManagedDecimal<M, D>
+        {
+            #[inline]
+            fn $assign_method(&mut self, other: &ManagedDecimal<M, D>) {
+                *self = (&*self).$method(other);
+            }
+        }
+    };
+}
+
+owned_operators! {Add, add, AddAssign, add_assign}
+owned_operators! {Sub, sub, SubAssign, sub_assign}
+owned_operators! {Mul, mul, MulAssign, mul_assign}
+owned_operators! {Div, div, DivAssign, div_assign}
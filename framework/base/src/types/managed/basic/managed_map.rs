@@ -1,11 +1,18 @@
 use crate::{
-    api::{use_raw_handle, ManagedMapApiImpl, ManagedTypeApi, StaticVarApiImpl},
+    api::{
+        const_handles, use_raw_handle, HandleConstraints, ManagedMapApiImpl, ManagedTypeApi,
+        StaticVarApiImpl,
+    },
     types::ManagedType,
 };
 
 use super::ManagedBuffer;
 
-/// A byte buffer managed by an external API.
+/// A map of byte buffers to byte buffers, managed by an external API.
+///
+/// Entries with empty values are considered absent.
+///
+/// The VM cannot list the entries of a map, use `IterableManagedMap` when they need to be iterated.
 #[repr(transparent)]
 pub struct ManagedMap<M: ManagedTypeApi> {
     pub(crate) handle: M::ManagedMapHandle,
@@ -33,6 +40,14 @@ impl<M: ManagedTypeApi> ManagedMap<M> {
         let new_handle = M::managed_type_impl().mm_new();
         ManagedMap::from_handle(new_handle)
     }
+
+    /// Releases the handle of the map. Works the same as `ManagedBuffer::release`,
+    /// so it must not be called on a handle shared with another value.
+    pub fn release(self) {
+        if !const_handles::is_reserved_handle(self.handle.get_raw_handle_unchecked()) {
+            M::managed_type_impl().mm_drop(self.handle);
+        }
+    }
 }
 
 impl<M: ManagedTypeApi> Default for ManagedMap<M> {
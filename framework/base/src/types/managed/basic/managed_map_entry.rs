@@ -0,0 +1,74 @@
+use crate::{
+    api::ManagedTypeApi,
+    types::{ManagedBuffer, ManagedVecItem},
+};
+
+use crate as multiversx_sc; // needed by the codec and TypeAbi generated code
+use crate::{
+    codec::{
+        self,
+        derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+    },
+    derive::TypeAbi,
+};
+
+/// A key-value pair, as stored in a `ManagedMap`.
+#[derive(
+    TopEncode, NestedEncode, TopDecode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug,
+)]
+pub struct ManagedMapEntry<M: ManagedTypeApi> {
+    pub key: ManagedBuffer<M>,
+    pub value: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> ManagedMapEntry<M> {
+    #[inline]
+    pub fn new(key: ManagedBuffer<M>, value: ManagedBuffer<M>) -> Self {
+        ManagedMapEntry { key, value }
+    }
+
+    #[inline]
+    pub fn into_tuple(self) -> (ManagedBuffer<M>, ManagedBuffer<M>) {
+        (self.key, self.value)
+    }
+}
+
+impl<M: ManagedTypeApi> From<(ManagedBuffer<M>, ManagedBuffer<M>)> for ManagedMapEntry<M> {
+    #[inline]
+    fn from(value: (ManagedBuffer<M>, ManagedBuffer<M>)) -> Self {
+        ManagedMapEntry::new(value.0, value.1)
+    }
+}
+
+impl<M: ManagedTypeApi> ManagedVecItem for ManagedMapEntry<M> {
+    const PAYLOAD_SIZE: usize = 8;
+    const SKIPS_RESERIALIZATION: bool = false;
+    type Ref<'a> = Self;
+
+    fn from_byte_reader<Reader: FnMut(&mut [u8])>(mut reader: Reader) -> Self {
+        let mut arr: [u8; 8] = [0u8; 8];
+        reader(&mut arr[..]);
+
+        let key = ManagedBuffer::from_byte_reader(|bytes| bytes.copy_from_slice(&arr[..4]));
+        let value = ManagedBuffer::from_byte_reader(|bytes| bytes.copy_from_slice(&arr[4..]));
+
+        ManagedMapEntry { key, value }
+    }
+
+    unsafe fn from_byte_reader_as_borrow<'a, Reader: FnMut(&mut [u8])>(
+        reader: Reader,
+    ) -> Self::Ref<'a> {
+        Self::from_byte_reader(reader)
+    }
+
+    fn to_byte_writer<R, Writer: FnMut(&[u8]) -> R>(&self, mut writer: Writer) -> R {
+        let mut arr: [u8; 8] = [0u8; 8];
+
+        self.key
+            .to_byte_writer(|bytes| arr[..4].copy_from_slice(bytes));
+        self.value
+            .to_byte_writer(|bytes| arr[4..].copy_from_slice(bytes));
+
+        writer(&arr[..])
+    }
+}
@@ -1,5 +1,6 @@
 mod big_float;
 mod big_float_cmp;
+mod big_float_math;
 mod big_float_operators;
 mod big_int;
 mod big_int_cmp;
@@ -12,15 +13,27 @@ mod big_uint_operators;
 mod cast_to_i64;
 mod elliptic_curve;
 mod managed_buffer;
+mod managed_decimal;
+mod managed_decimal_operators;
 
 pub use big_float::BigFloat;
+pub use big_float_math::BIG_FLOAT_DEFAULT_PRECISION;
 pub use big_int::BigInt;
 pub use big_int_sign::Sign;
 pub use big_uint::BigUint;
 pub use elliptic_curve::{EllipticCurve, EllipticCurveComponents};
 pub use managed_buffer::ManagedBuffer;
+pub use managed_decimal::{ConstDecimals, Decimals, ManagedDecimal, NumDecimals, RoundingMode};
 
+#[cfg(feature = "managed-map")]
+mod iterable_managed_map;
 #[cfg(feature = "managed-map")]
 mod managed_map;
 #[cfg(feature = "managed-map")]
+mod managed_map_entry;
+#[cfg(feature = "managed-map")]
+pub use iterable_managed_map::{IterableManagedMap, IterableManagedMapIterator};
+#[cfg(feature = "managed-map")]
 pub use managed_map::ManagedMap;
+#[cfg(feature = "managed-map")]
+pub use managed_map_entry::ManagedMapEntry;
@@ -0,0 +1,151 @@
+use crate::{
+    abi::{OutputAbis, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApi, ErrorApiImpl, ManagedTypeApi},
+    codec::{
+        multi_types::MultiValue2, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler,
+        TopDecodeMulti, TopDecodeMultiInput, TopEncodeMulti, TopEncodeMultiOutput,
+    },
+};
+
+use super::MultiValueEncoded;
+
+const NEXT_CURSOR_OUTPUT_NAME: &str = "next_cursor";
+const ITEMS_OUTPUT_NAME: &str = "items";
+
+static PAGE_LIMIT_ZERO_ERR_MSG: &[u8] = b"page limit must be greater than zero";
+
+/// One page of a collection that is too large to be returned by a single view call.
+///
+/// Encoded as the cursor of the next page, followed by the items of the current page.
+/// The next cursor is `None` on the last page.
+///
+/// Cursors are opaque, they should only be obtained from a previous page.
+/// The cursor of the first page is always `0`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Page<M, T>
+where
+    M: ManagedTypeApi,
+{
+    pub next_cursor: Option<usize>,
+    pub items: MultiValueEncoded<M, T>,
+}
+
+impl<M, T> Page<M, T>
+where
+    M: ManagedTypeApi,
+{
+    #[inline]
+    pub fn new(items: MultiValueEncoded<M, T>, next_cursor: Option<usize>) -> Self {
+        Page { next_cursor, items }
+    }
+
+    #[inline]
+    pub fn is_last(&self) -> bool {
+        self.next_cursor.is_none()
+    }
+
+    #[inline]
+    pub fn into_items(self) -> MultiValueEncoded<M, T> {
+        self.items
+    }
+}
+
+impl<M, T> Page<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+{
+    /// Signals an error if the limit is `0`.
+    ///
+    /// All page builders check it first, since an empty page would point back to its own cursor,
+    /// so anyone following the next cursor would never reach the end.
+    pub fn check_limit(limit: usize) {
+        if limit == 0 {
+            M::error_api_impl().signal_error(PAGE_LIMIT_ZERO_ERR_MSG);
+        }
+    }
+}
+
+impl<M, T> Page<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+    T: TopEncodeMulti,
+{
+    /// Pages any iterator, by skipping the first `cursor` items.
+    ///
+    /// Mappers that can jump straight to the start of the page provide their own `page` method,
+    /// which should be preferred, since this one still reads all the skipped items.
+    pub fn from_iter_skip<I>(iter: I, cursor: usize, limit: usize) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::check_limit(limit);
+        let mut iter = iter.into_iter().skip(cursor);
+        let items = iter.by_ref().take(limit).collect();
+        let next_cursor = iter.next().map(|_| cursor + limit);
+        Page::new(items, next_cursor)
+    }
+}
+
+impl<M, T> TopEncodeMulti for Page<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+    T: TopEncodeMulti,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        output.push_single_value(&self.next_cursor, h)?;
+        self.items.multi_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M, T> TopDecodeMulti for Page<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+    T: TopDecodeMulti,
+{
+    fn multi_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeMultiInput,
+        H: DecodeErrorHandler,
+    {
+        let next_cursor = input.next_value(h)?;
+        let items = MultiValueEncoded::multi_decode_or_handle_err(input, h)?;
+        Ok(Page { next_cursor, items })
+    }
+}
+
+/// Shows up as 2 results: the next cursor and the items, as a variadic.
+impl<M, T> TypeAbi for Page<M, T>
+where
+    M: ManagedTypeApi,
+    T: TypeAbi,
+{
+    fn type_name() -> TypeName {
+        MultiValue2::<Option<usize>, MultiValueEncoded<M, T>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+
+    fn output_abis(output_names: &[&'static str]) -> OutputAbis {
+        let mut result = Option::<usize>::output_abis(&[output_names
+            .first()
+            .copied()
+            .unwrap_or(NEXT_CURSOR_OUTPUT_NAME)]);
+        result.append(&mut MultiValueEncoded::<M, T>::output_abis(&[output_names
+            .get(1)
+            .copied()
+            .unwrap_or(ITEMS_OUTPUT_NAME)]));
+        result
+    }
+}
+
+impl<M, T> CodecFromSelf for Page<M, T> where M: ManagedTypeApi {}
@@ -3,6 +3,7 @@ use crate::{
     generate::{
         auto_impl::generate_auto_impls, auto_impl_proxy::generate_all_proxy_trait_imports,
         callback_gen::*, contract_gen::*, endpoints_mod_gen::generate_endpoints_mod,
+        event_struct_gen::generate_event_types_mod,
         function_selector::generate_function_selector_body, proxy_callback_gen::*, proxy_gen,
         supertrait_gen,
     },
@@ -23,6 +24,7 @@ pub fn contract_implementation(
     let auto_impl_defs = generate_auto_impl_defs(contract);
     let auto_impls = generate_auto_impls(contract);
     let endpoints_mod = generate_endpoints_mod(contract, is_contract_main);
+    let event_types_mod = generate_event_types_mod(contract);
     let function_selector_body = generate_function_selector_body(contract);
     let (callback_selector_body, callback_body) = generate_callback_selector_and_main(contract);
     let (callbacks_def, callbacks_impl, callback_proxies_obj) = generate_callback_proxies(contract);
@@ -143,6 +145,8 @@ pub fn contract_implementation(
 
         #endpoints_mod
 
+        #event_types_mod
+
         #proxy_trait
 
         #proxy_obj_code
@@ -9,6 +9,7 @@ fn generate_endpoint_snippet(
     endpoint_name: &str,
     only_owner: bool,
     only_admin: bool,
+    paginated: bool,
     mutability: EndpointMutabilityMetadata,
     endpoint_type: EndpointTypeMetadata,
 ) -> proc_macro2::TokenStream {
@@ -59,6 +60,7 @@ fn generate_endpoint_snippet(
             rust_method_name: #rust_method_name,
             only_owner: #only_owner,
             only_admin: #only_admin,
+            paginated: #paginated,
             mutability: #mutability_tokens,
             endpoint_type: #endpoint_type_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
@@ -82,6 +84,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     "init",
                     false,
                     false,
+                    false,
                     EndpointMutabilityMetadata::Mutable,
                     EndpointTypeMetadata::Init,
                 );
@@ -96,6 +99,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &endpoint_metadata.public_name.to_string(),
                     endpoint_metadata.only_owner,
                     endpoint_metadata.only_admin,
+                    endpoint_metadata.paginated,
                     endpoint_metadata.mutability.clone(),
                     EndpointTypeMetadata::Endpoint,
                 );
@@ -110,6 +114,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &callback_metadata.callback_name.to_string(),
                     false,
                     false,
+                    false,
                     EndpointMutabilityMetadata::Mutable,
                     EndpointTypeMetadata::PromisesCallback,
                 );
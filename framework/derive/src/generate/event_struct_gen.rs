@@ -0,0 +1,261 @@
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+
+use super::{convert_to_owned_type::convert_to_owned_type, util::*};
+use crate::model::{AutoImpl, ContractTrait, Method, MethodArgument, MethodImpl};
+
+const EVENT_STRUCT_SUFFIX: &str = "Event";
+
+/// Generates a struct for each event, which can decode the event back from a log.
+///
+/// The trait implementations only apply if the field types allow it,
+/// hence the (otherwise useless) higher-ranked bounds, which are allowed to be false.
+///
+/// They are all placed in an `event_types` module, next to the contract/module trait.
+pub fn generate_event_types_mod(contract: &ContractTrait) -> TokenStream {
+    let events: Vec<(&Method, &String)> = contract
+        .methods
+        .iter()
+        .filter_map(|m| {
+            if let MethodImpl::Generated(AutoImpl::Event { identifier }) = &m.implementation {
+                if m.generics.params.is_empty() {
+                    return Some((m, identifier));
+                }
+            }
+            None
+        })
+        .collect();
+    let struct_names: Vec<syn::Ident> = events
+        .iter()
+        .map(|(m, _)| event_struct_name(&m.name))
+        .collect();
+    let event_structs: Vec<TokenStream> = events
+        .iter()
+        .zip(struct_names.iter())
+        .map(|((m, identifier), struct_name)| {
+            generate_event_struct(m, identifier, struct_name, &struct_names)
+        })
+        .collect();
+
+    if event_structs.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        pub mod event_types {
+            #![allow(unused_imports)]
+            use super::*;
+
+            #(#event_structs)*
+        }
+    }
+}
+
+struct EventField<'a> {
+    name: syn::Ident,
+    ty: TokenStream,
+    arg: &'a MethodArgument,
+}
+
+fn generate_event_struct(
+    m: &Method,
+    identifier: &str,
+    struct_name: &syn::Ident,
+    struct_names: &[syn::Ident],
+) -> TokenStream {
+    let struct_name_str = struct_name.to_string();
+    let docs = &m.docs;
+
+    let mut is_generic = false;
+    let fields: Vec<EventField> = m
+        .method_args
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            let (ty, uses_api) = adapt_field_type(convert_to_owned_type(&arg.ty), struct_names);
+            is_generic |= uses_api;
+            EventField {
+                name: event_field_name(&arg.pat, index),
+                ty,
+                arg,
+            }
+        })
+        .collect();
+
+    let (struct_generics, impl_generics, type_generics) = if is_generic {
+        (
+            quote! { <A: multiversx_sc::api::ManagedTypeApi> },
+            quote! { <A: multiversx_sc::api::ManagedTypeApi> },
+            quote! { <A> },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {})
+    };
+
+    let field_decls = fields.iter().map(|field| {
+        let name = &field.name;
+        let ty = &field.ty;
+        quote! { pub #name: #ty, }
+    });
+    let field_names: Vec<&syn::Ident> = fields.iter().map(|field| &field.name).collect();
+    let field_types: Vec<&TokenStream> = fields.iter().map(|field| &field.ty).collect();
+    let field_name_strs: Vec<String> = field_names.iter().map(|name| name.to_string()).collect();
+
+    let field_decode_snippets = fields.iter().map(|field| {
+        let name = &field.name;
+        let ty = &field.ty;
+        if field.arg.metadata.event_topic {
+            quote! {
+                let #name = <#ty as multiversx_sc::codec::TopDecodeMulti>::multi_decode_or_handle_err(&mut ___topics___, ___h___)?;
+            }
+        } else {
+            quote! {
+                let #name = <#ty as multiversx_sc::codec::TopDecode>::top_decode_or_handle_err(___data___, ___h___)?;
+            }
+        }
+    });
+
+    let decode_bounds = fields.iter().map(|field| {
+        let ty = &field.ty;
+        if field.arg.metadata.event_topic {
+            quote! { for<'__a> #ty: multiversx_sc::codec::TopDecodeMulti, }
+        } else {
+            quote! { for<'__a> #ty: multiversx_sc::codec::TopDecode, }
+        }
+    });
+
+    let event_identifier_literal = byte_str_literal(identifier.as_bytes());
+
+    quote! {
+        #( #[doc = #docs] )*
+        pub struct #struct_name #struct_generics {
+            #(#field_decls)*
+        }
+
+        impl #impl_generics multiversx_sc::log_util::ContractEvent for #struct_name #type_generics
+        where
+            #(#decode_bounds)*
+        {
+            const IDENTIFIER: &'static [u8] = #event_identifier_literal;
+
+            #[allow(unused_mut, unused_variables)]
+            fn decode_event<I, D, H>(mut ___topics___: I, ___data___: D, ___h___: H) -> core::result::Result<Self, H::HandledErr>
+            where
+                I: multiversx_sc::codec::TopDecodeMultiInput,
+                D: multiversx_sc::codec::TopDecodeInput,
+                H: multiversx_sc::codec::DecodeErrorHandler,
+            {
+                #(#field_decode_snippets)*
+                multiversx_sc::codec::TopDecodeMultiInput::assert_no_more_args(&___topics___, ___h___)?;
+                core::result::Result::Ok(#struct_name { #(#field_names),* })
+            }
+        }
+
+        impl #impl_generics core::clone::Clone for #struct_name #type_generics
+        where
+            #(for<'__a> #field_types: core::clone::Clone,)*
+        {
+            fn clone(&self) -> Self {
+                #struct_name {
+                    #(#field_names: core::clone::Clone::clone(&self.#field_names),)*
+                }
+            }
+        }
+
+        impl #impl_generics core::cmp::PartialEq for #struct_name #type_generics
+        where
+            #(for<'__a> #field_types: core::cmp::PartialEq,)*
+        {
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& self.#field_names == other.#field_names)*
+            }
+        }
+
+        impl #impl_generics core::fmt::Debug for #struct_name #type_generics
+        where
+            #(for<'__a> #field_types: core::fmt::Debug,)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(#struct_name_str)
+                    #(.field(#field_name_strs, &self.#field_names))*
+                    .finish()
+            }
+        }
+    }
+}
+
+/// `transfer_event` -> `TransferEvent`, `deposit` -> `DepositEvent`.
+fn event_struct_name(method_name: &syn::Ident) -> syn::Ident {
+    let mut name = String::new();
+    for word in method_name.to_string().split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    if !name.ends_with(EVENT_STRUCT_SUFFIX) {
+        name.push_str(EVENT_STRUCT_SUFFIX);
+    }
+    syn::Ident::new(&name, method_name.span())
+}
+
+fn event_field_name(pat: &syn::Pat, index: usize) -> syn::Ident {
+    if let syn::Pat::Ident(pat_ident) = pat {
+        let name = pat_ident.ident.to_string();
+        let name = name.trim_start_matches('_');
+        if !name.is_empty() {
+            return syn::Ident::new(name, pat_ident.ident.span());
+        }
+    }
+    syn::Ident::new(&format!("field_{index}"), Span::call_site())
+}
+
+/// Replaces all occurrences of `Self::Api` with the struct generic `A`.
+///
+/// Contract types with the same name as one of the generated structs get referenced via `super::`,
+/// since the generated structs shadow them in the `event_types` module.
+///
+/// Also returns whether or not `Self::Api` was replaced.
+fn adapt_field_type(tokens: TokenStream, struct_names: &[syn::Ident]) -> (TokenStream, bool) {
+    let mut result = Vec::<TokenTree>::new();
+    let mut replaced = false;
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) if ident == "Api" && ends_with_self_path(&result) => {
+                result.truncate(result.len() - 3);
+                result.push(TokenTree::Ident(syn::Ident::new("A", ident.span())));
+                replaced = true;
+            },
+            TokenTree::Ident(ident)
+                if struct_names.contains(&ident) && !ends_with_path_separator(&result) =>
+            {
+                result.extend(quote! { super:: });
+                result.push(TokenTree::Ident(ident));
+            },
+            TokenTree::Group(group) => {
+                let (stream, group_replaced) = adapt_field_type(group.stream(), struct_names);
+                replaced |= group_replaced;
+                let mut new_group = Group::new(group.delimiter(), stream);
+                new_group.set_span(group.span());
+                result.push(TokenTree::Group(new_group));
+            },
+            _ => result.push(tt),
+        }
+    }
+    (result.into_iter().collect(), replaced)
+}
+
+fn ends_with_path_separator(tokens: &[TokenTree]) -> bool {
+    matches!(tokens.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == ':')
+}
+
+/// Checks whether the accumulated tokens end in `Self ::`.
+fn ends_with_self_path(tokens: &[TokenTree]) -> bool {
+    if let [.., TokenTree::Ident(self_ident), TokenTree::Punct(colon1), TokenTree::Punct(colon2)] =
+        tokens
+    {
+        self_ident == "Self" && colon1.as_char() == ':' && colon2.as_char() == ':'
+    } else {
+        false
+    }
+}
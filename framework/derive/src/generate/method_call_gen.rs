@@ -8,6 +8,7 @@ use crate::{
         payable_gen::*,
         restricted_caller_gen::*,
         snippets,
+        storage_cache_gen::generate_storage_cache_snippets,
         util::*,
     },
     model::Method,
@@ -67,6 +68,8 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
     let only_owner_snippet = generate_only_owner_snippet(m);
     let only_admin_snippet = generate_only_admin_snippet(m);
     let only_user_account_snippet = generate_only_user_account_snippet(m);
+    let (storage_cache_enable_snippet, storage_cache_flush_snippet) =
+        generate_storage_cache_snippets(m);
     let arg_load = generate_call_method_arg_load(m);
 
     let call = generate_call_to_method_expr(m);
@@ -78,8 +81,10 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
         #only_owner_snippet
         #only_admin_snippet
         #only_user_account_snippet
+        #storage_cache_enable_snippet
         #arg_load
         #body_with_result
+        #storage_cache_flush_snippet
     }
 }
 
@@ -7,6 +7,7 @@ pub mod callback_gen;
 pub mod contract_gen;
 pub mod convert_to_owned_type;
 pub mod endpoints_mod_gen;
+pub mod event_struct_gen;
 pub mod function_selector;
 pub mod method_call_gen;
 mod method_call_gen_arg;
@@ -16,5 +17,6 @@ pub mod proxy_callback_gen;
 pub mod proxy_gen;
 pub mod restricted_caller_gen;
 pub mod snippets;
+pub mod storage_cache_gen;
 pub mod supertrait_gen;
 pub mod util;
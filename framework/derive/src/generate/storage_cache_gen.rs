@@ -0,0 +1,20 @@
+use crate::model::{Method, PublicRole};
+
+/// Enables the storage cache before the endpoint runs and flushes it once it has finished.
+pub fn generate_storage_cache_snippets(
+    m: &Method,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.storage_cache {
+            return (
+                quote! {
+                    multiversx_sc::storage::storage_cache_enable::<Self::Api>();
+                },
+                quote! {
+                    multiversx_sc::storage::storage_cache_flush::<Self::Api>();
+                },
+            );
+        }
+    }
+    (quote! {}, quote! {})
+}
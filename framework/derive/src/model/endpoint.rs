@@ -12,6 +12,8 @@ pub struct EndpointMetadata {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub paginated: bool,
+    pub storage_cache: bool,
     pub mutability: EndpointMutabilityMetadata,
 }
 
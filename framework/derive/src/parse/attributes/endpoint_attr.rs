@@ -16,6 +16,14 @@ pub fn is_only_user_account(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_USER_ACCOUNT)
 }
 
+pub fn is_paginated(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_PAGINATED)
+}
+
+pub fn is_storage_cache(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_STORAGE_CACHE)
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
@@ -5,9 +5,9 @@ use crate::model::{
 
 use super::{
     attributes::{
-        is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account,
-        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, LabelAttribute,
-        OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
+        is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account, is_paginated,
+        is_storage_cache, CallbackAttribute, EndpointAttribute, ExternalViewAttribute,
+        LabelAttribute, OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
@@ -67,6 +67,77 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_paginated_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_paginated = is_paginated(attr);
+    if is_paginated {
+        pass_1_data.paginated = true;
+    }
+    is_paginated
+}
+
+/// Paginated methods must be views taking the page cursor and the page size as their last 2 arguments,
+/// and returning a `Page`. Tooling, like the snippet generator, relies on this layout.
+pub fn validate_paginated(pass_1_data: &MethodAttributesPass1, method: &Method) {
+    if !pass_1_data.paginated {
+        return;
+    }
+
+    assert!(
+        matches!(
+            &method.public_role,
+            PublicRole::Endpoint(endpoint_metadata)
+                if matches!(endpoint_metadata.mutability, EndpointMutabilityMetadata::Readonly)
+        ),
+        "Method '{}' is annotated with `#[paginated]`, but it is not a view.",
+        &pass_1_data.method_name
+    );
+
+    let endpoint_args: Vec<_> = method
+        .method_args
+        .iter()
+        .filter(|arg| arg.is_endpoint_arg())
+        .collect();
+    assert!(
+        endpoint_args.len() >= 2
+            && endpoint_args[endpoint_args.len() - 2..]
+                .iter()
+                .all(|arg| is_type_named(&arg.ty, "usize")),
+        "Paginated view '{}' must take the cursor and the limit as its last 2 arguments, both of type `usize`.",
+        &pass_1_data.method_name
+    );
+
+    assert!(
+        matches!(&method.return_type, syn::ReturnType::Type(_, ty) if is_type_named(ty, "Page")),
+        "Paginated view '{}' must return a `Page`.",
+        &pass_1_data.method_name
+    );
+}
+
+fn is_type_named(ty: &syn::Type, name: &str) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == name),
+        _ => false,
+    }
+}
+
+pub fn process_storage_cache_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_storage_cache = is_storage_cache(attr);
+    if is_storage_cache {
+        pass_1_data.storage_cache = true;
+    }
+    is_storage_cache
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -85,6 +156,8 @@ pub fn process_endpoint_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                paginated: pass_1_data.paginated,
+                storage_cache: pass_1_data.storage_cache,
                 mutability: EndpointMutabilityMetadata::Mutable,
             });
         })
@@ -109,6 +182,8 @@ pub fn process_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                paginated: pass_1_data.paginated,
+                storage_cache: pass_1_data.storage_cache,
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -133,6 +208,8 @@ pub fn process_external_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                paginated: pass_1_data.paginated,
+                storage_cache: pass_1_data.storage_cache,
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
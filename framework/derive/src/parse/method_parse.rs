@@ -5,13 +5,15 @@ use super::{
     auto_impl_parse::{
         process_event_attribute, process_proxy_attribute, process_storage_clear_attribute,
         process_storage_get_attribute, process_storage_is_empty_attribute,
-        process_storage_mapper_attribute, process_storage_set_attribute,
+        process_storage_mapper_attribute, process_storage_mapper_from_address_attribute,
+        process_storage_set_attribute,
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
     process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
     process_label_names_attribute, process_only_admin_attribute, process_only_owner_attribute,
-    process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
-    process_promises_callback_attribute, process_view_attribute,
+    process_only_user_account_attribute, process_output_names_attribute,
+    process_paginated_attribute, process_payable_attribute, process_promises_callback_attribute,
+    process_storage_cache_attribute, process_view_attribute, validate_paginated,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
@@ -19,6 +21,8 @@ pub struct MethodAttributesPass1 {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub paginated: bool,
+    pub storage_cache: bool,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -36,6 +40,8 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
         only_user_account: trait_attributes.only_user_account,
+        paginated: false,
+        storage_cache: false,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -65,6 +71,7 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
     );
 
     validate_method(&method);
+    validate_paginated(&first_pass_data, &method);
 
     method
 }
@@ -90,6 +97,8 @@ fn process_attribute_first_pass(
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_paginated_attribute(attr, first_pass_data)
+        || process_storage_cache_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -122,6 +131,7 @@ fn process_attribute_second_pass(
         || process_storage_get_attribute(attr, method)
         || process_storage_set_attribute(attr, method)
         || process_storage_mapper_attribute(attr, method)
+        || process_storage_mapper_from_address_attribute(attr, method)
         || process_storage_is_empty_attribute(attr, method)
         || process_storage_clear_attribute(attr, method)
         || process_output_names_attribute(attr, method)
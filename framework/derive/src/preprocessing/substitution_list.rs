@@ -50,6 +50,7 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     add_managed_type(substitutions, &quote!(EllipticCurve));
     add_managed_type(substitutions, &quote!(ManagedBuffer));
     add_managed_type(substitutions, &quote!(ManagedMap));
+    add_managed_type(substitutions, &quote!(IterableManagedMap));
 
     // types::managed::multi-value
     add_managed_type(substitutions, &quote!(ManagedAsyncCallError));
@@ -95,6 +96,14 @@ fn add_storage_mapper_single_generic_arg(
     substitutions.add_substitution(quote!(#mapper_name), quote!(#mapper_name<Self::Api>));
 }
 
+fn add_storage_mapper_single_generic_arg_with_source(
+    substitutions: &mut SubstitutionsMap,
+    mapper_name: &proc_macro2::TokenStream,
+) {
+    add_storage_mapper_single_generic_arg(substitutions, mapper_name);
+    add_managed_type_with_generics(substitutions, mapper_name);
+}
+
 fn add_storage_mapper(
     substitutions: &mut SubstitutionsMap,
     mapper_name: &proc_macro2::TokenStream,
@@ -107,7 +116,7 @@ fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
     add_storage_mapper_single_generic_arg(substitutions, &quote!(NonFungibleTokenMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(TokenAttributesMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UniqueIdMapper));
-    add_storage_mapper_single_generic_arg(substitutions, &quote!(UserMapper));
+    add_storage_mapper_single_generic_arg_with_source(substitutions, &quote!(UserMapper));
 
     add_storage_mapper(substitutions, &quote!(BiDiMapper));
     add_storage_mapper(substitutions, &quote!(LinkedListMapper));
@@ -4,7 +4,7 @@ mod endpoint_abi_json;
 mod event_abi_json;
 mod type_abi_json;
 
-pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
+pub use build_info_abi_json::{BuildInfoAbiJson, BuildOptionsAbiJson, RustcAbiJson};
 pub use contract_abi_json::*;
 pub use endpoint_abi_json::*;
 pub use event_abi_json::*;
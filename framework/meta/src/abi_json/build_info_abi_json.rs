@@ -1,12 +1,18 @@
 use multiversx_sc::abi::{BuildInfoAbi, ContractCrateBuildAbi, FrameworkBuildAbi};
 use serde::{Deserialize, Serialize};
 
+use crate::cli_args::BuildArgs;
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildInfoAbiJson {
     pub rustc: RustcAbiJson,
     pub contract_crate: ContractCrateBuildAbiJson,
     pub framework: FrameworkBuildAbiJson,
+
+    /// Only recorded in the mxsc file, since the ABI does not depend on it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_options: Option<BuildOptionsAbiJson>,
 }
 
 impl From<&BuildInfoAbi> for BuildInfoAbiJson {
@@ -15,6 +21,52 @@ impl From<&BuildInfoAbi> for BuildInfoAbiJson {
             rustc: RustcAbiJson::create(),
             contract_crate: ContractCrateBuildAbiJson::from(&abi.contract_crate),
             framework: FrameworkBuildAbiJson::from(&abi.framework),
+            build_options: None,
+        }
+    }
+}
+
+/// The build arguments that influence the compiled code, needed to reproduce the build.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildOptionsAbiJson {
+    pub locked: bool,
+    pub wasm_symbols: bool,
+    pub remap_paths: bool,
+    pub wasm_opt: bool,
+
+    /// The output file names depend on them, so the rebuilt contract can be found.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm_name_override: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm_name_suffix: Option<String>,
+}
+
+impl From<&BuildArgs> for BuildOptionsAbiJson {
+    fn from(build_args: &BuildArgs) -> Self {
+        BuildOptionsAbiJson {
+            locked: build_args.locked,
+            wasm_symbols: build_args.wasm_symbols,
+            remap_paths: build_args.remap_paths,
+            wasm_opt: build_args.wasm_opt,
+            wasm_name_override: build_args.wasm_name_override.clone(),
+            wasm_name_suffix: build_args.wasm_name_suffix.clone(),
+        }
+    }
+}
+
+impl BuildOptionsAbiJson {
+    /// Build arguments that produce the same code, under the same file names,
+    /// all others are left to their defaults.
+    pub fn to_build_args(&self) -> BuildArgs {
+        BuildArgs {
+            locked: self.locked,
+            wasm_symbols: self.wasm_symbols,
+            remap_paths: self.remap_paths,
+            wasm_opt: self.wasm_opt,
+            wasm_name_override: self.wasm_name_override.clone(),
+            wasm_name_suffix: self.wasm_name_suffix.clone(),
+            ..Default::default()
         }
     }
 }
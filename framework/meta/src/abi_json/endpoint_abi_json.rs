@@ -90,6 +90,8 @@ pub struct EndpointAbiJson {
     #[serde(rename = "onlyAdmin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paginated: Option<bool>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -107,6 +109,7 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
             only_admin: if abi.only_admin { Some(true) } else { None },
+            paginated: if abi.paginated { Some(true) } else { None },
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
@@ -12,6 +12,11 @@ pub struct BuildArgs {
     #[arg(long = "wasm-symbols", verbatim_doc_comment)]
     pub wasm_symbols: bool,
 
+    /// Replaces the absolute paths of the build machine in the compiled binary with fixed placeholders.
+    /// Required for reproducible builds, when the source is not always located in the same place.
+    #[arg(long = "remap-paths", verbatim_doc_comment)]
+    pub remap_paths: bool,
+
     /// Overrides the main contract output name.
     #[arg(long = "wasm-name", verbatim_doc_comment)]
     pub wasm_name_override: Option<String>,
@@ -91,6 +96,7 @@ impl Default for BuildArgs {
         BuildArgs {
             locked: false,
             wasm_symbols: false,
+            remap_paths: false,
             wasm_name_override: None,
             wasm_name_suffix: None,
             wasm_opt: true,
@@ -125,6 +131,9 @@ impl CliArgsToRaw for BuildArgs {
         if self.wasm_symbols {
             raw.push("--wasm-symbols".to_string());
         }
+        if self.remap_paths {
+            raw.push("--remap-paths".to_string());
+        }
         if let Some(wasm_name_override) = &self.wasm_name_override {
             raw.push("--wasm-name".to_string());
             raw.push(wasm_name_override.clone())
@@ -61,6 +61,11 @@ pub enum StandaloneCliAction {
         about = "Generates Rust integration tests based on scenarios provided in the scenarios folder of each contract."
     )]
     TestGen(TestGenArgs),
+
+    #[command(
+        about = "Rebuilds a contract in a pinned environment and checks that it matches the given mxsc file or deployed code."
+    )]
+    Verify(VerifyArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -164,6 +169,11 @@ pub struct UpgradeArgs {
     /// By default it will be the last version out.
     #[arg(long = "to", verbatim_doc_comment)]
     pub override_target_version: Option<String>,
+
+    /// Only prints the source code changes and version bumps as a diff, without writing anything.
+    /// Other Cargo.toml changes, file renames and post-processing are skipped.
+    #[arg(long = "dry-run", verbatim_doc_comment)]
+    pub dry_run: bool,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -198,6 +208,28 @@ pub struct TemplateArgs {
     /// Will be current directory if not specified.
     #[arg(long, verbatim_doc_comment)]
     pub path: Option<PathBuf>,
+
+    /// Values for the custom placeholders declared by the template, as KEY=VALUE.
+    #[arg(long, verbatim_doc_comment)]
+    pub placeholder: Vec<String>,
+
+    /// Local directory containing the framework repository, used instead of downloading it.
+    #[arg(long = "repo-path", verbatim_doc_comment)]
+    pub repo_path: Option<PathBuf>,
+
+    /// Pre-downloaded zip archive of the framework repository, used instead of downloading it.
+    #[arg(long = "repo-archive", verbatim_doc_comment)]
+    pub repo_archive: Option<PathBuf>,
+
+    /// TOML file listing additional template repositories.
+    /// Each can be a local directory, a zip archive, or a zip archive URL.
+    #[arg(long, verbatim_doc_comment)]
+    pub registry: Option<PathBuf>,
+
+    /// Never download anything.
+    /// Without --repo-path or --repo-archive, only the templates from the registry are available.
+    #[arg(long, verbatim_doc_comment)]
+    pub offline: bool,
 }
 
 impl CliArgsToRaw for TemplateArgs {
@@ -211,6 +243,24 @@ pub struct TemplateListArgs {
     /// The framework version referred to.
     #[arg(long = "tag", verbatim_doc_comment)]
     pub tag: Option<String>,
+
+    /// Local directory containing the framework repository, used instead of downloading it.
+    #[arg(long = "repo-path", verbatim_doc_comment)]
+    pub repo_path: Option<PathBuf>,
+
+    /// Pre-downloaded zip archive of the framework repository, used instead of downloading it.
+    #[arg(long = "repo-archive", verbatim_doc_comment)]
+    pub repo_archive: Option<PathBuf>,
+
+    /// TOML file listing additional template repositories.
+    /// Each can be a local directory, a zip archive, or a zip archive URL.
+    #[arg(long, verbatim_doc_comment)]
+    pub registry: Option<PathBuf>,
+
+    /// Never download anything.
+    /// Without --repo-path or --repo-archive, only the templates from the registry are available.
+    #[arg(long, verbatim_doc_comment)]
+    pub offline: bool,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -229,3 +279,26 @@ pub struct TestGenArgs {
     #[arg(long, verbatim_doc_comment)]
     pub create: bool,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct VerifyArgs {
+    /// The packed contract (.mxsc.json) to verify.
+    #[arg(long, verbatim_doc_comment)]
+    pub mxsc: String,
+
+    /// The expected code hash, hex or base64 encoded,
+    /// or the path to a .wasm or .mxsc.json file with the deployed code.
+    /// Will be the hash of the code in the mxsc file if not specified.
+    #[arg(long = "code-hash", verbatim_doc_comment)]
+    pub code_hash: Option<String>,
+
+    /// The contract crate to rebuild.
+    /// Will be the parent of the directory containing the mxsc file if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Overrides the Rust toolchain used for the rebuild.
+    /// By default it is derived from the build info in the mxsc file.
+    #[arg(long, verbatim_doc_comment)]
+    pub toolchain: Option<String>,
+}
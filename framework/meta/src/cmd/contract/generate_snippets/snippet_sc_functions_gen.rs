@@ -82,10 +82,15 @@ s
 fn write_endpoint_impl(file: &mut File, endpoint_abi: &EndpointAbi) {
     write_method_declaration(file, endpoint_abi.rust_method_name);
     write_payments_declaration(file, endpoint_abi.payable_in_tokens);
-    write_endpoint_args_declaration(file, &endpoint_abi.inputs);
-    if matches!(endpoint_abi.mutability, EndpointMutabilityAbi::Readonly) {
+    if is_paginated_view(endpoint_abi) {
+        let page_args_start = endpoint_abi.inputs.len() - 2;
+        write_endpoint_args_declaration(file, &endpoint_abi.inputs[..page_args_start]);
+        write_contract_query_all_pages(file, endpoint_abi);
+    } else if matches!(endpoint_abi.mutability, EndpointMutabilityAbi::Readonly) {
+        write_endpoint_args_declaration(file, &endpoint_abi.inputs);
         write_contract_query(file, endpoint_abi);
     } else {
+        write_endpoint_args_declaration(file, &endpoint_abi.inputs);
         write_contract_call(file, endpoint_abi);
     }
 
@@ -205,6 +210,48 @@ fn write_contract_query(file: &mut File, endpoint_abi: &EndpointAbi) {
     .unwrap();
 }
 
+/// Paginated views take the page cursor and the page size as their last 2 arguments.
+///
+/// The derive rejects `#[paginated]` on anything else, and requires a `Page` return type,
+/// whose last output is always the `variadic` list of items.
+fn is_paginated_view(endpoint_abi: &EndpointAbi) -> bool {
+    endpoint_abi.paginated
+        && matches!(endpoint_abi.mutability, EndpointMutabilityAbi::Readonly)
+        && endpoint_abi.inputs.len() >= 2
+}
+
+fn write_contract_query_all_pages(file: &mut File, endpoint_abi: &EndpointAbi) {
+    let page_args_start = endpoint_abi.inputs.len() - 2;
+    let mut args = String::new();
+    for input in &endpoint_abi.inputs[..page_args_start] {
+        args.push_str(input.arg_name);
+        args.push_str(".clone(), ");
+    }
+    args.push_str("cursor, limit");
+
+    let item_type = endpoint_abi
+        .outputs
+        .last()
+        .and_then(|output| output.type_name.strip_prefix("variadic<"))
+        .and_then(|type_name| type_name.strip_suffix('>'))
+        .map(|type_name| map_abi_type_to_rust_type(type_name.to_string()))
+        .expect("paginated view should return a page");
+    writeln!(
+        file,
+        r#"        let result_value: Vec<{}> = self
+            .interactor
+            .quick_query_all_pages(PAGE_LIMIT, |cursor, limit| {{
+                self.contract.{}({})
+            }})
+            .await;
+"#,
+        item_type.get_type_name(),
+        endpoint_abi.rust_method_name,
+        args,
+    )
+    .unwrap();
+}
+
 fn map_output_types_to_rust_types(outputs: &[OutputAbi]) -> String {
     let results_len = outputs.len();
     if results_len == 0 {
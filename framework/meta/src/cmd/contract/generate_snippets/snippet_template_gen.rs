@@ -42,7 +42,8 @@ const SC_ADDRESS: &str = \"\";
 const SYSTEM_SC_BECH32: &str = \"erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u\";
 const DEFAULT_ADDRESS_EXPR: &str =
     \"0x0000000000000000000000000000000000000000000000000000000000000000\";
-const TOKEN_ISSUE_COST: u64 = 50_000_000_000_000_000;"
+const TOKEN_ISSUE_COST: u64 = 50_000_000_000_000_000;
+const PAGE_LIMIT: usize = 100;"
     )
     .unwrap();
 
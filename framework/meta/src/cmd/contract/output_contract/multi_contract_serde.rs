@@ -46,6 +46,18 @@ pub struct OutputContractSerde {
     #[serde(rename = "stack-size")]
     pub stack_size: Option<String>,
 
+    #[serde(default)]
+    #[serde(rename = "max-size")]
+    pub max_size: Option<String>,
+
+    #[serde(default)]
+    #[serde(rename = "max-size-growth")]
+    pub max_size_growth: Option<String>,
+
+    #[serde(default)]
+    #[serde(rename = "size-baseline")]
+    pub size_baseline: Option<String>,
+
     #[serde(default)]
     pub features: Vec<String>,
 
@@ -6,7 +6,7 @@ use std::{
 };
 
 use super::{
-    oc_settings::{parse_allocator, parse_check_ei, parse_stack_size},
+    oc_settings::{parse_allocator, parse_check_ei, parse_size_budget, parse_stack_size},
     MultiContractConfigSerde, OutputContract, OutputContractGlobalConfig, OutputContractSerde,
     OutputContractSettings,
 };
@@ -57,6 +57,11 @@ impl OutputContractBuilder {
                     check_ei: parse_check_ei(&cms.ei),
                     allocator: parse_allocator(&cms.allocator),
                     stack_size: parse_stack_size(&cms.stack_size),
+                    size_budget: parse_size_budget(
+                        &cms.max_size,
+                        &cms.max_size_growth,
+                        &cms.size_baseline,
+                    ),
                     features: cms.features.clone(),
                     kill_legacy_callback: cms.kill_legacy_callback,
                 },
@@ -114,6 +114,10 @@ impl OutputContract {
         format!("{}.mxsc.json", self.output_name_base(build_args))
     }
 
+    pub fn size_report_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.size.json", self.output_name_base(build_args))
+    }
+
     pub fn imports_json_output_name(&self, build_args: &BuildArgs) -> String {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
@@ -1,10 +1,12 @@
 mod oc_allocator;
 mod oc_parse;
 mod oc_parse_stack_size;
+mod oc_size_budget;
 
 pub use oc_allocator::ContractAllocator;
 pub use oc_parse::*;
 pub use oc_parse_stack_size::*;
+pub use oc_size_budget::*;
 
 use crate::ei::EIVersion;
 
@@ -26,6 +28,9 @@ pub struct OutputContractSettings {
 
     pub stack_size: usize,
 
+    /// Size limits, checked after the build.
+    pub size_budget: ContractSizeBudget,
+
     /// Features that are activated on the contract crate, from wasm.
     pub features: Vec<String>,
 
@@ -41,6 +46,7 @@ impl Default for OutputContractSettings {
             check_ei: Some(EIVersion::default()),
             allocator: Default::default(),
             stack_size: DEFAULT_STACK_SIZE,
+            size_budget: Default::default(),
             features: Default::default(),
             kill_legacy_callback: false,
         }
@@ -1,4 +1,9 @@
-#[derive(Default, Clone, PartialEq, Eq)]
+use super::oc_parse_stack_size::try_parse_size_expr;
+
+const STATIC_ALLOCATOR_PREFIX: &str = "static<";
+const STATIC_ALLOCATOR_SUFFIX: &str = ">";
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
 pub enum ContractAllocator {
     /// No allocation is allowed. Any attempt causes `signalError` to be thrown.
     #[default]
@@ -12,6 +17,18 @@ pub enum ContractAllocator {
     /// It also never deallocates.
     StaticAllocator64K,
 
+    /// An allocator that uses a statically pre-allocated chunk of memory, of configurable size, in bytes.
+    ///
+    /// Configured as `"static<N>"`, where `N` is a size expression, e.g. `"static<128k>"` or `"static<4 pages>"`.
+    ///
+    /// It also never deallocates.
+    StaticAllocator(usize),
+
+    /// An allocator that reuses deallocated memory, by keeping one free list per power-of-two size class.
+    ///
+    /// New memory is obtained the same way as for the leaking allocator.
+    FreeListAllocator,
+
     /// Uses wee-alloc, but wee-alloc needs to be explicitly imported by the contract wasm crate.
     ///
     /// Mostly present for historical reasons, or if in some extreme case the contract needs deallocation.
@@ -24,23 +41,84 @@ impl ContractAllocator {
             "fail" => Some(ContractAllocator::AllocationForbidden),
             "leaking" => Some(ContractAllocator::LeakingAllocator),
             "static64k" => Some(ContractAllocator::StaticAllocator64K),
+            "free-list" => Some(ContractAllocator::FreeListAllocator),
             "wee_alloc" => Some(ContractAllocator::WeeAlloc),
-            _ => None,
+            _ => Self::parse_static_allocator(s),
+        }
+    }
+
+    fn parse_static_allocator(s: &str) -> Option<Self> {
+        let size_expr = s
+            .strip_prefix(STATIC_ALLOCATOR_PREFIX)?
+            .strip_suffix(STATIC_ALLOCATOR_SUFFIX)?;
+        match try_parse_size_expr(size_expr)? {
+            0 => None,
+            size => Some(ContractAllocator::StaticAllocator(size)),
         }
     }
 
     pub fn parse_or_panic(s: &str) -> Self {
         Self::parse(s).unwrap_or_else(|| {
-            panic!("Unknown allocator option '{s}'. Valid options are: 'fail', 'leaking', 'static64k', 'wee_alloc'.")
+            panic!("Unknown allocator option '{s}'. Valid options are: 'fail', 'leaking', 'static64k', 'static<size>', 'free-list', 'wee_alloc'.")
         })
     }
 
-    pub fn to_allocator_macro_selector(&self) -> &'static str {
+    pub fn to_allocator_macro_selector(&self) -> String {
         match self {
-            ContractAllocator::AllocationForbidden => "",
-            ContractAllocator::LeakingAllocator => "leaking",
-            ContractAllocator::StaticAllocator64K => "static64k",
-            ContractAllocator::WeeAlloc => "wee_alloc",
+            ContractAllocator::AllocationForbidden => String::new(),
+            ContractAllocator::LeakingAllocator => "leaking".to_string(),
+            ContractAllocator::StaticAllocator64K => "static64k".to_string(),
+            ContractAllocator::StaticAllocator(size) => format!("static<{size}>"),
+            ContractAllocator::FreeListAllocator => "free_list".to_string(),
+            ContractAllocator::WeeAlloc => "wee_alloc".to_string(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_allocator() {
+        assert_eq!(
+            ContractAllocator::parse("static64k"),
+            Some(ContractAllocator::StaticAllocator64K)
+        );
+        assert_eq!(
+            ContractAllocator::parse("static<128k>"),
+            Some(ContractAllocator::StaticAllocator(128 * 1024))
+        );
+        assert_eq!(
+            ContractAllocator::parse("static<2 pages>"),
+            Some(ContractAllocator::StaticAllocator(2 * 65536))
+        );
+        assert_eq!(
+            ContractAllocator::parse("static<1000>"),
+            Some(ContractAllocator::StaticAllocator(1000))
+        );
+        assert_eq!(
+            ContractAllocator::parse("free-list"),
+            Some(ContractAllocator::FreeListAllocator)
+        );
+        assert_eq!(ContractAllocator::parse("static<0>"), None);
+        assert_eq!(ContractAllocator::parse("static<abc>"), None);
+        assert_eq!(ContractAllocator::parse("static"), None);
+    }
+
+    #[test]
+    fn test_allocator_macro_selector() {
+        assert_eq!(
+            ContractAllocator::StaticAllocator(131072).to_allocator_macro_selector(),
+            "static<131072>"
+        );
+        assert_eq!(
+            ContractAllocator::FreeListAllocator.to_allocator_macro_selector(),
+            "free_list"
+        );
+        assert_eq!(
+            ContractAllocator::AllocationForbidden.to_allocator_macro_selector(),
+            ""
+        );
+    }
+}
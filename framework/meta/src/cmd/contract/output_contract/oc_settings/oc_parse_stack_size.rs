@@ -16,19 +16,25 @@ pub fn parse_stack_size(stack_size: &Option<String>) -> usize {
 }
 
 fn parse_stack_size_expr(stack_size_str: &str) -> usize {
-    if let Some(s) = stack_size_str.strip_suffix(STACK_SIZE_SUFFIX_KILO) {
-        parse_stack_size_str(s) * STACK_SIZE_MULIPLIER_KILO
-    } else if let Some(s) = stack_size_str.strip_suffix(STACK_SIZE_SUFFIX_PAGES) {
-        parse_stack_size_str(s) * WASM_PAGE_SIZE
+    try_parse_size_expr(stack_size_str)
+        .unwrap_or_else(|| panic!("could not parse stack size expression: {stack_size_str}"))
+}
+
+/// Parses a memory size expression, in bytes, kilobytes (`"32k"`) or wasm pages (`"2 pages"`).
+///
+/// Used for both the stack size and the static allocator size.
+pub(super) fn try_parse_size_expr(size_str: &str) -> Option<usize> {
+    if let Some(s) = size_str.strip_suffix(STACK_SIZE_SUFFIX_KILO) {
+        parse_size_str(s).map(|size| size * STACK_SIZE_MULIPLIER_KILO)
+    } else if let Some(s) = size_str.strip_suffix(STACK_SIZE_SUFFIX_PAGES) {
+        parse_size_str(s).map(|size| size * WASM_PAGE_SIZE)
     } else {
-        parse_stack_size_str(stack_size_str)
+        parse_size_str(size_str)
     }
 }
 
-fn parse_stack_size_str(s: &str) -> usize {
-    s.trim()
-        .parse()
-        .unwrap_or_else(|_| panic!("could not parse stack size expression: {s}"))
+fn parse_size_str(s: &str) -> Option<usize> {
+    s.trim().parse().ok()
 }
 
 #[cfg(test)]
@@ -44,5 +50,6 @@ mod tests {
         assert_eq!(parse_stack_size_expr("1 pages"), 65536);
         assert_eq!(parse_stack_size_expr("2 pages"), 65536 * 2);
         assert_eq!(parse_stack_size_expr("10 pages"), 65536 * 10);
+        assert_eq!(try_parse_size_expr("10 bytes"), None);
     }
 }
@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use super::oc_parse_stack_size::try_parse_size_expr;
+
+const PERCENT_SUFFIX: &str = "%";
+
+/// Limits on the size of the compiled contract, checked at the end of the build.
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct ContractSizeBudget {
+    /// Absolute limit, in bytes.
+    pub max_size: Option<usize>,
+
+    /// Limit on how much the contract can grow, compared to the size report in `size_baseline`.
+    pub max_size_growth: Option<SizeGrowth>,
+
+    /// Path to a committed size report, relative to the contract crate root.
+    pub size_baseline: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SizeGrowth {
+    Bytes(usize),
+    Percent(usize),
+}
+
+impl SizeGrowth {
+    pub fn parse(s: &str) -> Option<Self> {
+        if let Some(percent) = s.strip_suffix(PERCENT_SUFFIX) {
+            percent.trim().parse().ok().map(SizeGrowth::Percent)
+        } else {
+            try_parse_size_expr(s).map(SizeGrowth::Bytes)
+        }
+    }
+
+    /// The largest size allowed, given the baseline size.
+    pub fn max_size_from_baseline(&self, baseline_size: usize) -> usize {
+        match self {
+            SizeGrowth::Bytes(bytes) => baseline_size + bytes,
+            SizeGrowth::Percent(percent) => baseline_size + baseline_size * percent / 100,
+        }
+    }
+}
+
+impl ContractSizeBudget {
+    /// The baseline size report path, resolved against the contract crate root.
+    pub fn size_baseline_path(&self) -> Option<PathBuf> {
+        self.size_baseline
+            .as_ref()
+            .map(|baseline| contract_crate_dir().join(baseline))
+    }
+}
+
+/// The build runs from the meta crate, which sits directly in the contract crate root.
+///
+/// Cargo provides the meta crate path when running it, otherwise the current directory is assumed.
+fn contract_crate_dir() -> PathBuf {
+    let meta_crate_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().expect("failed to read current directory"));
+    meta_crate_dir
+        .parent()
+        .expect("meta crate has no parent directory")
+        .to_path_buf()
+}
+
+pub fn parse_size_budget(
+    max_size: &Option<String>,
+    max_size_growth: &Option<String>,
+    size_baseline: &Option<String>,
+) -> ContractSizeBudget {
+    let max_size = max_size.as_ref().map(|s| {
+        try_parse_size_expr(s).unwrap_or_else(|| panic!("could not parse max size expression: {s}"))
+    });
+    let max_size_growth = max_size_growth.as_ref().map(|s| {
+        SizeGrowth::parse(s)
+            .unwrap_or_else(|| panic!("could not parse max size growth expression: {s}"))
+    });
+    assert!(
+        max_size_growth.is_none() || size_baseline.is_some(),
+        "max-size-growth requires a size-baseline"
+    );
+    ContractSizeBudget {
+        max_size,
+        max_size_growth,
+        size_baseline: size_baseline.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_size_growth() {
+        assert_eq!(SizeGrowth::parse("5%"), Some(SizeGrowth::Percent(5)));
+        assert_eq!(SizeGrowth::parse("1k"), Some(SizeGrowth::Bytes(1024)));
+        assert_eq!(SizeGrowth::parse("100"), Some(SizeGrowth::Bytes(100)));
+        assert_eq!(SizeGrowth::parse("x%"), None);
+
+        assert_eq!(SizeGrowth::Percent(10).max_size_from_baseline(2000), 2200);
+        assert_eq!(SizeGrowth::Bytes(100).max_size_from_baseline(2000), 2100);
+    }
+}
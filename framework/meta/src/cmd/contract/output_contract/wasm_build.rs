@@ -2,14 +2,21 @@ use std::{ffi::OsStr, fs, process::Command};
 
 use super::OutputContract;
 use crate::{
-    abi_json::ContractAbiJson,
+    abi_json::{BuildOptionsAbiJson, ContractAbiJson},
     cli_args::BuildArgs,
     ei::EIVersion,
     mxsc_file_json::{save_mxsc_file_json, MxscFileJson},
     print_util::*,
-    tools::post_build,
+    size_report_json::{load_size_report_json, save_size_report_json, SizeReportJson},
+    tools::{
+        find_workspace_root, post_build,
+        wasm_size::{parse_wasm_size_info, WasmSizeInfo},
+    },
 };
 
+/// How many functions to list when the size budget is exceeded.
+const SIZE_BREAKDOWN_NUM_FUNCTIONS: usize = 10;
+
 impl OutputContract {
     pub fn build_contract(&self, build_args: &BuildArgs, output_path: &str) {
         let mut command = self.compose_build_command(build_args);
@@ -52,6 +59,12 @@ impl OutputContract {
             rustflags.push_flag("-C link-arg=-s");
         }
 
+        if build_args.remap_paths {
+            for (from, to) in remapped_path_prefixes() {
+                rustflags.push_flag(&format!("--remap-path-prefix={from}={to}"));
+            }
+        }
+
         rustflags.push_flag(&format!(
             "-C link-arg=-zstack-size={}",
             self.settings.stack_size
@@ -74,6 +87,7 @@ impl OutputContract {
         self.extract_imports(build_args, output_path);
         self.run_twiggy(build_args, output_path);
         self.pack_mxsc_file(build_args, output_path);
+        self.write_size_report_and_check_budget(build_args, output_path);
     }
 
     fn copy_contracts_to_output(&self, build_args: &BuildArgs, output_path: &str) {
@@ -91,7 +105,8 @@ impl OutputContract {
         print_pack_mxsc_file(&output_mxsc_path);
         print_contract_size(compiled_bytes.len());
         let mut abi = ContractAbiJson::from(&self.abi);
-        let build_info = core::mem::take(&mut abi.build_info).unwrap();
+        let mut build_info = core::mem::take(&mut abi.build_info).unwrap();
+        build_info.build_options = Some(BuildOptionsAbiJson::from(build_args));
         let mxsc_file_json = MxscFileJson {
             build_info,
             abi,
@@ -164,6 +179,65 @@ fn validate_ei(import_names: &[String], check_ei: &Option<EIVersion>) {
     }
 }
 
+impl OutputContract {
+    fn write_size_report_and_check_budget(&self, build_args: &BuildArgs, output_path: &str) {
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let compiled_bytes = fs::read(output_wasm_path).expect("failed to open compiled contract");
+        let size_info = parse_wasm_size_info(&compiled_bytes)
+            .unwrap_or_else(|err| panic!("failed to analyze compiled contract: {err}"));
+
+        let max_size = self.effective_max_size();
+        let output_size_report_path =
+            format!("{output_path}/{}", self.size_report_output_name(build_args));
+        print_write_size_report(&output_size_report_path);
+        let size_report = SizeReportJson::new(self.contract_name.clone(), max_size, &size_info);
+        save_size_report_json(&size_report, output_size_report_path);
+
+        if let Some(max_size) = max_size {
+            check_size_budget(&size_info, max_size);
+        }
+    }
+
+    /// The strictest of the absolute limit and the limit derived from the baseline, if any.
+    fn effective_max_size(&self) -> Option<usize> {
+        let budget = &self.settings.size_budget;
+        let max_size_from_growth = budget.max_size_growth.as_ref().map(|growth| {
+            let baseline_path = budget
+                .size_baseline_path()
+                .expect("max-size-growth requires a size-baseline");
+            let baseline_report = load_size_report_json(&baseline_path).unwrap_or_else(|| {
+                panic!(
+                    "size baseline {} not found, cannot check the size growth",
+                    baseline_path.display()
+                )
+            });
+            growth.max_size_from_baseline(baseline_report.size)
+        });
+
+        match (budget.max_size, max_size_from_growth) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+fn check_size_budget(size_info: &WasmSizeInfo, max_size: usize) {
+    print_check_size_budget(max_size);
+    if size_info.total_size <= max_size {
+        print_check_size_budget_ok();
+        return;
+    }
+
+    let largest_functions: Vec<(String, usize)> = size_info
+        .functions
+        .iter()
+        .take(SIZE_BREAKDOWN_NUM_FUNCTIONS)
+        .map(|function| (function.name.clone(), function.size))
+        .collect();
+    print_size_budget_exceeded(size_info.total_size, max_size, &largest_functions);
+    panic!("contract size budget exceeded (build with --wasm-symbols for function names)");
+}
+
 impl OutputContract {
     fn run_twiggy(&self, build_args: &BuildArgs, output_path: &str) {
         if build_args.has_twiggy_call() {
@@ -205,6 +279,28 @@ impl OutputContract {
     }
 }
 
+/// The contract crate root, its workspace root and the Cargo home are replaced with fixed paths,
+/// so the binary does not depend on where the source and the dependencies are located.
+///
+/// When several prefixes match, rustc applies the last one,
+/// so the contract root comes after the workspace that contains it.
+fn remapped_path_prefixes() -> Vec<(String, &'static str)> {
+    let mut result = Vec::new();
+    if let Ok(cargo_home) = std::env::var("CARGO_HOME") {
+        result.push((cargo_home, "/cargo"));
+    } else if let Ok(home) = std::env::var("HOME") {
+        result.push((format!("{home}/.cargo"), "/cargo"));
+    }
+    // the meta crate is always called from its own directory
+    if let Ok(contract_root) = fs::canonicalize("..") {
+        if let Some(workspace_root) = find_workspace_root(&contract_root) {
+            result.push((workspace_root.to_string_lossy().to_string(), "/project"));
+        }
+        result.push((contract_root.to_string_lossy().to_string(), "/contract"));
+    }
+    result
+}
+
 /// For convenience, for building rustflags.
 #[derive(Default)]
 struct Rustflags(String);
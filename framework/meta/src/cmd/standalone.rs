@@ -2,7 +2,8 @@ mod all;
 mod info;
 mod local_deps;
 pub mod scen_test_gen;
-pub(crate) mod upgrade;
+pub mod upgrade;
+mod verify;
 
 use crate::{
     cli_args::{StandaloneCliAction, StandaloneCliArgs},
@@ -14,6 +15,7 @@ use info::call_info;
 use local_deps::local_deps;
 use scen_test_gen::test_gen_tool;
 use upgrade::upgrade_sc;
+use verify::verify;
 
 /// Entry point in the program when calling it as a standalone tool.
 pub async fn cli_main_standalone() {
@@ -36,6 +38,9 @@ pub async fn cli_main_standalone() {
         Some(StandaloneCliAction::TestGen(args)) => {
             test_gen_tool(args);
         },
+        Some(StandaloneCliAction::Verify(args)) => {
+            verify(args);
+        },
         None => {},
     }
 }
@@ -2,8 +2,11 @@ mod upgrade_0_31;
 mod upgrade_0_32;
 mod upgrade_0_39;
 pub(crate) mod upgrade_common;
+mod upgrade_migrations;
 mod upgrade_print;
 mod upgrade_selector;
+pub mod upgrade_source_migration;
+mod upgrade_source_migrator;
 
 pub use upgrade_print::print_tree_dir_metadata;
 pub use upgrade_selector::upgrade_sc;
@@ -1,20 +1,16 @@
-use super::upgrade_common::{replace_in_files, version_bump_in_cargo_toml};
+use super::{
+    upgrade_source_migration::SourceMigration::{self, *},
+    upgrade_source_migrator::SourceMigrator,
+};
 use crate::folder_structure::RelevantDirectory;
-use ruplacer::Query;
-use std::path::Path;
+
+/// Variable arguments are detected from the type, the annotation is no longer needed.
+pub const MIGRATIONS_0_31_0: &[SourceMigration] = &[RemoveAttribute { name: "var_args" }];
 
 /// Migrate `0.30` to `0.31.0`, including the version bump.
-pub fn upgrade_to_31_0(dir: &RelevantDirectory) {
-    v_0_31_replace_in_files(dir.path.as_ref());
+pub fn upgrade_to_31_0(migrator: &mut SourceMigrator, dir: &RelevantDirectory) {
+    migrator.migrate_dir(&dir.path, MIGRATIONS_0_31_0);
 
     let (from_version, to_version) = dir.upgrade_in_progress.unwrap();
-    version_bump_in_cargo_toml(&dir.path, from_version, to_version);
-}
-
-fn v_0_31_replace_in_files(sc_crate_path: &Path) {
-    replace_in_files(
-        sc_crate_path,
-        "*rs",
-        &[Query::substring("#[var_args]", "")][..],
-    );
+    migrator.version_bump_in_cargo_toml(&dir.path, from_version, to_version);
 }
@@ -1,23 +1,19 @@
-use super::upgrade_common::{replace_in_files, version_bump_in_cargo_toml};
+use super::{
+    upgrade_source_migration::SourceMigration::{self, *},
+    upgrade_source_migrator::SourceMigrator,
+};
 use crate::folder_structure::RelevantDirectory;
-use ruplacer::Query;
-use std::path::Path;
 
-/// Migrate `0.30` to `0.31.0`, including the version bump.
-pub fn upgrade_to_32_0(dir: &RelevantDirectory) {
-    v_0_32_replace_in_files(dir.path.as_ref());
+/// `TokenIdentifier` only refers to ESDT tokens, EGLD moved to `EgldOrEsdtTokenIdentifier`.
+#[rustfmt::skip]
+pub const MIGRATIONS_0_32_0: &[SourceMigration] = &[
+    ReplacePath { from: "TokenIdentifier::egld", to: "EgldOrEsdtTokenIdentifier::egld" },
+];
 
-    let (from_version, to_version) = dir.upgrade_in_progress.unwrap();
-    version_bump_in_cargo_toml(&dir.path, from_version, to_version);
-}
+/// Migrate `0.31` to `0.32.0`, including the version bump.
+pub fn upgrade_to_32_0(migrator: &mut SourceMigrator, dir: &RelevantDirectory) {
+    migrator.migrate_dir(&dir.path, MIGRATIONS_0_32_0);
 
-fn v_0_32_replace_in_files(sc_crate_path: &Path) {
-    replace_in_files(
-        sc_crate_path,
-        "*rs",
-        &[Query::substring(
-            "TokenIdentifier::egld()",
-            "EgldOrEsdtTokenIdentifier::egld()",
-        )][..],
-    );
+    let (from_version, to_version) = dir.upgrade_in_progress.unwrap();
+    migrator.version_bump_in_cargo_toml(&dir.path, from_version, to_version);
 }
@@ -1,10 +1,10 @@
 use std::path::Path;
 
 use super::{
-    upgrade_common::{
-        re_generate_wasm_crate, rename_files, replace_in_files, version_bump_in_cargo_toml,
-    },
+    upgrade_common::{re_generate_wasm_crate, rename_files, replace_in_files},
     upgrade_print::*,
+    upgrade_source_migration::SourceMigration::{self, *},
+    upgrade_source_migrator::SourceMigrator,
 };
 use crate::{
     folder_structure::{DirectoryType, RelevantDirectory},
@@ -19,17 +19,46 @@ pub const SCENARIO_FILE_PATTERNS: &[(&str, &str)] = &[
     ("mandos_rs", "scenario_rs"),
 ];
 
+/// The rename from `elrond-wasm` to `multiversx-sc`.
+///
+/// Whole paths go first, since the crate renames would make them unrecognizable.
+#[rustfmt::skip]
+pub const MIGRATIONS_0_39_0: &[SourceMigration] = &[
+    ReplacePath { from: "elrond_wasm_debug::meta::perform", to: "multiversx_sc_meta::cli_main" },
+    ReplacePath { from: "elrond_wasm_debug::mandos_go", to: "multiversx_sc_scenario::run_go" },
+    ReplacePath { from: "elrond_wasm_debug::mandos_rs", to: "multiversx_sc_scenario::run_rs" },
+    RenamePathSegment { from: "elrond_codec", to: "codec" },
+    RenamePathSegment { from: "elrond_wasm_debug", to: "multiversx_sc_scenario" },
+    RenamePathSegment { from: "elrond_wasm_modules", to: "multiversx_sc_modules" },
+    RenamePathSegment { from: "elrond_wasm_node", to: "multiversx_sc_wasm_adapter" },
+    RenamePathSegment { from: "elrond_wasm", to: "multiversx_sc" },
+    RenamePathSegment { from: "BlockchainMock", to: "ScenarioWorld" },
+    RenamePathSegment { from: "testing_framework", to: "whitebox" },
+    RenamePathSegment { from: "tx_mock", to: "whitebox" },
+    RenameMethod { from: "register_contract_builder", to: "register_contract" },
+    RenameMethodWithTupleArg { from: "add_esdt_token_transfer", to: "with_esdt_transfer" },
+    RenameMethodWithTupleArg {
+        from: "with_egld_or_single_esdt_token_transfer",
+        to: "with_egld_or_single_esdt_transfer",
+    },
+];
+
 /// Migrate `0.38.0` to `0.39.0`, including the version bump.
-pub fn upgrade_to_39_0(dir: &RelevantDirectory) {
-    if dir.dir_type == DirectoryType::Contract {
-        v_0_39_prepare_meta(&dir.path);
-        v_0_39_prepare_wasm(&dir.path);
+///
+/// In dry-run mode only the source migrations and the version bump are computed.
+pub fn upgrade_to_39_0(migrator: &mut SourceMigrator, dir: &RelevantDirectory) {
+    migrator.migrate_dir(&dir.path, MIGRATIONS_0_39_0);
+    if !migrator.is_dry_run() {
+        if dir.dir_type == DirectoryType::Contract {
+            v_0_39_prepare_meta(&dir.path);
+            v_0_39_prepare_wasm(&dir.path);
+        }
+        v_0_39_replace_in_files(&dir.path);
+        rename_files(dir.path.as_ref(), SCENARIO_FILE_PATTERNS);
     }
-    v_0_39_replace_in_files(&dir.path);
-    rename_files(dir.path.as_ref(), SCENARIO_FILE_PATTERNS);
 
     let (from_version, to_version) = dir.upgrade_in_progress.unwrap();
-    version_bump_in_cargo_toml(&dir.path, from_version, to_version);
+    migrator.version_bump_in_cargo_toml(&dir.path, from_version, to_version);
 }
 
 /// Post-processing: re-generate the wasm crates.
@@ -92,32 +121,4 @@ fn v_0_39_replace_in_files(sc_crate_path: &Path) {
             Query::substring("elrond-wasm", "multiversx-sc"),
         ][..],
     );
-
-    replace_in_files(
-        sc_crate_path,
-        "*rs",
-        &[
-            Query::substring("elrond_codec", "codec"),
-            Query::substring(
-                "elrond_wasm_debug::meta::perform",
-                "multiversx_sc_meta::cli_main",
-            ),
-            Query::substring(
-                "elrond_wasm_debug::mandos_go",
-                "multiversx_sc_scenario::run_go",
-            ),
-            Query::substring(
-                "elrond_wasm_debug::mandos_rs",
-                "multiversx_sc_scenario::run_rs",
-            ),
-            Query::substring("elrond_wasm_debug", "multiversx_sc_scenario"),
-            Query::substring("elrond_wasm_modules", "multiversx_sc_modules"),
-            Query::substring("elrond_wasm_node", "multiversx_sc_wasm_adapter"),
-            Query::substring("elrond_wasm", "multiversx_sc"),
-            Query::substring("BlockchainMock", "ScenarioWorld"),
-            Query::substring("testing_framework", "whitebox"),
-            Query::substring("tx_mock", "whitebox"),
-            Query::substring("register_contract_builder", "register_contract"),
-        ][..],
-    );
 }
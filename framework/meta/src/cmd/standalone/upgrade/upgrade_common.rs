@@ -9,9 +9,7 @@ use toml::Value;
 use crate::{
     cargo_toml_contents::{CARGO_TOML_DEPENDENCIES, CARGO_TOML_DEV_DEPENDENCIES},
     cmd::standalone::all::call_contract_meta,
-    folder_structure::{
-        DirectoryType, RelevantDirectory, VersionReq, CARGO_TOML_FILE_NAME, FRAMEWORK_CRATE_NAMES,
-    },
+    folder_structure::{DirectoryType, RelevantDirectory, VersionReq, FRAMEWORK_CRATE_NAMES},
     CargoTomlContents,
 };
 
@@ -74,41 +72,24 @@ fn try_replace_file_name(file_name_str: &str, patterns: &[(&str, &str)]) -> Opti
     None
 }
 
-/// Uses `CargoTomlContents`. Will only replace versions of framework crates.
-pub fn version_bump_in_cargo_toml(path: &Path, from_version: &str, to_version: &str) {
-    if is_cargo_toml_file(path) {
-        let mut cargo_toml_contents = CargoTomlContents::load_from_file(path);
-        upgrade_all_dependency_versions(
-            &mut cargo_toml_contents,
-            CARGO_TOML_DEPENDENCIES,
-            from_version,
-            to_version,
-        );
-        upgrade_all_dependency_versions(
-            &mut cargo_toml_contents,
-            CARGO_TOML_DEV_DEPENDENCIES,
-            from_version,
-            to_version,
-        );
-        cargo_toml_contents.save_to_file(path);
-        return;
-    }
-
-    if path.is_dir() {
-        let read_dir = fs::read_dir(path).expect("error reading directory");
-        for child_result in read_dir {
-            let child = child_result.unwrap();
-            version_bump_in_cargo_toml(child.path().as_path(), from_version, to_version);
-        }
-    }
-}
-
-fn is_cargo_toml_file(path: &Path) -> bool {
-    if let Some(file_name_str) = try_get_file_name_str(path) {
-        file_name_str == CARGO_TOML_FILE_NAME
-    } else {
-        false
-    }
+/// Will only replace versions of framework crates.
+pub fn version_bump_in_cargo_toml_contents(
+    cargo_toml_contents: &mut CargoTomlContents,
+    from_version: &str,
+    to_version: &str,
+) {
+    upgrade_all_dependency_versions(
+        cargo_toml_contents,
+        CARGO_TOML_DEPENDENCIES,
+        from_version,
+        to_version,
+    );
+    upgrade_all_dependency_versions(
+        cargo_toml_contents,
+        CARGO_TOML_DEV_DEPENDENCIES,
+        from_version,
+        to_version,
+    );
 }
 
 fn upgrade_all_dependency_versions(
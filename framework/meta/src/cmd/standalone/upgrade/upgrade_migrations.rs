@@ -0,0 +1,190 @@
+use super::{
+    upgrade_0_31::MIGRATIONS_0_31_0,
+    upgrade_0_32::MIGRATIONS_0_32_0,
+    upgrade_0_39::MIGRATIONS_0_39_0,
+    upgrade_source_migration::{
+        GenericArgSource::*,
+        SourceMigration::{self, *},
+    },
+};
+
+/// The source code migrations to perform when upgrading to a given version.
+///
+/// Versions from `version_history.rs` that are missing here need no source changes.
+pub fn source_migrations(to_version: &str) -> &'static [SourceMigration] {
+    match to_version {
+        "0.29.0" => MIGRATIONS_0_29_0,
+        "0.29.3" => MIGRATIONS_0_29_3,
+        "0.31.0" => MIGRATIONS_0_31_0,
+        "0.32.0" => MIGRATIONS_0_32_0,
+        "0.36.1" => MIGRATIONS_0_36_1,
+        "0.39.0" => MIGRATIONS_0_39_0,
+        "0.41.0" => MIGRATIONS_0_41_0,
+        "0.42.0" => MIGRATIONS_0_42_0,
+        _ => &[],
+    }
+}
+
+/// Multi-value type renames.
+#[rustfmt::skip]
+const MIGRATIONS_0_29_0: &[SourceMigration] = &[
+    RenamePathSegment { from: "ManagedVarArgs", to: "MultiValueEncoded" },
+    RenamePathSegment { from: "ManagedMultiResultVec", to: "MultiValueEncoded" },
+    RenamePathSegment { from: "ManagedVarArgsEager", to: "MultiValueManagedVec" },
+    RenamePathSegment { from: "ManagedMultiResultVecEager", to: "MultiValueManagedVec" },
+    RenamePathSegment { from: "ManagedCountedVarArgs", to: "MultiValueManagedVecCounted" },
+    RenamePathSegment { from: "ManagedCountedMultiResultVec", to: "MultiValueManagedVecCounted" },
+    RenamePathSegment { from: "VarArgs", to: "MultiValueVec" },
+    RenamePathSegment { from: "MultiArgVec", to: "MultiValueVec" },
+    RenamePathSegment { from: "MultiResultVec", to: "MultiValueVec" },
+    RenamePathSegment { from: "IgnoreVarArgs", to: "IgnoreValue" },
+    RenamePathSegment { from: "OptionalArg", to: "OptionalValue" },
+    RenamePathSegment { from: "OptionalResult", to: "OptionalValue" },
+    RenamePathSegment { from: "MultiArg2", to: "MultiValue2" },
+    RenamePathSegment { from: "MultiArg3", to: "MultiValue3" },
+    RenamePathSegment { from: "MultiArg4", to: "MultiValue4" },
+    RenamePathSegment { from: "MultiArg5", to: "MultiValue5" },
+    RenamePathSegment { from: "MultiArg6", to: "MultiValue6" },
+    RenamePathSegment { from: "MultiArg7", to: "MultiValue7" },
+    RenamePathSegment { from: "MultiArg8", to: "MultiValue8" },
+    RenamePathSegment { from: "MultiArg9", to: "MultiValue9" },
+    RenamePathSegment { from: "MultiArg10", to: "MultiValue10" },
+    RenamePathSegment { from: "MultiArg11", to: "MultiValue11" },
+    RenamePathSegment { from: "MultiArg12", to: "MultiValue12" },
+    RenamePathSegment { from: "MultiArg13", to: "MultiValue13" },
+    RenamePathSegment { from: "MultiArg14", to: "MultiValue14" },
+    RenamePathSegment { from: "MultiArg15", to: "MultiValue15" },
+    RenamePathSegment { from: "MultiArg16", to: "MultiValue16" },
+    RenamePathSegment { from: "MultiResult2", to: "MultiValue2" },
+    RenamePathSegment { from: "MultiResult3", to: "MultiValue3" },
+    RenamePathSegment { from: "MultiResult4", to: "MultiValue4" },
+    RenamePathSegment { from: "MultiResult5", to: "MultiValue5" },
+    RenamePathSegment { from: "MultiResult6", to: "MultiValue6" },
+    RenamePathSegment { from: "MultiResult7", to: "MultiValue7" },
+    RenamePathSegment { from: "MultiResult8", to: "MultiValue8" },
+    RenamePathSegment { from: "MultiResult9", to: "MultiValue9" },
+    RenamePathSegment { from: "MultiResult10", to: "MultiValue10" },
+    RenamePathSegment { from: "MultiResult11", to: "MultiValue11" },
+    RenamePathSegment { from: "MultiResult12", to: "MultiValue12" },
+    RenamePathSegment { from: "MultiResult13", to: "MultiValue13" },
+    RenamePathSegment { from: "MultiResult14", to: "MultiValue14" },
+    RenamePathSegment { from: "MultiResult15", to: "MultiValue15" },
+    RenamePathSegment { from: "MultiResult16", to: "MultiValue16" },
+];
+
+#[rustfmt::skip]
+const MIGRATIONS_0_29_3: &[SourceMigration] = &[
+    RenamePathSegment { from: "EsdtTokenPaymentMultiArg", to: "EsdtTokenPaymentMultiValue" },
+];
+
+#[rustfmt::skip]
+const MIGRATIONS_0_36_1: &[SourceMigration] = &[
+    RenameMethod {
+        from: "execute_on_dest_context_ignore_result",
+        to: "execute_on_dest_context::<IgnoreValue>",
+    },
+];
+
+/// The legacy address getters are replaced by their managed counterparts.
+#[rustfmt::skip]
+const MIGRATIONS_0_41_0: &[SourceMigration] = &[
+    ReplaceMethodCall { from: "get_caller_legacy", to: "get_caller", append: ".to_address()" },
+    ReplaceMethodCall { from: "get_sc_address_legacy", to: "get_sc_address", append: ".to_address()" },
+];
+
+/// `DebugApi` only works on the VM context stack, contract proxies in tests and interactors
+/// need the `StaticApi` instead. The full path spares adding an import.
+#[rustfmt::skip]
+const MIGRATIONS_0_42_0: &[SourceMigration] = &[
+    MapGenericArgs {
+        type_name: "Proxy",
+        args: &[Replaced { index: 0, from: "DebugApi", to: "multiversx_sc_scenario::api::StaticApi" }],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cmd::standalone::upgrade::upgrade_source_migration::migrate_source,
+        version_history::VERSIONS,
+    };
+
+    const SOURCE_0_28: &str = r#"#![no_std]
+
+elrond_wasm::imports!();
+
+use elrond_wasm::elrond_codec::TopEncode;
+
+/// Keeps track of OptionalArg and VarArgs, in comments nothing changes.
+#[elrond_wasm::contract]
+pub trait Adder {
+    #[endpoint]
+    fn add(&self, #[var_args] values: VarArgs<BigUint>, opt: OptionalArg<u32>) {
+        let caller = self.blockchain().get_caller_legacy();
+        let egld = TokenIdentifier::egld();
+        self.other_proxy(caller.into())
+            .do_something()
+            .add_esdt_token_transfer(token_id, 0, amount)
+            .execute_on_dest_context_ignore_result();
+        let _ = "OptionalArg in a string";
+    }
+
+    #[proxy]
+    fn other_proxy(&self, to: ManagedAddress) -> other::Proxy<Self::Api>;
+}
+"#;
+
+    const SOURCE_LATEST: &str = r#"#![no_std]
+
+multiversx_sc::imports!();
+
+use multiversx_sc::codec::TopEncode;
+
+/// Keeps track of OptionalArg and VarArgs, in comments nothing changes.
+#[multiversx_sc::contract]
+pub trait Adder {
+    #[endpoint]
+    fn add(&self, values: MultiValueVec<BigUint>, opt: OptionalValue<u32>) {
+        let caller = self.blockchain().get_caller().to_address();
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.other_proxy(caller.into())
+            .do_something()
+            .with_esdt_transfer((token_id, 0, amount))
+            .execute_on_dest_context::<IgnoreValue>();
+        let _ = "OptionalArg in a string";
+    }
+
+    #[proxy]
+    fn other_proxy(&self, to: ManagedAddress) -> other::Proxy<Self::Api>;
+}
+"#;
+
+    #[test]
+    fn test_migrate_across_all_versions() {
+        let mut source = SOURCE_0_28.to_string();
+        for version in VERSIONS {
+            source = migrate_source(&source, source_migrations(version)).unwrap();
+        }
+        assert_eq!(source, SOURCE_LATEST);
+    }
+
+    #[test]
+    fn test_source_migrations_only_for_known_versions() {
+        for version in [
+            "0.29.0", "0.29.3", "0.31.0", "0.32.0", "0.36.1", "0.39.0", "0.41.0", "0.42.0",
+        ] {
+            assert!(VERSIONS.contains(&version));
+            assert!(!source_migrations(version).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_migrate_blackbox_test_proxy() {
+        let source = "type AdderContract = ContractInfo<adder::Proxy<DebugApi>>;\n\nfn whitebox(_: BigUint<DebugApi>) {}\n";
+        assert_eq!(
+            migrate_source(source, source_migrations("0.42.0")).unwrap(),
+            "type AdderContract = ContractInfo<adder::Proxy<multiversx_sc_scenario::api::StaticApi>>;\n\nfn whitebox(_: BigUint<DebugApi>) {}\n",
+        );
+    }
+}
@@ -3,7 +3,10 @@ use crate::folder_structure::{
     RelevantDirectory,
 };
 use colored::Colorize;
-use std::path::Path;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
 
 pub fn print_upgrading(dir: &RelevantDirectory) {
     if let Some((from_version, to_version)) = dir.upgrade_in_progress {
@@ -119,3 +122,78 @@ pub fn print_cargo_check_fail() {
         .red();
     println!("\n{message}");
 }
+
+pub fn print_source_migrated(path: &Path) {
+    println!("Migrated {}", path.display().to_string().green());
+}
+
+pub fn print_source_migration_failed(path: &Path, err: &syn::Error) {
+    println!(
+        "{}",
+        format!(
+            "Could not parse {}, source migrations cannot be applied: {err}",
+            path.display()
+        )
+        .red()
+    );
+}
+
+pub fn print_source_migration_abort() {
+    let message =
+        "Automatic upgrade stopped. Fix the syntax error or migrate the file manually, then continue automatic upgrade!"
+        .red();
+    println!("\n{message}");
+}
+
+pub fn print_source_diff(path: &Path, original: &str, migrated: &str) {
+    let path_str = path.display().to_string();
+    let diff = similar::TextDiff::from_lines(original, migrated);
+    let unified_diff = diff
+        .unified_diff()
+        .context_radius(2)
+        .header(&path_str, &path_str)
+        .to_string();
+    println!();
+    for line in unified_diff.lines() {
+        if line.starts_with("+++") || line.starts_with("---") {
+            println!("{}", line.bold());
+        } else if line.starts_with('+') {
+            println!("{}", line.green());
+        } else if line.starts_with('-') {
+            println!("{}", line.red());
+        } else if line.starts_with("@@") {
+            println!("{}", line.cyan());
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+pub fn print_dry_run_start() {
+    println!(
+        "\n{}",
+        "Dry run: only source code changes and version bumps are computed, nothing is written to disk.".yellow()
+    );
+}
+
+pub fn print_dry_run_summary(num_changed_files: usize, failed_files: &BTreeSet<PathBuf>) {
+    println!(
+        "\n{}\n{}",
+        format!("Dry run: {num_changed_files} files would change.").yellow(),
+        "Dependency renames, file renames, wasm crate re-generation and cargo check were skipped."
+            .yellow(),
+    );
+    if !failed_files.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "{} files could not be parsed and need to be migrated manually:",
+                failed_files.len()
+            )
+            .red()
+        );
+        for path in failed_files {
+            println!("    {}", path.display().to_string().red());
+        }
+    }
+}
@@ -8,8 +8,10 @@ use super::{
     upgrade_0_31::upgrade_to_31_0,
     upgrade_0_32::upgrade_to_32_0,
     upgrade_0_39::{postprocessing_after_39_0, upgrade_to_39_0},
-    upgrade_common::{cargo_check, version_bump_in_cargo_toml},
+    upgrade_common::cargo_check,
+    upgrade_migrations::source_migrations,
     upgrade_print::*,
+    upgrade_source_migrator::SourceMigrator,
 };
 
 pub fn upgrade_sc(args: &UpgradeArgs) {
@@ -39,6 +41,11 @@ pub fn upgrade_sc(args: &UpgradeArgs) {
         print_tree_dir_metadata(dir, last_version.as_str())
     });
 
+    if args.dry_run {
+        print_dry_run_start();
+    }
+
+    let mut source_migrator = SourceMigrator::new(args.dry_run);
     for (from_version, to_version) in versions_iter(last_version) {
         if dirs.count_for_version(from_version) == 0 {
             continue;
@@ -46,37 +53,49 @@ pub fn upgrade_sc(args: &UpgradeArgs) {
 
         print_upgrading_all(from_version, to_version);
         dirs.start_upgrade(from_version, to_version);
+        source_migrator.start_step();
         for dir in dirs.iter_version(from_version) {
-            upgrade_function_selector(dir);
+            upgrade_function_selector(&mut source_migrator, dir);
         }
 
-        for dir in dirs.iter_version(from_version) {
-            upgrade_post_processing(dir);
+        if !args.dry_run {
+            for dir in dirs.iter_version(from_version) {
+                upgrade_post_processing(dir);
+            }
         }
 
         // // change the version in memory for the next iteration (dirs is not reloaded from disk)
         // dirs.update_versions_in_memory(from_version, to_version);
         dirs.finish_upgrade();
     }
+
+    if args.dry_run {
+        source_migrator.print_diffs();
+        print_dry_run_summary(
+            source_migrator.num_changed_files(),
+            source_migrator.failed_files(),
+        );
+    }
 }
 
-fn upgrade_function_selector(dir: &RelevantDirectory) {
+fn upgrade_function_selector(source_migrator: &mut SourceMigrator, dir: &RelevantDirectory) {
     if dir.upgrade_in_progress.is_some() {
         print_upgrading(dir);
     }
 
     match dir.upgrade_in_progress {
         Some((_, "0.31.0")) => {
-            upgrade_to_31_0(dir);
+            upgrade_to_31_0(source_migrator, dir);
         },
         Some((_, "0.32.0")) => {
-            upgrade_to_32_0(dir);
+            upgrade_to_32_0(source_migrator, dir);
         },
         Some((_, "0.39.0")) => {
-            upgrade_to_39_0(dir);
+            upgrade_to_39_0(source_migrator, dir);
         },
         Some((from_version, to_version)) => {
-            version_bump_in_cargo_toml(&dir.path, from_version, to_version);
+            source_migrator.migrate_dir(&dir.path, source_migrations(to_version));
+            source_migrator.version_bump_in_cargo_toml(&dir.path, from_version, to_version);
         },
         None => {},
     }
@@ -0,0 +1,626 @@
+use proc_macro2::{LineColumn, Span};
+use syn::{
+    parse::ParseStream,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit::{self, Visit},
+    Expr, Ident, Item, Token, UseTree,
+};
+
+/// Where each generic argument of a rewritten type comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenericArgSource {
+    /// The argument at the given position in the original list.
+    Original(usize),
+
+    /// A new argument, as source code.
+    Literal(&'static str),
+
+    /// The argument at the given position, which must be exactly `from`, replaced by `to`.
+    ///
+    /// Occurrences with any other argument in that position are left unchanged.
+    Replaced {
+        index: usize,
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+/// A source code transformation, expressed in terms of the syntax tree.
+///
+/// Only the affected tokens are rewritten, the rest of the file, including formatting
+/// and comments, is kept as it was. Code in string literals and comments is never changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SourceMigration {
+    /// Renames a path segment (crate, module, type, trait or function name)
+    /// wherever it appears: types, expressions, `use` declarations, attributes, macro paths.
+    ///
+    /// Aliases introduced by `use ... as ...` are kept, so code using the alias needs no change.
+    RenamePathSegment {
+        from: &'static str,
+        to: &'static str,
+    },
+
+    /// Replaces the start of paths, e.g. `a::b::c` -> `x::y`.
+    ///
+    /// Paths starting with all the segments in `from` get them replaced by `to`.
+    ReplacePath {
+        from: &'static str,
+        to: &'static str,
+    },
+
+    /// Renames method calls, e.g. `x.from(...)` -> `x.to(...)`.
+    ///
+    /// The new name can also contain a turbofish, e.g. `method::<T>`.
+    RenameMethod {
+        from: &'static str,
+        to: &'static str,
+    },
+
+    /// Replaces calls to a deprecated method with an equivalent expression,
+    /// e.g. `x.from(...)` -> `x.to(...).append`.
+    ReplaceMethodCall {
+        from: &'static str,
+        to: &'static str,
+        append: &'static str,
+    },
+
+    /// Renames a method whose arguments were merged into a single tuple argument,
+    /// e.g. `x.from(a, b, c)` -> `x.to((a, b, c))`.
+    RenameMethodWithTupleArg {
+        from: &'static str,
+        to: &'static str,
+    },
+
+    /// Rewrites the generic arguments of all occurrences of a type, e.g. `T<A, B>` -> `T<B, X, A>`.
+    MapGenericArgs {
+        type_name: &'static str,
+        args: &'static [GenericArgSource],
+    },
+
+    /// Removes an attribute, e.g. `#[var_args]`.
+    RemoveAttribute { name: &'static str },
+}
+
+/// Applies the migrations one after the other.
+///
+/// Fails if the source code cannot be parsed, in which case nothing should be changed.
+pub fn migrate_source(source: &str, migrations: &[SourceMigration]) -> syn::Result<String> {
+    let mut current = source.to_string();
+    for migration in migrations {
+        let file = syn::parse_file(&current)?;
+        let mut collector = EditCollector::new(&current, migration);
+        collector.visit_file(&file);
+        current = apply_edits(&current, collector.edits);
+    }
+    Ok(current)
+}
+
+/// Replaces a range of bytes from the source.
+#[derive(Debug)]
+struct SourceEdit {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+/// Edits are applied in order. Edits overlapping with previous ones are ignored.
+fn apply_edits(source: &str, mut edits: Vec<SourceEdit>) -> String {
+    edits.sort_by_key(|edit| (edit.start, edit.end));
+    let mut result = String::with_capacity(source.len());
+    let mut pos = 0;
+    for edit in edits {
+        if edit.start < pos {
+            continue;
+        }
+        result.push_str(&source[pos..edit.start]);
+        result.push_str(&edit.replacement);
+        pos = edit.end;
+    }
+    result.push_str(&source[pos..]);
+    result
+}
+
+/// Converts span locations, which are given as line and character column, to byte offsets.
+struct LineOffsets<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineOffsets<'a> {
+    fn new(source: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        for (i, c) in source.char_indices() {
+            if c == '\n' {
+                line_starts.push(i + 1);
+            }
+        }
+        LineOffsets {
+            source,
+            line_starts,
+        }
+    }
+
+    fn offset(&self, location: LineColumn) -> usize {
+        let line_start = self.line_starts[location.line - 1];
+        self.source[line_start..]
+            .char_indices()
+            .nth(location.column)
+            .map(|(i, _)| line_start + i)
+            .unwrap_or(self.source.len())
+    }
+
+    fn start(&self, span: Span) -> usize {
+        self.offset(span.start())
+    }
+
+    fn end(&self, span: Span) -> usize {
+        self.offset(span.end())
+    }
+}
+
+struct EditCollector<'a> {
+    offsets: LineOffsets<'a>,
+    migration: &'a SourceMigration,
+    edits: Vec<SourceEdit>,
+}
+
+impl<'a> EditCollector<'a> {
+    fn new(source: &'a str, migration: &'a SourceMigration) -> Self {
+        EditCollector {
+            offsets: LineOffsets::new(source),
+            migration,
+            edits: Vec::new(),
+        }
+    }
+
+    fn replace_span(&mut self, span: Span, replacement: &str) {
+        self.replace_range(
+            self.offsets.start(span),
+            self.offsets.end(span),
+            replacement,
+        );
+    }
+
+    fn replace_range(&mut self, start: usize, end: usize, replacement: &str) {
+        self.edits.push(SourceEdit {
+            start,
+            end,
+            replacement: replacement.to_string(),
+        });
+    }
+
+    fn rename_ident(&mut self, ident: &Ident) {
+        if let SourceMigration::RenamePathSegment { from, to } = self.migration {
+            if ident == from {
+                self.replace_span(ident.span(), to);
+            }
+        }
+    }
+
+    fn replace_path_prefix(&mut self, segments: &[&Ident]) {
+        if let SourceMigration::ReplacePath { from, to } = self.migration {
+            if path_starts_with(segments, from) {
+                let num_segments = from.split("::").count();
+                let start = self.offsets.start(segments[0].span());
+                let end = self.offsets.end(segments[num_segments - 1].span());
+                self.replace_range(start, end, to);
+            }
+        }
+    }
+
+    /// Segments inside a `{}` group are not contiguous with the ones before the group,
+    /// so each group item starts with a fresh prefix.
+    fn visit_use_tree_with_prefix(&mut self, tree: &UseTree, prefix: &mut Vec<Ident>) {
+        match tree {
+            UseTree::Path(use_path) => {
+                self.rename_ident(&use_path.ident);
+                prefix.push(use_path.ident.clone());
+                self.check_use_prefix(prefix);
+                self.visit_use_tree_with_prefix(&use_path.tree, prefix);
+                prefix.pop();
+            },
+            UseTree::Name(use_name) => {
+                self.rename_ident(&use_name.ident);
+                prefix.push(use_name.ident.clone());
+                self.check_use_prefix(prefix);
+                prefix.pop();
+            },
+            UseTree::Rename(use_rename) => {
+                // only the original name changes, the alias stays
+                self.rename_ident(&use_rename.ident);
+                prefix.push(use_rename.ident.clone());
+                self.check_use_prefix(prefix);
+                prefix.pop();
+            },
+            UseTree::Group(use_group) => {
+                for item in &use_group.items {
+                    self.visit_use_tree_with_prefix(item, &mut Vec::new());
+                }
+            },
+            UseTree::Glob(_) => {},
+        }
+    }
+
+    fn check_use_prefix(&mut self, prefix: &[Ident]) {
+        if let SourceMigration::ReplacePath { from, .. } = self.migration {
+            // only replace once, when the prefix exactly covers the replaced path
+            if prefix.len() == from.split("::").count() {
+                let idents: Vec<&Ident> = prefix.iter().collect();
+                self.replace_path_prefix(&idents);
+            }
+        }
+    }
+
+    fn visit_macro_body(&mut self, mac: &syn::Macro) {
+        // macro bodies are opaque token streams, but most of them are plain expressions or items
+        if let Ok(exprs) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for expr in &exprs {
+                self.visit_expr(expr);
+            }
+        } else if let Ok(items) = mac.parse_body_with(parse_items) {
+            for item in &items {
+                self.visit_item(item);
+            }
+        }
+    }
+}
+
+fn parse_items(input: ParseStream) -> syn::Result<Vec<Item>> {
+    let mut items = Vec::new();
+    while !input.is_empty() {
+        items.push(input.parse()?);
+    }
+    Ok(items)
+}
+
+fn path_starts_with(segments: &[&Ident], path: &str) -> bool {
+    let path_segments: Vec<&str> = path.split("::").collect();
+    segments.len() >= path_segments.len()
+        && segments
+            .iter()
+            .zip(path_segments.iter())
+            .all(|(ident, segment)| ident == segment)
+}
+
+impl<'a, 'ast> Visit<'ast> for EditCollector<'a> {
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        match self.migration {
+            SourceMigration::RenamePathSegment { .. } => {
+                for segment in &path.segments {
+                    self.rename_ident(&segment.ident);
+                }
+            },
+            SourceMigration::ReplacePath { from, .. } => {
+                let num_segments = from.split("::").count();
+                // the replaced segments, except the last one, cannot have generic arguments
+                let simple_prefix = path
+                    .segments
+                    .iter()
+                    .take(num_segments.saturating_sub(1))
+                    .all(|segment| segment.arguments.is_none());
+                let idents: Vec<&Ident> =
+                    path.segments.iter().map(|segment| &segment.ident).collect();
+                if simple_prefix {
+                    self.replace_path_prefix(&idents);
+                }
+            },
+            SourceMigration::MapGenericArgs { type_name, args } => {
+                for segment in &path.segments {
+                    if segment.ident == type_name {
+                        if let syn::PathArguments::AngleBracketed(angle_bracketed) =
+                            &segment.arguments
+                        {
+                            self.map_generic_args(angle_bracketed, args);
+                        }
+                    }
+                }
+            },
+            _ => {},
+        }
+        visit::visit_path(self, path);
+    }
+
+    fn visit_item_use(&mut self, item_use: &'ast syn::ItemUse) {
+        for attr in &item_use.attrs {
+            self.visit_attribute(attr);
+        }
+        let mut prefix = Vec::new();
+        self.visit_use_tree_with_prefix(&item_use.tree, &mut prefix);
+    }
+
+    fn visit_item_extern_crate(&mut self, item: &'ast syn::ItemExternCrate) {
+        self.rename_ident(&item.ident);
+        visit::visit_item_extern_crate(self, item);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        match self.migration {
+            SourceMigration::RenameMethod { from, to } if call.method == from => {
+                self.replace_span(call.method.span(), to);
+            },
+            SourceMigration::ReplaceMethodCall { from, to, append } if call.method == from => {
+                self.replace_span(call.method.span(), to);
+                let call_end = self.offsets.end(call.paren_token.span.close());
+                self.replace_range(call_end, call_end, append);
+            },
+            SourceMigration::RenameMethodWithTupleArg { from, to } if call.method == from => {
+                self.replace_span(call.method.span(), to);
+                if call.args.len() > 1 {
+                    let args_start = self.offsets.end(call.paren_token.span.open());
+                    let args_end = self.offsets.start(call.paren_token.span.close());
+                    self.replace_range(args_start, args_start, "(");
+                    self.replace_range(args_end, args_end, ")");
+                }
+            },
+            _ => {},
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_attribute(&mut self, attr: &'ast syn::Attribute) {
+        if let SourceMigration::RemoveAttribute { name } = self.migration {
+            if attr.path().is_ident(name) {
+                let start = self.offsets.start(attr.pound_token.span);
+                let end = self.offsets.end(attr.bracket_token.span.close());
+                let (start, end) = extend_removal(self.offsets.source, start, end);
+                self.replace_range(start, end, "");
+                return;
+            }
+        }
+        visit::visit_attribute(self, attr);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        visit::visit_macro(self, mac);
+        self.visit_macro_body(mac);
+    }
+}
+
+impl<'a> EditCollector<'a> {
+    fn map_generic_args(
+        &mut self,
+        angle_bracketed: &syn::AngleBracketedGenericArguments,
+        arg_sources: &[GenericArgSource],
+    ) {
+        let original_args: Vec<&str> = angle_bracketed
+            .args
+            .iter()
+            .map(|arg| {
+                let span = arg.span();
+                &self.offsets.source[self.offsets.start(span)..self.offsets.end(span)]
+            })
+            .collect();
+        let mut new_args = Vec::new();
+        for arg_source in arg_sources {
+            match arg_source {
+                GenericArgSource::Original(index) => {
+                    if let Some(original_arg) = original_args.get(*index) {
+                        new_args.push(original_arg.to_string());
+                    } else {
+                        // the type is not used the way the migration expects, leave it alone
+                        return;
+                    }
+                },
+                GenericArgSource::Literal(literal) => new_args.push(literal.to_string()),
+                GenericArgSource::Replaced { index, from, to } => {
+                    if original_args.get(*index) == Some(from) {
+                        new_args.push(to.to_string());
+                    } else {
+                        return;
+                    }
+                },
+            }
+        }
+
+        if new_args.is_empty() {
+            let start_span = angle_bracketed
+                .colon2_token
+                .map(|colon2| colon2.spans[0])
+                .unwrap_or(angle_bracketed.lt_token.span);
+            let start = self.offsets.start(start_span);
+            let end = self.offsets.end(angle_bracketed.gt_token.span);
+            self.replace_range(start, end, "");
+        } else {
+            let start = self.offsets.end(angle_bracketed.lt_token.span);
+            let end = self.offsets.start(angle_bracketed.gt_token.span);
+            self.replace_range(start, end, &new_args.join(", "));
+        }
+    }
+}
+
+/// Also removes the whitespace after a removed attribute,
+/// or the whole line, if the attribute was alone on its line.
+fn extend_removal(source: &str, start: usize, end: usize) -> (usize, usize) {
+    let bytes = source.as_bytes();
+    let mut new_end = end;
+    while new_end < bytes.len() && (bytes[new_end] == b' ' || bytes[new_end] == b'\t') {
+        new_end += 1;
+    }
+
+    let mut line_start = start;
+    while line_start > 0 && (bytes[line_start - 1] == b' ' || bytes[line_start - 1] == b'\t') {
+        line_start -= 1;
+    }
+    let alone_on_line = (line_start == 0 || bytes[line_start - 1] == b'\n')
+        && (new_end == bytes.len() || bytes[new_end] == b'\n' || bytes[new_end] == b'\r');
+    if alone_on_line {
+        while new_end < bytes.len() && bytes[new_end] != b'\n' {
+            new_end += 1;
+        }
+        if new_end < bytes.len() {
+            new_end += 1;
+        }
+        (line_start, new_end)
+    } else {
+        (start, new_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(source: &str, migration: SourceMigration) -> String {
+        migrate_source(source, &[migration]).unwrap()
+    }
+
+    #[test]
+    fn test_rename_path_segment() {
+        let migration = SourceMigration::RenamePathSegment {
+            from: "OptionalArg",
+            to: "OptionalValue",
+        };
+        assert_eq!(
+            migrate(
+                "// OptionalArg stays in comments\nfn f(x: OptionalArg<u32>) -> OptionalArgs { let _ = \"OptionalArg\"; }\n",
+                migration,
+            ),
+            "// OptionalArg stays in comments\nfn f(x: OptionalValue<u32>) -> OptionalArgs { let _ = \"OptionalArg\"; }\n",
+        );
+        assert_eq!(
+            migrate(
+                "use a::{b::OptionalArg as Opt, OptionalArg};\nfn f(x: Opt<u32>) {}\n",
+                migration
+            ),
+            "use a::{b::OptionalValue as Opt, OptionalValue};\nfn f(x: Opt<u32>) {}\n",
+        );
+        assert_eq!(
+            migrate(
+                "fn f() { let v = vec![OptionalArg::Some(1)]; require!(x == OptionalArg::None, \"err\"); }",
+                migration
+            ),
+            "fn f() { let v = vec![OptionalValue::Some(1)]; require!(x == OptionalValue::None, \"err\"); }",
+        );
+    }
+
+    #[test]
+    fn test_replace_path() {
+        let migration = SourceMigration::ReplacePath {
+            from: "a::b",
+            to: "x::y::z",
+        };
+        assert_eq!(
+            migrate(
+                "use a::b::c;\nuse a::{b};\nfn f() { a::b::g(); a::bb(); c::a::b(); }\n",
+                migration
+            ),
+            "use x::y::z::c;\nuse a::{b};\nfn f() { x::y::z::g(); a::bb(); c::a::b(); }\n",
+        );
+    }
+
+    #[test]
+    fn test_rename_method() {
+        let migration = SourceMigration::RenameMethod {
+            from: "old",
+            to: "new::<T>",
+        };
+        assert_eq!(
+            migrate("fn f() { x.old(1).old(); old(); }", migration),
+            "fn f() { x.new::<T>(1).new::<T>(); old(); }",
+        );
+    }
+
+    #[test]
+    fn test_replace_method_call() {
+        let migration = SourceMigration::ReplaceMethodCall {
+            from: "get_legacy",
+            to: "get",
+            append: ".into_legacy()",
+        };
+        assert_eq!(
+            migrate(
+                "fn f() { let x = a.get_legacy(b.get_legacy()).len(); }",
+                migration
+            ),
+            "fn f() { let x = a.get(b.get().into_legacy()).into_legacy().len(); }",
+        );
+    }
+
+    #[test]
+    fn test_rename_method_with_tuple_arg() {
+        let migration = SourceMigration::RenameMethodWithTupleArg {
+            from: "add",
+            to: "with",
+        };
+        assert_eq!(
+            migrate("fn f() { x.add(a, 0, b).add(p); }", migration),
+            "fn f() { x.with((a, 0, b)).with(p); }",
+        );
+    }
+
+    #[test]
+    fn test_map_generic_args() {
+        let migration = SourceMigration::MapGenericArgs {
+            type_name: "Pair",
+            args: &[
+                GenericArgSource::Original(1),
+                GenericArgSource::Literal("Self::Api"),
+                GenericArgSource::Original(0),
+            ],
+        };
+        assert_eq!(
+            migrate(
+                "fn f(p: Pair<u8, Vec<u16>>) -> Pair<u8> { Pair::<u8, u16>::new() }",
+                migration
+            ),
+            "fn f(p: Pair<Vec<u16>, Self::Api, u8>) -> Pair<u8> { Pair::<u16, Self::Api, u8>::new() }",
+        );
+
+        let remove_all = SourceMigration::MapGenericArgs {
+            type_name: "Pair",
+            args: &[],
+        };
+        assert_eq!(
+            migrate("fn f(p: Pair<u8>) { Pair::<u8>::new() }", remove_all),
+            "fn f(p: Pair) { Pair::new() }",
+        );
+
+        let replace_first = SourceMigration::MapGenericArgs {
+            type_name: "Pair",
+            args: &[
+                GenericArgSource::Replaced {
+                    index: 0,
+                    from: "u8",
+                    to: "u16",
+                },
+                GenericArgSource::Original(1),
+            ],
+        };
+        assert_eq!(
+            migrate(
+                "fn f(p: Pair<u8, A>, q: Pair<u32, B>) -> Pair<u8> {}",
+                replace_first
+            ),
+            "fn f(p: Pair<u16, A>, q: Pair<u32, B>) -> Pair<u8> {}",
+        );
+    }
+
+    #[test]
+    fn test_remove_attribute() {
+        let migration = SourceMigration::RemoveAttribute { name: "var_args" };
+        assert_eq!(
+            migrate(
+                "trait T {\n    #[endpoint]\n    fn f(&self, #[var_args] args: A);\n    #[var_args]\n    fn g(&self);\n}\n",
+                migration
+            ),
+            "trait T {\n    #[endpoint]\n    fn f(&self, args: A);\n    fn g(&self);\n}\n",
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_columns() {
+        let migration = SourceMigration::RenamePathSegment { from: "A", to: "B" };
+        assert_eq!(
+            migrate("fn f() { let _ = \"ăîș\"; A::g(); }", migration),
+            "fn f() { let _ = \"ăîș\"; B::g(); }",
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(
+            migrate_source("fn f( {", &[SourceMigration::RemoveAttribute { name: "x" }]).is_err()
+        );
+    }
+}
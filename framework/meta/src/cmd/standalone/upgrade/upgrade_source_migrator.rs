@@ -0,0 +1,260 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::{folder_structure::CARGO_TOML_FILE_NAME, CargoTomlContents};
+
+use super::{
+    upgrade_common::version_bump_in_cargo_toml_contents,
+    upgrade_print::*,
+    upgrade_source_migration::{migrate_source, SourceMigration},
+};
+
+const RUST_FILE_EXTENSION: &str = "rs";
+const TARGET_DIR_NAME: &str = "target";
+
+struct MigratedFile {
+    original: String,
+    current: String,
+}
+
+/// Applies source migrations to all Rust files in a directory tree,
+/// and bumps the framework versions in the `Cargo.toml` files.
+///
+/// In dry-run mode nothing gets written to disk, the migrated files are kept in memory instead,
+/// so that the following upgrade steps build on them. The accumulated changes can be printed
+/// as a diff at the end.
+///
+/// Files that cannot be parsed are never partially migrated. Outside dry-run mode the upgrade
+/// stops at the first such file, so it can be fixed before trying again.
+pub struct SourceMigrator {
+    dry_run: bool,
+    files: BTreeMap<PathBuf, MigratedFile>,
+    failed_files: BTreeSet<PathBuf>,
+
+    /// Directories can be nested, so the same file can be reached more than once in an upgrade step.
+    migrated_in_step: BTreeSet<PathBuf>,
+}
+
+impl SourceMigrator {
+    pub fn new(dry_run: bool) -> Self {
+        SourceMigrator {
+            dry_run,
+            files: BTreeMap::new(),
+            failed_files: BTreeSet::new(),
+            migrated_in_step: BTreeSet::new(),
+        }
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    pub fn start_step(&mut self) {
+        self.migrated_in_step.clear();
+    }
+
+    pub fn migrate_dir(&mut self, path: &Path, migrations: &[SourceMigration]) {
+        if migrations.is_empty() {
+            return;
+        }
+
+        for file_path in find_files(path, &is_rust_file) {
+            if self.migrated_in_step.insert(file_path.clone()) {
+                self.migrate_file(file_path, migrations);
+            }
+        }
+    }
+
+    fn migrate_file(&mut self, file_path: PathBuf, migrations: &[SourceMigration]) {
+        let current = self.current_contents(&file_path);
+        match migrate_source(&current, migrations) {
+            Ok(migrated) => self.update_file(file_path, current, migrated),
+            Err(err) => {
+                // in dry-run mode, the same file is reached in every upgrade step
+                if self.failed_files.insert(file_path.clone()) {
+                    print_source_migration_failed(&file_path, &err);
+                }
+                if !self.dry_run {
+                    print_source_migration_abort();
+                    process::exit(1);
+                }
+            },
+        }
+    }
+
+    /// Only replaces versions of framework crates, see `version_bump_in_cargo_toml_contents`.
+    pub fn version_bump_in_cargo_toml(
+        &mut self,
+        path: &Path,
+        from_version: &str,
+        to_version: &str,
+    ) {
+        for file_path in find_files(path, &is_cargo_toml_file) {
+            if !self.migrated_in_step.insert(file_path.clone()) {
+                continue;
+            }
+
+            let current = self.current_contents(&file_path);
+            let toml_value = current
+                .parse::<toml::Value>()
+                .expect("failed to parse Cargo.toml toml format");
+            let mut cargo_toml_contents = CargoTomlContents {
+                path: file_path.clone(),
+                toml_value: toml_value.clone(),
+            };
+            version_bump_in_cargo_toml_contents(&mut cargo_toml_contents, from_version, to_version);
+            if cargo_toml_contents.toml_value != toml_value {
+                self.update_file(
+                    file_path,
+                    current,
+                    cargo_toml_contents.toml_value.to_string(),
+                );
+            }
+        }
+    }
+
+    /// Outside dry-run mode other upgrade steps also change files, so the disk is the reference.
+    fn current_contents(&self, file_path: &Path) -> String {
+        if self.dry_run {
+            if let Some(migrated_file) = self.files.get(file_path) {
+                return migrated_file.current.clone();
+            }
+        }
+        fs::read_to_string(file_path).expect("failed to read file")
+    }
+
+    fn update_file(&mut self, file_path: PathBuf, current: String, migrated: String) {
+        if migrated == current {
+            return;
+        }
+
+        if !self.dry_run {
+            print_source_migrated(&file_path);
+            fs::write(&file_path, &migrated).expect("failed to write file");
+        }
+        self.files
+            .entry(file_path)
+            .or_insert_with(|| MigratedFile {
+                original: current,
+                current: String::new(),
+            })
+            .current = migrated;
+    }
+
+    /// Prints the difference between the original and the migrated version of each changed file.
+    pub fn print_diffs(&self) {
+        for (path, migrated_file) in &self.files {
+            if migrated_file.original != migrated_file.current {
+                print_source_diff(path, &migrated_file.original, &migrated_file.current);
+            }
+        }
+    }
+
+    pub fn num_changed_files(&self) -> usize {
+        self.files
+            .values()
+            .filter(|migrated_file| migrated_file.original != migrated_file.current)
+            .count()
+    }
+
+    /// Files that could not be parsed, and need to be migrated by hand.
+    pub fn failed_files(&self) -> &BTreeSet<PathBuf> {
+        &self.failed_files
+    }
+}
+
+fn is_rust_file(path: &Path) -> bool {
+    path.extension()
+        .map_or(false, |ext| ext == RUST_FILE_EXTENSION)
+}
+
+fn is_cargo_toml_file(path: &Path) -> bool {
+    path.file_name()
+        .map_or(false, |file_name| file_name == CARGO_TOML_FILE_NAME)
+}
+
+fn find_files(path: &Path, filter: &dyn Fn(&Path) -> bool) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    collect_files(path, filter, &mut result);
+    result.sort();
+    result
+}
+
+fn collect_files(path: &Path, filter: &dyn Fn(&Path) -> bool, result: &mut Vec<PathBuf>) {
+    let read_dir = match fs::read_dir(path) {
+        Ok(read_dir) => read_dir,
+        Err(_) => return,
+    };
+    for entry in read_dir.flatten() {
+        let entry_path = entry.path();
+        let file_name = entry.file_name();
+        let file_name_str = file_name.to_string_lossy();
+        if entry_path.is_dir() {
+            if !file_name_str.starts_with('.') && file_name_str != TARGET_DIR_NAME {
+                collect_files(&entry_path, filter, result);
+            }
+        } else if filter(&entry_path) {
+            result.push(entry_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIGRATIONS: &[SourceMigration] = &[SourceMigration::RenamePathSegment {
+        from: "OptionalArg",
+        to: "OptionalValue",
+    }];
+
+    #[test]
+    fn test_dry_run_keeps_files_unchanged() {
+        let dir = std::env::temp_dir().join(format!(
+            "sc-meta-source-migrator-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        let source = "fn f(_x: OptionalArg<u32>) {}\n";
+        fs::write(dir.join("src/lib.rs"), source).unwrap();
+        fs::write(dir.join("target/ignored.rs"), source).unwrap();
+        fs::write(dir.join("src/broken.rs"), "fn f( {\n").unwrap();
+        let cargo_toml =
+            "[package]\nname = \"adder\"\n\n[dependencies.multiversx-sc]\nversion = \"0.41.0\"\n";
+        fs::write(dir.join("Cargo.toml"), cargo_toml).unwrap();
+
+        let mut migrator = SourceMigrator::new(true);
+        migrator.start_step();
+        migrator.migrate_dir(&dir, MIGRATIONS);
+        // nested directories are only migrated once per step
+        migrator.migrate_dir(&dir.join("src"), MIGRATIONS);
+        migrator.version_bump_in_cargo_toml(&dir, "0.41.0", "0.41.1");
+        migrator.start_step();
+        migrator.version_bump_in_cargo_toml(&dir, "0.41.1", "0.41.2");
+
+        assert_eq!(migrator.num_changed_files(), 2);
+        let migrated_file = &migrator.files[&dir.join("src/lib.rs")];
+        assert_eq!(migrated_file.current, "fn f(_x: OptionalValue<u32>) {}\n");
+        assert_eq!(fs::read_to_string(dir.join("src/lib.rs")).unwrap(), source);
+
+        // the version bumps build on each other
+        let migrated_cargo_toml = &migrator.files[&dir.join("Cargo.toml")];
+        assert!(migrated_cargo_toml.current.contains("version = \"0.41.2\""));
+        assert_eq!(
+            fs::read_to_string(dir.join("Cargo.toml")).unwrap(),
+            cargo_toml
+        );
+
+        assert_eq!(
+            migrator.failed_files().iter().collect::<Vec<_>>(),
+            vec![&dir.join("src/broken.rs")]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
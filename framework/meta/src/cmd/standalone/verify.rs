@@ -0,0 +1,84 @@
+mod verify_build_info_diff;
+mod verify_code_hash;
+mod verify_print;
+mod verify_rebuild;
+mod verify_toolchain;
+
+use std::{path::PathBuf, process};
+
+use crate::{cli_args::VerifyArgs, mxsc_file_json::load_mxsc_file_json};
+
+use verify_build_info_diff::diff_build_info;
+use verify_code_hash::{code_hash, code_hash_of_mxsc, parse_expected_code_hash};
+use verify_print::*;
+use verify_rebuild::rebuild_contract;
+use verify_toolchain::toolchain_from_build_info;
+
+/// Rebuilds the contract in a pinned environment and compares the result to the expected code.
+pub fn verify(args: &VerifyArgs) {
+    let mxsc_path = PathBuf::from(&args.mxsc);
+    let original = load_mxsc_file_json(&mxsc_path);
+    let recorded_hash = code_hash_of_mxsc(&original);
+    let expected_hash = if let Some(code_hash_arg) = &args.code_hash {
+        parse_expected_code_hash(code_hash_arg)
+    } else {
+        recorded_hash
+    };
+
+    let mut ok = true;
+    if recorded_hash != expected_hash {
+        print_recorded_code_mismatch(&recorded_hash, &expected_hash);
+        ok = false;
+    }
+
+    let contract_path = if let Some(path) = &args.path {
+        PathBuf::from(path)
+    } else {
+        contract_path_from_mxsc_path(&mxsc_path)
+    };
+    let toolchain = if let Some(toolchain) = &args.toolchain {
+        toolchain.clone()
+    } else {
+        toolchain_from_build_info(&original.build_info.rustc)
+    };
+
+    let rebuilt = rebuild_contract(
+        &contract_path,
+        &mxsc_path,
+        original.build_info.build_options.as_ref(),
+        &toolchain,
+    );
+
+    let build_info_diffs = diff_build_info(&original.build_info, &rebuilt.build_info);
+    if !build_info_diffs.is_empty() {
+        print_build_info_diffs(&build_info_diffs);
+    }
+
+    let rebuilt_hash =
+        code_hash(&hex::decode(&rebuilt.code).expect("invalid code in rebuilt mxsc file"));
+    print_code_hashes(&expected_hash, &rebuilt_hash, original.size, rebuilt.size);
+    if rebuilt_hash != expected_hash {
+        ok = false;
+    }
+
+    if ok {
+        print_verify_ok();
+    } else {
+        print_verify_fail();
+        process::exit(1);
+    }
+}
+
+/// Contracts are packed in `<contract crate>/output/<name>.mxsc.json`.
+fn contract_path_from_mxsc_path(mxsc_path: &std::path::Path) -> PathBuf {
+    let output_dir = mxsc_path
+        .canonicalize()
+        .expect("could not resolve mxsc file path")
+        .parent()
+        .expect("mxsc file has no parent directory")
+        .to_path_buf();
+    output_dir
+        .parent()
+        .expect("mxsc file is not in a contract output directory")
+        .to_path_buf()
+}
@@ -0,0 +1,152 @@
+use serde_json::Value;
+
+use crate::abi_json::BuildInfoAbiJson;
+
+/// A build info field that differs between the original and the rebuilt contract.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BuildInfoDiff {
+    pub field: String,
+    pub original: String,
+    pub rebuilt: String,
+}
+
+/// Lists all differing fields, by their path in the JSON representation, e.g. `rustc.commitHash`.
+pub fn diff_build_info(
+    original: &BuildInfoAbiJson,
+    rebuilt: &BuildInfoAbiJson,
+) -> Vec<BuildInfoDiff> {
+    let original = serde_json::to_value(original).unwrap();
+    let rebuilt = serde_json::to_value(rebuilt).unwrap();
+    let mut diffs = Vec::new();
+    diff_values("", &original, &rebuilt, &mut diffs);
+    diffs
+}
+
+fn diff_values(path: &str, original: &Value, rebuilt: &Value, diffs: &mut Vec<BuildInfoDiff>) {
+    if let (Value::Object(original_map), Value::Object(rebuilt_map)) = (original, rebuilt) {
+        let mut keys: Vec<&String> = original_map.keys().collect();
+        for key in rebuilt_map.keys() {
+            if !original_map.contains_key(key) {
+                keys.push(key);
+            }
+        }
+        for key in keys {
+            let field_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{path}.{key}")
+            };
+            diff_values(
+                &field_path,
+                original_map.get(key).unwrap_or(&Value::Null),
+                rebuilt_map.get(key).unwrap_or(&Value::Null),
+                diffs,
+            );
+        }
+    } else if original != rebuilt {
+        diffs.push(BuildInfoDiff {
+            field: path.to_string(),
+            original: display_value(original),
+            rebuilt: display_value(rebuilt),
+        });
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "<missing>".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{abi_json::BuildOptionsAbiJson, cli_args::BuildArgs};
+
+    fn build_info(
+        commit_hash: &str,
+        contract_version: &str,
+        git_version: &str,
+    ) -> BuildInfoAbiJson {
+        serde_json::from_value(serde_json::json!({
+            "rustc": {
+                "version": "1.74.0-nightly",
+                "commitHash": commit_hash,
+                "commitDate": "2023-09-09",
+                "channel": "Nightly",
+                "short": "rustc 1.74.0-nightly",
+            },
+            "contractCrate": {
+                "name": "adder",
+                "version": contract_version,
+                "gitVersion": git_version,
+            },
+            "framework": {
+                "name": "multiversx-sc",
+                "version": "0.43.4",
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_diff_build_info_equal() {
+        let original = build_info("abc", "0.0.1", "v1");
+        let rebuilt = build_info("abc", "0.0.1", "v1");
+        assert!(diff_build_info(&original, &rebuilt).is_empty());
+    }
+
+    #[test]
+    fn test_diff_build_info() {
+        let original = build_info("abc", "0.0.1", "v1");
+        let rebuilt = build_info("def", "0.0.1", "");
+        assert_eq!(
+            diff_build_info(&original, &rebuilt),
+            vec![
+                BuildInfoDiff {
+                    field: "contractCrate.gitVersion".to_string(),
+                    original: "v1".to_string(),
+                    rebuilt: "<missing>".to_string(),
+                },
+                BuildInfoDiff {
+                    field: "rustc.commitHash".to_string(),
+                    original: "abc".to_string(),
+                    rebuilt: "def".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_build_info_options() {
+        let mut original = build_info("abc", "0.0.1", "v1");
+        original.build_options = Some(BuildOptionsAbiJson::from(&BuildArgs {
+            remap_paths: true,
+            ..Default::default()
+        }));
+        let mut rebuilt = build_info("abc", "0.0.1", "v1");
+        rebuilt.build_options = Some(BuildOptionsAbiJson::from(&BuildArgs::default()));
+        assert_eq!(
+            diff_build_info(&original, &rebuilt),
+            vec![BuildInfoDiff {
+                field: "buildOptions.remapPaths".to_string(),
+                original: "true".to_string(),
+                rebuilt: "false".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_build_options_replay_wasm_name() {
+        let build_options = BuildOptionsAbiJson::from(&BuildArgs {
+            wasm_name_suffix: Some("dbg".to_string()),
+            ..Default::default()
+        });
+        let replayed = build_options.to_build_args();
+        assert_eq!(replayed.wasm_name_suffix.as_deref(), Some("dbg"));
+        assert_eq!(replayed.wasm_name_override, None);
+        assert_eq!(BuildOptionsAbiJson::from(&replayed), build_options);
+    }
+}
@@ -0,0 +1,71 @@
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use std::{fs, path::Path};
+
+use crate::mxsc_file_json::{load_mxsc_file_json, MxscFileJson};
+
+pub type CodeHash = [u8; 32];
+
+const MXSC_FILE_SUFFIX: &str = ".mxsc.json";
+
+/// The code hash, as computed by the protocol: blake2b with a 256-bit digest.
+pub fn code_hash(code: &[u8]) -> CodeHash {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(code);
+    hasher.finalize().into()
+}
+
+pub fn code_hash_of_mxsc(mxsc: &MxscFileJson) -> CodeHash {
+    let code = hex::decode(&mxsc.code).expect("invalid code in mxsc file");
+    code_hash(&code)
+}
+
+/// Accepts a hex or base64 encoded hash, or a path to a `.wasm` or `.mxsc.json` file.
+pub fn parse_expected_code_hash(arg: &str) -> CodeHash {
+    let path = Path::new(arg);
+    if path.is_file() {
+        if arg.ends_with(MXSC_FILE_SUFFIX) {
+            return code_hash_of_mxsc(&load_mxsc_file_json(path));
+        }
+        let code = fs::read(path).unwrap_or_else(|_| panic!("failed to read code file {arg}"));
+        return code_hash(&code);
+    }
+
+    decode_code_hash(arg).unwrap_or_else(|| {
+        panic!("invalid code hash '{arg}': expected 32 bytes hex or base64, or an existing file")
+    })
+}
+
+fn decode_code_hash(s: &str) -> Option<CodeHash> {
+    let s = s.trim();
+    let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))
+        .ok()
+        .or_else(|| base64::decode(s).ok())?;
+    bytes.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_hash() {
+        // blake2b-256 of the empty string
+        assert_eq!(
+            hex::encode(code_hash(b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+
+    #[test]
+    fn test_decode_code_hash() {
+        let hash = code_hash(b"code");
+        assert_eq!(decode_code_hash(&hex::encode(hash)), Some(hash));
+        assert_eq!(
+            decode_code_hash(&format!("0x{}", hex::encode(hash))),
+            Some(hash)
+        );
+        assert_eq!(decode_code_hash(&base64::encode(hash)), Some(hash));
+        assert_eq!(decode_code_hash("abcd"), None);
+        assert_eq!(decode_code_hash("not a hash"), None);
+    }
+}
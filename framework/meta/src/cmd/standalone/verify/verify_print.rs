@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use colored::Colorize;
+
+use super::{verify_build_info_diff::BuildInfoDiff, verify_code_hash::CodeHash};
+
+pub fn print_recorded_code_mismatch(recorded_hash: &CodeHash, expected_hash: &CodeHash) {
+    println!(
+        "{}",
+        format!(
+            "The code in the mxsc file (hash {}) does not match the expected code hash {}.",
+            hex::encode(recorded_hash),
+            hex::encode(expected_hash)
+        )
+        .red()
+    );
+}
+
+pub fn print_rebuild_fail(toolchain: &str) {
+    println!(
+        "{}",
+        format!("Rebuilding the contract with toolchain {toolchain} failed.").red()
+    );
+}
+
+pub fn print_missing_lock_file(project_root: &Path) {
+    println!(
+        "{}",
+        format!(
+            "No Cargo.lock found in {}, the dependency versions of the original build cannot be reproduced.",
+            project_root.display()
+        )
+        .red()
+    );
+}
+
+pub fn print_build_info_diffs(diffs: &[BuildInfoDiff]) {
+    println!("{}", "Build info mismatch:".yellow());
+    for diff in diffs {
+        println!(
+            "{}",
+            format!(
+                "    {}: original \"{}\", rebuilt \"{}\"",
+                diff.field, diff.original, diff.rebuilt
+            )
+            .yellow()
+        );
+    }
+}
+
+pub fn print_code_hashes(
+    expected_hash: &CodeHash,
+    rebuilt_hash: &CodeHash,
+    original_size: usize,
+    rebuilt_size: usize,
+) {
+    println!(
+        "{}",
+        format!(
+            "Expected code hash: {} ({original_size} bytes in mxsc file)",
+            hex::encode(expected_hash)
+        )
+        .blue()
+    );
+    println!(
+        "{}",
+        format!(
+            "Rebuilt code hash:  {} ({rebuilt_size} bytes)",
+            hex::encode(rebuilt_hash)
+        )
+        .blue()
+    );
+}
+
+pub fn print_verify_ok() {
+    println!(
+        "{}",
+        "Verification OK, the contract matches the source.".green()
+    );
+}
+
+pub fn print_verify_fail() {
+    println!("{}", "Verification FAILED.".red());
+}
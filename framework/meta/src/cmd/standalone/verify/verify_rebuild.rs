@@ -0,0 +1,135 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use crate::{
+    abi_json::BuildOptionsAbiJson,
+    cli_args::{BuildArgs, CliArgsToRaw},
+    mxsc_file_json::{load_mxsc_file_json, MxscFileJson},
+    print_util::print_all_command,
+    tools::find_workspace_root,
+};
+
+use super::verify_print::{print_missing_lock_file, print_rebuild_fail};
+
+/// Build artifacts are never copied into the rebuild project.
+const SKIPPED_DIR_NAMES: &[&str] = &["target"];
+
+/// Builds the contract from scratch, with the given toolchain.
+///
+/// The build runs in a temporary copy of the project, with fresh target directories,
+/// so nothing is written next to the original sources.
+/// The build flags are taken from the original mxsc file, when it records them.
+/// Compiler flags from the environment are ignored.
+///
+/// The build is always `--locked`, so the project needs a `Cargo.lock`,
+/// otherwise the dependency versions could differ from the original build.
+pub fn rebuild_contract(
+    contract_path: &Path,
+    mxsc_path: &Path,
+    build_options: Option<&BuildOptionsAbiJson>,
+    toolchain: &str,
+) -> MxscFileJson {
+    let contract_path = contract_path
+        .canonicalize()
+        .expect("could not resolve contract path");
+    assert!(
+        contract_path.join("meta").exists(),
+        "Contract meta crate not found at {}",
+        contract_path.join("meta").display()
+    );
+
+    let project_root = project_root(&contract_path);
+    if !project_root.join("Cargo.lock").exists() {
+        print_missing_lock_file(&project_root);
+        process::exit(1);
+    }
+    let contract_relative_path = contract_path
+        .strip_prefix(&project_root)
+        .expect("contract is not inside its project")
+        .to_path_buf();
+
+    let temp_dir = tempfile::tempdir().expect("failed to create temporary directory");
+    let project_copy = temp_dir.path().join("project");
+    copy_project(&project_root, &project_copy);
+    let contract_copy = project_copy.join(&contract_relative_path);
+    let output_copy = contract_copy.join("output");
+    if output_copy.exists() {
+        fs::remove_dir_all(&output_copy).expect("failed to clear copied output directory");
+    }
+
+    let target_dir_meta = temp_dir.path().join("target-meta");
+    let target_dir_wasm = temp_dir.path().join("target-wasm");
+    let build_args = BuildArgs {
+        locked: true,
+        target_dir_wasm: Some(target_dir_wasm.to_string_lossy().to_string()),
+        ..build_options
+            .map(BuildOptionsAbiJson::to_build_args)
+            .unwrap_or_default()
+    };
+
+    let mut cargo_run_args = vec![
+        format!("+{toolchain}"),
+        "run".to_string(),
+        "--locked".to_string(),
+        "--target-dir".to_string(),
+        target_dir_meta.to_string_lossy().to_string(),
+        "build".to_string(),
+    ];
+    cargo_run_args.append(&mut build_args.to_raw());
+
+    let meta_path = contract_copy.join("meta");
+    print_all_command(&meta_path, &cargo_run_args);
+
+    let exit_status = Command::new("cargo")
+        .current_dir(&meta_path)
+        .args(&cargo_run_args)
+        .env_remove("RUSTFLAGS")
+        .env_remove("CARGO_ENCODED_RUSTFLAGS")
+        .env_remove("CARGO_TARGET_DIR")
+        .spawn()
+        .expect("failed to spawn cargo run process in meta crate")
+        .wait()
+        .expect("cargo run process in meta crate was not running");
+    if !exit_status.success() {
+        print_rebuild_fail(toolchain);
+        process::exit(1);
+    }
+
+    let file_name = mxsc_path.file_name().expect("invalid mxsc file path");
+    load_mxsc_file_json(output_copy.join(file_name))
+}
+
+/// The closest workspace containing the contract, since its crates and lock file are needed for the build.
+///
+/// Standalone contracts are their own project.
+fn project_root(contract_path: &Path) -> PathBuf {
+    find_workspace_root(contract_path).unwrap_or_else(|| contract_path.to_path_buf())
+}
+
+fn copy_project(source: &Path, target: &Path) {
+    fs::create_dir_all(target).expect("failed to create project copy directory");
+    let entries = fs::read_dir(source)
+        .unwrap_or_else(|err| panic!("failed to read dir {}: {err:?}", source.display()));
+    for entry in entries {
+        let entry = entry.expect("failed to read dir entry");
+        let source_entry = entry.path();
+        let target_entry = target.join(entry.file_name());
+        let file_type = entry.file_type().expect("failed to read file type");
+        if file_type.is_dir() {
+            if !SKIPPED_DIR_NAMES.contains(&entry.file_name().to_string_lossy().as_ref()) {
+                copy_project(&source_entry, &target_entry);
+            }
+        } else {
+            fs::copy(&source_entry, &target_entry).unwrap_or_else(|err| {
+                panic!(
+                    "failed to copy {} to {}: {err:?}",
+                    source_entry.display(),
+                    target_entry.display()
+                )
+            });
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::abi_json::RustcAbiJson;
+
+const CHANNEL_STABLE: &str = "Stable";
+const CHANNEL_BETA: &str = "Beta";
+const CHANNEL_NIGHTLY: &str = "Nightly";
+
+/// The rustup toolchain that produced the recorded compiler version.
+///
+/// Nightly and beta toolchains are published the day after their commit date.
+pub fn toolchain_from_build_info(rustc: &RustcAbiJson) -> String {
+    match rustc.channel.as_str() {
+        CHANNEL_STABLE => rustc.version.clone(),
+        CHANNEL_BETA => format!("beta-{}", release_date(rustc)),
+        CHANNEL_NIGHTLY => format!("nightly-{}", release_date(rustc)),
+        other => panic!(
+            "cannot determine toolchain for rustc channel '{other}', please specify it explicitly with --toolchain"
+        ),
+    }
+}
+
+fn release_date(rustc: &RustcAbiJson) -> String {
+    next_day(&rustc.commit_date).unwrap_or_else(|| {
+        panic!(
+            "invalid rustc commit date '{}', please specify the toolchain explicitly with --toolchain",
+            rustc.commit_date
+        )
+    })
+}
+
+/// Adds one day to a `YYYY-MM-DD` date.
+fn next_day(date: &str) -> Option<String> {
+    let mut parts = date.split('-').map(|part| part.parse::<u32>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some() || !(1..=12).contains(&month) {
+        return None;
+    }
+    let days_in_month = match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if day == 0 || day > days_in_month {
+        return None;
+    }
+
+    let (year, month, day) = if day < days_in_month {
+        (year, month, day + 1)
+    } else if month < 12 {
+        (year, month + 1, 1)
+    } else {
+        (year + 1, 1, 1)
+    };
+    Some(format!("{year:04}-{month:02}-{day:02}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rustc(channel: &str, version: &str, commit_date: &str) -> RustcAbiJson {
+        RustcAbiJson {
+            version: version.to_string(),
+            commit_hash: String::new(),
+            commit_date: commit_date.to_string(),
+            channel: channel.to_string(),
+            short: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_next_day() {
+        assert_eq!(next_day("2023-09-09").as_deref(), Some("2023-09-10"));
+        assert_eq!(next_day("2023-09-30").as_deref(), Some("2023-10-01"));
+        assert_eq!(next_day("2023-12-31").as_deref(), Some("2024-01-01"));
+        assert_eq!(next_day("2024-02-28").as_deref(), Some("2024-02-29"));
+        assert_eq!(next_day("2023-02-28").as_deref(), Some("2023-03-01"));
+        assert_eq!(next_day("2023-02-30"), None);
+        assert_eq!(next_day("2023-13-01"), None);
+        assert_eq!(next_day("yesterday"), None);
+    }
+
+    #[test]
+    fn test_toolchain_from_build_info() {
+        assert_eq!(
+            toolchain_from_build_info(&rustc("Stable", "1.71.0", "2023-07-12")),
+            "1.71.0"
+        );
+        assert_eq!(
+            toolchain_from_build_info(&rustc("Nightly", "1.74.0-nightly", "2023-09-09")),
+            "nightly-2023-09-10"
+        );
+    }
+}
@@ -6,6 +6,7 @@ pub mod ei;
 mod folder_structure;
 mod mxsc_file_json;
 mod print_util;
+mod size_report_json;
 pub mod template;
 mod tools;
 pub mod version_history;
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use std::{fs::File, io::Write, path::Path};
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
 
 use crate::abi_json::{BuildInfoAbiJson, ContractAbiJson};
 
@@ -27,3 +31,11 @@ pub fn save_mxsc_file_json(mxsc_file_json: &MxscFileJson, path: impl AsRef<Path>
     let mut mxsc_file = File::create(path).unwrap();
     write!(mxsc_file, "{mxsc_file_string}").unwrap();
 }
+
+pub fn load_mxsc_file_json(path: impl AsRef<Path>) -> MxscFileJson {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|_| panic!("failed to read mxsc file {}", path.display()));
+    serde_json::from_str(&contents)
+        .unwrap_or_else(|err| panic!("failed to parse mxsc file {}: {err}", path.display()))
+}
@@ -113,3 +113,40 @@ pub fn print_check_ei_ok() {
 pub fn print_ignore_ei_check() {
     println!("{}", "EI version check explicitly ignored".yellow(),);
 }
+
+pub fn print_write_size_report(size_report_path: &str) {
+    println!(
+        "{}",
+        format!("Writing size report to {size_report_path} ...").green(),
+    );
+}
+
+pub fn print_check_size_budget(max_size: usize) {
+    print!(
+        "{}",
+        format!("Checking contract size against budget of {max_size} bytes ...").green(),
+    );
+}
+
+pub fn print_check_size_budget_ok() {
+    println!("{}", " OK".green(),);
+}
+
+pub fn print_size_budget_exceeded(
+    size: usize,
+    max_size: usize,
+    largest_functions: &[(String, usize)],
+) {
+    println!(
+        "\n{}",
+        format!(
+            "Contract size of {size} bytes exceeds budget of {max_size} bytes, by {} bytes.",
+            size - max_size
+        )
+        .red(),
+    );
+    println!("{}", "Largest functions:".red());
+    for (name, function_size) in largest_functions {
+        println!("{}", format!("{function_size:>10}  {name}").red());
+    }
+}
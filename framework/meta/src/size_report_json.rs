@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+use crate::tools::wasm_size::WasmSizeInfo;
+
+/// Machine-readable size report, written next to the `*.mxsc.json` file.
+///
+/// Can also be committed and used as a baseline for the `max-size-growth` check.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeReportJson {
+    pub contract_name: String,
+    pub size: usize,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<usize>,
+    #[serde(default)]
+    pub sections: Vec<SectionSizeJson>,
+    #[serde(default)]
+    pub functions: Vec<FunctionSizeJson>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SectionSizeJson {
+    pub name: String,
+    pub size: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSizeJson {
+    pub index: usize,
+    pub name: String,
+    pub size: usize,
+}
+
+impl SizeReportJson {
+    pub fn new(contract_name: String, max_size: Option<usize>, size_info: &WasmSizeInfo) -> Self {
+        SizeReportJson {
+            contract_name,
+            size: size_info.total_size,
+            max_size,
+            sections: size_info
+                .sections
+                .iter()
+                .map(|section| SectionSizeJson {
+                    name: section.name.clone(),
+                    size: section.size,
+                })
+                .collect(),
+            functions: size_info
+                .functions
+                .iter()
+                .map(|function| FunctionSizeJson {
+                    index: function.index,
+                    name: function.name.clone(),
+                    size: function.size,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn save_size_report_json(size_report: &SizeReportJson, path: impl AsRef<Path>) {
+    let mut serialized = serde_json::to_string_pretty(size_report).unwrap();
+    serialized.push('\n');
+    fs::write(path, serialized).expect("failed to write size report file");
+}
+
+pub fn load_size_report_json(path: impl AsRef<Path>) -> Option<SizeReportJson> {
+    let contents = fs::read_to_string(path).ok()?;
+    Some(serde_json::from_str(&contents).expect("failed to parse size report file"))
+}
@@ -1,17 +1,24 @@
 mod contract_creator;
 mod contract_creator_target;
 mod repo_source;
+mod repo_temp_archive;
 mod repo_temp_download;
 mod repo_version;
 mod template_adjuster;
 mod template_list;
 mod template_metadata;
+mod template_registry;
+mod template_repos;
 mod template_source;
 
 pub use contract_creator::{create_contract, ContractCreator};
 pub use contract_creator_target::ContractCreatorTarget;
 pub use repo_source::RepoSource;
+pub use repo_temp_archive::RepoTempArchive;
 pub use repo_temp_download::RepoTempDownload;
 pub use repo_version::RepoVersion;
 pub use template_adjuster::TemplateAdjuster;
 pub use template_list::{print_template_names, template_names_from_repo};
+pub use template_metadata::{PlaceholderCase, TemplateMetadata, TemplatePlaceholder};
+pub use template_registry::{TemplateRegistry, TemplateRegistryEntry, TemplateRegistryLocation};
+pub use template_repos::{TemplateRepo, TemplateRepoOptions, TemplateRepos};
@@ -1,20 +1,27 @@
+use std::collections::BTreeMap;
+
 use crate::{
     cli_args::TemplateArgs,
     version_history::{validate_template_tag, LAST_TEMPLATE_VERSION},
 };
 
 use super::{
+    template_repos::{TemplateRepoOptions, TemplateRepos},
     template_source::{template_sources, TemplateSource},
     ContractCreatorTarget, RepoSource, RepoVersion, TemplateAdjuster,
 };
 
 /// Creates a new contract on disk, from a template, given a name.
 pub async fn create_contract(args: &TemplateArgs) {
-    let version = get_repo_version(&args.tag);
-    let repo_temp_download = RepoSource::download_from_github(version, std::env::temp_dir()).await;
+    let repos = TemplateRepos::load(&TemplateRepoOptions::from(args)).await;
+    let (repo, template_source) = repos
+        .find_template(&args.template)
+        .unwrap_or_else(|| panic!("Unknown template {}", args.template));
     let target = target_from_args(args);
 
-    let creator = ContractCreator::new(&repo_temp_download, args.template.clone(), target, false);
+    let mut creator =
+        ContractCreator::from_template_source(&repo.source, template_source, target, false);
+    creator.adjuster.placeholder_args = placeholder_args(&args.placeholder);
 
     creator.create_contract();
 }
@@ -28,6 +35,18 @@ fn target_from_args(args: &TemplateArgs) -> ContractCreatorTarget {
     }
 }
 
+fn placeholder_args(raw_args: &[String]) -> BTreeMap<String, String> {
+    raw_args
+        .iter()
+        .map(|raw_arg| {
+            let (key, value) = raw_arg.split_once('=').unwrap_or_else(|| {
+                panic!("invalid placeholder argument `{raw_arg}`, expected KEY=VALUE")
+            });
+            (key.to_string(), value.to_string())
+        })
+        .collect()
+}
+
 pub(crate) fn get_repo_version(args_tag: &Option<String>) -> RepoVersion {
     if let Some(tag) = args_tag {
         assert!(validate_template_tag(tag), "invalid template tag");
@@ -58,6 +77,15 @@ impl<'a> ContractCreator<'a> {
             .find(|source| source.metadata.name == template_name)
             .unwrap_or_else(|| panic!("Unknown template {template_name}"));
 
+        Self::from_template_source(repo_source, template_source, target, keep_paths)
+    }
+
+    pub fn from_template_source(
+        repo_source: &'a RepoSource,
+        template_source: TemplateSource<'a>,
+        target: ContractCreatorTarget,
+        keep_paths: bool,
+    ) -> Self {
         let metadata = template_source.metadata.clone();
         ContractCreator {
             repo_source,
@@ -67,6 +95,7 @@ impl<'a> ContractCreator<'a> {
                 metadata,
                 target,
                 keep_paths,
+                placeholder_args: BTreeMap::new(),
             },
         }
     }
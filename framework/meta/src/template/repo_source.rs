@@ -3,11 +3,12 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use super::{RepoTempDownload, RepoVersion};
+use super::{RepoTempArchive, RepoTempDownload, RepoVersion};
 
 pub enum RepoSource {
     Downloaded(RepoTempDownload),
     LocalPath(PathBuf),
+    Archive(RepoTempArchive),
 }
 
 impl RepoSource {
@@ -20,12 +21,26 @@ impl RepoSource {
         RepoSource::LocalPath(repo_local_path.as_ref().to_path_buf())
     }
 
+    /// Extracts a pre-downloaded zip archive of the repository, no network access needed.
+    pub fn from_archive(archive_path: impl AsRef<Path>, temp_dir_path: PathBuf) -> Self {
+        RepoSource::Archive(RepoTempArchive::extract_from_file(
+            archive_path.as_ref(),
+            temp_dir_path,
+        ))
+    }
+
+    /// Downloads a zip archive of the repository from an arbitrary URL.
+    pub async fn download_archive(url: &str, temp_dir_path: PathBuf) -> Self {
+        RepoSource::Archive(RepoTempArchive::download_and_extract(url, temp_dir_path).await)
+    }
+
     pub fn repo_path(&self) -> PathBuf {
         match self {
             RepoSource::Downloaded(repo_temp_download) => {
                 repo_temp_download.repository_temp_dir_path()
             },
             RepoSource::LocalPath(local_path) => local_path.clone(),
+            RepoSource::Archive(repo_temp_archive) => repo_temp_archive.repository_temp_dir_path(),
         }
     }
 }
@@ -0,0 +1,75 @@
+use std::{
+    fs::{self, File},
+    io::{Cursor, ErrorKind, Read, Seek},
+    path::{Path, PathBuf},
+};
+
+/// A repository extracted from a zip archive into a temporary directory.
+///
+/// The archive can be a local file, for environments without internet access,
+/// or downloaded from an arbitrary URL.
+pub struct RepoTempArchive {
+    pub extract_dir_path: PathBuf,
+}
+
+impl RepoTempArchive {
+    pub fn extract_from_file(archive_path: &Path, extract_dir_path: PathBuf) -> Self {
+        let file = File::open(archive_path).unwrap_or_else(|error| {
+            panic!(
+                "could not open template archive {}: {error}",
+                archive_path.display()
+            )
+        });
+        Self::extract(file, extract_dir_path)
+    }
+
+    pub async fn download_and_extract(url: &str, extract_dir_path: PathBuf) -> Self {
+        let response = reqwest::get(url)
+            .await
+            .and_then(|response| response.error_for_status())
+            .unwrap_or_else(|error| panic!("could not download template archive {url}: {error}"))
+            .bytes()
+            .await
+            .unwrap_or_else(|error| panic!("could not download template archive {url}: {error}"));
+        Self::extract(Cursor::new(response), extract_dir_path)
+    }
+
+    fn extract<R: Read + Seek>(reader: R, extract_dir_path: PathBuf) -> Self {
+        let repo_temp_archive = RepoTempArchive { extract_dir_path };
+        repo_temp_archive.delete_temp_folder();
+        fs::create_dir_all(&repo_temp_archive.extract_dir_path).unwrap();
+
+        let mut zip = zip::ZipArchive::new(reader).expect("invalid template archive");
+        zip.extract(&repo_temp_archive.extract_dir_path)
+            .expect("could not unzip template archive");
+        repo_temp_archive
+    }
+
+    /// Archives downloaded from GitHub wrap the repository in a single top-level folder,
+    /// in which case the repository starts there.
+    pub fn repository_temp_dir_path(&self) -> PathBuf {
+        let entries: Vec<PathBuf> = fs::read_dir(&self.extract_dir_path)
+            .expect("error reading extracted template archive")
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        match entries.as_slice() {
+            [single_entry] if single_entry.is_dir() => single_entry.clone(),
+            _ => self.extract_dir_path.clone(),
+        }
+    }
+
+    fn delete_temp_folder(&self) {
+        fs::remove_dir_all(&self.extract_dir_path).unwrap_or_else(|error| {
+            // don't throw error if the temp folder doesn't exist
+            if error.kind() != ErrorKind::NotFound {
+                panic!("{:?}", error);
+            }
+        });
+    }
+}
+
+impl Drop for RepoTempArchive {
+    fn drop(&mut self) {
+        self.delete_temp_folder();
+    }
+}
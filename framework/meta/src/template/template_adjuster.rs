@@ -5,6 +5,7 @@ use crate::{
 };
 use convert_case::{Case, Casing};
 use ruplacer::Query;
+use std::{collections::BTreeMap, fs, path::Path};
 use toml::value::Table;
 
 const TEST_DIRECTORY: &str = "./tests";
@@ -16,6 +17,9 @@ pub struct TemplateAdjuster {
     pub metadata: TemplateMetadata,
     pub target: ContractCreatorTarget,
     pub keep_paths: bool,
+
+    /// Placeholder values given explicitly, they take precedence over the ones in the metadata.
+    pub placeholder_args: BTreeMap<String, String>,
 }
 impl TemplateAdjuster {
     pub fn update_dependencies(&self) {
@@ -60,6 +64,7 @@ impl TemplateAdjuster {
     }
 
     pub fn rename_template_to(&self) {
+        self.substitute_placeholders();
         self.rename_trait_to();
         self.rename_in_cargo_toml_root();
         self.rename_in_cargo_toml_meta();
@@ -68,6 +73,34 @@ impl TemplateAdjuster {
         self.rename_solution_files();
     }
 
+    fn placeholder_values(&self) -> Vec<(String, String)> {
+        self.metadata
+            .placeholders
+            .iter()
+            .map(|placeholder| {
+                let cli_value = self.placeholder_args.get(&placeholder.key);
+                let value =
+                    placeholder.resolve_value(&self.target.new_name, cli_value.map(String::as_str));
+                (placeholder.key.clone(), value)
+            })
+            .collect()
+    }
+
+    fn substitute_placeholders(&self) {
+        let values = self.placeholder_values();
+        if values.is_empty() {
+            return;
+        }
+
+        substitute_in_files(&self.target.contract_dir(), &values);
+
+        let pattern: Vec<(&str, &str)> = values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        rename_files(&self.target.contract_dir(), &pattern);
+    }
+
     fn rename_trait_to(&self) {
         let new_trait = self.target.new_name.to_case(Case::UpperCamel);
         let old_trait = &self.metadata.contract_trait;
@@ -184,6 +217,27 @@ impl TemplateAdjuster {
     }
 }
 
+/// Binary files are left untouched.
+fn substitute_in_files(path: &Path, values: &[(String, String)]) {
+    if path.is_dir() {
+        let read_dir = fs::read_dir(path).expect("error reading directory");
+        for child_result in read_dir {
+            substitute_in_files(&child_result.unwrap().path(), values);
+        }
+        return;
+    }
+
+    if let Ok(contents) = fs::read_to_string(path) {
+        let mut substituted = contents.clone();
+        for (key, value) in values {
+            substituted = substituted.replace(key.as_str(), value);
+        }
+        if substituted != contents {
+            fs::write(path, substituted).expect("failed to write template file");
+        }
+    }
+}
+
 fn wasm_file_name(name: &str) -> String {
     format!("{name}.wasm",)
 }
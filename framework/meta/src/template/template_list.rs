@@ -1,13 +1,21 @@
 use crate::cli_args::TemplateListArgs;
 
-use super::{contract_creator::get_repo_version, template_source::template_sources, RepoSource};
+use super::{
+    template_repos::{TemplateRepoOptions, TemplateRepos},
+    template_source::template_sources,
+    RepoSource,
+};
 
 pub async fn print_template_names(args: &TemplateListArgs) {
-    let version = get_repo_version(&args.tag);
-    let repo_temp_download = RepoSource::download_from_github(version, std::env::temp_dir()).await;
-    let template_names = template_names_from_repo(&repo_temp_download);
-    for template_name in template_names {
-        println!("{template_name}");
+    let repos = TemplateRepos::load(&TemplateRepoOptions::from(args)).await;
+    for repo in &repos.0 {
+        for source in repo.template_sources() {
+            if let Some(repo_name) = &repo.name {
+                println!("{} ({repo_name})", source.metadata.name);
+            } else {
+                println!("{}", source.metadata.name);
+            }
+        }
     }
 }
 
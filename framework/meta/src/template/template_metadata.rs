@@ -1,3 +1,4 @@
+use convert_case::{Case, Casing};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -7,6 +8,66 @@ pub struct TemplateMetadata {
     pub src_file: String,
     pub rename_pairs: Vec<(String, String)>,
     pub files_include: Vec<String>,
+
+    /// Text to substitute in all template files and file names, when creating a contract.
+    #[serde(default)]
+    pub placeholders: Vec<TemplatePlaceholder>,
+}
+
+/// A custom placeholder, for templates that need more than renaming the contract.
+///
+/// The value is taken from the command line, if given there.
+/// Otherwise it is derived from the new contract name, if `case` is specified,
+/// or it falls back to `default`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TemplatePlaceholder {
+    pub key: String,
+
+    #[serde(default)]
+    pub case: Option<PlaceholderCase>,
+
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+/// Format of the new contract name, when used as placeholder value.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PlaceholderCase {
+    Kebab,
+    Snake,
+    UpperCamel,
+    UpperSnake,
+}
+
+impl PlaceholderCase {
+    pub fn apply(self, contract_name: &str) -> String {
+        let case = match self {
+            PlaceholderCase::Kebab => Case::Kebab,
+            PlaceholderCase::Snake => Case::Snake,
+            PlaceholderCase::UpperCamel => Case::UpperCamel,
+            PlaceholderCase::UpperSnake => Case::UpperSnake,
+        };
+        contract_name.to_case(case)
+    }
+}
+
+impl TemplatePlaceholder {
+    /// Signals missing values with a panic, the template cannot be created without them.
+    pub fn resolve_value(&self, contract_name: &str, cli_value: Option<&str>) -> String {
+        if let Some(value) = cli_value {
+            return value.to_string();
+        }
+        if let Some(case) = self.case {
+            return case.apply(contract_name);
+        }
+        self.default.clone().unwrap_or_else(|| {
+            panic!(
+                "missing value for template placeholder `{}`, provide it via --placeholder {}=<value>",
+                self.key, self.key
+            )
+        })
+    }
 }
 
 #[cfg(test)]
@@ -47,5 +108,60 @@ mod tests {
         assert_eq!(parsed.files_include[1], "/src");
         assert_eq!(parsed.files_include[2], "/wasm");
         assert_eq!(parsed.files_include[3], "/Cargo.toml");
+        assert!(parsed.placeholders.is_empty());
+    }
+
+    #[test]
+    fn test_template_placeholders() {
+        let parsed: TemplateMetadata = toml::from_str(
+            r#"
+            name = "my-contract"
+            contract_trait = "MyContract"
+            src_file = "my_contract.rs"
+            rename_pairs = []
+            files_include = ["/src"]
+
+            [[placeholders]]
+            key = "__CONST_NAME__"
+            case = "upper-snake"
+
+            [[placeholders]]
+            key = "{{team}}"
+            default = "core"
+
+            [[placeholders]]
+            key = "{{owner}}"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.placeholders.len(), 3);
+        assert_eq!(
+            parsed.placeholders[0].resolve_value("new-contract", None),
+            "NEW_CONTRACT"
+        );
+        assert_eq!(
+            parsed.placeholders[0].resolve_value("new-contract", Some("X")),
+            "X"
+        );
+        assert_eq!(
+            parsed.placeholders[1].resolve_value("new-contract", None),
+            "core"
+        );
+        assert_eq!(
+            parsed.placeholders[2].resolve_value("new-contract", Some("alice")),
+            "alice"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing value for template placeholder `{{owner}}`")]
+    fn test_template_placeholder_missing_value() {
+        let placeholder = TemplatePlaceholder {
+            key: "{{owner}}".to_string(),
+            case: None,
+            default: None,
+        };
+        placeholder.resolve_value("new-contract", None);
     }
 }
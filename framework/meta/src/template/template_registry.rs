@@ -0,0 +1,170 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A list of additional template repositories, loaded from a TOML file.
+///
+/// Example:
+///
+/// ```toml
+/// [[repository]]
+/// name = "in-house"
+/// path = "../in-house-templates"
+/// templates-path = "contracts"
+///
+/// [[repository]]
+/// name = "mirror"
+/// archive = "/opt/mirror/templates.zip"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateRegistry {
+    #[serde(default, rename = "repository")]
+    pub repositories: Vec<TemplateRegistryEntry>,
+}
+
+/// One template repository. Exactly one of `path`, `archive` and `url` must be specified.
+///
+/// Relative paths are resolved relative to the registry file.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct TemplateRegistryEntry {
+    pub name: String,
+
+    /// A local directory containing the repository.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+
+    /// A local zip archive of the repository.
+    ///
+    /// If the archive has a single top-level folder, like the ones GitHub produces,
+    /// the repository is considered to start there.
+    #[serde(default)]
+    pub archive: Option<PathBuf>,
+
+    /// URL of a zip archive of the repository.
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Where to look for templates in the repository. The whole repository by default.
+    #[serde(default)]
+    pub templates_path: Option<String>,
+}
+
+/// Where the contents of a template repository come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateRegistryLocation {
+    Path(PathBuf),
+    Archive(PathBuf),
+    Url(String),
+}
+
+impl TemplateRegistry {
+    pub fn load_from_file(path: &Path) -> Self {
+        let contents = fs::read_to_string(path).unwrap_or_else(|error| {
+            panic!(
+                "could not read template registry {}: {error}",
+                path.display()
+            )
+        });
+        let mut registry: TemplateRegistry =
+            toml::from_str(contents.as_str()).unwrap_or_else(|error| {
+                panic!(
+                    "error parsing template registry {}: {error}",
+                    path.display()
+                )
+            });
+        if let Some(base_dir) = path.parent() {
+            registry.resolve_relative_paths(base_dir);
+        }
+        registry
+    }
+
+    fn resolve_relative_paths(&mut self, base_dir: &Path) {
+        for entry in &mut self.repositories {
+            for path in [&mut entry.path, &mut entry.archive].into_iter().flatten() {
+                if path.is_relative() {
+                    *path = base_dir.join(&*path);
+                }
+            }
+        }
+    }
+}
+
+impl TemplateRegistryEntry {
+    pub fn location(&self) -> TemplateRegistryLocation {
+        match (&self.path, &self.archive, &self.url) {
+            (Some(path), None, None) => TemplateRegistryLocation::Path(path.clone()),
+            (None, Some(archive), None) => TemplateRegistryLocation::Archive(archive.clone()),
+            (None, None, Some(url)) => TemplateRegistryLocation::Url(url.clone()),
+            _ => panic!(
+                "template repository {} must specify exactly one of `path`, `archive` or `url`",
+                self.name
+            ),
+        }
+    }
+
+    pub fn templates_path(&self) -> &str {
+        self.templates_path.as_deref().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_registry_parse() {
+        let mut registry: TemplateRegistry = toml::from_str(
+            r#"
+            [[repository]]
+            name = "local"
+            path = "templates"
+            templates-path = "contracts"
+
+            [[repository]]
+            name = "offline"
+            archive = "/opt/templates.zip"
+
+            [[repository]]
+            name = "remote"
+            url = "https://example.com/templates.zip"
+        "#,
+        )
+        .unwrap();
+        registry.resolve_relative_paths(Path::new("/registry"));
+
+        assert_eq!(registry.repositories.len(), 3);
+        assert_eq!(
+            registry.repositories[0].location(),
+            TemplateRegistryLocation::Path(PathBuf::from("/registry/templates"))
+        );
+        assert_eq!(registry.repositories[0].templates_path(), "contracts");
+        assert_eq!(
+            registry.repositories[1].location(),
+            TemplateRegistryLocation::Archive(PathBuf::from("/opt/templates.zip"))
+        );
+        assert_eq!(registry.repositories[1].templates_path(), "");
+        assert_eq!(
+            registry.repositories[2].location(),
+            TemplateRegistryLocation::Url("https://example.com/templates.zip".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must specify exactly one")]
+    fn test_template_registry_ambiguous_location() {
+        let registry: TemplateRegistry = toml::from_str(
+            r#"
+            [[repository]]
+            name = "ambiguous"
+            path = "templates"
+            url = "https://example.com/templates.zip"
+        "#,
+        )
+        .unwrap();
+        registry.repositories[0].location();
+    }
+}
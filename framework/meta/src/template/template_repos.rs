@@ -0,0 +1,148 @@
+use std::path::PathBuf;
+
+use crate::cli_args::{TemplateArgs, TemplateListArgs};
+
+use super::{
+    contract_creator::get_repo_version,
+    template_registry::{TemplateRegistry, TemplateRegistryLocation},
+    template_source::{template_sources_at, TemplateSource, TEMPLATES_PATH_IN_REPO},
+    RepoSource,
+};
+
+/// Where templates can be taken from, as specified in the command line.
+#[derive(Default, Clone, Debug)]
+pub struct TemplateRepoOptions {
+    pub tag: Option<String>,
+    pub repo_path: Option<PathBuf>,
+    pub repo_archive: Option<PathBuf>,
+    pub registry: Option<PathBuf>,
+    pub offline: bool,
+}
+
+impl From<&TemplateArgs> for TemplateRepoOptions {
+    fn from(args: &TemplateArgs) -> Self {
+        TemplateRepoOptions {
+            tag: args.tag.clone(),
+            repo_path: args.repo_path.clone(),
+            repo_archive: args.repo_archive.clone(),
+            registry: args.registry.clone(),
+            offline: args.offline,
+        }
+    }
+}
+
+impl From<&TemplateListArgs> for TemplateRepoOptions {
+    fn from(args: &TemplateListArgs) -> Self {
+        TemplateRepoOptions {
+            tag: args.tag.clone(),
+            repo_path: args.repo_path.clone(),
+            repo_archive: args.repo_archive.clone(),
+            registry: args.registry.clone(),
+            offline: args.offline,
+        }
+    }
+}
+
+/// A repository containing templates.
+pub struct TemplateRepo {
+    /// The name from the registry. `None` for the framework repository.
+    pub name: Option<String>,
+    pub source: RepoSource,
+    pub templates_path: String,
+}
+
+impl TemplateRepo {
+    pub fn template_sources(&self) -> Vec<TemplateSource<'_>> {
+        template_sources_at(&self.source, &self.templates_path)
+    }
+}
+
+/// All repositories to search for templates, in order of priority:
+/// the framework repository first, then the ones from the registry.
+pub struct TemplateRepos(pub Vec<TemplateRepo>);
+
+impl TemplateRepos {
+    pub async fn load(options: &TemplateRepoOptions) -> Self {
+        let mut repos = Vec::new();
+        if let Some(framework_source) = framework_repo_source(options).await {
+            repos.push(TemplateRepo {
+                name: None,
+                source: framework_source,
+                templates_path: TEMPLATES_PATH_IN_REPO.to_string(),
+            });
+        }
+
+        if let Some(registry_path) = &options.registry {
+            let registry = TemplateRegistry::load_from_file(registry_path);
+            for entry in &registry.repositories {
+                let source = match entry.location() {
+                    TemplateRegistryLocation::Path(path) => RepoSource::from_local_path(path),
+                    TemplateRegistryLocation::Archive(archive_path) => {
+                        RepoSource::from_archive(archive_path, temp_extract_dir_path(&entry.name))
+                    },
+                    TemplateRegistryLocation::Url(url) => {
+                        assert!(
+                            !options.offline,
+                            "template repository {} cannot be downloaded in offline mode",
+                            entry.name
+                        );
+                        RepoSource::download_archive(&url, temp_extract_dir_path(&entry.name)).await
+                    },
+                };
+                repos.push(TemplateRepo {
+                    name: Some(entry.name.clone()),
+                    source,
+                    templates_path: entry.templates_path().to_string(),
+                });
+            }
+        }
+
+        assert!(
+            !repos.is_empty(),
+            "no template sources available in offline mode, specify a local repository, an archive, or a registry"
+        );
+        TemplateRepos(repos)
+    }
+
+    /// The first template with the given name, together with the repository it comes from.
+    pub fn find_template(
+        &self,
+        template_name: &str,
+    ) -> Option<(&TemplateRepo, TemplateSource<'_>)> {
+        self.0.iter().find_map(|repo| {
+            repo.template_sources()
+                .into_iter()
+                .find(|source| source.metadata.name == template_name)
+                .map(|source| (repo, source))
+        })
+    }
+}
+
+async fn framework_repo_source(options: &TemplateRepoOptions) -> Option<RepoSource> {
+    assert!(
+        options.repo_path.is_none() || options.repo_archive.is_none(),
+        "only one of --repo-path and --repo-archive can be specified"
+    );
+    if let Some(repo_path) = &options.repo_path {
+        return Some(RepoSource::from_local_path(repo_path));
+    }
+    if let Some(archive_path) = &options.repo_archive {
+        return Some(RepoSource::from_archive(
+            archive_path,
+            temp_extract_dir_path("framework"),
+        ));
+    }
+    if options.offline {
+        return None;
+    }
+
+    let version = get_repo_version(&options.tag);
+    Some(RepoSource::download_from_github(version, std::env::temp_dir()).await)
+}
+
+fn temp_extract_dir_path(repo_name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "sc-meta-templates-{repo_name}-{}",
+        std::process::id()
+    ))
+}
@@ -7,7 +7,7 @@ use crate::folder_structure::{whitelisted_deep_copy, RelevantDirectories};
 
 use super::{template_metadata::TemplateMetadata, RepoSource};
 
-const TEMPLATES_PATH_IN_REPO: &str = "contracts/examples";
+pub(super) const TEMPLATES_PATH_IN_REPO: &str = "contracts/examples";
 const TEMPLATE_TOML_FILE_NAME: &str = "mxsc-template.toml";
 
 pub struct TemplateSource<'a> {
@@ -27,7 +27,15 @@ impl<'a> TemplateSource<'a> {
 }
 
 pub fn template_sources(repo_temp_dir: &RepoSource) -> Vec<TemplateSource<'_>> {
-    let templates_path = repo_temp_dir.repo_path().join(TEMPLATES_PATH_IN_REPO);
+    template_sources_at(repo_temp_dir, TEMPLATES_PATH_IN_REPO)
+}
+
+/// Templates found under the given path, relative to the repository root.
+pub fn template_sources_at<'a>(
+    repo_temp_dir: &'a RepoSource,
+    templates_path_in_repo: &str,
+) -> Vec<TemplateSource<'a>> {
+    let templates_path = repo_temp_dir.repo_path().join(templates_path_in_repo);
     let dirs = RelevantDirectories::find_all(&templates_path, &[]);
     let mut sources = Vec::new();
     for dir in dirs.iter_contract_crates() {
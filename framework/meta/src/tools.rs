@@ -1,4 +1,7 @@
 mod git_describe;
 pub mod post_build;
+pub mod wasm_size;
+mod workspace_root;
 
 pub use git_describe::git_describe;
+pub use workspace_root::find_workspace_root;
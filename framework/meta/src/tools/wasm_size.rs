@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const WASM_HEADER_SIZE: usize = 8;
+
+const SECTION_ID_CUSTOM: u8 = 0;
+const SECTION_ID_IMPORT: u8 = 2;
+const SECTION_ID_EXPORT: u8 = 7;
+const SECTION_ID_CODE: u8 = 10;
+
+const IMPORT_KIND_FUNCTION: u8 = 0;
+const EXPORT_KIND_FUNCTION: u8 = 0;
+const NAME_SUBSECTION_FUNCTIONS: u8 = 1;
+
+/// Size of one section of a wasm binary, including its header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmSectionSize {
+    pub name: String,
+    pub size: usize,
+}
+
+/// Size of the body of one function defined in a wasm binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmFunctionSize {
+    pub index: usize,
+    pub name: String,
+    pub size: usize,
+}
+
+/// Size breakdown of a wasm binary, obtained without any external tools.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmSizeInfo {
+    pub total_size: usize,
+    pub sections: Vec<WasmSectionSize>,
+
+    /// Functions defined in the module, largest first.
+    pub functions: Vec<WasmFunctionSize>,
+}
+
+/// Splits a wasm binary into sections and function bodies.
+///
+/// Function names come from the `name` custom section, when present,
+/// otherwise from the exports. Functions with neither are named by index.
+pub fn parse_wasm_size_info(wasm: &[u8]) -> Result<WasmSizeInfo, String> {
+    if wasm.len() < WASM_HEADER_SIZE || &wasm[..4] != WASM_MAGIC {
+        return Err("not a wasm binary".to_string());
+    }
+
+    let mut reader = WasmReader::new(&wasm[WASM_HEADER_SIZE..]);
+    let mut sections = Vec::new();
+    let mut num_imported_functions = 0;
+    let mut body_sizes = Vec::new();
+    let mut names = HashMap::new();
+    let mut export_names = HashMap::new();
+
+    while !reader.is_empty() {
+        let section_start = reader.pos;
+        let id = reader.read_u8()?;
+        let section_len = reader.read_leb_u32()? as usize;
+        let mut section_reader = WasmReader::new(reader.read_bytes(section_len)?);
+        let section_name = match id {
+            SECTION_ID_CUSTOM => {
+                let custom_name = section_reader.read_name()?;
+                if custom_name == "name" {
+                    read_function_names(&mut section_reader, &mut names)?;
+                }
+                format!("custom:{custom_name}")
+            },
+            SECTION_ID_IMPORT => {
+                num_imported_functions = count_imported_functions(&mut section_reader)?;
+                standard_section_name(id)
+            },
+            SECTION_ID_EXPORT => {
+                read_exported_functions(&mut section_reader, &mut export_names)?;
+                standard_section_name(id)
+            },
+            SECTION_ID_CODE => {
+                body_sizes = read_function_body_sizes(&mut section_reader)?;
+                standard_section_name(id)
+            },
+            _ => standard_section_name(id),
+        };
+        sections.push(WasmSectionSize {
+            name: section_name,
+            size: reader.pos - section_start,
+        });
+    }
+
+    let mut functions: Vec<WasmFunctionSize> = body_sizes
+        .into_iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let index = num_imported_functions + i;
+            let name = names
+                .remove(&index)
+                .or_else(|| export_names.remove(&index))
+                .unwrap_or_else(|| format!("func[{index}]"));
+            WasmFunctionSize { index, name, size }
+        })
+        .collect();
+    functions.sort_by(|a, b| b.size.cmp(&a.size).then(a.index.cmp(&b.index)));
+
+    Ok(WasmSizeInfo {
+        total_size: wasm.len(),
+        sections,
+        functions,
+    })
+}
+
+fn standard_section_name(id: u8) -> String {
+    match id {
+        1 => "type",
+        2 => "import",
+        3 => "function",
+        4 => "table",
+        5 => "memory",
+        6 => "global",
+        7 => "export",
+        8 => "start",
+        9 => "element",
+        10 => "code",
+        11 => "data",
+        12 => "datacount",
+        _ => "unknown",
+    }
+    .to_string()
+}
+
+fn count_imported_functions(reader: &mut WasmReader) -> Result<usize, String> {
+    let count = reader.read_leb_u32()?;
+    let mut num_functions = 0;
+    for _ in 0..count {
+        reader.read_name()?; // module
+        reader.read_name()?; // field
+        match reader.read_u8()? {
+            IMPORT_KIND_FUNCTION => {
+                reader.read_leb_u32()?; // type index
+                num_functions += 1;
+            },
+            1 => {
+                // table: element type + limits
+                reader.read_u8()?;
+                reader.read_limits()?;
+            },
+            2 => {
+                reader.read_limits()?;
+            },
+            3 => {
+                // global: value type + mutability
+                reader.read_u8()?;
+                reader.read_u8()?;
+            },
+            kind => return Err(format!("unknown import kind {kind}")),
+        }
+    }
+    Ok(num_functions)
+}
+
+fn read_exported_functions(
+    reader: &mut WasmReader,
+    export_names: &mut HashMap<usize, String>,
+) -> Result<(), String> {
+    let count = reader.read_leb_u32()?;
+    for _ in 0..count {
+        let name = reader.read_name()?;
+        let kind = reader.read_u8()?;
+        let index = reader.read_leb_u32()? as usize;
+        if kind == EXPORT_KIND_FUNCTION {
+            export_names.entry(index).or_insert(name);
+        }
+    }
+    Ok(())
+}
+
+fn read_function_body_sizes(reader: &mut WasmReader) -> Result<Vec<usize>, String> {
+    let count = reader.read_leb_u32()?;
+    let mut sizes = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let body_start = reader.pos;
+        let body_len = reader.read_leb_u32()? as usize;
+        reader.read_bytes(body_len)?;
+        sizes.push(reader.pos - body_start);
+    }
+    Ok(sizes)
+}
+
+fn read_function_names(
+    reader: &mut WasmReader,
+    names: &mut HashMap<usize, String>,
+) -> Result<(), String> {
+    while !reader.is_empty() {
+        let subsection_id = reader.read_u8()?;
+        let subsection_len = reader.read_leb_u32()? as usize;
+        let mut subsection_reader = WasmReader::new(reader.read_bytes(subsection_len)?);
+        if subsection_id != NAME_SUBSECTION_FUNCTIONS {
+            continue;
+        }
+        let count = subsection_reader.read_leb_u32()?;
+        for _ in 0..count {
+            let index = subsection_reader.read_leb_u32()? as usize;
+            let name = subsection_reader.read_name()?;
+            names.insert(index, name);
+        }
+    }
+    Ok(())
+}
+
+struct WasmReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WasmReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        WasmReader { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| "unexpected end of wasm binary".to_string())?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos + len;
+        if end > self.bytes.len() {
+            return Err("unexpected end of wasm binary".to_string());
+        }
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_leb_u32(&mut self) -> Result<u32, String> {
+        let mut result = 0u32;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift >= 32 {
+                return Err("invalid LEB128 integer".to_string());
+            }
+            result |= ((byte & 0x7f) as u32) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_name(&mut self) -> Result<String, String> {
+        let len = self.read_leb_u32()? as usize;
+        let bytes = self.read_bytes(len)?;
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    fn read_limits(&mut self) -> Result<(), String> {
+        let flags = self.read_u8()?;
+        self.read_leb_u32()?;
+        if flags & 1 != 0 {
+            self.read_leb_u32()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Module with one imported function, two defined functions,
+    /// one of them exported, and a name for the other one.
+    const TEST_WASM: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type: () -> ()
+        0x02, 0x07, 0x01, 0x01, b'm', 0x01, b'f', 0x00, 0x00, // import m.f
+        0x03, 0x03, 0x02, 0x00, 0x00, // function: 2 functions
+        0x07, 0x07, 0x01, 0x03, b'r', b'u', b'n', 0x00, 0x01, // export "run" = func 1
+        0x0a, 0x0b, 0x02, // code: 2 bodies
+        0x02, 0x00, 0x0b, // body 1: 3 bytes
+        0x06, 0x00, 0x10, 0x00, 0x10, 0x00, 0x0b, // body 2: 7 bytes
+        0x00, 0x0e, 0x04, b'n', b'a', b'm', b'e', // custom "name"
+        0x01, 0x07, 0x01, 0x02, 0x04, b'i', b'n', b'n', b'r', // func 2 = "innr"
+    ];
+
+    #[test]
+    fn test_parse_wasm_size_info() {
+        let info = parse_wasm_size_info(TEST_WASM).unwrap();
+        assert_eq!(info.total_size, TEST_WASM.len());
+
+        let section_names: Vec<&str> = info.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            section_names,
+            vec![
+                "type",
+                "import",
+                "function",
+                "export",
+                "code",
+                "custom:name"
+            ]
+        );
+        let sections_total: usize = info.sections.iter().map(|s| s.size).sum();
+        assert_eq!(sections_total + WASM_HEADER_SIZE, TEST_WASM.len());
+
+        assert_eq!(
+            info.functions,
+            vec![
+                WasmFunctionSize {
+                    index: 2,
+                    name: "innr".to_string(),
+                    size: 7,
+                },
+                WasmFunctionSize {
+                    index: 1,
+                    name: "run".to_string(),
+                    size: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_wasm_size_info_invalid() {
+        assert!(parse_wasm_size_info(b"not wasm").is_err());
+        assert!(parse_wasm_size_info(&TEST_WASM[..20]).is_err());
+    }
+}
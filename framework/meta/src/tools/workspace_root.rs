@@ -0,0 +1,21 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The closest directory containing the given path whose `Cargo.toml` declares a workspace.
+///
+/// `None` for standalone crates.
+pub fn find_workspace_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|dir| is_workspace_root(dir))
+        .map(Path::to_path_buf)
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|contents| contents.parse::<toml::Value>().ok())
+        .map(|value| value.get("workspace").is_some())
+        .unwrap_or(false)
+}
@@ -17,6 +17,9 @@ fn get_serialized_toml() -> MultiContractConfigSerde {
         name = "contract2-name"
         add-labels = ["label1", "label2"]
         external-view = true
+        max-size = "16k"
+        max-size-growth = "5%"
+        size-baseline = "size-baseline/contract2-name.size.json"
         
         [labels-for-contracts]
         default = ["main-contract"]
@@ -71,6 +74,14 @@ fn test_serialize_multi_contract() {
             .external_view,
         Some(true)
     );
+    assert_eq!(
+        multi_contract
+            .contracts
+            .get("secondary-contract")
+            .unwrap()
+            .max_size,
+        Some("16k".to_string())
+    );
 
     assert_eq!(
         multi_contract.labels_for_contracts.get("default").unwrap(),
@@ -123,4 +134,16 @@ fn test_output_contract_config() {
             .endpoint_names(),
         ["endpoint1", "endpoint2", "endpoint3", "endpoint4"]
     );
+
+    assert_eq!(main_contract.settings.size_budget.max_size, None);
+    let secondary_size_budget = &contract_config
+        .get_contract_by_name("contract2-name".to_string())
+        .unwrap()
+        .settings
+        .size_budget;
+    assert_eq!(secondary_size_budget.max_size, Some(16 * 1024));
+    assert_eq!(
+        secondary_size_budget.size_baseline.as_deref(),
+        Some("size-baseline/contract2-name.size.json")
+    );
 }
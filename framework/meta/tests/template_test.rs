@@ -1,11 +1,13 @@
 use multiversx_sc_meta::{
     template::{
         template_names_from_repo, ContractCreator, ContractCreatorTarget, RepoSource, RepoVersion,
+        TemplateRepoOptions, TemplateRepos,
     },
     version_history,
 };
 use std::{
     fs,
+    io::Write,
     path::{Path, PathBuf},
     process::Command,
 };
@@ -29,6 +31,143 @@ fn test_template_list() {
     );
 }
 
+const CUSTOM_TEMPLATE_TOML: &str = r#"
+name = "team-empty"
+contract_trait = "EmptyContract"
+src_file = "empty.rs"
+rename_pairs = []
+files_include = ["meta", "src", "wasm/Cargo.toml", "Cargo.toml", "{{team}}.md"]
+
+[[placeholders]]
+key = "{{team}}"
+default = "core"
+
+[[placeholders]]
+key = "__CONTRACT_CONST__"
+case = "upper-snake"
+"#;
+
+/// A repository with a single in-house template, based on the `empty` example.
+fn prepare_custom_template_repo(repo_path: &Path) {
+    if repo_path.exists() {
+        fs::remove_dir_all(repo_path).unwrap();
+    }
+    let template_path = repo_path.join("templates").join("team-empty");
+    let empty_path = find_workspace().join("contracts/examples/empty");
+    for file in [
+        "Cargo.toml",
+        "src/empty.rs",
+        "meta/Cargo.toml",
+        "meta/src/main.rs",
+        "wasm/Cargo.toml",
+        "multiversx.json",
+    ] {
+        let target_file = template_path.join(file);
+        fs::create_dir_all(target_file.parent().unwrap()).unwrap();
+        let contents = fs::read_to_string(empty_path.join(file)).unwrap();
+        // paths become absolute, since the template is not in the workspace
+        let contents = contents.replace(
+            "../../../framework",
+            &format!("{}/framework", find_workspace().display()),
+        );
+        fs::write(target_file, contents).unwrap();
+    }
+    fs::write(
+        template_path.join("mxsc-template.toml"),
+        CUSTOM_TEMPLATE_TOML,
+    )
+    .unwrap();
+    fs::write(
+        template_path.join("{{team}}.md"),
+        "Maintained by {{team}}, constant __CONTRACT_CONST__.\n",
+    )
+    .unwrap();
+}
+
+/// Wraps everything in a top-level folder, the way GitHub archives do.
+fn zip_dir(dir_path: &Path, zip_path: &Path) {
+    let mut zip = zip::ZipWriter::new(fs::File::create(zip_path).unwrap());
+    let mut dirs = vec![dir_path.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                let name = Path::new("in-house-main").join(path.strip_prefix(dir_path).unwrap());
+                zip.start_file(name.to_str().unwrap(), zip::write::FileOptions::default())
+                    .unwrap();
+                zip.write_all(&fs::read(&path).unwrap()).unwrap();
+            }
+        }
+    }
+    zip.finish().unwrap();
+}
+
+#[tokio::test]
+async fn test_template_registry_offline() {
+    let test_dir =
+        std::env::temp_dir().join(format!("template-registry-test-{}", std::process::id()));
+    let repo_path = test_dir.join("in-house");
+    prepare_custom_template_repo(&repo_path);
+    zip_dir(&repo_path, &test_dir.join("in-house.zip"));
+
+    let registry_path = test_dir.join("registry.toml");
+    fs::write(
+        &registry_path,
+        r#"
+        [[repository]]
+        name = "in-house"
+        path = "in-house"
+        templates-path = "templates"
+
+        [[repository]]
+        name = "in-house-archive"
+        archive = "in-house.zip"
+        templates-path = "templates"
+        "#,
+    )
+    .unwrap();
+
+    let repos = TemplateRepos::load(&TemplateRepoOptions {
+        registry: Some(registry_path),
+        offline: true,
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(repos.0.len(), 2);
+    for repo in &repos.0 {
+        let names: Vec<String> = repo
+            .template_sources()
+            .iter()
+            .map(|source| source.metadata.name.clone())
+            .collect();
+        assert_eq!(names, ["team-empty".to_string()]);
+    }
+
+    let (repo, template_source) = repos.find_template("team-empty").unwrap();
+    assert_eq!(repo.name.as_deref(), Some("in-house"));
+
+    let target = ContractCreatorTarget {
+        target_path: test_dir.join("output"),
+        new_name: "my-contract".to_string(),
+    };
+    let mut creator =
+        ContractCreator::from_template_source(&repo.source, template_source, target.clone(), true);
+    creator
+        .adjuster
+        .placeholder_args
+        .insert("{{team}}".to_string(), "payments".to_string());
+    creator.create_contract();
+
+    let readme = fs::read_to_string(target.contract_dir().join("payments.md")).unwrap();
+    assert_eq!(readme, "Maintained by payments, constant MY_CONTRACT.\n");
+    assert!(target.contract_dir().join("src/my_contract.rs").is_file());
+
+    drop(repos);
+    fs::remove_dir_all(&test_dir).unwrap();
+}
+
 #[test]
 #[cfg_attr(not(feature = "template-test-current"), ignore)]
 fn template_current_adder() {
@@ -22,18 +22,21 @@ impl VMHooksApiBackend for DebugApiBackend {
         F: FnOnce(&dyn VMHooks) -> R,
     {
         let top_context = TxContextStack::static_peek();
-        let wrapper = DebugApiVMHooksHandler::new(top_context);
-        let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
-        f(&dispatcher)
+        with_debug_dispatcher(top_context, |dispatcher| f(dispatcher))
     }
 
     fn with_vm_hooks_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
-        let wrapper = DebugApiVMHooksHandler::new(handle.context);
-        let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
-        f(&dispatcher)
+        with_debug_dispatcher(handle.context, |dispatcher| f(dispatcher))
+    }
+
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        with_debug_dispatcher(handle.context, f)
     }
 
     fn with_vm_hooks_ctx_2<R, F>(handle1: Self::HandleType, handle2: Self::HandleType, f: F) -> R
@@ -93,6 +96,18 @@ impl std::fmt::Debug for DebugApi {
     }
 }
 
+/// Also enforces the managed types limits after each hook.
+fn with_debug_dispatcher<R, F>(context: Arc<TxContext>, f: F) -> R
+where
+    F: FnOnce(&VMHooksDispatcher) -> R,
+{
+    let wrapper = DebugApiVMHooksHandler::new(context);
+    let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
+    let result = f(&dispatcher);
+    dispatcher.check_managed_types_limits();
+    result
+}
+
 fn debugger_panic(status: u64, message: &str) {
     TxContextRef::new_from_static().replace_tx_result_with_error(TxPanic::new(status, message));
     std::panic::panic_any(BreakpointValue::SignalError);
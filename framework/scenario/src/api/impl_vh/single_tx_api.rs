@@ -35,6 +35,17 @@ impl VMHooksApiBackend for SingleTxApiBackend {
         })
     }
 
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(_handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        SINGLE_TX_API_VH_CELL.with(|cell| {
+            let handler = cell.lock().unwrap().clone();
+            let dispatcher = VMHooksDispatcher::new(Box::new(handler));
+            f(&dispatcher)
+        })
+    }
+
     fn with_static_data<R, F>(f: F) -> R
     where
         F: FnOnce(&StaticVarData) -> R,
@@ -32,6 +32,13 @@ impl VMHooksApiBackend for StaticApiBackend {
         STATIC_API_VH_CELL.with(|vh| f(vh))
     }
 
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(_handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R,
+    {
+        STATIC_API_VH_CELL.with(|vh| f(vh))
+    }
+
     fn with_static_data<R, F>(f: F) -> R
     where
         F: FnOnce(&StaticVarData) -> R,
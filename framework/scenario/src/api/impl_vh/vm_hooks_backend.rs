@@ -1,4 +1,4 @@
-use multiversx_chain_vm::executor::VMHooks;
+use multiversx_chain_vm::{executor::VMHooks, vm_hooks::VMHooksDispatcher};
 use multiversx_sc::api::HandleConstraints;
 
 use crate::debug_executor::StaticVarData;
@@ -38,6 +38,12 @@ pub trait VMHooksApiBackend: Clone + Send + Sync + 'static {
         Self::with_vm_hooks(f)
     }
 
+    /// Gives access to the hooks that are not part of the `VMHooks` interface,
+    /// in the context of 1 handle.
+    fn with_vm_hooks_dispatcher_ctx_1<R, F>(handle: Self::HandleType, f: F) -> R
+    where
+        F: FnOnce(&VMHooksDispatcher) -> R;
+
     fn assert_live_handle(_handle: &Self::HandleType) {
         // by default, no check
     }
@@ -80,4 +80,30 @@ impl<VHB: VMHooksApiBackend> StaticVarApiImpl for VMHooksApi<VHB> {
             use_raw_handle(data.static_vars_cell.borrow().call_value_multi_esdt_handle)
         })
     }
+
+    fn set_storage_cache_map_handle(&self, handle: RawHandle) {
+        self.with_static_data(|data| {
+            data.static_vars_cell.borrow_mut().storage_cache_map_handle = handle;
+        })
+    }
+
+    fn get_storage_cache_map_handle(&self) -> RawHandle {
+        self.with_static_data(|data| data.static_vars_cell.borrow().storage_cache_map_handle)
+    }
+
+    fn set_storage_cache_dirty_keys_handle(&self, handle: RawHandle) {
+        self.with_static_data(|data| {
+            data.static_vars_cell
+                .borrow_mut()
+                .storage_cache_dirty_keys_handle = handle;
+        })
+    }
+
+    fn get_storage_cache_dirty_keys_handle(&self) -> RawHandle {
+        self.with_static_data(|data| {
+            data.static_vars_cell
+                .borrow()
+                .storage_cache_dirty_keys_handle
+        })
+    }
 }
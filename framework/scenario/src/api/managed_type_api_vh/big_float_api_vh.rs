@@ -148,4 +148,10 @@ impl<VHB: VMHooksApiBackend> BigFloatApiImpl for VMHooksApi<VHB> {
             vh.big_float_get_const_pi(dest.get_raw_handle_unchecked())
         });
     }
+
+    fn bf_drop(&self, handle: Self::BigFloatHandle) {
+        self.with_vm_hooks_dispatcher_ctx_1(&handle, |vh| {
+            vh.big_float_drop(handle.get_raw_handle_unchecked())
+        });
+    }
 }
@@ -124,4 +124,10 @@ impl<VHB: VMHooksApiBackend> BigIntApiImpl for VMHooksApi<VHB> {
             )
         });
     }
+
+    fn bi_drop(&self, handle: Self::BigIntHandle) {
+        self.with_vm_hooks_dispatcher_ctx_1(&handle, |vh| {
+            vh.big_int_drop(handle.get_raw_handle_unchecked())
+        });
+    }
 }
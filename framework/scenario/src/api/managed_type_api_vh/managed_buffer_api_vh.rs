@@ -174,4 +174,10 @@ impl<VHB: VMHooksApiBackend> ManagedBufferApiImpl for VMHooksApi<VHB> {
             )
         })
     }
+
+    fn mb_drop(&self, handle: Self::ManagedBufferHandle) {
+        self.with_vm_hooks_dispatcher_ctx_1(&handle, |vh| {
+            vh.managed_buffer_drop(handle.get_raw_handle_unchecked())
+        });
+    }
 }
@@ -65,4 +65,10 @@ impl<VHB: VMHooksApiBackend> ManagedMapApiImpl for VMHooksApi<VHB> {
             )
         }))
     }
+
+    fn mm_drop(&self, map_handle: Self::ManagedMapHandle) {
+        self.with_vm_hooks_dispatcher_ctx_1(&map_handle, |vh| {
+            vh.managed_map_drop(map_handle.get_raw_handle_unchecked())
+        });
+    }
 }
@@ -7,6 +7,8 @@ pub struct TxStaticVars {
     pub num_arguments: i32,
     pub call_value_egld_handle: RawHandle,
     pub call_value_multi_esdt_handle: RawHandle,
+    pub storage_cache_map_handle: RawHandle,
+    pub storage_cache_dirty_keys_handle: RawHandle,
 }
 
 impl Default for TxStaticVars {
@@ -17,6 +19,8 @@ impl Default for TxStaticVars {
             num_arguments: -1,
             call_value_egld_handle: const_handles::UNINITIALIZED_HANDLE,
             call_value_multi_esdt_handle: const_handles::UNINITIALIZED_HANDLE,
+            storage_cache_map_handle: const_handles::UNINITIALIZED_HANDLE,
+            storage_cache_dirty_keys_handle: const_handles::UNINITIALIZED_HANDLE,
         }
     }
 }
@@ -1,9 +1,13 @@
 mod contract_info;
 mod debugger_backend;
 mod scenario_world;
+mod scenario_world_async_calls;
+mod scenario_world_managed_types;
 mod scenario_world_runner;
+mod scenario_world_state;
 mod scenario_world_steps;
 mod scenario_world_steps_deprecated;
+mod scenario_world_storage_report;
 mod scenario_world_whitebox;
 mod whitebox_contract;
 
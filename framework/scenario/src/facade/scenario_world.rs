@@ -1,5 +1,5 @@
 use multiversx_chain_scenario_format::interpret_trait::InterpretableFrom;
-use multiversx_chain_vm::world_mock::BlockchainState;
+use multiversx_chain_vm::{tx_execution::MockTxHandler, world_mock::BlockchainState};
 
 use crate::{
     api::DebugApi,
@@ -10,7 +10,7 @@ use crate::{
     },
     scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
     scenario_format::{interpret_trait::InterpreterContext, value_interpreter::interpret_string},
-    scenario_model::BytesValue,
+    scenario_model::{AddressValue, BytesValue},
     vm_go_tool::run_vm_go_tool,
 };
 use std::path::{Path, PathBuf};
@@ -208,6 +208,34 @@ impl ScenarioWorld {
         );
     }
 
+    /// Adds a builtin function implemented in Rust to the debugger VM.
+    ///
+    /// Registering a protocol builtin function name replaces the default mock.
+    pub fn register_builtin_function<H>(&mut self, function_name: &str, handler: H) -> &mut Self
+    where
+        H: MockTxHandler + 'static,
+    {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .register_builtin_function(function_name, handler);
+        self
+    }
+
+    /// Simulates a system or third-party contract at the given address with a Rust handler,
+    /// instead of registering a contract implementation.
+    pub fn register_mock_contract<H>(&mut self, address_expr: &str, handler: H) -> &mut Self
+    where
+        H: MockTxHandler + 'static,
+    {
+        let address = AddressValue::interpret_from(address_expr, &self.interpreter_context());
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .register_mock_contract(address.to_vm_address(), handler);
+        self
+    }
+
     /// Exports current scenario to a JSON file, as created.
     pub fn write_scenario_trace<P: AsRef<Path>>(&mut self, file_path: P) {
         if let Some(trace) = &mut self.get_mut_debugger_backend().trace {
@@ -0,0 +1,17 @@
+use multiversx_chain_vm::tx_mock::AsyncCallNode;
+
+use crate::facade::ScenarioWorld;
+
+/// Async call inspection, only available for the contract debugger backend.
+impl ScenarioWorld {
+    /// The async calls and promises executed by the last transaction, with their callbacks,
+    /// in execution order.
+    ///
+    /// Includes the calls that failed and were rolled back.
+    pub fn last_tx_async_call_tree(&self) -> &[AsyncCallNode] {
+        self.get_debugger_backend()
+            .vm_runner
+            .last_async_call_tree
+            .as_slice()
+    }
+}
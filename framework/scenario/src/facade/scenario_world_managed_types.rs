@@ -0,0 +1,24 @@
+use multiversx_chain_vm::tx_mock::{ManagedTypesLimits, ManagedTypesStats};
+
+use crate::facade::ScenarioWorld;
+
+/// Managed memory limits and profiling, only available for the contract debugger backend.
+impl ScenarioWorld {
+    /// Limits the handles and bytes each call can keep alive, starting with the next transaction.
+    ///
+    /// Calls that exceed them fail with an execution error, as they would on the blockchain.
+    pub fn set_managed_types_limits(&mut self, limits: ManagedTypesLimits) -> &mut Self {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .set_managed_types_limits(limits);
+        self
+    }
+
+    /// Managed memory usage of the contract called in the last transaction.
+    pub fn last_tx_managed_types_stats(&self) -> ManagedTypesStats {
+        self.get_debugger_backend()
+            .vm_runner
+            .last_managed_types_stats
+    }
+}
@@ -0,0 +1,389 @@
+use multiversx_chain_vm::{
+    types::VMAddress,
+    world_mock::{AccountData, EsdtInstanceMetadata},
+};
+use num_traits::Zero;
+
+use crate::{
+    facade::ScenarioWorld,
+    multiversx_sc::{
+        codec::{top_encode_to_vec_u8_or_panic, TopDecode, TopEncode},
+        types::{heap::Address, EsdtLocalRole},
+    },
+    scenario::{
+        raw_converter::{
+            account_as_check_state_raw, account_as_raw, bytes_to_hex,
+            bytes_to_scenario_string_or_hex,
+        },
+        ScenarioRunner,
+    },
+    scenario_format::{
+        interpret_trait::{InterpretableFrom, InterpreterContext},
+        serde_raw::ValueSubTree,
+    },
+    scenario_model::{
+        Account, AddressValue, BigUintValue, CheckAccounts, CheckStateStep, SetStateStep,
+    },
+};
+
+/// Typed state helpers, ported from the legacy `BlockchainStateWrapper`.
+///
+/// The setters and checkers also record the resulting account in the scenario trace, if one was started.
+impl ScenarioWorld {
+    /// Sets the block epoch, recording it as a set state step.
+    pub fn set_block_epoch(&mut self, block_epoch: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().block_epoch(block_epoch))
+    }
+
+    pub fn set_block_nonce(&mut self, block_nonce: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().block_nonce(block_nonce))
+    }
+
+    pub fn set_block_random_seed(&mut self, block_random_seed: &[u8; 48]) -> &mut Self {
+        self.set_state_step(SetStateStep::new().block_random_seed(block_random_seed.as_slice()))
+    }
+
+    pub fn set_block_round(&mut self, block_round: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().block_round(block_round))
+    }
+
+    pub fn set_block_timestamp(&mut self, block_timestamp: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().block_timestamp(block_timestamp))
+    }
+
+    pub fn set_prev_block_epoch(&mut self, block_epoch: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().prev_block_epoch(block_epoch))
+    }
+
+    pub fn set_prev_block_nonce(&mut self, block_nonce: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().prev_block_nonce(block_nonce))
+    }
+
+    pub fn set_prev_block_random_seed(&mut self, block_random_seed: &[u8; 48]) -> &mut Self {
+        self.set_state_step(
+            SetStateStep::new().prev_block_random_seed(block_random_seed.as_slice()),
+        )
+    }
+
+    pub fn set_prev_block_round(&mut self, block_round: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().prev_block_round(block_round))
+    }
+
+    pub fn set_prev_block_timestamp(&mut self, block_timestamp: u64) -> &mut Self {
+        self.set_state_step(SetStateStep::new().prev_block_timestamp(block_timestamp))
+    }
+
+    /// Sets the balance of an NFT/SFT/META-ESDT instance, with no other properties.
+    ///
+    /// The account needs to exist.
+    pub fn set_nft_balance<A, V, T>(
+        &mut self,
+        address: A,
+        token_id: &[u8],
+        nonce: u64,
+        balance: V,
+        attributes: &T,
+    ) -> &mut Self
+    where
+        AddressValue: From<A>,
+        BigUintValue: From<V>,
+        T: TopEncode,
+    {
+        self.set_nft_balance_all_properties(
+            address,
+            token_id,
+            nonce,
+            balance,
+            attributes,
+            0,
+            None,
+            None,
+            None,
+            &[],
+        )
+    }
+
+    /// Sets the balance and all metadata of an NFT/SFT/META-ESDT instance,
+    /// overwriting the instance, if it already exists.
+    ///
+    /// The account needs to exist.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_nft_balance_all_properties<A, V, T>(
+        &mut self,
+        address: A,
+        token_id: &[u8],
+        nonce: u64,
+        balance: V,
+        attributes: &T,
+        royalties: u64,
+        creator: Option<&Address>,
+        name: Option<&[u8]>,
+        hash: Option<&[u8]>,
+        uris: &[Vec<u8>],
+    ) -> &mut Self
+    where
+        AddressValue: From<A>,
+        BigUintValue: From<V>,
+        T: TopEncode,
+    {
+        let balance = BigUintValue::from(balance);
+        let metadata = EsdtInstanceMetadata {
+            creator: creator.map(|creator| VMAddress::from(creator.as_array())),
+            attributes: top_encode_to_vec_u8_or_panic(attributes),
+            royalties,
+            name: name.unwrap_or_default().to_vec(),
+            hash: hash.map(|h| h.to_vec()),
+            uri: uris.to_vec(),
+        };
+        self.update_account(
+            AddressValue::from(address),
+            "set_nft_balance_all_properties",
+            |account| {
+                account
+                    .esdt
+                    .set_esdt_balance(token_id.to_vec(), nonce, &balance.value, metadata);
+            },
+        )
+    }
+
+    /// Overwrites the local roles of an account for a token.
+    ///
+    /// The account needs to exist.
+    pub fn set_esdt_local_roles<A>(
+        &mut self,
+        address: A,
+        token_id: &[u8],
+        roles: &[EsdtLocalRole],
+    ) -> &mut Self
+    where
+        AddressValue: From<A>,
+    {
+        let roles_raw = roles
+            .iter()
+            .map(|role| role.as_role_name().to_vec())
+            .collect();
+        self.update_account(
+            AddressValue::from(address),
+            "set_esdt_local_roles",
+            |account| {
+                account.esdt.set_roles(token_id.to_vec(), roles_raw);
+            },
+        )
+    }
+
+    /// Checks the balance and, optionally, the decoded attributes of an NFT/SFT/META-ESDT instance.
+    ///
+    /// A missing account or instance counts as zero balance.
+    /// If the check passes, the full state of the account is recorded as a check state step.
+    pub fn check_nft_balance<T, A, V>(
+        &mut self,
+        address: A,
+        token_id: &[u8],
+        nonce: u64,
+        expected_balance: V,
+        opt_expected_attributes: Option<&T>,
+    ) -> &mut Self
+    where
+        AddressValue: From<A>,
+        BigUintValue: From<V>,
+        T: TopDecode + PartialEq + core::fmt::Debug,
+    {
+        let address = AddressValue::from(address);
+        let expected_balance = BigUintValue::from(expected_balance);
+        let vm_address = address.to_vm_address();
+        let (actual_balance, actual_attributes_serialized) =
+            match self.get_state().accounts.get(&vm_address) {
+                Some(account) => match account
+                    .esdt
+                    .get_by_identifier_or_default(token_id)
+                    .instances
+                    .get_by_nonce(nonce)
+                {
+                    Some(instance) => (
+                        instance.balance.clone(),
+                        instance.metadata.attributes.clone(),
+                    ),
+                    None => (num_bigint::BigUint::zero(), Vec::new()),
+                },
+                None => (num_bigint::BigUint::zero(), Vec::new()),
+            };
+
+        assert!(
+            expected_balance.value == actual_balance,
+            "ESDT NFT balance mismatch for address {}\n Token: {}, nonce: {}\n Expected: {}\n Have: {}\n",
+            address,
+            String::from_utf8_lossy(token_id),
+            nonce,
+            expected_balance.value,
+            actual_balance
+        );
+
+        if let Some(expected_attributes) = opt_expected_attributes {
+            let actual_attributes = T::top_decode(actual_attributes_serialized)
+                .unwrap_or_else(|err| panic!("Failed to decode NFT attributes: {err:?}"));
+            assert!(
+                expected_attributes == &actual_attributes,
+                "ESDT NFT attributes mismatch for address {}\n Token: {}, nonce: {}\n Expected: {:?}\n Have: {:?}\n",
+                address,
+                String::from_utf8_lossy(token_id),
+                nonce,
+                expected_attributes,
+                actual_attributes,
+            );
+        }
+
+        self.trace_check_account(&vm_address);
+        self
+    }
+
+    /// Decodes the attributes of an NFT/SFT/META-ESDT instance.
+    ///
+    /// Returns `None` if the account does not hold the instance. Panics if the account does not exist.
+    pub fn get_nft_attributes<T, A>(&self, address: A, token_id: &[u8], nonce: u64) -> Option<T>
+    where
+        AddressValue: From<A>,
+        T: TopDecode,
+    {
+        let address = AddressValue::from(address);
+        let account = self.get_account_or_panic(&address, "get_nft_attributes");
+        account
+            .esdt
+            .get_by_identifier(token_id)?
+            .instances
+            .get_by_nonce(nonce)
+            .map(|instance| T::top_decode(instance.metadata.attributes.clone()).unwrap())
+    }
+
+    /// Prints the state of all accounts, with any token attributes as hex.
+    pub fn dump_state(&self) -> &Self {
+        for vm_address in self.get_state().accounts.keys() {
+            self.dump_state_for_account_hex_attributes(&Address::from(*vm_address.as_array()));
+            println!();
+        }
+        self
+    }
+
+    /// Prints the state of an account, with any token attributes as hex.
+    pub fn dump_state_for_account_hex_attributes<A>(&self, address: A) -> &Self
+    where
+        AddressValue: From<A>,
+    {
+        self.dump_state_for_account::<Vec<u8>, A>(address)
+    }
+
+    /// Prints the state of an account, with token attributes decoded as the provided type, if possible.
+    pub fn dump_state_for_account<AttributesType, A>(&self, address: A) -> &Self
+    where
+        AddressValue: From<A>,
+        AttributesType: 'static + TopDecode + core::fmt::Debug,
+    {
+        let address = AddressValue::from(address);
+        let account = self.get_account_or_panic(&address, "dump_state_for_account");
+
+        println!("State for account: {address}");
+        println!("EGLD: {}", account.egld_balance);
+
+        if !account.esdt.is_empty() {
+            println!("ESDT Tokens:");
+        }
+        for (token_id, esdt_data) in account.esdt.iter() {
+            println!("  Token: {}", String::from_utf8_lossy(token_id));
+
+            for (token_nonce, instance) in esdt_data.instances.get_instances() {
+                let attributes = &instance.metadata.attributes;
+                let attributes_str = if std::any::TypeId::of::<AttributesType>()
+                    == std::any::TypeId::of::<Vec<u8>>()
+                {
+                    bytes_to_hex(attributes)
+                } else {
+                    match AttributesType::top_decode(attributes.as_slice()) {
+                        Ok(decoded) => format!("{decoded:?}"),
+                        Err(_) => bytes_to_hex(attributes),
+                    }
+                };
+                println!(
+                    "      Nonce {token_nonce}, balance: {}, attributes: {attributes_str}",
+                    instance.balance
+                );
+            }
+        }
+
+        if !account.storage.is_empty() {
+            println!();
+            println!("Storage: ");
+        }
+        for (key, value) in &account.storage {
+            let key_str = String::from_utf8(key.clone()).unwrap_or_else(|_| bytes_to_hex(key));
+            println!("  {key_str}: {}", bytes_to_hex(value));
+        }
+
+        self
+    }
+
+    fn get_account_or_panic(&self, address: &AddressValue, operation: &str) -> &AccountData {
+        self.get_state()
+            .accounts
+            .get(&address.to_vm_address())
+            .unwrap_or_else(|| panic!("{operation}: Account {address} does not exist"))
+    }
+
+    /// Modifies an existing account directly in the debugger state,
+    /// then records its full new state in the trace.
+    fn update_account<F>(&mut self, address: AddressValue, operation: &str, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut AccountData),
+    {
+        let vm_address = address.to_vm_address();
+        match self.get_mut_state().accounts.get_mut(&vm_address) {
+            Some(account) => f(account),
+            None => panic!("{operation}: Account {address} does not exist"),
+        }
+
+        self.trace_set_account(&vm_address);
+        self
+    }
+
+    fn trace_set_account(&mut self, vm_address: &VMAddress) {
+        let Some(mut account) = self.get_state().accounts.get(vm_address).cloned() else {
+            return;
+        };
+        let debugger = self.get_mut_debugger_backend();
+        let Some(trace) = &mut debugger.trace else {
+            return;
+        };
+
+        // the code can be arbitrary bytes, so it cannot be converted as the contract path string
+        let code = account.contract_path.take();
+        let mut account_raw = account_as_raw(&account);
+        account_raw.code =
+            code.map(|code| ValueSubTree::Str(bytes_to_scenario_string_or_hex(&code)));
+
+        let account = Account::interpret_from(account_raw, &trace_interpreter_context());
+        trace.run_set_state_step(
+            &SetStateStep::new().put_account(&Address::from(*vm_address.as_array()), account),
+        );
+    }
+
+    fn trace_check_account(&mut self, vm_address: &VMAddress) {
+        let Some(account) = self.get_state().accounts.get(vm_address).cloned() else {
+            return;
+        };
+        let debugger = self.get_mut_debugger_backend();
+        let Some(trace) = &mut debugger.trace else {
+            return;
+        };
+
+        let accounts = CheckAccounts::interpret_from(
+            account_as_check_state_raw(&account),
+            &trace_interpreter_context(),
+        );
+        trace.run_check_state_step(&CheckStateStep {
+            comment: None,
+            accounts,
+        });
+    }
+}
+
+fn trace_interpreter_context() -> InterpreterContext {
+    InterpreterContext::default().with_allowed_missing_files()
+}
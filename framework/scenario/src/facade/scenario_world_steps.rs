@@ -1,9 +1,9 @@
-use multiversx_sc::types::{heap::Address, ContractCall};
+use multiversx_sc::types::{heap::Address, ContractCall, Page};
 
 use crate::{
     api::StaticApi,
     facade::ScenarioWorld,
-    multiversx_sc::codec::{CodecFrom, TopEncodeMulti},
+    multiversx_sc::codec::{CodecFrom, TopDecodeMulti, TopEncodeMulti},
     scenario::{model::*, ScenarioRunner},
 };
 
@@ -134,6 +134,36 @@ impl ScenarioWorld {
         self.sc_query_get_result(ScQueryStep::new().call(contract_call))
     }
 
+    /// Queries a paginated view page by page, until the last page, and collects all items.
+    ///
+    /// The closure receives the cursor and the page size and produces the call for that page.
+    /// The page size needs to be greater than zero.
+    pub fn quick_query_all_pages<CC, T, F>(
+        &mut self,
+        page_limit: usize,
+        mut contract_call_fn: F,
+    ) -> Vec<T>
+    where
+        CC: ContractCall<StaticApi>,
+        Page<StaticApi, T>: CodecFrom<CC::OriginalResult>,
+        T: TopDecodeMulti,
+        F: FnMut(usize, usize) -> CC,
+    {
+        assert!(page_limit > 0, "page limit must be greater than zero");
+
+        let mut items = Vec::new();
+        let mut cursor = 0;
+        loop {
+            let page: Page<StaticApi, T> = self.quick_query(contract_call_fn(cursor, page_limit));
+            let next_cursor = page.next_cursor;
+            items.extend(page.into_items());
+            match next_cursor {
+                Some(next_cursor) => cursor = next_cursor,
+                None => return items,
+            }
+        }
+    }
+
     /// Adds a SC deploy step, then executes it.
     pub fn sc_deploy<S>(&mut self, mut step: S) -> &mut Self
     where
@@ -0,0 +1,45 @@
+use multiversx_chain_vm::tx_mock::{AccountStorageReport, StorageAccessStats, StorageReport};
+
+use crate::{facade::ScenarioWorld, scenario_model::AddressValue};
+
+/// Storage footprint reports, only available for the contract debugger backend.
+///
+/// Changes are grouped by account, and then by storage mapper base key.
+impl ScenarioWorld {
+    /// Storage size changes caused by the last executed transaction, including its async calls.
+    pub fn last_tx_storage_report(&self) -> &StorageReport {
+        &self.get_debugger_backend().vm_runner.last_storage_report
+    }
+
+    /// Number of storage loads and stores performed by the contract called in the last transaction.
+    pub fn last_tx_storage_access_stats(&self) -> StorageAccessStats {
+        self.get_debugger_backend()
+            .vm_runner
+            .last_storage_access_stats
+    }
+
+    /// Storage size changes caused by all transactions since the start, or since the last reset.
+    pub fn cumulative_storage_report(&self) -> &StorageReport {
+        &self
+            .get_debugger_backend()
+            .vm_runner
+            .cumulative_storage_report
+    }
+
+    pub fn reset_cumulative_storage_report(&mut self) -> &mut Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .cumulative_storage_report = StorageReport::default();
+        self
+    }
+
+    /// Cumulative storage size changes of a single account.
+    pub fn account_storage_report<A>(&self, address: A) -> AccountStorageReport
+    where
+        AddressValue: From<A>,
+    {
+        let address = AddressValue::from(address);
+        self.cumulative_storage_report()
+            .account(&address.to_vm_address())
+    }
+}
@@ -1,5 +1,8 @@
 use multiversx_chain_vm::tx_mock::{TxFunctionName, TxResult};
-use multiversx_sc::contract_base::{CallableContract, ContractBase};
+use multiversx_sc::{
+    contract_base::{CallableContract, ContractBase},
+    types::OperationCompletionStatus,
+};
 
 use crate::{
     debug_executor::contract_instance_wrapped_execution,
@@ -99,6 +102,34 @@ impl ScenarioWorld {
         self
     }
 
+    /// Repeats the same whitebox call until the resumable operation it performs
+    /// (e.g. a storage migration) reports that it has completed.
+    ///
+    /// Panics if the operation is still unfinished after `max_calls` calls.
+    pub fn whitebox_call_until_completed<ContractObj, F>(
+        &mut self,
+        whitebox_contract: &WhiteboxContract<ContractObj>,
+        sc_call_step: ScCallStep,
+        max_calls: usize,
+        f: F,
+    ) -> &mut Self
+    where
+        ContractObj: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        F: Fn(ContractObj) -> OperationCompletionStatus,
+    {
+        for _ in 0..max_calls {
+            let mut status = OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            self.whitebox_call(whitebox_contract, sc_call_step.clone(), |sc| {
+                status = f(sc);
+            });
+            if status == OperationCompletionStatus::Completed {
+                return self;
+            }
+        }
+
+        panic!("operation not completed after {max_calls} calls");
+    }
+
     pub fn whitebox_deploy<ContractObj, F>(
         &mut self,
         whitebox_contract: &WhiteboxContract<ContractObj>,
@@ -124,6 +124,14 @@ impl Account {
         self.esdt.get_mut(token_id).unwrap()
     }
 
+    pub fn storage(mut self, key: &str, value: &str) -> Self {
+        self.storage.insert(
+            BytesKey::interpret_from(key, &InterpreterContext::default()),
+            BytesValue::interpret_from(value, &InterpreterContext::default()),
+        );
+        self
+    }
+
     pub fn code<V>(mut self, code_expr: V) -> Self
     where
         BytesValue: From<V>,
@@ -91,7 +91,9 @@ impl ScCallStep {
     }
 
     pub fn multi_esdt_transfer<T>(mut self, tokens: T) -> Self
-    where T: IntoIterator<Item = TxESDT> {
+    where
+        T: IntoIterator<Item = TxESDT>,
+    {
         if self.tx.egld_value.value > 0u32.into() {
             panic!("Cannot transfer both EGLD and ESDT");
         }
@@ -130,6 +132,15 @@ impl ScCallStep {
         self
     }
 
+    /// Co-signs the transaction with the guardian of the sender.
+    pub fn guardian<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.guardian = Some(AddressValue::from(address));
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "to"
     /// - "function"
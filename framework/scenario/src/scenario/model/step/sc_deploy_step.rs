@@ -95,6 +95,15 @@ impl ScDeployStep {
         self
     }
 
+    /// Co-signs the transaction with the guardian of the sender.
+    pub fn guardian<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.guardian = Some(AddressValue::from(address));
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "function"
     /// - "arguments"
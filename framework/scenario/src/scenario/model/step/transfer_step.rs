@@ -79,4 +79,13 @@ impl TransferStep {
         self.tx.gas_limit = U64Value::from(value);
         self
     }
+
+    /// Co-signs the transaction with the guardian of the sender.
+    pub fn guardian<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.guardian = Some(AddressValue::from(address));
+        self
+    }
 }
@@ -1,9 +1,69 @@
 use crate::scenario_model::BytesValue;
+use multiversx_chain_vm::tx_mock::TxLog;
+use multiversx_sc::{codec::PanicErrorHandler, log_util::ContractEvent};
+use multiversx_sdk::data::transaction::Events;
 
 #[derive(Debug, Clone)]
 pub struct Log {
     pub address: BytesValue,
     pub endpoint: BytesValue,
+    /// The first topic is always the event identifier.
     pub topics: Vec<BytesValue>,
     pub data: BytesValue,
 }
+
+impl Log {
+    /// Decodes the log as an event of type `E`.
+    ///
+    /// Returns `None` if the log has a different identifier.
+    /// Panics if the identifier matches, but the topics or data cannot be decoded.
+    pub fn decode_event<E: ContractEvent>(&self) -> Option<E> {
+        let (identifier, topics) = self.topics.split_first()?;
+        if identifier.value.as_slice() != E::IDENTIFIER {
+            return None;
+        }
+
+        let topics: Vec<Vec<u8>> = topics.iter().map(|topic| topic.value.clone()).collect();
+        let Ok(event) = E::decode_event(topics, self.data.value.as_slice(), PanicErrorHandler);
+        Some(event)
+    }
+}
+
+impl From<TxLog> for Log {
+    fn from(tx_log: TxLog) -> Self {
+        Log {
+            address: tx_log.address.to_vec().into(),
+            endpoint: tx_log.endpoint.into_bytes().into(),
+            topics: tx_log.topics.into_iter().map(BytesValue::from).collect(),
+            data: tx_log.data.into(),
+        }
+    }
+}
+
+impl From<&Events> for Log {
+    /// On the network, the identifier of the log is the endpoint name.
+    /// The topics, including the event identifier, and the data are base64-encoded.
+    fn from(event: &Events) -> Self {
+        let topics = event
+            .topics
+            .iter()
+            .flatten()
+            .map(|topic| decode_base64_or_panic(topic).into())
+            .collect();
+        let data = event
+            .data
+            .as_deref()
+            .map(decode_base64_or_panic)
+            .unwrap_or_default();
+        Log {
+            address: event.address.to_bytes().to_vec().into(),
+            endpoint: event.identifier.as_bytes().into(),
+            topics,
+            data: data.into(),
+        }
+    }
+}
+
+fn decode_base64_or_panic(encoded: &str) -> Vec<u8> {
+    base64::decode(encoded).expect("invalid base64 in transaction log")
+}
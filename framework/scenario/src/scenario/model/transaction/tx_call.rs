@@ -22,6 +22,9 @@ pub struct TxCall {
     pub arguments: Vec<BytesValue>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+
+    /// The guardian co-signing the transaction, only needed for guarded accounts.
+    pub guardian: Option<AddressValue>,
 }
 
 impl Default for TxCall {
@@ -35,6 +38,7 @@ impl Default for TxCall {
             arguments: Default::default(),
             gas_limit: U64Value::from(DEFAULT_GAS_EXPR),
             gas_price: Default::default(),
+            guardian: None,
         }
     }
 }
@@ -58,6 +62,9 @@ impl InterpretableFrom<TxCallRaw> for TxCall {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price, context),
+            guardian: from
+                .guardian
+                .map(|guardian| AddressValue::interpret_from(guardian, context)),
         }
     }
 }
@@ -82,6 +89,7 @@ impl IntoRaw<TxCallRaw> for TxCall {
                 .collect(),
             gas_limit: self.gas_limit.into_raw(),
             gas_price: self.gas_price.into_raw(),
+            guardian: self.guardian.map(|guardian| guardian.into_raw()),
         }
     }
 }
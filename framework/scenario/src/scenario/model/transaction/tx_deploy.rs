@@ -18,6 +18,9 @@ pub struct TxDeploy {
     pub arguments: Vec<BytesValue>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+
+    /// The guardian co-signing the transaction, only needed for guarded accounts.
+    pub guardian: Option<AddressValue>,
 }
 
 impl Default for TxDeploy {
@@ -30,6 +33,7 @@ impl Default for TxDeploy {
             arguments: Default::default(),
             gas_limit: U64Value::from(DEFAULT_GAS_EXPR),
             gas_price: Default::default(),
+            guardian: None,
         }
     }
 }
@@ -48,6 +52,9 @@ impl InterpretableFrom<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price, context),
+            guardian: from
+                .guardian
+                .map(|guardian| AddressValue::interpret_from(guardian, context)),
         }
     }
 }
@@ -66,6 +73,7 @@ impl IntoRaw<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: self.gas_limit.into_raw(),
             gas_price: self.gas_price.into_raw(),
+            guardian: self.guardian.map(|guardian| guardian.into_raw()),
         }
     }
 }
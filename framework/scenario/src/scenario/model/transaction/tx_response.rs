@@ -1,4 +1,4 @@
-use crate::multiversx_sc::types::Address;
+use crate::multiversx_sc::{log_util::ContractEvent, types::Address};
 use multiversx_chain_vm::tx_mock::TxResult;
 use multiversx_sdk::data::transaction::{
     ApiLogs, ApiSmartContractResult, Events, TransactionOnNetwork,
@@ -45,15 +45,22 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            logs: tx_result.result_logs.into_iter().map(Log::from).collect(),
             ..Default::default()
         }
     }
 
     /// Creates a [`TxResponse`] from a [`TransactionOnNetwork`].
     pub fn from_network_tx(tx: TransactionOnNetwork) -> Self {
+        let logs = tx
+            .logs
+            .iter()
+            .flat_map(|api_logs| api_logs.events.iter().map(Log::from))
+            .collect();
         let mut response = Self {
             api_scrs: tx.smart_contract_results.unwrap_or_default(),
             api_logs: tx.logs,
+            logs,
             ..Default::default()
         };
 
@@ -101,6 +108,13 @@ impl TxResponse {
         self.tx_error.is_success()
     }
 
+    /// Decodes all events of a given type, in the order in which they were logged.
+    ///
+    /// Works the same for debugger runs and for transactions on the network.
+    pub fn events<E: ContractEvent>(&self) -> Vec<E> {
+        self.logs.iter().filter_map(Log::decode_event).collect()
+    }
+
     fn process_signal_error(&self) -> TxResponseStatus {
         if let Some(event) = self.find_log(LOG_IDENTIFIER_SIGNAL_ERROR) {
             let topics = event.topics.as_ref();
@@ -207,8 +221,59 @@ impl TxResponse {
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        multiversx_sc::{
+            codec::{DecodeErrorHandler, TopDecodeInput, TopDecodeMulti, TopDecodeMultiInput},
+            log_util::ContractEvent,
+            types::Address,
+        },
+        scenario_model::TxResponse,
+    };
     use multiversx_sdk::data::transaction::{TransactionInfo, TransactionOnNetwork};
-    use crate::scenario_model::TxResponse;
+
+    /// The `swap` event logged by the DEX pair contract, with the data left undecoded.
+    struct SwapEvent {
+        token_in: Vec<u8>,
+        token_out: Vec<u8>,
+        _caller: Address,
+        epoch: u64,
+    }
+
+    impl ContractEvent for SwapEvent {
+        const IDENTIFIER: &'static [u8] = b"swap";
+
+        fn decode_event<I, D, H>(mut topics: I, _data: D, h: H) -> Result<Self, H::HandledErr>
+        where
+            I: TopDecodeMultiInput,
+            D: TopDecodeInput,
+            H: DecodeErrorHandler,
+        {
+            let event = SwapEvent {
+                token_in: Vec::<u8>::multi_decode_or_handle_err(&mut topics, h)?,
+                token_out: Vec::<u8>::multi_decode_or_handle_err(&mut topics, h)?,
+                _caller: Address::multi_decode_or_handle_err(&mut topics, h)?,
+                epoch: u64::multi_decode_or_handle_err(&mut topics, h)?,
+            };
+            topics.assert_no_more_args(h)?;
+            Ok(event)
+        }
+    }
+
+    /// Not present in the transaction.
+    struct OtherEvent;
+
+    impl ContractEvent for OtherEvent {
+        const IDENTIFIER: &'static [u8] = b"other";
+
+        fn decode_event<I, D, H>(_topics: I, _data: D, _h: H) -> Result<Self, H::HandledErr>
+        where
+            I: TopDecodeMultiInput,
+            D: TopDecodeInput,
+            H: DecodeErrorHandler,
+        {
+            Ok(OtherEvent)
+        }
+    }
 
     #[test]
     fn test_with_tx_that_has_sc_result() {
@@ -463,7 +528,14 @@ mod tests {
             hex::decode("10").unwrap()
         ];
 
-        assert_eq!(tx_response.out, expected)
+        assert_eq!(tx_response.out, expected);
+
+        let swap_events = tx_response.events::<SwapEvent>();
+        assert_eq!(swap_events.len(), 1);
+        assert_eq!(swap_events[0].token_in, b"HTM-fe1f69");
+        assert_eq!(swap_events[0].token_out, b"WEGLD-d7c6bb");
+        assert_eq!(swap_events[0].epoch, 6333);
+        assert!(tx_response.events::<OtherEvent>().is_empty());
     }
 
     #[test]
@@ -16,6 +16,9 @@ pub struct TxTransfer {
     pub esdt_value: Vec<TxESDT>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+
+    /// The guardian co-signing the transaction, only needed for guarded accounts.
+    pub guardian: Option<AddressValue>,
 }
 
 impl InterpretableFrom<TxTransferRaw> for TxTransfer {
@@ -31,6 +34,9 @@ impl InterpretableFrom<TxTransferRaw> for TxTransfer {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit.unwrap_or_default(), context),
             gas_price: U64Value::interpret_from(from.gas_price.unwrap_or_default(), context),
+            guardian: from
+                .guardian
+                .map(|guardian| AddressValue::interpret_from(guardian, context)),
         }
     }
 }
@@ -49,6 +55,7 @@ impl IntoRaw<TxTransferRaw> for TxTransfer {
                 .collect(),
             gas_limit: self.gas_limit.into_raw_opt(),
             gas_price: self.gas_price.into_raw_opt(),
+            guardian: self.guardian.map(|guardian| guardian.into_raw()),
         }
     }
 }
@@ -65,6 +72,7 @@ impl TxTransfer {
             arguments: Vec::new(),
             gas_limit: self.gas_limit.clone(),
             gas_price: self.gas_price.clone(),
+            guardian: self.guardian.clone(),
         }
     }
 }
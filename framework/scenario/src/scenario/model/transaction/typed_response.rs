@@ -1,5 +1,8 @@
 use super::{Log, TxResponse, TxResponseStatus};
-use multiversx_sc::codec::{PanicErrorHandler, TopDecodeMulti};
+use multiversx_sc::{
+    codec::{PanicErrorHandler, TopDecodeMulti},
+    log_util::ContractEvent,
+};
 
 pub struct TypedResponse<T>
 where
@@ -33,4 +36,9 @@ where
             refund: raw_response.refund,
         }
     }
+
+    /// Decodes all events of a given type, in the order in which they were logged.
+    pub fn events<E: ContractEvent>(&self) -> Vec<E> {
+        self.logs.iter().filter_map(Log::decode_event).collect()
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     multiversx_sc::codec::{CodecFrom, PanicErrorHandler, TopEncodeMulti},
-    scenario::model::{ScCallStep, TxESDT, TypedScCall},
+    scenario::model::{AddressValue, ScCallStep, TxESDT, TypedScCall},
     scenario_model::TxResponse,
 };
 
@@ -54,11 +54,13 @@ impl ScenarioVMRunner {
             .state
             .increase_account_nonce(&tx_input.from);
 
-        self.blockchain_mock.vm.sc_call_with_async_and_callback(
+        let tx_result = self.blockchain_mock.vm.sc_call_with_async_and_callback(
             tx_input,
             &mut self.blockchain_mock.state,
             f,
-        )
+        );
+        self.record_tx_result(&tx_result);
+        tx_result
     }
 
     pub fn perform_sc_call_lambda_and_check<F>(
@@ -93,6 +95,7 @@ fn tx_input_from_call(sc_call_step: &ScCallStep) -> TxInput {
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash(&sc_call_step.id, &sc_call_step.explicit_tx_hash),
+        guardian: tx.guardian.as_ref().map(AddressValue::to_vm_address),
         ..Default::default()
     }
 }
@@ -1,5 +1,7 @@
 use crate::{
-    multiversx_sc::types::heap::Address, scenario::model::ScDeployStep, scenario_model::TxResponse,
+    multiversx_sc::types::heap::Address,
+    scenario::model::{AddressValue, ScDeployStep},
+    scenario_model::TxResponse,
 };
 
 use multiversx_chain_vm::{
@@ -41,6 +43,7 @@ impl ScenarioVMRunner {
             tx_result.pending_calls.no_calls(),
             "Async calls from constructors are currently not supported"
         );
+        self.record_tx_result(&tx_result);
         (new_address.as_array().into(), tx_result)
     }
 
@@ -76,6 +79,7 @@ fn tx_input_from_deploy(sc_deploy_step: &ScDeployStep) -> TxInput {
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash(&sc_deploy_step.id, &sc_deploy_step.explicit_tx_hash),
+        guardian: tx.guardian.as_ref().map(AddressValue::to_vm_address),
         ..Default::default()
     }
 }
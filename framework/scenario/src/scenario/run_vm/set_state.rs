@@ -3,8 +3,8 @@ use crate::scenario::model::SetStateStep;
 use multiversx_chain_vm::{
     types::VMAddress,
     world_mock::{
-        AccountData, AccountEsdt, BlockInfo as CrateBlockInfo, BlockchainState, EsdtData,
-        EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles,
+        AccountData, AccountEsdt, AccountGuardians, BlockInfo as CrateBlockInfo, BlockchainState,
+        EsdtData, EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles,
     },
 };
 
@@ -63,6 +63,7 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
                 .as_ref()
                 .map(|rewards| rewards.value.clone())
                 .unwrap_or_default(),
+            guardians: AccountGuardians::default(),
         });
     }
     for new_address in set_state_step.new_addresses.iter() {
@@ -1,19 +1,20 @@
 use super::{sc_call::tx_esdt_transfers_from_scenario, ScenarioVMRunner};
-use crate::scenario::model::{TransferStep, TxTransfer, ValidatorRewardStep};
+use crate::scenario::model::{AddressValue, TransferStep, TxTransfer, ValidatorRewardStep};
 use multiversx_chain_vm::{
     tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
-    tx_mock::{TxFunctionName, TxInput},
+    tx_mock::{TxFunctionName, TxInput, TxResult},
     with_shared::Shareable,
     world_mock::BlockchainState,
 };
 
 impl ScenarioVMRunner {
     pub fn perform_transfer(&mut self, transfer_step: &TransferStep) {
-        execute(
+        let tx_result = execute(
             self.blockchain_mock.vm.clone(),
             &mut self.blockchain_mock.state,
             &transfer_step.tx,
         );
+        self.record_tx_result(&tx_result);
     }
 
     pub fn perform_validator_reward(&mut self, validator_rewards_step: &ValidatorRewardStep) {
@@ -34,11 +35,19 @@ fn tx_input_from_transfer(tx_transfer: &TxTransfer) -> TxInput {
         args: Vec::new(),
         gas_limit: tx_transfer.gas_limit.value,
         gas_price: tx_transfer.gas_price.value,
+        guardian: tx_transfer
+            .guardian
+            .as_ref()
+            .map(AddressValue::to_vm_address),
         ..Default::default()
     }
 }
 
-fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_transfer: &TxTransfer) {
+fn execute(
+    vm: BlockchainVMRef,
+    state: &mut Shareable<BlockchainState>,
+    tx_transfer: &TxTransfer,
+) -> TxResult {
     let tx_input = tx_input_from_transfer(tx_transfer);
 
     // nonce gets increased irrespective of whether the tx fails or not
@@ -46,4 +55,5 @@ fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_trans
 
     let tx_result = vm.execute_sc_call_lambda(tx_input, state, execute_current_tx_context_input);
     tx_result.assert_ok();
+    tx_result
 }
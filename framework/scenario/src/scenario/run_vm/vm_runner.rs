@@ -1,6 +1,9 @@
 use crate::{
     debug_executor::ContractMapRef,
-    multiversx_chain_vm::BlockchainMock,
+    multiversx_chain_vm::{
+        tx_mock::{AsyncCallNode, ManagedTypesStats, StorageAccessStats, StorageReport, TxResult},
+        BlockchainMock,
+    },
     scenario::{model::*, ScenarioRunner},
 };
 
@@ -10,6 +13,21 @@ use crate::{
 pub struct ScenarioVMRunner {
     pub contract_map_ref: ContractMapRef,
     pub blockchain_mock: BlockchainMock,
+
+    /// Storage size changes of the last executed transaction.
+    pub last_storage_report: StorageReport,
+
+    /// Storage size changes accumulated over all transactions, since the last reset.
+    pub cumulative_storage_report: StorageReport,
+
+    /// Managed memory usage of the last executed transaction.
+    pub last_managed_types_stats: ManagedTypesStats,
+
+    /// Storage loads and stores of the last executed transaction.
+    pub last_storage_access_stats: StorageAccessStats,
+
+    /// Async calls and promises executed by the last transaction.
+    pub last_async_call_tree: Vec<AsyncCallNode>,
 }
 
 impl ScenarioVMRunner {
@@ -19,8 +37,23 @@ impl ScenarioVMRunner {
         ScenarioVMRunner {
             contract_map_ref,
             blockchain_mock,
+            last_storage_report: StorageReport::default(),
+            cumulative_storage_report: StorageReport::default(),
+            last_managed_types_stats: ManagedTypesStats::default(),
+            last_storage_access_stats: StorageAccessStats::default(),
+            last_async_call_tree: Vec::new(),
         }
     }
+
+    /// Keeps the reports of the last transaction around, for inspection in tests.
+    pub(super) fn record_tx_result(&mut self, tx_result: &TxResult) {
+        self.cumulative_storage_report
+            .merge(&tx_result.storage_report);
+        self.last_storage_report = tx_result.storage_report.clone();
+        self.last_managed_types_stats = tx_result.managed_types_stats;
+        self.last_storage_access_stats = tx_result.storage_access_stats;
+        self.last_async_call_tree = tx_result.async_call_tree.clone();
+    }
 }
 
 impl ScenarioRunner for ScenarioVMRunner {
@@ -3,7 +3,7 @@
 mod address_factory;
 mod contract_obj_wrapper;
 mod mandos_generator;
-mod raw_converter;
+pub(crate) mod raw_converter;
 mod tx_mandos;
 
 use address_factory::*;
@@ -9,7 +9,6 @@ use crate::{
         types::{heap::Address, EsdtLocalRole},
     },
     scenario_model::{Account, BytesValue, ScCallStep, SetStateStep},
-    testing_framework::raw_converter::bytes_to_hex,
     ScenarioWorld,
 };
 use multiversx_chain_scenario_format::interpret_trait::InterpretableFrom;
@@ -762,32 +761,18 @@ impl BlockchainStateWrapper {
         token_id: &[u8],
         token_nonce: u64,
     ) -> Option<T> {
-        match self.world.get_state().accounts.get(&to_vm_address(address)) {
-            Some(acc) => match acc.esdt.get_by_identifier(token_id) {
-                Some(esdt_data) => esdt_data
-                    .instances
-                    .get_by_nonce(token_nonce)
-                    .map(|inst| T::top_decode(inst.metadata.attributes.clone()).unwrap()),
-                None => None,
-            },
-            None => panic!(
-                "get_nft_attributes: Account {:?} does not exist",
-                address_to_hex(address)
-            ),
-        }
+        self.world
+            .get_nft_attributes(address, token_id, token_nonce)
     }
 
     pub fn dump_state(&self) {
-        for addr in self.world.get_state().accounts.keys() {
-            self.dump_state_for_account_hex_attributes(&to_framework_address(addr));
-            println!();
-        }
+        self.world.dump_state();
     }
 
     #[inline]
     /// Prints the state for the account, with any token attributes as hex
     pub fn dump_state_for_account_hex_attributes(&self, address: &Address) {
-        self.dump_state_for_account::<Vec<u8>>(address)
+        self.world.dump_state_for_account_hex_attributes(address);
     }
 
     /// Prints the state for the account, with token attributes decoded as the provided type, if possible
@@ -795,60 +780,8 @@ impl BlockchainStateWrapper {
         &self,
         address: &Address,
     ) {
-        let vm_address = to_vm_address(address);
-        let account = match self.world.get_state().accounts.get(&vm_address) {
-            Some(acc) => acc,
-            None => panic!(
-                "dump_state_for_account: Account {:?} does not exist",
-                address_to_hex(address)
-            ),
-        };
-
-        println!("State for account: {:?}", address_to_hex(address));
-        println!("EGLD: {}", account.egld_balance);
-
-        if !account.esdt.is_empty() {
-            println!("ESDT Tokens:");
-        }
-        for (token_id, acc_esdt) in account.esdt.iter() {
-            let token_id_str = String::from_utf8(token_id.to_vec()).unwrap();
-            println!("  Token: {token_id_str}");
-
-            for (token_nonce, instance) in acc_esdt.instances.get_instances() {
-                if std::any::TypeId::of::<AttributesType>() == std::any::TypeId::of::<Vec<u8>>() {
-                    print_token_balance_raw(
-                        *token_nonce,
-                        &instance.balance,
-                        &instance.metadata.attributes,
-                    );
-                } else {
-                    match AttributesType::top_decode(&instance.metadata.attributes[..]) {
-                        core::result::Result::Ok(attr) => {
-                            print_token_balance_specialized(*token_nonce, &instance.balance, &attr)
-                        },
-                        core::result::Result::Err(_) => print_token_balance_raw(
-                            *token_nonce,
-                            &instance.balance,
-                            &instance.metadata.attributes,
-                        ),
-                    }
-                }
-            }
-        }
-
-        if !account.storage.is_empty() {
-            println!();
-            println!("Storage: ");
-        }
-        for (key, value) in &account.storage {
-            let key_str = match String::from_utf8(key.to_vec()) {
-                core::result::Result::Ok(s) => s,
-                core::result::Result::Err(_) => bytes_to_hex(key),
-            };
-            let value_str = bytes_to_hex(value);
-
-            println!("  {key_str}: {value_str}");
-        }
+        self.world
+            .dump_state_for_account::<AttributesType, _>(address);
     }
 }
 
@@ -865,27 +798,6 @@ fn serialize_attributes<T: TopEncode>(attributes: &T) -> Vec<u8> {
     serialized_attributes
 }
 
-fn print_token_balance_raw(
-    token_nonce: u64,
-    token_balance: &num_bigint::BigUint,
-    attributes: &[u8],
-) {
-    println!(
-        "      Nonce {}, balance: {}, attributes: {}",
-        token_nonce,
-        token_balance,
-        bytes_to_hex(attributes)
-    );
-}
-
-fn print_token_balance_specialized<T: core::fmt::Debug>(
-    token_nonce: u64,
-    token_balance: &num_bigint::BigUint,
-    attributes: &T,
-) {
-    println!("      Nonce {token_nonce}, balance: {token_balance}, attributes: {attributes:?}");
-}
-
 fn create_contract_obj_box<CB, ContractObjBuilder>(
     func: ContractObjBuilder,
 ) -> Box<dyn CallableContract>
@@ -900,7 +812,3 @@ where
 fn to_vm_address(address: &Address) -> VMAddress {
     address.as_array().into()
 }
-
-fn to_framework_address(vm_address: &VMAddress) -> Address {
-    vm_address.as_array().into()
-}
@@ -0,0 +1,112 @@
+#![allow(clippy::excessive_precision)]
+
+use multiversx_sc::types::BigFloat;
+use multiversx_sc_scenario::api::StaticApi;
+
+// Reference values computed with arbitrary precision arithmetic, rounded to 18 decimals,
+// even if f64 cannot represent all of the digits.
+// The Rust VM backs BigFloat with f64, so results are compared with a relative tolerance.
+const TOLERANCE: f64 = 1e-13;
+
+fn big_float(numerator: i64, denominator: i64) -> BigFloat<StaticApi> {
+    BigFloat::from_frac(numerator, denominator)
+}
+
+fn assert_close(actual: BigFloat<StaticApi>, expected: f64) {
+    // keeps the fixed point representation within i64 range
+    let integer_digits = expected.abs().log10().ceil().max(0.0) as i32;
+    let scale = BigFloat::<StaticApi>::from(10).pow(15 - integer_digits);
+    let actual_f64 =
+        actual.to_fixed_point(&scale).to_i64().unwrap() as f64 / 10f64.powi(15 - integer_digits);
+    let diff = (actual_f64 - expected).abs();
+    assert!(
+        diff <= TOLERANCE * expected.abs().max(1.0),
+        "expected {expected}, got {actual_f64}"
+    );
+}
+
+#[test]
+fn test_big_float_ln() {
+    assert_close(big_float(1, 1).ln(), 0.0);
+    assert_close(big_float(2, 1).ln(), 0.693147180559945309);
+    assert_close(big_float(10, 1).ln(), 2.302585092994045684);
+    assert_close(big_float(1, 3).ln(), -1.098612288668109691);
+    assert_close(big_float(1_000_000, 1).ln(), 13.815510557964274104);
+    assert_close(big_float(27_182_818, 10_000_000).ln(), 0.999999989530502358);
+}
+
+#[test]
+fn test_big_float_ln_extreme_magnitudes() {
+    assert_close(big_float(10, 1).pow(300).ln(), 690.775527898213705205);
+    assert_close(big_float(1, 10).pow(300).ln(), -690.775527898213705205);
+    assert_close(big_float(2, 1).pow(1000).log2(), 1000.0);
+    assert_close(big_float(1, 2).pow(1000).log2(), -1000.0);
+}
+
+#[test]
+fn test_big_float_log2() {
+    assert_close(big_float(1024, 1).log2(), 10.0);
+    assert_close(big_float(1, 8).log2(), -3.0);
+    assert_close(big_float(3, 1).log2(), 1.584962500721156181);
+    assert_close(big_float(1_000, 1).log2(), 9.965784284662087044);
+}
+
+#[test]
+fn test_big_float_exp() {
+    assert_close(big_float(0, 1).exp(), 1.0);
+    assert_close(big_float(1, 1).exp(), 2.718281828459045235);
+    assert_close(big_float(-1, 1).exp(), 0.367879441171442322);
+    assert_close(big_float(5, 2).exp(), 12.182493960703473438);
+    assert_close(big_float(20, 1).exp(), 485_165_195.409790277969106830);
+    assert_close(big_float(-20, 1).exp(), 0.000000002061153622);
+}
+
+#[test]
+fn test_big_float_pow_frac() {
+    assert_close(
+        big_float(2, 1).pow_frac(&big_float(1, 2)),
+        1.414213562373095049,
+    );
+    assert_close(
+        big_float(10, 1).pow_frac(&big_float(3, 2)),
+        31.622776601683793320,
+    );
+    assert_close(
+        big_float(5, 4).pow_frac(&big_float(-7, 3)),
+        0.594123370702435698,
+    );
+    assert_close(big_float(0, 1).pow_frac(&big_float(1, 2)), 0.0);
+    assert_close(big_float(0, 1).pow_frac(&big_float(0, 1)), 1.0);
+}
+
+#[test]
+fn test_big_float_nth_root() {
+    assert_close(big_float(27, 1).nth_root(3), 3.0);
+    assert_close(big_float(-27, 1).nth_root(3), -3.0);
+    assert_close(big_float(2, 1).nth_root(2), 1.414213562373095049);
+    assert_close(big_float(100, 1).nth_root(7), 1.930697728883250167);
+    assert_close(big_float(1, 1000).nth_root(3), 0.1);
+    assert_close(big_float(5, 1).nth_root(1), 5.0);
+}
+
+#[test]
+fn test_big_float_precision() {
+    // lower precision stops the series earlier, giving a coarser approximation
+    let low = big_float(2, 1).ln_with_precision(3);
+    let diff = (&low - &big_float(2, 1).ln()).magnitude();
+    assert!(diff > 0);
+    assert!(diff < big_float(1, 100));
+    assert_close(big_float(2, 1).ln_with_precision(15), 0.693147180559945309);
+}
+
+#[test]
+#[should_panic]
+fn test_big_float_ln_non_positive() {
+    let _ = big_float(0, 1).ln();
+}
+
+#[test]
+#[should_panic]
+fn test_big_float_even_root_negative() {
+    let _ = big_float(-4, 1).nth_root(2);
+}
@@ -0,0 +1,146 @@
+use multiversx_sc::{
+    codec::test_util::{check_dep_encode_decode, check_top_encode_decode},
+    formatter::{FormatBuffer, SCDisplay},
+    types::{
+        BigFloat, BigUint, ConstDecimals, ManagedBufferCachedBuilder, ManagedDecimal, NumDecimals,
+        RoundingMode,
+    },
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn decimal_const<const N: usize>(raw: u64) -> ManagedDecimal<StaticApi, ConstDecimals<N>> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw), ConstDecimals)
+}
+
+fn decimal_var(raw: u64, num_decimals: NumDecimals) -> ManagedDecimal<StaticApi, NumDecimals> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw), num_decimals)
+}
+
+fn to_display_string<T: SCDisplay>(item: &T) -> String {
+    let mut builder = ManagedBufferCachedBuilder::<StaticApi>::default();
+    builder.append_display(item);
+    String::from_utf8(builder.into_managed_buffer().to_vec()).unwrap()
+}
+
+#[test]
+fn test_managed_decimal_operators() {
+    let a = decimal_const::<2>(125); // 1.25
+    let b = decimal_const::<2>(250); // 2.50
+
+    assert_eq!(&a + &b, decimal_const::<2>(375));
+    assert_eq!(&b - &a, decimal_const::<2>(125));
+    assert_eq!(&a * &b, decimal_const::<2>(312)); // 3.125, truncated
+    assert_eq!(&b / &a, decimal_const::<2>(200));
+    assert_eq!(&a / &b, decimal_const::<2>(50));
+
+    let mut c = a.clone();
+    c += &b;
+    c -= a;
+    assert_eq!(c, b);
+}
+
+#[test]
+fn test_managed_decimal_checked() {
+    let a = decimal_var(125, 2);
+    let b = decimal_var(250, 2);
+    let other_scale = decimal_var(250, 3);
+    let zero = decimal_var(0, 2);
+
+    assert_eq!(a.checked_add(&b), Some(decimal_var(375, 2)));
+    assert_eq!(a.checked_add(&other_scale), None);
+
+    assert_eq!(b.checked_sub(&a), Some(decimal_var(125, 2)));
+    assert_eq!(a.checked_sub(&b), None);
+
+    assert_eq!(a.checked_mul(&b), Some(decimal_var(312, 2)));
+    assert_eq!(
+        a.checked_mul_with_rounding(&b, RoundingMode::HalfUp),
+        Some(decimal_var(313, 2))
+    );
+    assert_eq!(a.checked_mul(&other_scale), None);
+
+    assert_eq!(a.checked_div(&zero), None);
+    assert_eq!(
+        decimal_var(100, 2).checked_div_with_rounding(&decimal_var(300, 2), RoundingMode::Up),
+        Some(decimal_var(34, 2))
+    );
+}
+
+#[test]
+fn test_managed_decimal_rescale() {
+    let value = decimal_const::<4>(12_345); // 1.2345
+
+    assert_eq!(value.rescale(6usize), decimal_var(1_234_500, 6));
+    assert_eq!(value.rescale(2usize), decimal_var(123, 2));
+    assert_eq!(
+        value.rescale_with_rounding(2usize, RoundingMode::Up),
+        decimal_var(124, 2)
+    );
+    assert_eq!(
+        value.rescale_with_rounding(ConstDecimals::<3>, RoundingMode::HalfUp),
+        decimal_const::<3>(1_235)
+    );
+    assert_eq!(value.rescale(0usize).into_raw_units(), BigUint::from(1u32));
+
+    // values with different scales compare by their actual value
+    assert_eq!(decimal_var(5, 1), decimal_var(500, 3));
+    assert!(decimal_var(5, 1) < decimal_var(501, 3));
+}
+
+#[test]
+fn test_managed_decimal_rounding() {
+    let round = |raw: u64, mode: RoundingMode| decimal_var(raw, 1).round(mode).to_u64().unwrap();
+
+    assert_eq!(round(25, RoundingMode::Down), 2);
+    assert_eq!(round(25, RoundingMode::Up), 3);
+    assert_eq!(round(25, RoundingMode::HalfUp), 3);
+    assert_eq!(round(25, RoundingMode::HalfEven), 2);
+    assert_eq!(round(35, RoundingMode::HalfEven), 4);
+    assert_eq!(round(24, RoundingMode::HalfUp), 2);
+    assert_eq!(round(26, RoundingMode::HalfEven), 3);
+    assert_eq!(round(20, RoundingMode::Up), 2);
+}
+
+#[test]
+fn test_managed_decimal_conversions() {
+    let from_int = ManagedDecimal::<StaticApi, ConstDecimals<3>>::from(BigUint::from(7u32));
+    assert_eq!(from_int.raw_units(), &BigUint::from(7_000u32));
+    assert_eq!(from_int.trunc(), BigUint::from(7u32));
+
+    let float = BigFloat::<StaticApi>::from_frac(5, 4); // 1.25
+    assert_eq!(
+        ManagedDecimal::from_big_float(&float, 1usize),
+        decimal_var(12, 1)
+    );
+    assert_eq!(
+        ManagedDecimal::from_big_float_with_rounding(&float, 1usize, RoundingMode::HalfUp),
+        decimal_var(13, 1)
+    );
+    assert_eq!(
+        ManagedDecimal::from_big_float_with_rounding(&float, 1usize, RoundingMode::HalfEven),
+        decimal_var(12, 1)
+    );
+
+    assert_eq!(decimal_var(125, 2).to_big_float(), float);
+    assert_eq!(
+        BigFloat::from(decimal_const::<2>(50)),
+        BigFloat::from_frac(1, 2)
+    );
+}
+
+#[test]
+fn test_managed_decimal_codec() {
+    check_top_encode_decode(decimal_const::<2>(0x0102), &[1, 2]);
+    check_dep_encode_decode(decimal_const::<2>(0x0102), &[0, 0, 0, 2, 1, 2]);
+
+    check_top_encode_decode(decimal_var(0x0102, 18), &[0, 0, 0, 2, 1, 2, 0, 0, 0, 18]);
+    check_dep_encode_decode(decimal_var(0x0102, 18), &[0, 0, 0, 2, 1, 2, 0, 0, 0, 18]);
+}
+
+#[test]
+fn test_managed_decimal_display() {
+    assert_eq!(to_display_string(&decimal_const::<3>(12_050)), "12.050");
+    assert_eq!(to_display_string(&decimal_const::<3>(5)), "0.005");
+    assert_eq!(to_display_string(&decimal_var(123, 0)), "123");
+    assert_eq!(to_display_string(&decimal_var(0, 2)), "0.00");
+}
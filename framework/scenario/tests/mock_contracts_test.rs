@@ -0,0 +1,125 @@
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{
+        tx_mock::{TxCache, TxInput, TxResult},
+        world_mock::EsdtInstanceMetadata,
+    },
+    num_bigint::BigUint,
+    scenario_model::{Account, CheckAccount, CheckStateStep, ScCallStep, SetStateStep, TxExpect},
+    ScenarioWorld,
+};
+
+const USER_ADDRESS_EXPR: &str = "address:user";
+const DELEGATION_ADDRESS_EXPR: &str = "sc:delegation";
+const CREDIT_TOKEN_ID_EXPR: &str = "str:CREDIT-123456";
+
+/// Stands in for a delegation system contract, only keeping track of the total amount delegated.
+fn delegation_mock(tx_input: &TxInput, tx_cache: &TxCache) -> TxResult {
+    match tx_input.func_name.as_str() {
+        "delegate" => {
+            if tx_input.egld_value == BigUint::from(0u32) {
+                return TxResult::from_vm_error("delegate value must be positive");
+            }
+            tx_cache.with_account_mut(&tx_input.to, |account| {
+                let total = account
+                    .storage
+                    .get(b"total".as_slice())
+                    .map(|bytes| BigUint::from_bytes_be(bytes))
+                    .unwrap_or_default();
+                let new_total = total + &tx_input.egld_value;
+                account
+                    .storage
+                    .insert(b"total".to_vec(), new_total.to_bytes_be());
+            });
+            TxResult::empty()
+        },
+        "fail" => TxResult::from_vm_error("delegation failed"),
+        _ => TxResult::from_vm_error("invalid function (not found)"),
+    }
+}
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world
+        .register_mock_contract(DELEGATION_ADDRESS_EXPR, delegation_mock)
+        .register_builtin_function("ClaimCredits", |tx_input: &TxInput, tx_cache: &TxCache| {
+            let amount = BigUint::from_bytes_be(&tx_input.args[0]);
+            tx_cache.increase_esdt_balance(
+                &tx_input.from,
+                b"CREDIT-123456",
+                0,
+                &amount,
+                EsdtInstanceMetadata::default(),
+            );
+            TxResult::empty()
+        });
+    world
+}
+
+#[test]
+fn mock_contract_test() {
+    let mut world = world();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1).balance(1_000u64)),
+    );
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .to(DELEGATION_ADDRESS_EXPR)
+            .function("delegate")
+            .egld_value(300u64)
+            .expect(TxExpect::ok().no_result()),
+    );
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .to(DELEGATION_ADDRESS_EXPR)
+            .function("delegate")
+            .egld_value(200u64)
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    // changes are reverted if the handler fails, including the value transfer
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .to(DELEGATION_ADDRESS_EXPR)
+            .function("fail")
+            .egld_value(100u64)
+            .expect(TxExpect::err(10, "str:delegation failed")),
+    );
+
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, CheckAccount::new().balance(500u64))
+            .put_account(
+                DELEGATION_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .balance(500u64)
+                    .check_storage("str:total", "500"),
+            ),
+    );
+}
+
+#[test]
+fn custom_builtin_function_test() {
+    let mut world = world();
+    world.set_state_step(
+        SetStateStep::new().put_account(USER_ADDRESS_EXPR, Account::new().nonce(1)),
+    );
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(USER_ADDRESS_EXPR)
+            .to(USER_ADDRESS_EXPR)
+            .function("ClaimCredits")
+            .argument("25")
+            .expect(TxExpect::ok().no_result()),
+    );
+
+    world.check_state_step(CheckStateStep::new().put_account(
+        USER_ADDRESS_EXPR,
+        CheckAccount::new().esdt_balance(CREDIT_TOKEN_ID_EXPR, 25u64),
+    ));
+}
@@ -170,3 +170,23 @@ fn transfer_esdt_rs() {
 fn validator_reward_rs() {
     world().run("tests/scenarios-self/validatorReward.scen.json");
 }
+
+#[test]
+fn builtin_func_save_key_value_rs() {
+    world().run("tests/scenarios-self/builtin-func-save-key-value.scen.json");
+}
+
+#[test]
+fn builtin_func_esdt_freeze_wipe_rs() {
+    world().run("tests/scenarios-self/builtin-func-esdt-freeze-wipe.scen.json");
+}
+
+#[test]
+fn builtin_func_esdt_limited_transfer_rs() {
+    world().run("tests/scenarios-self/builtin-func-esdt-limited-transfer.scen.json");
+}
+
+#[test]
+fn builtin_func_guardian_rs() {
+    world().run("tests/scenarios-self/builtin-func-guardian.scen.json");
+}
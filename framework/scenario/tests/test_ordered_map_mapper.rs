@@ -0,0 +1,143 @@
+use multiversx_sc::storage::{
+    mappers::{OrderedMapMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use multiversx_sc_scenario::api::SingleTxApi;
+
+fn create_map() -> OrderedMapMapper<SingleTxApi, u64, u64> {
+    let base_key = StorageKey::new(&b"my_ordered_map"[..]);
+    OrderedMapMapper::new(base_key)
+}
+
+fn check_map(map: &OrderedMapMapper<SingleTxApi, u64, u64>, expected_keys: Vec<u64>) {
+    assert_eq!(map.len(), expected_keys.len());
+    let keys: Vec<u64> = map.keys().collect();
+    assert_eq!(keys, expected_keys);
+    assert!(map.check_internal_consistency());
+}
+
+#[test]
+fn test_ordered_map_simple() {
+    let mut map = create_map();
+    assert!(map.check_internal_consistency());
+    assert_eq!(map.len(), 0);
+    assert_eq!(map.get(&42), None);
+    assert_eq!(map.insert(42, 142), None);
+    assert_eq!(map.len(), 1);
+    assert!(map.contains_key(&42));
+    assert!(!map.contains_key(&50));
+    assert_eq!(map.insert(42, 242), Some(142));
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&42), Some(242));
+    assert!(map.check_internal_consistency());
+}
+
+#[test]
+fn test_ordered_map_sorted() {
+    let mut map = create_map();
+    for key in [50, 20, 80, 10, 30, 70, 90, 60, 40] {
+        map.insert(key, key + 100);
+        assert!(map.check_internal_consistency());
+    }
+    check_map(&map, vec![10, 20, 30, 40, 50, 60, 70, 80, 90]);
+    let values: Vec<u64> = map.values().collect();
+    assert_eq!(values, vec![110, 120, 130, 140, 150, 160, 170, 180, 190]);
+    assert_eq!(map.first(), Some((10, 110)));
+    assert_eq!(map.last(), Some((90, 190)));
+}
+
+#[test]
+fn test_ordered_map_sequential_inserts_stay_balanced() {
+    let mut map = create_map();
+    for key in 0..100 {
+        map.insert(key, key);
+    }
+    check_map(&map, (0..100).collect());
+    for key in (0..100).rev() {
+        assert_eq!(map.remove(&key), Some(key));
+        assert!(map.check_internal_consistency());
+    }
+    check_map(&map, vec![]);
+}
+
+#[test]
+fn test_ordered_map_remove() {
+    let mut map = create_map();
+    for key in 1..=15 {
+        map.insert(key * 10, key);
+    }
+    assert_eq!(map.remove(&80), Some(8));
+    assert_eq!(map.remove(&80), None);
+    assert_eq!(map.remove(&85), None);
+    assert_eq!(map.remove(&10), Some(1));
+    assert_eq!(map.remove(&150), Some(15));
+    assert_eq!(map.remove(&40), Some(4));
+    check_map(&map, vec![20, 30, 50, 60, 70, 90, 100, 110, 120, 130, 140]);
+    assert_eq!(map.get(&100), Some(10));
+}
+
+#[test]
+fn test_ordered_map_pop() {
+    let mut map = create_map();
+    assert_eq!(map.pop_first(), None);
+    assert_eq!(map.pop_last(), None);
+    for key in [3, 1, 4, 5, 9, 2, 6] {
+        map.insert(key, key * 2);
+    }
+    assert_eq!(map.pop_first(), Some((1, 2)));
+    check_map(&map, vec![2, 3, 4, 5, 6, 9]);
+    assert_eq!(map.pop_last(), Some((9, 18)));
+    check_map(&map, vec![2, 3, 4, 5, 6]);
+    assert_eq!(map.pop_first(), Some((2, 4)));
+    assert_eq!(map.pop_first(), Some((3, 6)));
+    check_map(&map, vec![4, 5, 6]);
+}
+
+#[test]
+fn test_ordered_map_bounds() {
+    let mut map = create_map();
+    for key in [10, 20, 30] {
+        map.insert(key, key + 1);
+    }
+    assert_eq!(map.lower_bound(&5), Some((10, 11)));
+    assert_eq!(map.lower_bound(&20), Some((20, 21)));
+    assert_eq!(map.lower_bound(&25), Some((30, 31)));
+    assert_eq!(map.lower_bound(&31), None);
+    assert_eq!(map.upper_bound(&5), Some((10, 11)));
+    assert_eq!(map.upper_bound(&20), Some((30, 31)));
+    assert_eq!(map.upper_bound(&30), None);
+}
+
+#[test]
+fn test_ordered_map_range() {
+    let mut map = create_map();
+    for key in (0..50).map(|i| i * 37 % 50) {
+        map.insert(key, key * 10);
+    }
+    assert!(map.check_internal_consistency());
+
+    let range: Vec<(u64, u64)> = map.range(10..13).collect();
+    assert_eq!(range, vec![(10, 100), (11, 110), (12, 120)]);
+    let keys: Vec<u64> = map.range(45..).map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![45, 46, 47, 48, 49]);
+    let keys: Vec<u64> = map.range(..=2).map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![0, 1, 2]);
+    assert_eq!(map.range(20..20).count(), 0);
+    assert_eq!(map.range(60..).count(), 0);
+    assert_eq!(map.range(..).count(), 50);
+}
+
+#[test]
+fn test_ordered_map_clear() {
+    let mut map = create_map();
+    for key in 0..10 {
+        map.insert(key, key);
+    }
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.first(), None);
+    check_map(&map, vec![]);
+
+    map.insert(7, 77);
+    check_map(&map, vec![7]);
+}
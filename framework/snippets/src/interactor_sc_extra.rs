@@ -2,8 +2,8 @@ use crate::Interactor;
 use multiversx_sc_scenario::{
     api::StaticApi,
     multiversx_sc::{
-        codec::{CodecFrom, TopEncodeMulti},
-        types::{Address, ContractCall},
+        codec::{CodecFrom, TopDecodeMulti, TopEncodeMulti},
+        types::{Address, ContractCall, Page},
     },
     scenario_model::{
         ScCallStep, ScDeployStep, ScQueryStep, TxResponse, TypedResponse, TypedScCall,
@@ -108,6 +108,37 @@ impl Interactor {
         typed_response.result.unwrap()
     }
 
+    /// Queries a paginated view page by page, until the last page, and collects all items.
+    ///
+    /// The closure receives the cursor and the page size and produces the call for that page.
+    /// The page size needs to be greater than zero.
+    pub async fn quick_query_all_pages<CC, T, F>(
+        &mut self,
+        page_limit: usize,
+        mut contract_call_fn: F,
+    ) -> Vec<T>
+    where
+        CC: ContractCall<StaticApi>,
+        Page<StaticApi, T>: CodecFrom<CC::OriginalResult>,
+        T: TopDecodeMulti,
+        F: FnMut(usize, usize) -> CC,
+    {
+        assert!(page_limit > 0, "page limit must be greater than zero");
+
+        let mut items = Vec::new();
+        let mut cursor = 0;
+        loop {
+            let page: Page<StaticApi, T> =
+                self.quick_query(contract_call_fn(cursor, page_limit)).await;
+            let next_cursor = page.next_cursor;
+            items.extend(page.into_items());
+            match next_cursor {
+                Some(next_cursor) => cursor = next_cursor,
+                None => return items,
+            }
+        }
+    }
+
     pub async fn sc_deploy_use_raw_response<S, F>(
         &mut self,
         mut step: S,
@@ -11,6 +11,8 @@ static mut NEXT_HANDLE: i32 = const_handles::NEW_HANDLE_START_FROM;
 static mut NUM_ARGUMENTS: i32 = 0;
 static mut CALL_VALUE_EGLD_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
 static mut CALL_VALUE_MULTI_ESDT_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
+static mut STORAGE_CACHE_MAP_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
+static mut STORAGE_CACHE_DIRTY_KEYS_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
 
 // The compiler seems to enjoy inlining this method no matter how many times it shows up.
 // Hence the rather drastic directive.
@@ -78,4 +80,24 @@ impl StaticVarApiImpl for VmApiImpl {
     fn get_call_value_multi_esdt_handle(&self) -> RawHandle {
         unsafe { CALL_VALUE_MULTI_ESDT_HANDLE }
     }
+
+    fn set_storage_cache_map_handle(&self, handle: RawHandle) {
+        unsafe {
+            STORAGE_CACHE_MAP_HANDLE = handle;
+        }
+    }
+
+    fn get_storage_cache_map_handle(&self) -> RawHandle {
+        unsafe { STORAGE_CACHE_MAP_HANDLE }
+    }
+
+    fn set_storage_cache_dirty_keys_handle(&self, handle: RawHandle) {
+        unsafe {
+            STORAGE_CACHE_DIRTY_KEYS_HANDLE = handle;
+        }
+    }
+
+    fn get_storage_cache_dirty_keys_handle(&self) -> RawHandle {
+        unsafe { STORAGE_CACHE_DIRTY_KEYS_HANDLE }
+    }
 }
@@ -1,9 +1,11 @@
 mod fail_allocator;
+mod free_list_allocator;
 mod leaking_allocator;
 mod memory_grow;
 mod static_allocator;
 
 pub use fail_allocator::FailAllocator;
+pub use free_list_allocator::FreeListAllocator;
 pub use leaking_allocator::LeakingAllocator;
 pub use static_allocator::{StaticAllocator, StaticAllocator64K};
 
@@ -0,0 +1,197 @@
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    cell::UnsafeCell,
+    mem, ptr,
+};
+
+/// Smallest block handed out, large enough to hold the free list link.
+const MIN_BLOCK_SIZE: usize = 8;
+
+/// One free list per power of two.
+const NUM_SIZE_CLASSES: usize = usize::BITS as usize;
+
+/// A non-thread safe size-class allocator, that reuses freed memory.
+///
+/// Every allocation is rounded up to a power of two (at least 8 bytes), and only gets the alignment of its layout.
+/// Freed blocks are kept in one free list per size class, and are handed out again
+/// to the first allocation in the same class they are suitably aligned for. Blocks are never split or merged.
+///
+/// New blocks are requested from the inner allocator, which only needs to be able to bump.
+/// This makes it a good fit for contracts that allocate and free similar-sized buffers repeatedly.
+pub struct FreeListAllocator<A: GlobalAlloc> {
+    inner: A,
+    free_lists: UnsafeCell<[*mut u8; NUM_SIZE_CLASSES]>,
+}
+
+/// Single-threaded context only.
+unsafe impl<A: GlobalAlloc> Sync for FreeListAllocator<A> {}
+
+impl<A: GlobalAlloc> FreeListAllocator<A> {
+    pub const fn new(inner: A) -> Self {
+        FreeListAllocator {
+            inner,
+            free_lists: UnsafeCell::new([ptr::null_mut(); NUM_SIZE_CLASSES]),
+        }
+    }
+}
+
+/// The size class of an allocation, i.e. the exponent of its block size.
+fn size_class(layout: &Layout) -> usize {
+    let block_size = layout
+        .size()
+        .max(layout.align())
+        .max(MIN_BLOCK_SIZE)
+        .next_power_of_two();
+    block_size.trailing_zeros() as usize
+}
+
+/// Blocks also need to be able to hold the free list link.
+fn block_align(layout: &Layout) -> usize {
+    layout.align().max(mem::align_of::<*mut u8>())
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for FreeListAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let class = size_class(&layout);
+        let align = block_align(&layout);
+        // The first block usually fits, only over-aligned layouts need to look further.
+        let mut link: *mut *mut u8 = &mut (*self.free_lists.get())[class];
+        while !(*link).is_null() {
+            let block = *link;
+            if (block as usize) % align == 0 {
+                *link = *(block as *mut *mut u8);
+                return block;
+            }
+            link = block as *mut *mut u8;
+        }
+
+        let block_size = 1usize << class;
+        match Layout::from_size_align(block_size, align) {
+            Ok(block_layout) => self.inner.alloc(block_layout),
+            // too large to round up, reported like any other failed allocation
+            Err(_) => ptr::null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let class = size_class(&layout);
+        let free_list = &mut (*self.free_lists.get())[class];
+        *(ptr as *mut *mut u8) = *free_list;
+        *free_list = ptr;
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        if size_class(&layout) == size_class(&new_layout) {
+            return ptr;
+        }
+
+        let new_ptr = self.alloc(new_layout);
+        if new_ptr.is_null() {
+            return new_ptr;
+        }
+        ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+        self.dealloc(ptr, layout);
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::alloc::System;
+
+    fn layout(size: usize) -> Layout {
+        Layout::from_size_align(size, 1).unwrap()
+    }
+
+    #[repr(C, align(64))]
+    struct AlignedBuffer([u8; 256]);
+
+    #[test]
+    fn test_free_list_reuses_freed_blocks() {
+        let allocator = FreeListAllocator::new(System);
+        unsafe {
+            let first = allocator.alloc(layout(20));
+            allocator.dealloc(first, layout(20));
+            let second = allocator.alloc(layout(30));
+            assert_eq!(first, second);
+
+            // free list is empty again, so a new block is created
+            let third = allocator.alloc(layout(30));
+            assert_ne!(second, third);
+        }
+    }
+
+    #[test]
+    fn test_free_list_size_classes() {
+        let allocator = FreeListAllocator::new(System);
+        unsafe {
+            let small = allocator.alloc(layout(8));
+            allocator.dealloc(small, layout(8));
+            let large = allocator.alloc(layout(100));
+            assert_ne!(small, large);
+
+            let small_again = allocator.alloc(layout(3));
+            assert_eq!(small, small_again);
+        }
+    }
+
+    #[test]
+    fn test_free_list_over_aligned() {
+        let allocator = FreeListAllocator::new(System);
+        let over_aligned = Layout::from_size_align(16, 64).unwrap();
+        unsafe {
+            let ptr = allocator.alloc(over_aligned);
+            assert_eq!(ptr as usize % 64, 0);
+            allocator.dealloc(ptr, over_aligned);
+            assert_eq!(allocator.alloc(over_aligned), ptr);
+        }
+    }
+
+    #[test]
+    fn test_free_list_mixed_alignments() {
+        let allocator = FreeListAllocator::new(System);
+        let mut buffer = AlignedBuffer([0; 256]);
+        let start = buffer.0.as_mut_ptr();
+        let small_align = Layout::from_size_align(64, 8).unwrap();
+        let large_align = Layout::from_size_align(64, 64).unwrap();
+        unsafe {
+            // same size class, the head of the list is not aligned to 64
+            let aligned = start.add(128);
+            let misaligned = start.add(8);
+            allocator.dealloc(aligned, small_align);
+            allocator.dealloc(misaligned, small_align);
+
+            // the aligned block is found behind the head, instead of leaking it
+            assert_eq!(allocator.alloc(large_align), aligned);
+            assert_eq!(allocator.alloc(small_align), misaligned);
+        }
+    }
+
+    #[test]
+    fn test_free_list_realloc() {
+        let allocator = FreeListAllocator::new(System);
+        unsafe {
+            let ptr = allocator.alloc(layout(10));
+            for i in 0..10 {
+                *ptr.add(i) = i as u8;
+            }
+
+            let same_class = allocator.realloc(ptr, layout(10), 16);
+            assert_eq!(ptr, same_class);
+
+            let grown = allocator.realloc(same_class, layout(16), 40);
+            assert_ne!(same_class, grown);
+            for i in 0..10 {
+                assert_eq!(*grown.add(i), i as u8);
+            }
+
+            // the old block went back to its free list
+            let reused = allocator.alloc(layout(16));
+            assert_eq!(ptr, reused);
+        }
+    }
+}
@@ -3,9 +3,9 @@ use core::{
     cell::UnsafeCell,
 };
 
-/// The pre-allocated buffer size.
+/// The default pre-allocated buffer size.
 ///
-/// TODO: make configurable, experiment with it.
+/// Other sizes can be configured via `allocator = "static<N>"` in `multicontract.toml`.
 pub const SIZE_64K: usize = 64 * 1024;
 
 pub type StaticAllocator64K = StaticAllocator<SIZE_64K>;
@@ -35,22 +35,80 @@ impl<const SIZE: usize> StaticAllocator<SIZE> {
 
 unsafe impl<const SIZE: usize> Sync for StaticAllocator<SIZE> {}
 
-unsafe impl<const SIZE: usize> GlobalAlloc for StaticAllocator<SIZE> {
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+impl<const SIZE: usize> StaticAllocator<SIZE> {
+    /// Bumps the head past the new allocation, or returns `None` if the arena is exhausted.
+    unsafe fn bump(&self, layout: Layout) -> Option<*mut u8> {
         let size = layout.size();
         let align = layout.align();
 
         // Find the next address that has the right alignment.
-        let idx = (*self.head.get()).next_multiple_of(align);
+        let arena_start = self.arena.get() as usize;
+        let head = arena_start + *self.head.get();
+        let start = head.next_multiple_of(align) - arena_start;
+        let end = start + size;
+        if end > SIZE {
+            return None;
+        }
         // Bump the head to the next free byte
-        *self.head.get() = idx + size;
-        let arena: &mut [u8; SIZE] = &mut (*self.arena.get());
-        // If we ran out of arena space, kill execution with mem_alloc_error.
-        match arena.get_mut(idx) {
-            Some(item) => item as *mut u8,
-            _ => super::mem_alloc_error(),
+        *self.head.get() = end;
+        Some((self.arena.get() as *mut u8).add(start))
+    }
+}
+
+unsafe impl<const SIZE: usize> GlobalAlloc for StaticAllocator<SIZE> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        match self.bump(layout) {
+            Some(ptr) => ptr,
+            // If we ran out of arena space, kill execution with mem_alloc_error.
+            None => super::mem_alloc_error(),
         }
     }
 
     unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `alloc` itself signals the error through the VM, so the tests go through `bump`.
+
+    #[test]
+    fn test_static_allocator_alignment() {
+        let allocator = StaticAllocator::<1024>::new();
+        unsafe {
+            let first = allocator.bump(Layout::from_size_align(3, 1).unwrap());
+            let second = allocator.bump(Layout::from_size_align(8, 8).unwrap());
+            let arena_start = allocator.arena.get() as usize;
+            assert_eq!(first, Some(arena_start as *mut u8));
+            assert_eq!(second, Some((arena_start + 8) as *mut u8));
+        }
+    }
+
+    #[test]
+    fn test_static_allocator_custom_size() {
+        let allocator = StaticAllocator::<100>::new();
+        unsafe {
+            let ptr = allocator.bump(Layout::from_size_align(100, 1).unwrap());
+            assert_eq!(ptr, Some(allocator.arena.get() as *mut u8));
+        }
+    }
+
+    #[test]
+    fn test_static_allocator_exhausted() {
+        let allocator = StaticAllocator::<100>::new();
+        unsafe {
+            assert!(allocator
+                .bump(Layout::from_size_align(64, 1).unwrap())
+                .is_some());
+            assert!(allocator
+                .bump(Layout::from_size_align(64, 1).unwrap())
+                .is_none());
+
+            // a failed allocation does not move the head
+            assert!(allocator
+                .bump(Layout::from_size_align(36, 1).unwrap())
+                .is_some());
+        }
+    }
+}
@@ -15,6 +15,19 @@ macro_rules! allocator {
         static ALLOC: multiversx_sc_wasm_adapter::wasm_alloc::StaticAllocator64K =
             multiversx_sc_wasm_adapter::wasm_alloc::StaticAllocator64K::new();
     };
+    (static<$size:literal>) => {
+        #[global_allocator]
+        static ALLOC: multiversx_sc_wasm_adapter::wasm_alloc::StaticAllocator<$size> =
+            multiversx_sc_wasm_adapter::wasm_alloc::StaticAllocator::new();
+    };
+    (free_list) => {
+        #[global_allocator]
+        static ALLOC: multiversx_sc_wasm_adapter::wasm_alloc::FreeListAllocator<
+            multiversx_sc_wasm_adapter::wasm_alloc::LeakingAllocator,
+        > = multiversx_sc_wasm_adapter::wasm_alloc::FreeListAllocator::new(
+            multiversx_sc_wasm_adapter::wasm_alloc::LeakingAllocator::new(),
+        );
+    };
     (wee_alloc) => {
         #[global_allocator]
         static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
@@ -1,4 +1,5 @@
 pub mod blockchain;
 pub mod crypto;
 pub mod data;
+pub mod tx_data;
 pub mod wallet;
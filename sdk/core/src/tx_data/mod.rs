@@ -0,0 +1,19 @@
+//! Encoding and decoding of the transaction `data` field.
+//!
+//! Also used by the mxpy snippet generator tool, to produce the tx data of its calls.
+
+mod tx_data_abi;
+mod tx_data_builder;
+mod tx_data_decode;
+mod tx_data_payment;
+
+pub use tx_data_abi::DecodedArgument;
+pub use tx_data_builder::TxDataBuilder;
+pub use tx_data_decode::{decode_tx_data, DecodedTxData, DecodedTxKind};
+pub use tx_data_payment::TxDataPayment;
+
+/// The only VM type currently available on the network: WASM.
+pub const VM_TYPE_WASM: [u8; 2] = [5, 0];
+
+/// All contract code starts with the WASM magic number, `\0asm`.
+const WASM_MAGIC_NUMBER_HEX: &str = "0061736d";
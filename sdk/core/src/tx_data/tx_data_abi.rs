@@ -0,0 +1,208 @@
+use multiversx_sc::abi::EndpointAbi;
+use num_bigint::{BigInt, BigUint};
+
+use crate::data::address::Address;
+
+/// A raw argument from the tx data, annotated with its name and type from the ABI, if available.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedArgument {
+    pub raw: Vec<u8>,
+    pub name: Option<String>,
+    pub type_name: Option<String>,
+
+    /// Human-readable value, only for the basic types that need no further type descriptions.
+    pub display: Option<String>,
+}
+
+impl DecodedArgument {
+    fn new(raw: Vec<u8>, opt_name_and_type: Option<(&str, &str)>) -> Self {
+        match opt_name_and_type {
+            Some((name, type_name)) => DecodedArgument {
+                display: display_basic_type(type_name, &raw),
+                raw,
+                name: Some(name.to_string()),
+                type_name: Some(type_name.to_string()),
+            },
+            None => DecodedArgument {
+                raw,
+                name: None,
+                type_name: None,
+                display: None,
+            },
+        }
+    }
+}
+
+/// Matches the raw arguments to the endpoint inputs.
+///
+/// Multi-value inputs (`variadic`, `optional`, `multi`) can consume any number of raw arguments.
+/// For `counted-variadic` inputs, the number of groups is read first.
+/// Arguments beyond what the ABI describes are left unannotated.
+pub(super) fn annotate_arguments(
+    raw_args: Vec<Vec<u8>>,
+    opt_endpoint_abi: Option<&EndpointAbi>,
+) -> Vec<DecodedArgument> {
+    let mut remaining = raw_args.into_iter().peekable();
+    let mut result = Vec::new();
+
+    if let Some(endpoint_abi) = opt_endpoint_abi {
+        for input in &endpoint_abi.inputs {
+            if remaining.peek().is_none() {
+                break;
+            }
+
+            if !input.multi_arg {
+                let raw = remaining.next().unwrap();
+                result.push(DecodedArgument::new(
+                    raw,
+                    Some((input.arg_name, input.type_name.as_str())),
+                ));
+                continue;
+            }
+
+            let (single_types, repeat) = expand_multi_type(&input.type_name);
+            let mut num_groups = match repeat {
+                MultiRepeat::Once => Some(1),
+                MultiRepeat::Variadic => None,
+                MultiRepeat::Counted => {
+                    let raw_count = remaining.next().unwrap();
+                    let count = decode_count(&raw_count);
+                    result.push(DecodedArgument::new(
+                        raw_count,
+                        Some((input.arg_name, COUNT_TYPE_NAME)),
+                    ));
+                    match count {
+                        Some(count) => Some(count),
+                        // the rest cannot be matched to the ABI
+                        None => break,
+                    }
+                },
+            };
+            if single_types.is_empty() {
+                continue;
+            }
+            while num_groups != Some(0) && remaining.peek().is_some() {
+                for single_type in &single_types {
+                    let Some(raw) = remaining.next() else {
+                        break;
+                    };
+                    result.push(DecodedArgument::new(
+                        raw,
+                        Some((input.arg_name, single_type.as_str())),
+                    ));
+                }
+                num_groups = num_groups.map(|n| n - 1);
+            }
+        }
+    }
+
+    result.extend(remaining.map(|raw| DecodedArgument::new(raw, None)));
+    result
+}
+
+/// How many times the group of single arguments of a multi-value input can occur.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MultiRepeat {
+    Once,
+
+    /// Any number of groups, until the arguments run out.
+    Variadic,
+
+    /// The number of groups comes first, as a separate argument.
+    Counted,
+}
+
+/// The count of a `counted-variadic` input is serialized like a `usize`, on 4 bytes at most.
+const COUNT_TYPE_NAME: &str = "u32";
+
+fn decode_count(raw: &[u8]) -> Option<usize> {
+    if raw.len() > 4 {
+        return None;
+    }
+    Some(raw.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize))
+}
+
+/// Splits a multi-value type into the types of the single arguments it consumes.
+///
+/// Also returns how many times the group can occur.
+fn expand_multi_type(type_name: &str) -> (Vec<String>, MultiRepeat) {
+    if let Some(inner) = strip_generic(type_name, "variadic") {
+        let (single_types, _) = expand_multi_type(inner);
+        return (single_types, MultiRepeat::Variadic);
+    }
+    if let Some(inner) = strip_generic(type_name, "counted-variadic") {
+        let (single_types, _) = expand_multi_type(inner);
+        return (single_types, MultiRepeat::Counted);
+    }
+    if let Some(inner) = strip_generic(type_name, "optional") {
+        return expand_multi_type(inner);
+    }
+    if let Some(inner) = strip_generic(type_name, "multi") {
+        let single_types = split_top_level(inner)
+            .into_iter()
+            .flat_map(|item| expand_multi_type(item).0)
+            .collect();
+        return (single_types, MultiRepeat::Once);
+    }
+    (vec![type_name.to_string()], MultiRepeat::Once)
+}
+
+fn strip_generic<'a>(type_name: &'a str, wrapper: &str) -> Option<&'a str> {
+    type_name
+        .strip_prefix(wrapper)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// Splits by the commas that are not nested inside other generics.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(s[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    result.push(s[start..].trim());
+    result
+}
+
+fn display_basic_type(type_name: &str, raw: &[u8]) -> Option<String> {
+    match type_name {
+        "u8" | "u16" | "u32" | "u64" | "usize" => {
+            if raw.len() > 8 {
+                return None;
+            }
+            let value = raw.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+            Some(value.to_string())
+        },
+        "i8" | "i16" | "i32" | "i64" | "isize" => {
+            if raw.len() > 8 {
+                return None;
+            }
+            Some(BigInt::from_signed_bytes_be(raw).to_string())
+        },
+        "bool" => match raw {
+            [] => Some("false".to_string()),
+            [1] => Some("true".to_string()),
+            _ => None,
+        },
+        "BigUint" => Some(BigUint::from_bytes_be(raw).to_string()),
+        "BigInt" => Some(BigInt::from_signed_bytes_be(raw).to_string()),
+        "Address" => {
+            let bytes: [u8; 32] = raw.try_into().ok()?;
+            Address::from_bytes(bytes).to_bech32_string().ok()
+        },
+        "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" | "utf-8 string" => {
+            String::from_utf8(raw.to_vec()).ok()
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,216 @@
+use multiversx_sc::{
+    api::{
+        ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
+        UPGRADE_CONTRACT_FUNC_NAME,
+    },
+    codec::{top_encode_to_vec_u8_or_panic, TopEncode},
+    types::CodeMetadata,
+    HexCallDataSerializer,
+};
+use num_bigint::BigUint;
+
+use super::{TxDataPayment, VM_TYPE_WASM};
+use crate::data::address::Address;
+
+enum TxDataKind {
+    Call {
+        to: Address,
+        function: String,
+    },
+    Deploy {
+        code: Vec<u8>,
+        code_metadata: CodeMetadata,
+    },
+    Upgrade {
+        to: Address,
+        code: Vec<u8>,
+        code_metadata: CodeMetadata,
+    },
+}
+
+/// Builds the `data` field of a transaction from typed arguments.
+///
+/// ESDT payments are converted to the appropriate builtin function call,
+/// the same way contracts do it:
+/// - a single fungible token is sent via `ESDTTransfer`;
+/// - a single NFT/SFT/META-ESDT is sent via `ESDTNFTTransfer`;
+/// - more tokens are sent via `MultiESDTNFTTransfer`.
+///
+/// The last two are sent to the sender itself, use [`TxDataBuilder::tx_receiver`]
+/// to get the correct receiver of the transaction.
+pub struct TxDataBuilder {
+    kind: TxDataKind,
+    payments: Vec<TxDataPayment>,
+    args: Vec<Vec<u8>>,
+}
+
+impl TxDataBuilder {
+    /// Calls an endpoint of the contract at address `to`.
+    pub fn call(to: &Address, function: &str) -> Self {
+        Self::new(TxDataKind::Call {
+            to: to.clone(),
+            function: function.to_string(),
+        })
+    }
+
+    /// Deploys new contract code. The constructor arguments get added as regular arguments.
+    pub fn deploy(code: &[u8], code_metadata: CodeMetadata) -> Self {
+        Self::new(TxDataKind::Deploy {
+            code: code.to_vec(),
+            code_metadata,
+        })
+    }
+
+    /// Upgrades the contract at address `to`.
+    /// The upgrade constructor arguments get added as regular arguments.
+    pub fn upgrade(to: &Address, code: &[u8], code_metadata: CodeMetadata) -> Self {
+        Self::new(TxDataKind::Upgrade {
+            to: to.clone(),
+            code: code.to_vec(),
+            code_metadata,
+        })
+    }
+
+    fn new(kind: TxDataKind) -> Self {
+        TxDataBuilder {
+            kind,
+            payments: Vec::new(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Adds an ESDT payment to an endpoint call.
+    ///
+    /// Only calls can receive ESDT payments.
+    pub fn esdt_payment(
+        mut self,
+        token_identifier: &str,
+        token_nonce: u64,
+        amount: BigUint,
+    ) -> Self {
+        assert!(
+            matches!(self.kind, TxDataKind::Call { .. }),
+            "ESDT payments are only allowed in contract calls"
+        );
+        self.payments
+            .push(TxDataPayment::new(token_identifier, token_nonce, amount));
+        self
+    }
+
+    /// Serializes and adds an argument.
+    pub fn argument<T: TopEncode>(mut self, arg: &T) -> Self {
+        self.args.push(top_encode_to_vec_u8_or_panic(arg));
+        self
+    }
+
+    /// Adds an argument that is already serialized.
+    pub fn raw_argument(mut self, arg: Vec<u8>) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    /// The address that the transaction needs to be sent to, given its sender.
+    ///
+    /// NFT and multi-token transfers are sent to the sender itself, deploys to the zero address.
+    pub fn tx_receiver(&self, sender: &Address) -> Address {
+        match &self.kind {
+            TxDataKind::Call { to, .. } => {
+                if self.payments.len() > 1
+                    || self.payments.len() == 1 && !self.payments[0].is_fungible()
+                {
+                    sender.clone()
+                } else {
+                    to.clone()
+                }
+            },
+            TxDataKind::Deploy { .. } => Address::from_bytes([0u8; 32]),
+            TxDataKind::Upgrade { to, .. } => to.clone(),
+        }
+    }
+
+    /// Produces the `data` field, in the `function@arg1@arg2` format.
+    pub fn build(&self) -> String {
+        let mut serializer = match &self.kind {
+            TxDataKind::Call { to, function } => self.call_serializer(to, function),
+            TxDataKind::Deploy {
+                code,
+                code_metadata,
+            } => {
+                let mut serializer = HexCallDataSerializer::new(hex::encode(code).as_bytes());
+                serializer.push_argument_bytes(&VM_TYPE_WASM);
+                serializer.push_argument_bytes(&code_metadata.to_byte_array());
+                serializer
+            },
+            TxDataKind::Upgrade {
+                code,
+                code_metadata,
+                ..
+            } => {
+                let mut serializer =
+                    HexCallDataSerializer::new(UPGRADE_CONTRACT_FUNC_NAME.as_bytes());
+                serializer.push_argument_bytes(code);
+                serializer.push_argument_bytes(&code_metadata.to_byte_array());
+                serializer
+            },
+        };
+
+        for arg in &self.args {
+            serializer.push_argument_bytes(arg);
+        }
+        String::from_utf8(serializer.into_vec()).unwrap()
+    }
+
+    fn call_serializer(&self, to: &Address, function: &str) -> HexCallDataSerializer {
+        match self.payments.as_slice() {
+            [] => HexCallDataSerializer::new(function.as_bytes()),
+            [payment] if payment.is_fungible() => {
+                let mut serializer = HexCallDataSerializer::new(ESDT_TRANSFER_FUNC_NAME.as_bytes());
+                serializer.push_argument_bytes(payment.token_identifier.as_bytes());
+                serializer.push_argument_bytes(&biguint_to_bytes(&payment.amount));
+                push_function(&mut serializer, function);
+                serializer
+            },
+            [payment] => {
+                let mut serializer =
+                    HexCallDataSerializer::new(ESDT_NFT_TRANSFER_FUNC_NAME.as_bytes());
+                serializer.push_argument_bytes(payment.token_identifier.as_bytes());
+                serializer
+                    .push_argument_bytes(&top_encode_to_vec_u8_or_panic(&payment.token_nonce));
+                serializer.push_argument_bytes(&biguint_to_bytes(&payment.amount));
+                serializer.push_argument_bytes(&to.to_bytes());
+                push_function(&mut serializer, function);
+                serializer
+            },
+            payments => {
+                let mut serializer =
+                    HexCallDataSerializer::new(ESDT_MULTI_TRANSFER_FUNC_NAME.as_bytes());
+                serializer.push_argument_bytes(&to.to_bytes());
+                serializer.push_argument_bytes(&top_encode_to_vec_u8_or_panic(&payments.len()));
+                for payment in payments {
+                    serializer.push_argument_bytes(payment.token_identifier.as_bytes());
+                    serializer
+                        .push_argument_bytes(&top_encode_to_vec_u8_or_panic(&payment.token_nonce));
+                    serializer.push_argument_bytes(&biguint_to_bytes(&payment.amount));
+                }
+                push_function(&mut serializer, function);
+                serializer
+            },
+        }
+    }
+}
+
+/// Plain transfers have no function name, in which case it is omitted.
+fn push_function(serializer: &mut HexCallDataSerializer, function: &str) {
+    if !function.is_empty() {
+        serializer.push_argument_bytes(function.as_bytes());
+    }
+}
+
+/// Same as the top-encoding of a `BigUint` in contracts: big endian, zero is empty.
+pub(super) fn biguint_to_bytes(value: &BigUint) -> Vec<u8> {
+    if value.bits() == 0 {
+        Vec::new()
+    } else {
+        value.to_bytes_be()
+    }
+}
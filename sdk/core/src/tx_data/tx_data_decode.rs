@@ -0,0 +1,184 @@
+use anyhow::{anyhow, Result};
+use multiversx_sc::{
+    abi::{ContractAbi, EndpointAbi},
+    api::{
+        ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
+        UPGRADE_CONTRACT_FUNC_NAME,
+    },
+    types::CodeMetadata,
+    HexCallDataDeserializer,
+};
+use num_bigint::BigUint;
+
+use super::{
+    tx_data_abi::annotate_arguments, DecodedArgument, TxDataPayment, VM_TYPE_WASM,
+    WASM_MAGIC_NUMBER_HEX,
+};
+use crate::data::address::Address;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedTxKind {
+    /// Endpoint call or simple transfer, possibly with ESDT payments.
+    Call,
+    Deploy {
+        code: Vec<u8>,
+        code_metadata: CodeMetadata,
+    },
+    Upgrade {
+        code: Vec<u8>,
+        code_metadata: CodeMetadata,
+    },
+}
+
+/// The contents of the `data` field of a transaction, split into its components.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedTxData {
+    pub kind: DecodedTxKind,
+
+    /// The called endpoint, with any ESDT transfer builtin function unwrapped.
+    ///
+    /// Empty for simple transfers, the constructor name for deploys and upgrades.
+    pub function: String,
+
+    pub payments: Vec<TxDataPayment>,
+
+    /// The destination of the call, if it differs from the transaction receiver,
+    /// as is the case for NFT and multi-token transfers.
+    pub destination: Option<Address>,
+
+    pub args: Vec<DecodedArgument>,
+}
+
+/// Splits the `data` field of a transaction into function name, payments and arguments.
+///
+/// If provided, the ABI is used to annotate the arguments with names and types.
+pub fn decode_tx_data(data: &[u8], opt_abi: Option<&ContractAbi>) -> Result<DecodedTxData> {
+    let mut de = HexCallDataDeserializer::new(data);
+    let func_name = String::from_utf8(de.get_func_name().to_vec())
+        .map_err(|_| anyhow!("function name is not valid UTF-8"))?;
+    let mut raw_args = Vec::new();
+    while let Some(arg) = de.next_argument().map_err(|err| anyhow!("{err}"))? {
+        raw_args.push(arg);
+    }
+    let mut args = raw_args.into_iter();
+
+    let mut decoded = DecodedTxData {
+        kind: DecodedTxKind::Call,
+        function: String::new(),
+        payments: Vec::new(),
+        destination: None,
+        args: Vec::new(),
+    };
+
+    if func_name.starts_with(WASM_MAGIC_NUMBER_HEX) {
+        let code = hex::decode(&func_name).map_err(|_| anyhow!("invalid contract code hex"))?;
+        let vm_type = next_arg(&mut args, "VM type")?;
+        if vm_type != VM_TYPE_WASM {
+            return Err(anyhow!("unknown VM type: {}", hex::encode(vm_type)));
+        }
+        let code_metadata = next_code_metadata(&mut args)?;
+        decoded.kind = DecodedTxKind::Deploy {
+            code,
+            code_metadata,
+        };
+    } else if func_name == UPGRADE_CONTRACT_FUNC_NAME {
+        let code = next_arg(&mut args, "contract code")?;
+        let code_metadata = next_code_metadata(&mut args)?;
+        decoded.kind = DecodedTxKind::Upgrade {
+            code,
+            code_metadata,
+        };
+    } else if func_name == ESDT_TRANSFER_FUNC_NAME {
+        decoded.payments.push(TxDataPayment {
+            token_identifier: next_token_identifier(&mut args)?,
+            token_nonce: 0,
+            amount: next_amount(&mut args)?,
+        });
+        decoded.function = next_function(&mut args)?;
+    } else if func_name == ESDT_NFT_TRANSFER_FUNC_NAME {
+        decoded.payments.push(next_nft_payment(&mut args)?);
+        decoded.destination = Some(next_address(&mut args)?);
+        decoded.function = next_function(&mut args)?;
+    } else if func_name == ESDT_MULTI_TRANSFER_FUNC_NAME {
+        decoded.destination = Some(next_address(&mut args)?);
+        let num_payments = next_u64(&mut args, "number of payments")?;
+        for _ in 0..num_payments {
+            decoded.payments.push(next_nft_payment(&mut args)?);
+        }
+        decoded.function = next_function(&mut args)?;
+    } else {
+        decoded.function = func_name;
+    }
+
+    let opt_endpoint_abi = opt_abi.and_then(|abi| find_endpoint_abi(abi, &decoded));
+    if let Some(endpoint_abi) = opt_endpoint_abi {
+        if decoded.kind != DecodedTxKind::Call {
+            decoded.function = endpoint_abi.name.to_string();
+        }
+    }
+    decoded.args = annotate_arguments(args.collect(), opt_endpoint_abi);
+    Ok(decoded)
+}
+
+fn find_endpoint_abi<'a>(abi: &'a ContractAbi, decoded: &DecodedTxData) -> Option<&'a EndpointAbi> {
+    match decoded.kind {
+        DecodedTxKind::Call => abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == decoded.function),
+        DecodedTxKind::Deploy { .. } | DecodedTxKind::Upgrade { .. } => abi.constructors.first(),
+    }
+}
+
+fn next_arg(args: &mut impl Iterator<Item = Vec<u8>>, what: &str) -> Result<Vec<u8>> {
+    args.next().ok_or_else(|| anyhow!("missing {what}"))
+}
+
+fn next_u64(args: &mut impl Iterator<Item = Vec<u8>>, what: &str) -> Result<u64> {
+    let raw = next_arg(args, what)?;
+    if raw.len() > 8 {
+        return Err(anyhow!("{what} is too large"));
+    }
+    Ok(raw.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+fn next_amount(args: &mut impl Iterator<Item = Vec<u8>>) -> Result<BigUint> {
+    Ok(BigUint::from_bytes_be(&next_arg(args, "amount")?))
+}
+
+fn next_token_identifier(args: &mut impl Iterator<Item = Vec<u8>>) -> Result<String> {
+    String::from_utf8(next_arg(args, "token identifier")?)
+        .map_err(|_| anyhow!("token identifier is not valid UTF-8"))
+}
+
+fn next_address(args: &mut impl Iterator<Item = Vec<u8>>) -> Result<Address> {
+    let bytes: [u8; 32] = next_arg(args, "destination address")?
+        .try_into()
+        .map_err(|_| anyhow!("destination address must be 32 bytes long"))?;
+    Ok(Address::from_bytes(bytes))
+}
+
+fn next_code_metadata(args: &mut impl Iterator<Item = Vec<u8>>) -> Result<CodeMetadata> {
+    let bytes: [u8; 2] = next_arg(args, "code metadata")?
+        .try_into()
+        .map_err(|_| anyhow!("code metadata must be 2 bytes long"))?;
+    Ok(CodeMetadata::from(bytes))
+}
+
+fn next_nft_payment(args: &mut impl Iterator<Item = Vec<u8>>) -> Result<TxDataPayment> {
+    Ok(TxDataPayment {
+        token_identifier: next_token_identifier(args)?,
+        token_nonce: next_u64(args, "token nonce")?,
+        amount: next_amount(args)?,
+    })
+}
+
+/// The function is optional after a transfer, its absence means a simple transfer.
+fn next_function(args: &mut impl Iterator<Item = Vec<u8>>) -> Result<String> {
+    match args.next() {
+        Some(raw) => {
+            String::from_utf8(raw).map_err(|_| anyhow!("function name is not valid UTF-8"))
+        },
+        None => Ok(String::new()),
+    }
+}
@@ -0,0 +1,24 @@
+use num_bigint::BigUint;
+
+/// An ESDT, NFT, SFT or META-ESDT payment, as encoded in the data of a transfer transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxDataPayment {
+    pub token_identifier: String,
+    pub token_nonce: u64,
+    pub amount: BigUint,
+}
+
+impl TxDataPayment {
+    pub fn new(token_identifier: &str, token_nonce: u64, amount: BigUint) -> Self {
+        TxDataPayment {
+            token_identifier: token_identifier.to_string(),
+            token_nonce,
+            amount,
+        }
+    }
+
+    /// Fungible tokens have nonce 0.
+    pub fn is_fungible(&self) -> bool {
+        self.token_nonce == 0
+    }
+}
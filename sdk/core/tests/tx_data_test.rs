@@ -0,0 +1,233 @@
+use multiversx_sc::{
+    abi::{ContractAbi, EndpointAbi, InputAbi},
+    codec::multi_types::MultiValueVec,
+    types::CodeMetadata,
+};
+use multiversx_sdk::{
+    data::address::Address,
+    tx_data::{decode_tx_data, DecodedTxKind, TxDataBuilder, TxDataPayment},
+};
+use num_bigint::BigUint;
+
+const CODE: &[u8] = b"\0asm\x01\0\0\0";
+
+fn sender() -> Address {
+    Address::from_bytes([1u8; 32])
+}
+
+fn contract() -> Address {
+    Address::from_bytes([2u8; 32])
+}
+
+fn test_abi() -> ContractAbi {
+    let mut constructor = EndpointAbi::default();
+    constructor.name = "init";
+    constructor.add_input::<u64>("initial_value");
+
+    let mut endpoint = EndpointAbi::endpoint_with_name_and_labels("add", &[]);
+    endpoint.add_input::<bool>("flag");
+    endpoint.add_input::<MultiValueVec<u32>>("values");
+
+    ContractAbi {
+        constructors: vec![constructor],
+        endpoints: vec![endpoint],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_tx_data_call() {
+    let builder = TxDataBuilder::call(&contract(), "add")
+        .argument(&true)
+        .argument(&5u32)
+        .argument(&6u32);
+    let data = builder.build();
+    assert_eq!(data, "add@01@05@06");
+    assert_eq!(builder.tx_receiver(&sender()), contract());
+
+    let decoded = decode_tx_data(data.as_bytes(), Some(&test_abi())).unwrap();
+    assert_eq!(decoded.kind, DecodedTxKind::Call);
+    assert_eq!(decoded.function, "add");
+    assert!(decoded.payments.is_empty());
+    assert_eq!(decoded.destination, None);
+
+    let names: Vec<_> = decoded.args.iter().map(|arg| arg.name.as_deref()).collect();
+    assert_eq!(names, [Some("flag"), Some("values"), Some("values")]);
+    let displays: Vec<_> = decoded
+        .args
+        .iter()
+        .map(|arg| arg.display.as_deref())
+        .collect();
+    assert_eq!(displays, [Some("true"), Some("5"), Some("6")]);
+}
+
+#[test]
+fn test_tx_data_call_without_abi() {
+    let decoded = decode_tx_data(b"unknown@0102", None).unwrap();
+    assert_eq!(decoded.function, "unknown");
+    assert_eq!(decoded.args.len(), 1);
+    assert_eq!(decoded.args[0].raw, vec![1, 2]);
+    assert_eq!(decoded.args[0].name, None);
+}
+
+#[test]
+fn test_tx_data_call_counted_variadic() {
+    let mut endpoint = EndpointAbi::endpoint_with_name_and_labels("addCounted", &[]);
+    endpoint.inputs.push(InputAbi {
+        arg_name: "values",
+        type_name: "counted-variadic<u32>".into(),
+        multi_arg: true,
+    });
+    endpoint.add_input::<bool>("flag");
+    let abi = ContractAbi {
+        endpoints: vec![endpoint],
+        ..Default::default()
+    };
+
+    let decoded = decode_tx_data(b"addCounted@02@05@06@01", Some(&abi)).unwrap();
+    let names: Vec<_> = decoded.args.iter().map(|arg| arg.name.as_deref()).collect();
+    assert_eq!(
+        names,
+        [Some("values"), Some("values"), Some("values"), Some("flag")]
+    );
+    let displays: Vec<_> = decoded
+        .args
+        .iter()
+        .map(|arg| arg.display.as_deref())
+        .collect();
+    assert_eq!(displays, [Some("2"), Some("5"), Some("6"), Some("true")]);
+}
+
+#[test]
+fn test_tx_data_esdt_transfer() {
+    let builder = TxDataBuilder::call(&contract(), "add")
+        .esdt_payment("TOKEN-123456", 0, BigUint::from(1000u32))
+        .argument(&false);
+    let data = builder.build();
+    assert_eq!(data, "ESDTTransfer@544f4b454e2d313233343536@03e8@616464@");
+    assert_eq!(builder.tx_receiver(&sender()), contract());
+
+    let decoded = decode_tx_data(data.as_bytes(), Some(&test_abi())).unwrap();
+    assert_eq!(decoded.function, "add");
+    assert_eq!(
+        decoded.payments,
+        [TxDataPayment::new(
+            "TOKEN-123456",
+            0,
+            BigUint::from(1000u32)
+        )]
+    );
+    assert_eq!(decoded.destination, None);
+    assert_eq!(decoded.args.len(), 1);
+    assert_eq!(decoded.args[0].display.as_deref(), Some("false"));
+}
+
+#[test]
+fn test_tx_data_simple_esdt_transfer() {
+    let data = TxDataBuilder::call(&contract(), "")
+        .esdt_payment("TOKEN-123456", 0, BigUint::from(1u32))
+        .build();
+    assert_eq!(data, "ESDTTransfer@544f4b454e2d313233343536@01");
+
+    let decoded = decode_tx_data(data.as_bytes(), None).unwrap();
+    assert_eq!(decoded.function, "");
+    assert_eq!(decoded.payments.len(), 1);
+    assert!(decoded.args.is_empty());
+}
+
+#[test]
+fn test_tx_data_nft_transfer() {
+    let builder = TxDataBuilder::call(&contract(), "add")
+        .esdt_payment("NFT-123456", 5, BigUint::from(1u32))
+        .argument(&true);
+    let data = builder.build();
+    assert_eq!(
+        data,
+        format!(
+            "ESDTNFTTransfer@4e46542d313233343536@05@01@{}@616464@01",
+            hex::encode(contract().to_bytes())
+        )
+    );
+    assert_eq!(builder.tx_receiver(&sender()), sender());
+
+    let decoded = decode_tx_data(data.as_bytes(), Some(&test_abi())).unwrap();
+    assert_eq!(decoded.function, "add");
+    assert_eq!(
+        decoded.payments,
+        [TxDataPayment::new("NFT-123456", 5, BigUint::from(1u32))]
+    );
+    assert_eq!(decoded.destination, Some(contract()));
+    assert_eq!(decoded.args[0].name.as_deref(), Some("flag"));
+}
+
+#[test]
+fn test_tx_data_multi_transfer() {
+    let payments = [
+        TxDataPayment::new("TOKEN-123456", 0, BigUint::from(100u32)),
+        TxDataPayment::new("NFT-123456", 5, BigUint::from(1u32)),
+    ];
+    let mut builder = TxDataBuilder::call(&contract(), "add");
+    for payment in &payments {
+        builder = builder.esdt_payment(
+            &payment.token_identifier,
+            payment.token_nonce,
+            payment.amount.clone(),
+        );
+    }
+    assert_eq!(builder.tx_receiver(&sender()), sender());
+    let data = builder.argument(&true).build();
+
+    let decoded = decode_tx_data(data.as_bytes(), Some(&test_abi())).unwrap();
+    assert_eq!(decoded.kind, DecodedTxKind::Call);
+    assert_eq!(decoded.function, "add");
+    assert_eq!(decoded.payments, payments);
+    assert_eq!(decoded.destination, Some(contract()));
+    assert_eq!(decoded.args.len(), 1);
+}
+
+#[test]
+fn test_tx_data_deploy() {
+    let builder = TxDataBuilder::deploy(CODE, CodeMetadata::UPGRADEABLE).argument(&7u64);
+    let data = builder.build();
+    assert_eq!(data, format!("{}@0500@0100@07", hex::encode(CODE)));
+    assert_eq!(
+        builder.tx_receiver(&sender()),
+        Address::from_bytes([0u8; 32])
+    );
+
+    let decoded = decode_tx_data(data.as_bytes(), Some(&test_abi())).unwrap();
+    assert_eq!(
+        decoded.kind,
+        DecodedTxKind::Deploy {
+            code: CODE.to_vec(),
+            code_metadata: CodeMetadata::UPGRADEABLE,
+        }
+    );
+    assert_eq!(decoded.function, "init");
+    assert_eq!(decoded.args[0].name.as_deref(), Some("initial_value"));
+    assert_eq!(decoded.args[0].display.as_deref(), Some("7"));
+}
+
+#[test]
+fn test_tx_data_upgrade() {
+    let data = TxDataBuilder::upgrade(&contract(), CODE, CodeMetadata::DEFAULT)
+        .argument(&7u64)
+        .build();
+
+    let decoded = decode_tx_data(data.as_bytes(), None).unwrap();
+    assert_eq!(
+        decoded.kind,
+        DecodedTxKind::Upgrade {
+            code: CODE.to_vec(),
+            code_metadata: CodeMetadata::DEFAULT,
+        }
+    );
+    assert_eq!(decoded.args.len(), 1);
+}
+
+#[test]
+fn test_tx_data_decode_errors() {
+    assert!(decode_tx_data(b"ESDTTransfer@544f4b454e", None).is_err());
+    assert!(decode_tx_data(b"ESDTNFTTransfer@4e4654@05@01@0102", None).is_err());
+    assert!(decode_tx_data(b"add@zz", None).is_err());
+}
@@ -29,4 +29,8 @@ pub struct TxCallRaw {
 
     pub gas_limit: ValueSubTree,
     pub gas_price: ValueSubTree,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<ValueSubTree>,
 }
@@ -22,4 +22,8 @@ pub struct TxDeployRaw {
 
     pub gas_limit: ValueSubTree,
     pub gas_price: ValueSubTree,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<ValueSubTree>,
 }
@@ -1,5 +1,3 @@
-use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode};
-
 const FLAG_PREFIX: &str = "--";
 const HEX_PREFIX: &str = "0x";
 const SPACE: &str = " ";
@@ -32,16 +30,10 @@ impl CmdBuilder {
         self.cmd += flag_name;
     }
 
-    pub fn to_hex<T: TopEncode>(arg: &T) -> String {
-        hex::encode(top_encode_to_vec_u8_or_panic(arg))
-    }
-
-    pub fn add_standalone_argument<T: TopEncode>(&mut self, arg: &T) {
-        let arg_as_hex = Self::to_hex(arg);
-
+    pub fn add_hex_argument(&mut self, arg_hex: &str) {
         self.add_space();
         self.cmd += HEX_PREFIX;
-        self.cmd += &arg_as_hex;
+        self.cmd += arg_hex;
     }
 
     pub fn add_numerical_argument(&mut self, arg_name: &str, arg: &num_bigint::BigUint) {
@@ -28,11 +28,6 @@ pub const VERBOSE_FLAG: &str = "verbose";
 pub const RECALL_NONCE_FLAG: &str = "recall-nonce";
 pub const SEND_FLAG: &str = "send";
 
-// built-in function names
-pub const ESDT_TRANSFER_FUNC_NAME: &str = "ESDTTransfer";
-pub const NFT_TRANSFER_FUNC_NAME: &str = "ESDTNFTTransfer";
-pub const MULTI_TRANSFER_FUNC_NAME: &str = "MultiESDTNFTTransfer";
-
 // default proxies
 pub const TESTNET_PROXY: &str = "https://testnet-gateway.multiversx.com";
 pub const DEVNET_PROXY: &str = "https://devnet-gateway.multiversx.com";
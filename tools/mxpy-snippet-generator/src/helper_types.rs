@@ -1,10 +1,6 @@
-use bech32::FromBase32;
-use multiversx_sc::types::heap::Address;
-
 use crate::constants::*;
 
 pub type EsdtTransferTuple = (String, u64, num_bigint::BigUint);
-const ADDRESS_LEN: usize = 32;
 
 pub enum WalletType {
     PemPath(String),
@@ -77,13 +73,3 @@ impl TransactionType {
         }
     }
 }
-
-pub fn bech32_to_bytes(bech32_address: &str) -> Address {
-    let (_, dest_address_bytes_u5, _) = bech32::decode(bech32_address).unwrap();
-    let dest_address_bytes = Vec::<u8>::from_base32(&dest_address_bytes_u5).unwrap();
-    if dest_address_bytes.len() != ADDRESS_LEN {
-        panic!("Invalid address length after decoding")
-    }
-
-    Address::from_slice(&dest_address_bytes)
-}
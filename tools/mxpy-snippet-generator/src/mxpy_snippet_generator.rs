@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode};
+use multiversx_sdk::{data::address::Address, tx_data::TxDataBuilder};
 use num_traits::Zero;
 
 mod cmd_builder;
@@ -149,43 +150,28 @@ impl MxpySnippetGenerator {
         self.sender_nonce = Some(nonce);
     }
 
-    fn encode_arg<T: TopEncode>(&mut self, arg: &T) -> Vec<u8> {
-        top_encode_to_vec_u8_or_panic(arg)
-    }
-
     pub fn add_argument<T: TopEncode>(&mut self, arg: &T) {
-        let arg_bytes = self.encode_arg(arg);
-        self.arguments.push(arg_bytes);
+        self.arguments.push(top_encode_to_vec_u8_or_panic(arg));
     }
 
-    pub fn print(mut self, print_option: PrintOption) {
+    pub fn print(self, print_option: PrintOption) {
         let mut cmd_builder = CmdBuilder::new(MXPY_PROGRAM_NAME);
         cmd_builder.add_flag(VERBOSE_FLAG);
         cmd_builder.add_command(CONTRACT_COMMAND_NAME);
 
-        let function_name: Option<String>;
+        let opt_tx_data: Option<String>;
+        let arguments_hex: Vec<String>;
         match &self.tx {
             TransactionType::Deploy {
                 deploy_type,
                 opt_json_out_file,
             } => {
                 cmd_builder.add_command(DEPLOY_COMMAND_NAME);
-
-                match deploy_type {
-                    DeployType::ProjectPath(path) => {
-                        cmd_builder.add_raw_named_argument(PROJECT_ARG_NAME, path);
-                    },
-                    DeployType::WasmFilePath(path) => {
-                        cmd_builder.add_raw_named_argument(WASM_PATH_ARG_NAME, path);
-                    },
-                }
-
-                if let Some(json_out_file) = opt_json_out_file {
-                    cmd_builder.add_raw_named_argument(OUT_FILE_PATH_ARG_NAME, json_out_file);
-                }
+                Self::add_deploy_type(&mut cmd_builder, deploy_type, opt_json_out_file);
 
                 self.handle_common_non_query_steps(&mut cmd_builder);
-                function_name = None;
+                opt_tx_data = None;
+                arguments_hex = self.arguments.iter().map(hex::encode).collect();
             },
             TransactionType::Upgrade {
                 dest_address_bech32,
@@ -194,22 +180,11 @@ impl MxpySnippetGenerator {
             } => {
                 cmd_builder.add_command(UPGRADE_COMMAND_NAME);
                 cmd_builder.append_string_no_quotes(dest_address_bech32);
-
-                match deploy_type {
-                    DeployType::ProjectPath(path) => {
-                        cmd_builder.add_raw_named_argument(PROJECT_ARG_NAME, path);
-                    },
-                    DeployType::WasmFilePath(path) => {
-                        cmd_builder.add_raw_named_argument(WASM_PATH_ARG_NAME, path);
-                    },
-                }
-
-                if let Some(json_out_file) = opt_json_out_file {
-                    cmd_builder.add_raw_named_argument(OUT_FILE_PATH_ARG_NAME, json_out_file);
-                }
+                Self::add_deploy_type(&mut cmd_builder, deploy_type, opt_json_out_file);
 
                 self.handle_common_non_query_steps(&mut cmd_builder);
-                function_name = None;
+                opt_tx_data = None;
+                arguments_hex = self.arguments.iter().map(hex::encode).collect();
             },
             TransactionType::Call {
                 sender_address_bech32,
@@ -219,66 +194,46 @@ impl MxpySnippetGenerator {
             } => {
                 cmd_builder.add_command(CALL_COMMAND_NAME);
 
-                match esdt_transfers.len() {
-                    0 => {
-                        let dest_clone = dest_address_bech32.clone();
-                        let function_clone = function.clone();
-
-                        function_name = Some(function.clone());
-                        self.contract_call_no_esdt(&mut cmd_builder, dest_clone, function_clone);
-                    },
-                    1 => {
-                        let sender_clone = sender_address_bech32.clone();
-                        let dest_clone = dest_address_bech32.clone();
-                        let function_clone = function.clone();
-                        let esdt_transfer_clone = esdt_transfers[0].clone();
-
-                        let transfer_func_name = self.contract_call_one_esdt(
-                            &mut cmd_builder,
-                            sender_clone,
-                            dest_clone,
-                            function_clone,
-                            esdt_transfer_clone,
-                        );
-                        function_name = Some(transfer_func_name);
-                    },
-                    _ => {
-                        let sender_clone = sender_address_bech32.clone();
-                        let dest_clone = dest_address_bech32.clone();
-                        let function_clone = function.clone();
-                        let transfers_clone = esdt_transfers.clone();
-
-                        self.contract_call_multi_transfer(
-                            &mut cmd_builder,
-                            sender_clone,
-                            dest_clone,
-                            function_clone,
-                            transfers_clone,
-                        );
-                        function_name = Some(MULTI_TRANSFER_FUNC_NAME.to_owned());
-                    },
+                // ESDT transfers are calls to builtin functions, the SC call is in their arguments
+                let mut tx_data_builder =
+                    TxDataBuilder::call(&parse_address(dest_address_bech32), function);
+                for (token_id, token_nonce, amount) in esdt_transfers {
+                    tx_data_builder =
+                        tx_data_builder.esdt_payment(token_id, *token_nonce, amount.clone());
                 }
+                let tx_data_builder = self.add_arguments(tx_data_builder);
+                let receiver = tx_data_builder.tx_receiver(&parse_address(sender_address_bech32));
+                let tx_data = tx_data_builder.build();
+
+                let (tx_function, tx_arguments_hex) = split_tx_data(&tx_data);
+                cmd_builder.append_string_no_quotes(&receiver.to_bech32_string().unwrap());
+                cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, &tx_function);
 
                 self.handle_common_non_query_steps(&mut cmd_builder);
+                opt_tx_data = Some(tx_data);
+                arguments_hex = tx_arguments_hex;
             },
             TransactionType::Query {
                 dest_address_bech32,
                 function,
             } => {
                 cmd_builder.add_command(QUERY_COMMAND_NAME);
-
-                let dest_clone = dest_address_bech32.clone();
-                let function_clone = function.clone();
-
-                function_name = Some(function.clone());
-                self.contract_call_no_esdt(&mut cmd_builder, dest_clone, function_clone);
+                cmd_builder.append_string_no_quotes(dest_address_bech32);
+                cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, function);
+
+                let tx_data_builder = self.add_arguments(TxDataBuilder::call(
+                    &parse_address(dest_address_bech32),
+                    function,
+                ));
+                opt_tx_data = Some(tx_data_builder.build());
+                arguments_hex = self.arguments.iter().map(hex::encode).collect();
             },
         }
 
-        if !self.arguments.is_empty() {
+        if !arguments_hex.is_empty() {
             cmd_builder.add_flag(ARGUMENTS_ARG_NAME);
-            for arg in &self.arguments {
-                cmd_builder.add_standalone_argument(arg);
+            for arg_hex in &arguments_hex {
+                cmd_builder.add_hex_argument(arg_hex);
             }
         }
 
@@ -288,29 +243,45 @@ impl MxpySnippetGenerator {
 
         match print_option {
             PrintOption::MxpySnippet => cmd_builder.print(),
-            PrintOption::TxData => Self::print_tx_data(function_name, &self.arguments),
+            PrintOption::TxData => Self::print_tx_data(opt_tx_data),
             PrintOption::Both => {
-                Self::print_tx_data(function_name, &self.arguments);
+                Self::print_tx_data(opt_tx_data);
                 println!();
                 cmd_builder.print();
             },
         }
     }
 
-    fn print_tx_data(opt_function_name: Option<String>, arguments: &[Vec<u8>]) {
-        let function_name = match opt_function_name {
-            Some(f) => f,
-            None => return,
-        };
-
-        let mut tx_data = function_name;
-        for arg in arguments {
-            let arg_as_hex = CmdBuilder::to_hex(arg);
-            tx_data += "@";
-            tx_data += &arg_as_hex;
+    fn print_tx_data(opt_tx_data: Option<String>) {
+        if let Some(tx_data) = opt_tx_data {
+            println!("{tx_data}");
+        }
+    }
+
+    fn add_arguments(&self, mut tx_data_builder: TxDataBuilder) -> TxDataBuilder {
+        for arg in &self.arguments {
+            tx_data_builder = tx_data_builder.raw_argument(arg.clone());
+        }
+        tx_data_builder
+    }
+
+    fn add_deploy_type(
+        cmd_builder: &mut CmdBuilder,
+        deploy_type: &DeployType,
+        opt_json_out_file: &Option<String>,
+    ) {
+        match deploy_type {
+            DeployType::ProjectPath(path) => {
+                cmd_builder.add_raw_named_argument(PROJECT_ARG_NAME, path);
+            },
+            DeployType::WasmFilePath(path) => {
+                cmd_builder.add_raw_named_argument(WASM_PATH_ARG_NAME, path);
+            },
         }
 
-        println!("{tx_data}");
+        if let Some(json_out_file) = opt_json_out_file {
+            cmd_builder.add_raw_named_argument(OUT_FILE_PATH_ARG_NAME, json_out_file);
+        }
     }
 
     fn handle_common_non_query_steps(&self, cmd_builder: &mut CmdBuilder) {
@@ -346,105 +317,18 @@ impl MxpySnippetGenerator {
             &num_bigint::BigUint::from(self.gas_limit),
         );
     }
+}
 
-    fn contract_call_no_esdt(
-        &mut self,
-        cmd_builder: &mut CmdBuilder,
-        dest_address_bech32: String,
-        function: String,
-    ) {
-        cmd_builder.append_string_no_quotes(&dest_address_bech32);
-        cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, &function);
-    }
-
-    fn contract_call_one_esdt(
-        &mut self,
-        cmd_builder: &mut CmdBuilder,
-        sender_address_bech32: String,
-        dest_address_bech32: String,
-        function: String,
-        esdt_transfer: EsdtTransferTuple,
-    ) -> String {
-        let (token_id, token_nonce, amount) = esdt_transfer;
-        if token_nonce == 0 {
-            cmd_builder.append_string_no_quotes(&dest_address_bech32);
-            cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, ESDT_TRANSFER_FUNC_NAME);
-
-            let mut args = Vec::new();
-            let token_id_encoded = self.encode_arg(&token_id);
-            let amount_encoded = self.encode_arg(&amount.to_bytes_be());
-            let function_encoded = self.encode_arg(&function);
-
-            args.push(token_id_encoded);
-            args.push(amount_encoded);
-            args.push(function_encoded);
-            args.extend_from_slice(&self.arguments);
-
-            self.arguments = args;
-
-            ESDT_TRANSFER_FUNC_NAME.to_owned()
-        } else {
-            cmd_builder.append_string_no_quotes(&sender_address_bech32);
-            cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, NFT_TRANSFER_FUNC_NAME);
-
-            let dest_addr = bech32_to_bytes(&dest_address_bech32);
-
-            let mut args = Vec::new();
-            let token_id_encoded = self.encode_arg(&token_id);
-            let token_nonce_encoded = self.encode_arg(&token_nonce);
-            let amount_encoded = self.encode_arg(&amount.to_bytes_be());
-            let dest_encoded = self.encode_arg(&dest_addr);
-            let function_encoded = self.encode_arg(&function);
-
-            args.push(token_id_encoded);
-            args.push(token_nonce_encoded);
-            args.push(amount_encoded);
-            args.push(dest_encoded);
-            args.push(function_encoded);
-            args.extend_from_slice(&self.arguments);
-
-            self.arguments = args;
-
-            NFT_TRANSFER_FUNC_NAME.to_owned()
-        }
-    }
-
-    fn contract_call_multi_transfer(
-        &mut self,
-        cmd_builder: &mut CmdBuilder,
-        sender_address_bech32: String,
-        dest_address_bech32: String,
-        function: String,
-        esdt_transfers: Vec<EsdtTransferTuple>,
-    ) {
-        cmd_builder.append_string_no_quotes(&sender_address_bech32);
-        cmd_builder.add_raw_named_argument(FUNCTION_ARG_NAME, MULTI_TRANSFER_FUNC_NAME);
-
-        let dest_addr = bech32_to_bytes(&dest_address_bech32);
-
-        let mut args = Vec::new();
-        let dest_encoded = self.encode_arg(&dest_addr);
-        let nr_transfers_encoded = self.encode_arg(&esdt_transfers.len());
-        let function_encoded = self.encode_arg(&function);
-
-        args.push(dest_encoded);
-        args.push(nr_transfers_encoded);
-
-        for (token_id, token_nonce, amount) in esdt_transfers {
-            let token_id_encoded = self.encode_arg(&token_id);
-            let token_nonce_encoded = self.encode_arg(&token_nonce);
-            let amount_encoded = self.encode_arg(&amount.to_bytes_be());
-
-            args.push(token_id_encoded);
-            args.push(token_nonce_encoded);
-            args.push(amount_encoded);
-        }
-
-        args.push(function_encoded);
-        args.extend_from_slice(&self.arguments);
+fn parse_address(bech32_address: &str) -> Address {
+    Address::from_bech32_string(bech32_address).expect("invalid bech32 address")
+}
 
-        self.arguments = args;
-    }
+/// Splits `function@arg1@arg2` into the function name and the hex-encoded arguments.
+fn split_tx_data(tx_data: &str) -> (String, Vec<String>) {
+    let mut parts = tx_data.split('@');
+    let function = parts.next().unwrap_or_default().to_string();
+    let arguments_hex = parts.map(str::to_string).collect();
+    (function, arguments_hex)
 }
 
 fn main() {
@@ -4,6 +4,8 @@ mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
 mod exec_general_tx;
+mod mock_contracts;
+mod mock_tx_handler;
 mod system_sc;
 
 pub use blockchain_vm::{BlockchainVM, BlockchainVMRef};
@@ -12,4 +14,6 @@ pub use exec_call::*;
 pub use exec_contract_endpoint::*;
 pub use exec_create::*;
 pub use exec_general_tx::*;
+pub use mock_contracts::MockContractContainer;
+pub use mock_tx_handler::MockTxHandler;
 pub use system_sc::*;
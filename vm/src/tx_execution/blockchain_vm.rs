@@ -1,12 +1,19 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
 
 use multiversx_chain_vm_executor::Executor;
 
-use super::BuiltinFunctionContainer;
+use crate::tx_mock::ManagedTypesLimits;
+
+use super::{BuiltinFunctionContainer, MockContractContainer};
 
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
+    pub mock_contracts: MockContractContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+    managed_types_limits: Mutex<ManagedTypesLimits>,
 }
 
 #[derive(Clone)]
@@ -15,10 +22,21 @@ pub struct BlockchainVMRef(Arc<BlockchainVM>);
 impl BlockchainVM {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVM {
-            builtin_functions: BuiltinFunctionContainer,
+            builtin_functions: BuiltinFunctionContainer::default(),
+            mock_contracts: MockContractContainer::default(),
             executor,
+            managed_types_limits: Mutex::new(ManagedTypesLimits::unlimited()),
         }
     }
+
+    /// Limits applied to the managed types of each call, starting with the next transaction.
+    pub fn set_managed_types_limits(&self, limits: ManagedTypesLimits) {
+        *self.managed_types_limits.lock().unwrap() = limits;
+    }
+
+    pub fn managed_types_limits(&self) -> ManagedTypesLimits {
+        *self.managed_types_limits.lock().unwrap()
+    }
 }
 
 impl BlockchainVMRef {
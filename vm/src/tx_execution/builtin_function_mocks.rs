@@ -1,5 +1,6 @@
 mod builtin_func_container;
 mod builtin_func_trait;
+mod esdt_admin;
 mod esdt_nft;
 mod general;
 mod transfer;
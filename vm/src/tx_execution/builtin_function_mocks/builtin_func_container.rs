@@ -1,27 +1,60 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
 use super::{
     builtin_func_trait::BuiltinFunction,
     builtin_function_names::*,
+    esdt_admin::{
+        ESDTFreeze, ESDTSetLimitedTransfer, ESDTUnFreeze, ESDTUnSetLimitedTransfer, ESDTWipe,
+    },
     esdt_nft::{
         ESDTLocalBurn, ESDTLocalMint, ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn,
         ESDTNftCreate, ESDTNftUpdateAttributes,
     },
-    general::{ChangeOwner, ClaimDeveloperRewards, DeleteUsername, SetUsername, UpgradeContract},
+    general::{
+        ChangeOwner, ClaimDeveloperRewards, DeleteUsername, GuardAccount, SaveKeyValue,
+        SetGuardian, SetUsername, UnGuardAccount, UpgradeContract,
+    },
     transfer::{ESDTMultiTransfer, ESDTNftTransfer, ESDTTransfer},
     BuiltinFunctionEsdtTransferInfo,
 };
 use crate::{
-    tx_execution::BlockchainVMRef,
+    tx_execution::{mock_contracts::execute_mock_tx_handler, BlockchainVMRef, MockTxHandler},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::EsdtLocalRole,
 };
 
 /// Container for builtin function logic.
 ///
-/// Currently has no data, but could conceivably be configurable in the future.
-pub struct BuiltinFunctionContainer;
+/// Besides the protocol builtin functions, it can hold custom builtin functions, implemented in Rust.
+#[derive(Default)]
+pub struct BuiltinFunctionContainer {
+    custom_functions: Mutex<HashMap<String, Arc<dyn MockTxHandler>>>,
+}
 
 impl BuiltinFunctionContainer {
-    /// If the call points to a builtin function, it executes it, otherwise calls the `or_else` closure.
+    /// Registers a custom builtin function, which takes precedence over any protocol builtin function with the same name.
+    pub fn register_custom_function<H>(&self, function_name: &str, handler: H)
+    where
+        H: MockTxHandler + 'static,
+    {
+        self.custom_functions
+            .lock()
+            .unwrap()
+            .insert(function_name.to_string(), Arc::new(handler));
+    }
+
+    fn get_custom_function(&self, function_name: &str) -> Option<Arc<dyn MockTxHandler>> {
+        self.custom_functions
+            .lock()
+            .unwrap()
+            .get(function_name)
+            .cloned()
+    }
+
+    /// If the call points to a builtin function (protocol or custom), it executes it, otherwise calls the `or_else` closure.
     ///
     /// It also checks that the appropriate roles are set, where applicable.
     pub fn execute_builtin_function_or_else<F, Else>(
@@ -79,6 +112,14 @@ impl<'a> BuiltinFunctionCall<'a> {
         F: FnOnce(),
         Else: FnOnce(TxInput, TxCache, F) -> (TxResult, BlockchainUpdate),
     {
+        if let Some(handler) = self
+            .vm
+            .builtin_functions
+            .get_custom_function(self.tx_input.func_name.as_str())
+        {
+            return execute_mock_tx_handler(handler.as_ref(), &self.tx_input, self.tx_cache);
+        }
+
         match self.tx_input.func_name.as_str() {
             ESDT_LOCAL_MINT_FUNC_NAME => {
                 self.check_role_and_execute(EsdtLocalRole::Mint, ESDTLocalMint, f)
@@ -112,6 +153,15 @@ impl<'a> BuiltinFunctionCall<'a> {
             SET_USERNAME_FUNC_NAME => self.execute_bf(SetUsername, f),
             DELETE_USERNAME_FUNC_NAME => self.execute_bf(DeleteUsername, f),
             UPGRADE_CONTRACT_FUNC_NAME => self.execute_bf(UpgradeContract, f),
+            SAVE_KEY_VALUE_FUNC_NAME => self.execute_bf(SaveKeyValue, f),
+            ESDT_FREEZE_FUNC_NAME => self.execute_bf(ESDTFreeze, f),
+            ESDT_UNFREEZE_FUNC_NAME => self.execute_bf(ESDTUnFreeze, f),
+            ESDT_WIPE_FUNC_NAME => self.execute_bf(ESDTWipe, f),
+            ESDT_SET_LIMITED_TRANSFER_FUNC_NAME => self.execute_bf(ESDTSetLimitedTransfer, f),
+            ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME => self.execute_bf(ESDTUnSetLimitedTransfer, f),
+            SET_GUARDIAN_FUNC_NAME => self.execute_bf(SetGuardian, f),
+            GUARD_ACCOUNT_FUNC_NAME => self.execute_bf(GuardAccount, f),
+            UNGUARD_ACCOUNT_FUNC_NAME => self.execute_bf(UnGuardAccount, f),
             MIGRATE_USERNAME_FUNC_NAME => {
                 panic!("builtin function {MIGRATE_USERNAME_FUNC_NAME} was dropped")
             },
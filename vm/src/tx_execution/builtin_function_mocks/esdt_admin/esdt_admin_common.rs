@@ -0,0 +1,28 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_execution::is_system_sc_address,
+    tx_mock::{TxInput, TxPanic},
+};
+
+/// The ESDT administration builtin functions can only be called by the ESDT system SC,
+/// with the token identifier as single argument.
+pub(super) fn check_esdt_admin_call(tx_input: &TxInput) -> Result<(), TxPanic> {
+    if tx_input.args.len() != 1 {
+        return Err(TxPanic::vm_error(
+            "invalid arguments to process built-in function",
+        ));
+    }
+
+    if !tx_input.egld_value.is_zero() {
+        return Err(TxPanic::vm_error(
+            "built in function called with tx value is not allowed",
+        ));
+    }
+
+    if !is_system_sc_address(&tx_input.from) {
+        return Err(TxPanic::vm_error("caller is not the ESDT system SC"));
+    }
+
+    Ok(())
+}
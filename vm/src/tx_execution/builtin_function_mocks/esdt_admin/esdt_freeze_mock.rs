@@ -0,0 +1,65 @@
+use crate::{
+    tx_execution::{
+        builtin_function_names::{ESDT_FREEZE_FUNC_NAME, ESDT_UNFREEZE_FUNC_NAME},
+        BlockchainVMRef,
+    },
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{super::builtin_func_trait::BuiltinFunction, esdt_admin_common::check_esdt_admin_call};
+
+/// Freezes the token for the destination account: no transfers to or from it are allowed anymore.
+pub struct ESDTFreeze;
+
+impl BuiltinFunction for ESDTFreeze {
+    fn name(&self) -> &str {
+        ESDT_FREEZE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        set_frozen(tx_input, tx_cache, true)
+    }
+}
+
+pub struct ESDTUnFreeze;
+
+impl BuiltinFunction for ESDTUnFreeze {
+    fn name(&self) -> &str {
+        ESDT_UNFREEZE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        set_frozen(tx_input, tx_cache, false)
+    }
+}
+
+fn set_frozen(tx_input: TxInput, tx_cache: TxCache, frozen: bool) -> (TxResult, BlockchainUpdate) {
+    if let Err(err) = check_esdt_admin_call(&tx_input) {
+        return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        account.esdt.set_frozen(token_identifier, frozen);
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
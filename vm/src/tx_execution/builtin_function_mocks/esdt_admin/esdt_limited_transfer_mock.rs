@@ -0,0 +1,80 @@
+use crate::{
+    tx_execution::{
+        builtin_function_names::{
+            ESDT_SET_LIMITED_TRANSFER_FUNC_NAME, ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME,
+        },
+        BlockchainVMRef,
+    },
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::ESDT_SYSTEM_ACCOUNT_ADDRESS_ARRAY,
+};
+
+use super::{super::builtin_func_trait::BuiltinFunction, esdt_admin_common::check_esdt_admin_call};
+
+/// Restricts transfers of the token to the ones where the sender or the receiver has the `ESDTTransferRole`.
+///
+/// The setting is global, so it is sent to the system account.
+pub struct ESDTSetLimitedTransfer;
+
+impl BuiltinFunction for ESDTSetLimitedTransfer {
+    fn name(&self) -> &str {
+        ESDT_SET_LIMITED_TRANSFER_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        set_limited_transfer(tx_input, tx_cache, true)
+    }
+}
+
+pub struct ESDTUnSetLimitedTransfer;
+
+impl BuiltinFunction for ESDTUnSetLimitedTransfer {
+    fn name(&self) -> &str {
+        ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        set_limited_transfer(tx_input, tx_cache, false)
+    }
+}
+
+fn set_limited_transfer(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    limited_transfer: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err) = check_esdt_admin_call(&tx_input) {
+        return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+    }
+
+    if tx_input.to.as_array() != &ESDT_SYSTEM_ACCOUNT_ADDRESS_ARRAY {
+        return (
+            TxResult::from_vm_error("only system account is accepted"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    tx_cache.update_esdt_global_metadata(&tx_input.args[0], |metadata| {
+        metadata.limited_transfer = limited_transfer;
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
@@ -0,0 +1,71 @@
+use crate::{
+    tx_execution::{builtin_function_names::ESDT_WIPE_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+};
+
+use super::{super::builtin_func_trait::BuiltinFunction, esdt_admin_common::check_esdt_admin_call};
+
+/// Burns the entire fungible balance of the destination account.
+///
+/// The account needs to have been frozen beforehand.
+pub struct ESDTWipe;
+
+impl BuiltinFunction for ESDTWipe {
+    fn name(&self) -> &str {
+        ESDT_WIPE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_esdt_admin_call(&tx_input) {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].clone();
+        if !tx_cache.is_esdt_frozen(&tx_input.to, &token_identifier) {
+            return (
+                TxResult::from_vm_error(
+                    "cannot wipe because the account is not frozen for this esdt token",
+                ),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        let balance = tx_cache.with_account(&tx_input.to, |account| {
+            account.esdt.get_esdt_balance(&token_identifier, 0)
+        });
+        let subtract_result =
+            tx_cache.subtract_esdt_balance(&tx_input.to, &token_identifier, 0, &balance);
+        if let Err(err) = subtract_result {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+
+        let esdt_wipe_log = TxLog {
+            address: tx_input.to.clone(),
+            endpoint: ESDT_WIPE_FUNC_NAME.into(),
+            topics: vec![
+                token_identifier,
+                Vec::new(),
+                balance.to_bytes_be(),
+                tx_input.to.to_vec(),
+            ],
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: 0,
+            result_logs: vec![esdt_wipe_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
@@ -0,0 +1,8 @@
+mod esdt_admin_common;
+mod esdt_freeze_mock;
+mod esdt_limited_transfer_mock;
+mod esdt_wipe_mock;
+
+pub use esdt_freeze_mock::*;
+pub use esdt_limited_transfer_mock::*;
+pub use esdt_wipe_mock::*;
@@ -0,0 +1,47 @@
+use crate::{
+    tx_execution::{builtin_function_names::GUARD_ACCOUNT_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{super::builtin_func_trait::BuiltinFunction, guardian_common::check_guardian_call};
+
+/// From now on, the caller can only send transactions co-signed by its active guardian.
+pub struct GuardAccount;
+
+impl BuiltinFunction for GuardAccount {
+    fn name(&self) -> &str {
+        GUARD_ACCOUNT_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_guardian_call(&tx_input, 0) {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+
+        let current_epoch = tx_cache.blockchain_ref().current_block_info.block_epoch;
+        let guard_result = tx_cache.with_account_mut(&tx_input.to, |account| {
+            if account.guardians.guarded {
+                return Err("account already guarded");
+            }
+            if account.guardians.active_guardian(current_epoch).is_none() {
+                return Err("no active guardian");
+            }
+            account.guardians.guarded = true;
+            Ok(())
+        });
+        if let Err(message) = guard_result {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
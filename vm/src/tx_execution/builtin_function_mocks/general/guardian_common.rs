@@ -0,0 +1,24 @@
+use num_traits::Zero;
+
+use crate::tx_mock::{TxInput, TxPanic};
+
+/// The guardian builtin functions can only be called by a user account on itself, without value.
+pub(super) fn check_guardian_call(tx_input: &TxInput, num_args: usize) -> Result<(), TxPanic> {
+    if tx_input.args.len() != num_args {
+        return Err(TxPanic::vm_error(
+            "invalid arguments to process built-in function",
+        ));
+    }
+
+    if !tx_input.egld_value.is_zero() {
+        return Err(TxPanic::vm_error(
+            "built in function called with tx value is not allowed",
+        ));
+    }
+
+    if tx_input.from != tx_input.to || tx_input.from.is_smart_contract_address() {
+        return Err(TxPanic::vm_error("operation in account not permitted"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_execution::{builtin_function_names::SAVE_KEY_VALUE_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::reserved::STORAGE_RESERVED_PREFIX,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Lets a user account write directly to its own storage.
+///
+/// Arguments come in key-value pairs. Reserved keys cannot be written.
+pub struct SaveKeyValue;
+
+impl BuiltinFunction for SaveKeyValue {
+    fn name(&self) -> &str {
+        SAVE_KEY_VALUE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if tx_input.args.is_empty() || tx_input.args.len() % 2 != 0 {
+            return (
+                TxResult::from_vm_error("invalid arguments to process built-in function"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        if !tx_input.egld_value.is_zero() {
+            return (
+                TxResult::from_vm_error("built in function called with tx value is not allowed"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        let is_reserved_key_included = tx_input
+            .args
+            .chunks(2)
+            .any(|pair| pair[0].starts_with(STORAGE_RESERVED_PREFIX));
+        if tx_input.from != tx_input.to || is_reserved_key_included {
+            return (
+                TxResult::from_vm_error("operation in account not permitted"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        tx_cache.with_account_mut(&tx_input.to, |account| {
+            for pair in tx_input.args.chunks(2) {
+                account.storage.insert(pair[0].clone(), pair[1].clone());
+            }
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
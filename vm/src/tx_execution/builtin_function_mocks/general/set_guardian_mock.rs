@@ -0,0 +1,65 @@
+use crate::{
+    tx_execution::{builtin_function_names::SET_GUARDIAN_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+    world_mock::Guardian,
+};
+
+use super::{super::builtin_func_trait::BuiltinFunction, guardian_common::check_guardian_call};
+
+/// Number of epochs after which a newly set guardian becomes active.
+pub const GUARDIAN_ACTIVATION_EPOCHS: u64 = 20;
+
+/// Sets a new guardian for the caller, which only becomes active after `GUARDIAN_ACTIVATION_EPOCHS`.
+///
+/// Arguments: guardian address, guardian service UID.
+pub struct SetGuardian;
+
+impl BuiltinFunction for SetGuardian {
+    fn name(&self) -> &str {
+        SET_GUARDIAN_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_guardian_call(&tx_input, 2) {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+
+        let guardian_address = VMAddress::from_slice(&tx_input.args[0]);
+        if guardian_address == tx_input.from {
+            return (
+                TxResult::from_vm_error("cannot set own address as guardian"),
+                BlockchainUpdate::empty(),
+            );
+        }
+        if guardian_address.is_smart_contract_address() {
+            return (
+                TxResult::from_vm_error("guardian cannot be a smart contract"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        let current_epoch = tx_cache.blockchain_ref().current_block_info.block_epoch;
+        let guardian = Guardian {
+            address: guardian_address,
+            activation_epoch: current_epoch + GUARDIAN_ACTIVATION_EPOCHS,
+            service_uid: tx_input.args[1].clone(),
+        };
+        tx_cache.with_account_mut(&tx_input.to, |account| {
+            account
+                .guardians
+                .set_pending_guardian(guardian, current_epoch);
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
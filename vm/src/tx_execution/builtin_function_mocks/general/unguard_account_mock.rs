@@ -0,0 +1,44 @@
+use crate::{
+    tx_execution::{builtin_function_names::UNGUARD_ACCOUNT_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{super::builtin_func_trait::BuiltinFunction, guardian_common::check_guardian_call};
+
+/// Removes the guard from the caller.
+///
+/// Like any other transaction from a guarded account, it needs to be co-signed by the active guardian.
+pub struct UnGuardAccount;
+
+impl BuiltinFunction for UnGuardAccount {
+    fn name(&self) -> &str {
+        UNGUARD_ACCOUNT_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_guardian_call(&tx_input, 0) {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+
+        let was_guarded = tx_cache.with_account_mut(&tx_input.to, |account| {
+            std::mem::replace(&mut account.guardians.guarded, false)
+        });
+        if !was_guarded {
+            return (
+                TxResult::from_vm_error("account is not guarded"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
@@ -1,18 +1,19 @@
 use crate::{
     tx_mock::{
         async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
-        AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxContext, TxContextStack, TxInput,
-        TxPanic, TxResult, TxResultCalls,
+        AsyncCallKind, AsyncCallNode, AsyncCallTxData, AsyncCallbackNode, BlockchainUpdate,
+        Promise, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput, TxPanic, TxResult,
+        TxResultCalls,
     },
     types::VMAddress,
     with_shared::Shareable,
-    world_mock::{AccountData, AccountEsdt, BlockchainState},
+    world_mock::{AccountData, AccountEsdt, AccountGuardians, BlockchainState},
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
 use std::collections::HashMap;
 
-use super::BlockchainVMRef;
+use super::{check_guardian_co_signature, BlockchainVMRef};
 
 /// Executes the SC endpoint, as given by the current TxInput in the current TxContext.
 ///
@@ -87,54 +88,71 @@ impl BlockchainVMRef {
     {
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
-        let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
+        let (mut tx_result, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
+            if let Err(err) = check_guardian_co_signature(&tx_input, &tx_cache) {
+                return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+            }
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
         });
 
         if tx_result.result_status == 0 {
+            tx_result.storage_report = blockchain_updates.storage_report(state);
             blockchain_updates.apply(state);
         }
 
         tx_result
     }
 
+    /// Executes a legacy async call and its callback, then adds their results to the result of the parent call.
     pub fn execute_async_call_and_callback(
         &self,
+        parent_result: TxResult,
         async_data: AsyncCallTxData,
         state: &mut Shareable<BlockchainState>,
-    ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&async_data.to) {
-            let async_input = async_call_tx_input(&async_data);
-
-            let async_result = self.sc_call_with_async_and_callback(
-                async_input,
+    ) -> TxResult {
+        if !self.is_known_account(&async_data.to, state) {
+            return self.call_ghost_account(
+                parent_result,
+                AsyncCallKind::LegacyAsyncCall,
+                async_data,
                 state,
-                execute_current_tx_context_input,
             );
-
-            let callback_input =
-                async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
-            let callback_result = self.execute_sc_call_lambda(
-                callback_input,
-                state,
-                execute_current_tx_context_input,
-            );
-            assert!(
-                callback_result.pending_calls.async_call.is_none(),
-                "successive asyncs currently not supported"
-            );
-            (async_result, callback_result)
-        } else {
-            let result = self.insert_ghost_account(&async_data, state);
-            match result {
-                Ok(blockchain_updates) => {
-                    state.commit_updates(blockchain_updates);
-                    (TxResult::empty(), TxResult::empty())
-                },
-                Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-            }
         }
+
+        let async_input = async_call_tx_input(&async_data);
+        let gas_limit = async_input.gas_limit;
+        let async_result = self.sc_call_with_async_and_callback(
+            async_input,
+            state,
+            execute_current_tx_context_input,
+        );
+
+        let callback_input =
+            async_callback_tx_input(&async_data, &async_result, &self.builtin_functions);
+        let callback_gas_limit = callback_input.gas_limit;
+        let callback_result = self.sc_call_with_async_and_callback(
+            callback_input,
+            state,
+            execute_current_tx_context_input,
+        );
+
+        let mut node = AsyncCallNode::new(
+            AsyncCallKind::LegacyAsyncCall,
+            async_data,
+            gas_limit,
+            &async_result,
+        );
+        node.callback = Some(AsyncCallbackNode::new(
+            TxFunctionName::CALLBACK,
+            callback_gas_limit,
+            &callback_result,
+        ));
+
+        let mut tx_result = merge_results(parent_result, async_result);
+        tx_result = merge_results(tx_result, callback_result);
+        tx_result.async_call_tree.push(node);
+        tx_result
     }
 
     // TODO: refactor
@@ -148,74 +166,114 @@ impl BlockchainVMRef {
         F: FnOnce(),
     {
         // main call
-        let contract_address = tx_input.to.clone();
         let mut tx_result = self.execute_sc_call_lambda(tx_input, state, f);
 
         // take & clear pending calls
         let pending_calls = std::mem::replace(&mut tx_result.pending_calls, TxResultCalls::empty());
 
+        // a failed call is rolled back, together with the calls it launched
+        if tx_result.result_status != 0 {
+            return tx_result;
+        }
+
         // legacy async call
         // the async call also gets reset
-        if tx_result.result_status == 0 {
-            if let Some(async_data) = pending_calls.async_call {
-                let (async_result, callback_result) =
-                    self.execute_async_call_and_callback(async_data, state);
-
-                tx_result = merge_results(tx_result, async_result);
-                tx_result = merge_results(tx_result, callback_result);
-
-                return tx_result;
-            }
+        if let Some(async_data) = pending_calls.async_call {
+            return self.execute_async_call_and_callback(tx_result, async_data, state);
         }
 
-        // calling all promises
+        // calling all promises, in the order in which they were registered,
+        // each one immediately followed by its callback
         // the promises are also reset
-        for promise in pending_calls.promises {
-            let (async_result, callback_result) =
-                self.execute_promise_call_and_callback(&contract_address, &promise, state);
-
-            tx_result = merge_results(tx_result, async_result.clone());
-            tx_result = merge_results(tx_result, callback_result.clone());
+        for promise in &pending_calls.promises {
+            tx_result = self.execute_promise_call_and_callback(tx_result, promise, state);
         }
 
         tx_result
     }
 
+    /// Executes a promise and its callback, then adds their results to the result of the parent call.
+    ///
+    /// Failed calls and callbacks do not affect the other promises of the parent call.
     pub fn execute_promise_call_and_callback(
         &self,
-        address: &VMAddress,
+        parent_result: TxResult,
         promise: &Promise,
         state: &mut Shareable<BlockchainState>,
-    ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&promise.call.to) {
-            let async_input = async_call_tx_input(&promise.call);
-            let async_result = self.sc_call_with_async_and_callback(
-                async_input,
+    ) -> TxResult {
+        if !self.is_known_account(&promise.call.to, state) {
+            return self.call_ghost_account(
+                parent_result,
+                AsyncCallKind::Promise,
+                promise.call.clone(),
                 state,
-                execute_current_tx_context_input,
             );
+        }
+
+        let mut async_input = async_call_tx_input(&promise.call);
+        async_input.gas_limit = promise.gas_limit;
+        let async_result = self.sc_call_with_async_and_callback(
+            async_input,
+            state,
+            execute_current_tx_context_input,
+        );
 
-            let callback_input = async_promise_tx_input(address, promise, &async_result);
-            let callback_result = self.execute_sc_call_lambda(
+        let mut node = AsyncCallNode::new(
+            AsyncCallKind::Promise,
+            promise.call.clone(),
+            promise.gas_limit,
+            &async_result,
+        );
+
+        let callback_result = if promise.has_callback() {
+            let callback_input =
+                async_promise_tx_input(promise, &async_result, &self.builtin_functions);
+            let callback_func_name = callback_input.func_name.clone();
+            let callback_gas_limit = callback_input.gas_limit;
+            let callback_result = self.sc_call_with_async_and_callback(
                 callback_input,
                 state,
                 execute_current_tx_context_input,
             );
-            assert!(
-                callback_result.pending_calls.promises.is_empty(),
-                "successive promises currently not supported"
-            );
-            (async_result, callback_result)
+            node.callback = Some(AsyncCallbackNode::new(
+                callback_func_name,
+                callback_gas_limit,
+                &callback_result,
+            ));
+            callback_result
         } else {
-            let result = self.insert_ghost_account(&promise.call, state);
-            match result {
-                Ok(blockchain_updates) => {
-                    state.commit_updates(blockchain_updates);
-                    (TxResult::empty(), TxResult::empty())
-                },
-                Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-            }
-        }
+            TxResult::empty()
+        };
+
+        let mut tx_result = merge_results(parent_result, async_result);
+        tx_result = merge_results(tx_result, callback_result);
+        tx_result.async_call_tree.push(node);
+        tx_result
+    }
+
+    fn is_known_account(&self, address: &VMAddress, state: &BlockchainState) -> bool {
+        state.accounts.contains_key(address) || self.mock_contracts.is_mock_contract(address)
+    }
+
+    /// Async calls to accounts unknown to the state only transfer the value, and have no callback.
+    fn call_ghost_account(
+        &self,
+        parent_result: TxResult,
+        kind: AsyncCallKind,
+        async_data: AsyncCallTxData,
+        state: &mut Shareable<BlockchainState>,
+    ) -> TxResult {
+        let async_result = match self.insert_ghost_account(&async_data, state) {
+            Ok(blockchain_updates) => {
+                state.commit_updates(blockchain_updates);
+                TxResult::empty()
+            },
+            Err(err) => TxResult::from_panic_obj(&err),
+        };
+        let node = AsyncCallNode::new(kind, async_data, 0, &async_result);
+        let mut tx_result = merge_results(parent_result, async_result);
+        tx_result.async_call_tree.push(node);
+        tx_result
     }
 
     /// When calling a contract that is unknown to the state, we insert a ghost account.
@@ -237,6 +295,7 @@ impl BlockchainVMRef {
                 contract_path: None,
                 contract_owner: None,
                 developer_rewards: BigUint::zero(),
+                guardians: AccountGuardians::default(),
             });
             Ok(tx_cache.into_blockchain_updates())
         })
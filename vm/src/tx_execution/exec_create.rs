@@ -1,11 +1,11 @@
 use crate::{
-    tx_mock::{TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
     with_shared::Shareable,
     world_mock::BlockchainState,
 };
 
-use super::BlockchainVMRef;
+use super::{check_guardian_co_signature, BlockchainVMRef};
 
 impl BlockchainVMRef {
     pub fn sc_create<F>(
@@ -23,12 +23,20 @@ impl BlockchainVMRef {
         state.increase_account_nonce(&tx_input.from);
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
-        let (tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
+        let (mut tx_result, new_address, blockchain_updates) = state.with_shared(|state_arc| {
             let tx_cache = TxCache::new(state_arc);
+            if let Err(err) = check_guardian_co_signature(&tx_input, &tx_cache) {
+                return (
+                    TxResult::from_panic_obj(&err),
+                    VMAddress::zero(),
+                    BlockchainUpdate::empty(),
+                );
+            }
 
             self.deploy_contract(tx_input, contract_path.to_vec(), tx_cache, f)
         });
 
+        tx_result.storage_report = blockchain_updates.storage_report(state);
         blockchain_updates.apply(state);
 
         (new_address, tx_result)
@@ -1,16 +1,17 @@
 use num_traits::Zero;
 
 use crate::{
-    tx_execution::execute_system_sc,
+    tx_execution::{builtin_function_names::SET_GUARDIAN_FUNC_NAME, execute_system_sc},
     tx_mock::{
         BlockchainUpdate, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput, TxLog,
-        TxResult,
+        TxPanic, TxResult,
     },
     types::VMAddress,
     with_shared::Shareable,
+    world_mock::AccountData,
 };
 
-use super::{is_system_sc_address, BlockchainVMRef};
+use super::{is_system_sc_address, mock_contracts::execute_mock_tx_handler, BlockchainVMRef};
 
 fn should_execute_sc_call(tx_input: &TxInput) -> bool {
     // execute whitebox calls no matter what
@@ -27,6 +28,34 @@ fn should_execute_sc_call(tx_input: &TxInput) -> bool {
     !tx_input.func_name.is_empty()
 }
 
+/// Transactions from guarded accounts need to be co-signed by the active guardian.
+///
+/// Setting a new guardian is the only exception, the node allows it without co-signature,
+/// so that an account can recover from a lost guardian.
+pub(crate) fn check_guardian_co_signature(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<(), TxPanic> {
+    if !tx_cache.account_exists(&tx_input.from) {
+        return Ok(());
+    }
+
+    let current_epoch = tx_cache.blockchain_ref().current_block_info.block_epoch;
+    let check_result = tx_cache.with_account(&tx_input.from, |account| {
+        if account.guardians.guarded
+            && tx_input.guardian.is_none()
+            && tx_input.func_name.as_str() == SET_GUARDIAN_FUNC_NAME
+        {
+            return Ok(());
+        }
+
+        account
+            .guardians
+            .check_co_signature(tx_input.guardian.as_ref(), current_epoch)
+    });
+    check_result.map_err(TxPanic::vm_error)
+}
+
 impl BlockchainVMRef {
     /// Executes without builtin functions, directly on the contract or the given lambda closure.
     pub fn default_execution<F>(
@@ -38,6 +67,12 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
+        // mock contracts do not need to be added to the state beforehand
+        let mock_contract_handler = self.mock_contracts.get_handler(&tx_input.to);
+        if mock_contract_handler.is_some() && !tx_cache.account_exists(&tx_input.to) {
+            tx_cache.insert_account(AccountData::new_empty(tx_input.to.clone()));
+        }
+
         if let Err(err) =
             tx_cache.transfer_egld_balance(&tx_input.from, &tx_input.to, &tx_input.egld_value)
         {
@@ -79,6 +114,8 @@ impl BlockchainVMRef {
 
         let (mut tx_result, blockchain_updates) = if is_system_sc_address(&tx_input.to) {
             execute_system_sc(tx_input, tx_cache)
+        } else if let Some(handler) = mock_contract_handler {
+            execute_mock_tx_handler(handler.as_ref(), &tx_input, tx_cache)
         } else if should_execute_sc_call(&tx_input) {
             let tx_context = TxContext::new(self.clone(), tx_input, tx_cache);
             let mut tx_context_sh = Shareable::new(tx_context);
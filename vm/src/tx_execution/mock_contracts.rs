@@ -0,0 +1,55 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+};
+
+use super::MockTxHandler;
+
+/// Contracts implemented directly in Rust, registered by address.
+///
+/// Used to mock system smart contracts (e.g. delegation, staking, DNS) or external dApps,
+/// without having to deploy stand-in contracts.
+#[derive(Default)]
+pub struct MockContractContainer {
+    handlers: Mutex<HashMap<VMAddress, Arc<dyn MockTxHandler>>>,
+}
+
+impl MockContractContainer {
+    /// Replaces any handler previously registered at the same address.
+    pub fn register<H>(&self, address: VMAddress, handler: H)
+    where
+        H: MockTxHandler + 'static,
+    {
+        self.handlers
+            .lock()
+            .unwrap()
+            .insert(address, Arc::new(handler));
+    }
+
+    pub fn is_mock_contract(&self, address: &VMAddress) -> bool {
+        self.handlers.lock().unwrap().contains_key(address)
+    }
+
+    pub fn get_handler(&self, address: &VMAddress) -> Option<Arc<dyn MockTxHandler>> {
+        self.handlers.lock().unwrap().get(address).cloned()
+    }
+}
+
+/// Only commits the changes made by the handler if the result is successful.
+pub(crate) fn execute_mock_tx_handler(
+    handler: &dyn MockTxHandler,
+    tx_input: &TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let tx_result = handler.handle(tx_input, &tx_cache);
+    if tx_result.result_status == 0 {
+        (tx_result, tx_cache.into_blockchain_updates())
+    } else {
+        (tx_result, BlockchainUpdate::empty())
+    }
+}
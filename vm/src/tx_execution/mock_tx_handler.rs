@@ -0,0 +1,20 @@
+use crate::tx_mock::{TxCache, TxInput, TxResult};
+
+/// Rust implementation of a custom builtin function or of a mocked contract.
+///
+/// Handlers change the blockchain state via the `TxCache`.
+/// The changes are only committed if the returned result is successful.
+///
+/// Implemented for all compatible closures.
+pub trait MockTxHandler: Send + Sync {
+    fn handle(&self, tx_input: &TxInput, tx_cache: &TxCache) -> TxResult;
+}
+
+impl<F> MockTxHandler for F
+where
+    F: Fn(&TxInput, &TxCache) -> TxResult + Send + Sync,
+{
+    fn handle(&self, tx_input: &TxInput, tx_cache: &TxCache) -> TxResult {
+        self(tx_input, tx_cache)
+    }
+}
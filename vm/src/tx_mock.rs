@@ -3,9 +3,11 @@
 mod blockchain_rng;
 mod blockchain_update;
 mod tx_async_call_data;
+mod tx_async_call_tree;
 mod tx_async_promise;
 mod tx_cache;
 mod tx_cache_balance_util;
+mod tx_cache_esdt_util;
 mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
@@ -17,10 +19,13 @@ mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
 mod tx_result_calls;
+mod tx_storage_access;
+mod tx_storage_report;
 
 pub use blockchain_rng::*;
 pub use blockchain_update::BlockchainUpdate;
 pub use tx_async_call_data::*;
+pub use tx_async_call_tree::*;
 pub use tx_async_promise::*;
 pub use tx_cache::TxCache;
 pub use tx_cache_source::*;
@@ -34,3 +39,5 @@ pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
 pub use tx_result_calls::*;
+pub use tx_storage_access::*;
+pub use tx_storage_report::*;
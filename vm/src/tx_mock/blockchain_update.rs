@@ -5,6 +5,8 @@ use crate::{
     world_mock::{AccountData, BlockchainState},
 };
 
+use super::StorageReport;
+
 #[derive(Default)]
 pub struct BlockchainUpdate {
     pub accounts: HashMap<VMAddress, AccountData>,
@@ -16,6 +18,17 @@ impl BlockchainUpdate {
         BlockchainUpdate::default()
     }
 
+    /// Storage size changes of the updated accounts, relative to the state before applying the update.
+    pub fn storage_report(&self, blockchain: &BlockchainState) -> StorageReport {
+        StorageReport::from_storage_changes(self.accounts.iter().map(|(address, account)| {
+            let old_storage = blockchain
+                .accounts
+                .get(address)
+                .map(|old_account| &old_account.storage);
+            (address, old_storage, &account.storage)
+        }))
+    }
+
     pub fn apply(self, blockchain: &mut BlockchainState) {
         blockchain.update_accounts(self.accounts);
 
@@ -90,10 +90,14 @@ fn extract_callback_payments(
     callback_payments
 }
 
+/// The callback of a promise goes to the contract that registered it.
+///
+/// It gets the gas of the call, since the call never consumes any in the debugger,
+/// plus the extra gas reserved for the callback.
 pub fn async_promise_tx_input(
-    address: &VMAddress,
     promise: &Promise,
     async_result: &TxResult,
+    builtin_functions: &BuiltinFunctionContainer,
 ) -> TxInput {
     let mut args: Vec<Vec<u8>> = Vec::new();
     let serialized_bytes = async_result.result_status.to_be_bytes().to_vec();
@@ -105,17 +109,22 @@ pub fn async_promise_tx_input(
         args.push(async_result.result_message.clone().into_bytes());
         promise.error_callback.clone()
     };
+    let callback_payments =
+        extract_callback_payments(&promise.call.from, async_result, builtin_functions);
 
     TxInput {
-        from: promise.call.from.clone(),
-        to: address.clone(),
+        from: promise.call.to.clone(),
+        to: promise.call.from.clone(),
         egld_value: 0u32.into(),
         esdt_values: Vec::new(),
         func_name: callback_name,
         args,
-        gas_limit: 1000,
+        gas_limit: promise
+            .gas_limit
+            .saturating_add(promise.extra_gas_for_callback),
         gas_price: 0,
         tx_hash: promise.call.tx_hash.clone(),
+        callback_payments,
         promise_callback_closure_data: promise.callback_closure_data.clone(),
         ..Default::default()
     }
@@ -126,6 +135,7 @@ pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.storage_report.merge(&new.storage_report);
         original
     } else {
         new
@@ -0,0 +1,96 @@
+use super::{AsyncCallTxData, TxFunctionName, TxResult};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AsyncCallKind {
+    /// Launched via `async_call_raw`, at most one per call, ends the execution.
+    LegacyAsyncCall,
+
+    /// Registered via `create_async_call_raw`, any number per call.
+    Promise,
+}
+
+/// An async call or promise executed after the call that launched it, together with its callback.
+///
+/// Failed calls and callbacks appear in the tree, but their state changes were rolled back.
+#[derive(Clone, Debug)]
+pub struct AsyncCallNode {
+    pub kind: AsyncCallKind,
+    pub call: AsyncCallTxData,
+    pub gas_limit: u64,
+    pub result_status: u64,
+    pub result_message: String,
+    pub result_values: Vec<Vec<u8>>,
+
+    /// Async calls and promises launched by the called contract.
+    pub children: Vec<AsyncCallNode>,
+
+    /// Missing for promises without a callback, and for calls to accounts unknown to the state.
+    pub callback: Option<AsyncCallbackNode>,
+}
+
+/// The callback of an async call or promise, executed on the contract that launched it.
+#[derive(Clone, Debug)]
+pub struct AsyncCallbackNode {
+    pub func_name: TxFunctionName,
+    pub gas_limit: u64,
+    pub result_status: u64,
+    pub result_message: String,
+
+    /// Promises registered in the callback.
+    pub children: Vec<AsyncCallNode>,
+}
+
+impl AsyncCallNode {
+    pub fn new(
+        kind: AsyncCallKind,
+        call: AsyncCallTxData,
+        gas_limit: u64,
+        call_result: &TxResult,
+    ) -> Self {
+        AsyncCallNode {
+            kind,
+            call,
+            gas_limit,
+            result_status: call_result.result_status,
+            result_message: call_result.result_message.clone(),
+            result_values: call_result.result_values.clone(),
+            children: call_result.async_call_tree.clone(),
+            callback: None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.result_status == 0
+    }
+
+    /// Number of calls in the subtree, including this one, but not counting callbacks.
+    pub fn num_calls(&self) -> usize {
+        let callback_calls = self
+            .callback
+            .as_ref()
+            .map(|callback| count_calls(&callback.children))
+            .unwrap_or_default();
+        1 + count_calls(&self.children) + callback_calls
+    }
+}
+
+impl AsyncCallbackNode {
+    pub fn new(func_name: TxFunctionName, gas_limit: u64, callback_result: &TxResult) -> Self {
+        AsyncCallbackNode {
+            func_name,
+            gas_limit,
+            result_status: callback_result.result_status,
+            result_message: callback_result.result_message.clone(),
+            children: callback_result.async_call_tree.clone(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.result_status == 0
+    }
+}
+
+/// Number of calls in a call tree, not counting callbacks.
+pub fn count_calls(nodes: &[AsyncCallNode]) -> usize {
+    nodes.iter().map(AsyncCallNode::num_calls).sum()
+}
@@ -6,4 +6,17 @@ pub struct Promise {
     pub success_callback: TxFunctionName,
     pub error_callback: TxFunctionName,
     pub callback_closure_data: Vec<u8>,
+
+    /// Gas reserved for the call itself.
+    pub gas_limit: u64,
+
+    /// Gas reserved for the callback, on top of what the call leaves unused.
+    pub extra_gas_for_callback: u64,
+}
+
+impl Promise {
+    /// Promises registered without a callback have empty callback names.
+    pub fn has_callback(&self) -> bool {
+        !self.success_callback.is_empty() || !self.error_callback.is_empty()
+    }
 }
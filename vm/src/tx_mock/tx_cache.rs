@@ -48,6 +48,12 @@ impl TxCache {
         }
     }
 
+    /// Also looks for the account in the underlying state, if it is not yet in the cache.
+    pub fn account_exists(&self, address: &VMAddress) -> bool {
+        self.load_account_if_necessary(address);
+        self.accounts.lock().unwrap().contains_key(address)
+    }
+
     pub fn with_account<R, F>(&self, address: &VMAddress, f: F) -> R
     where
         F: FnOnce(&AccountData) -> R,
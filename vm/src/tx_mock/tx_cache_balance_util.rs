@@ -110,6 +110,7 @@ impl TxCache {
         value: &BigUint,
     ) -> Result<(), TxPanic> {
         if !is_system_sc_address(from) && !is_system_sc_address(to) {
+            self.check_esdt_transfer_allowed(from, to, esdt_token_identifier)?;
             let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value)?;
             self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
         }
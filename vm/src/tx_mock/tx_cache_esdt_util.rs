@@ -0,0 +1,96 @@
+use crate::{
+    tx_mock::TxPanic,
+    types::{EsdtLocalRole, VMAddress},
+    world_mock::{AccountData, EsdtGlobalMetadata, ESDT_SYSTEM_ACCOUNT_ADDRESS_ARRAY},
+};
+
+use super::TxCache;
+
+impl TxCache {
+    /// Global token settings are kept in the system account. If it does not exist, all settings are off.
+    pub fn get_esdt_global_metadata(&self, token_identifier: &[u8]) -> EsdtGlobalMetadata {
+        let system_account_address = VMAddress::from(ESDT_SYSTEM_ACCOUNT_ADDRESS_ARRAY);
+        if !self.account_exists(&system_account_address) {
+            return EsdtGlobalMetadata::default();
+        }
+
+        self.with_account(&system_account_address, |account| {
+            account
+                .storage
+                .get(&EsdtGlobalMetadata::storage_key(token_identifier))
+                .map(|bytes| EsdtGlobalMetadata::from_bytes(bytes))
+                .unwrap_or_default()
+        })
+    }
+
+    /// Also creates the system account, if it does not exist yet.
+    pub fn update_esdt_global_metadata<F>(&self, token_identifier: &[u8], f: F)
+    where
+        F: FnOnce(&mut EsdtGlobalMetadata),
+    {
+        let system_account_address = VMAddress::from(ESDT_SYSTEM_ACCOUNT_ADDRESS_ARRAY);
+        if !self.account_exists(&system_account_address) {
+            self.insert_account(AccountData::new_empty(system_account_address.clone()));
+        }
+
+        let mut metadata = self.get_esdt_global_metadata(token_identifier);
+        f(&mut metadata);
+        self.with_account_mut(&system_account_address, |account| {
+            account.storage.insert(
+                EsdtGlobalMetadata::storage_key(token_identifier),
+                metadata.to_bytes(),
+            );
+        });
+    }
+
+    pub fn is_esdt_frozen(&self, address: &VMAddress, token_identifier: &[u8]) -> bool {
+        self.with_account(address, |account| {
+            account
+                .esdt
+                .get_by_identifier(token_identifier)
+                .map(|esdt_data| esdt_data.frozen)
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn has_esdt_role(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        role: EsdtLocalRole,
+    ) -> bool {
+        self.with_account(address, |account| {
+            account
+                .esdt
+                .get_roles(token_identifier)
+                .iter()
+                .any(|available_role| available_role.as_slice() == role.name().as_bytes())
+        })
+    }
+
+    /// Same checks as the ones performed by the node before any ESDT transfer:
+    /// - neither account can be frozen for the token;
+    /// - if the token has limited transfer, one of the accounts needs the transfer role.
+    pub fn check_esdt_transfer_allowed(
+        &self,
+        from: &VMAddress,
+        to: &VMAddress,
+        token_identifier: &[u8],
+    ) -> Result<(), TxPanic> {
+        if self.is_esdt_frozen(from, token_identifier) || self.is_esdt_frozen(to, token_identifier)
+        {
+            return Err(TxPanic::vm_error("account is frozen for this esdt token"));
+        }
+
+        if self
+            .get_esdt_global_metadata(token_identifier)
+            .limited_transfer
+            && !self.has_esdt_role(from, token_identifier, EsdtLocalRole::Transfer)
+            && !self.has_esdt_role(to, token_identifier, EsdtLocalRole::Transfer)
+        {
+            return Err(TxPanic::vm_error("action is not allowed"));
+        }
+
+        Ok(())
+    }
+}
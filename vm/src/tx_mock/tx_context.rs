@@ -1,7 +1,7 @@
 use crate::{
     tx_execution::BlockchainVMRef,
     types::VMAddress,
-    world_mock::{AccountData, AccountEsdt, BlockchainState, FailingExecutor},
+    world_mock::{AccountData, AccountEsdt, AccountGuardians, BlockchainState, FailingExecutor},
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
@@ -24,11 +24,12 @@ pub struct TxContext {
 impl TxContext {
     pub fn new(vm_ref: BlockchainVMRef, tx_input: TxInput, tx_cache: TxCache) -> Self {
         let b_rng = Mutex::new(BlockchainRng::new(&tx_input, &tx_cache));
+        let managed_types = TxManagedTypes::new_with_limits(vm_ref.managed_types_limits());
         TxContext {
             vm_ref,
             tx_input_box: Box::new(tx_input),
             tx_cache: Arc::new(tx_cache),
-            managed_types: Mutex::new(TxManagedTypes::new()),
+            managed_types: Mutex::new(managed_types),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
         }
@@ -47,6 +48,7 @@ impl TxContext {
             contract_path: None,
             contract_owner: None,
             developer_rewards: BigUint::zero(),
+            guardians: AccountGuardians::default(),
         });
 
         let tx_input = TxInput {
@@ -148,6 +150,7 @@ impl TxContext {
             contract_path: Some(contract_path),
             contract_owner: Some(contract_owner),
             developer_rewards: BigUint::zero(),
+            guardians: AccountGuardians::default(),
         });
     }
 
@@ -158,7 +161,11 @@ impl TxContext {
 
     pub fn into_results(self) -> (TxResult, BlockchainUpdate) {
         let tx_cache = Arc::try_unwrap(self.tx_cache).unwrap();
-        let tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        let mut tx_result = Mutex::into_inner(self.tx_result_cell).unwrap();
+        // the managed types lock can be poisoned by a failed contract, the stats are still relevant
+        let managed_types =
+            Mutex::into_inner(self.managed_types).unwrap_or_else(|err| err.into_inner());
+        tx_result.managed_types_stats = managed_types.stats();
         let blockchain_updates = tx_cache.into_blockchain_updates();
         (tx_result, blockchain_updates)
     }
@@ -22,6 +22,11 @@ pub struct TxInput {
     pub tx_hash: H256,
     pub promise_callback_closure_data: Vec<u8>,
     pub callback_payments: CallbackPayments,
+
+    /// The guardian that co-signed the transaction, if any.
+    ///
+    /// Only transactions sent directly by user accounts can be co-signed.
+    pub guardian: Option<VMAddress>,
 }
 
 impl Default for TxInput {
@@ -38,6 +43,7 @@ impl Default for TxInput {
             tx_hash: H256::zero(),
             promise_callback_closure_data: Vec::new(),
             callback_payments: Default::default(),
+            guardian: None,
         }
     }
 }
@@ -3,15 +3,15 @@ mod tx_big_float;
 mod tx_big_int;
 mod tx_managed_buffer;
 mod tx_managed_map;
+mod tx_managed_types_limits;
 
-pub use handle_map::HandleMap;
+pub use handle_map::{HandleMap, ManagedValueSize};
 use num_bigint::BigInt;
 pub use tx_big_int::big_int_to_i64;
-
-use std::collections::HashMap;
+pub(crate) use tx_managed_map::ManagedMapImpl;
+pub use tx_managed_types_limits::{ManagedTypesLimits, ManagedTypesStats};
 
 pub(crate) type ManagedBufferImpl = Vec<u8>;
-pub(crate) type ManagedMapImpl = HashMap<Vec<u8>, Vec<u8>>;
 
 #[derive(Debug)]
 pub struct TxManagedTypes {
@@ -19,15 +19,23 @@ pub struct TxManagedTypes {
     pub(crate) big_float_map: HandleMap<f64>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
     pub(crate) managed_map_map: HandleMap<ManagedMapImpl>,
+    pub(crate) limits: ManagedTypesLimits,
+    pub(crate) stats: ManagedTypesStats,
 }
 
 impl TxManagedTypes {
     pub fn new() -> Self {
+        TxManagedTypes::new_with_limits(ManagedTypesLimits::unlimited())
+    }
+
+    pub fn new_with_limits(limits: ManagedTypesLimits) -> Self {
         TxManagedTypes {
             big_int_map: HandleMap::new(),
             big_float_map: HandleMap::new(),
             managed_buffer_map: HandleMap::new(),
             managed_map_map: HandleMap::new(),
+            limits,
+            stats: ManagedTypesStats::default(),
         }
     }
 }
@@ -1,10 +1,18 @@
 use crate::types::RawHandle;
 use std::collections::HashMap;
 
+/// Approximates how much managed memory a value occupies, in bytes.
+///
+/// Used for enforcing the managed memory limits.
+pub trait ManagedValueSize {
+    fn managed_size(&self) -> usize;
+}
+
 #[derive(Debug)]
 pub struct HandleMap<V> {
     next_handle: RawHandle,
-    pub map: HashMap<RawHandle, V>,
+    map: HashMap<RawHandle, V>,
+    total_size: usize,
 }
 
 impl<V> HandleMap<V> {
@@ -12,6 +20,7 @@ impl<V> HandleMap<V> {
         HandleMap {
             next_handle: 0,
             map: HashMap::new(),
+            total_size: 0,
         }
     }
 }
@@ -22,10 +31,10 @@ impl<V> Default for HandleMap<V> {
     }
 }
 
-impl<V> HandleMap<V> {
+impl<V: ManagedValueSize> HandleMap<V> {
     pub fn insert_new_handle_raw(&mut self, value: V) -> RawHandle {
         let new_handle = self.next_handle;
-        self.map.insert(new_handle, value);
+        self.insert(new_handle, value);
         self.next_handle += 1;
         new_handle
     }
@@ -37,14 +46,46 @@ impl<V> HandleMap<V> {
             .unwrap_or_else(|| panic!("handle not found"))
     }
 
-    pub fn get_mut(&mut self, handle: RawHandle) -> &mut V {
+    /// Changes a value in place, keeping track of its size.
+    pub fn update<R, F: FnOnce(&mut V) -> R>(&mut self, handle: RawHandle, f: F) -> R {
         // TODO: consider simulating the actual error from the VM
-        self.map
+        let value = self
+            .map
             .get_mut(&handle)
-            .unwrap_or_else(|| panic!("handle not found"))
+            .unwrap_or_else(|| panic!("handle not found"));
+        self.total_size -= value.managed_size();
+        let result = f(value);
+        self.total_size += value.managed_size();
+        result
     }
 
     pub fn insert(&mut self, handle: RawHandle, value: V) {
-        let _ = self.map.insert(handle, value);
+        self.total_size += value.managed_size();
+        if let Some(old_value) = self.map.insert(handle, value) {
+            self.total_size -= old_value.managed_size();
+        }
+    }
+
+    /// Releases a handle. Using it afterwards is an error, same as for a handle that was never created.
+    ///
+    /// Does nothing if the handle does not exist.
+    pub fn remove_handle(&mut self, handle: RawHandle) {
+        if let Some(old_value) = self.map.remove(&handle) {
+            self.total_size -= old_value.managed_size();
+        }
+    }
+
+    /// Number of live handles.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Sum of the sizes of all live values.
+    pub fn total_size(&self) -> usize {
+        self.total_size
     }
 }
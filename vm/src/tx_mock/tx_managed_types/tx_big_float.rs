@@ -1,6 +1,6 @@
 use crate::types::RawHandle;
 
-use super::TxManagedTypes;
+use super::{ManagedValueSize, TxManagedTypes};
 
 impl TxManagedTypes {
     pub fn bf_get_f64(&self, handle: RawHandle) -> f64 {
@@ -10,4 +10,14 @@ impl TxManagedTypes {
     pub fn bf_overwrite(&mut self, handle: RawHandle, value: f64) {
         self.big_float_map.insert(handle, value);
     }
+
+    pub fn bf_drop(&mut self, handle: RawHandle) {
+        self.big_float_map.remove_handle(handle);
+    }
+}
+
+impl ManagedValueSize for f64 {
+    fn managed_size(&self) -> usize {
+        core::mem::size_of::<f64>()
+    }
 }
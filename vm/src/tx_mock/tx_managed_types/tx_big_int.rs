@@ -4,7 +4,7 @@ use crate::types::RawHandle;
 use num_bigint::Sign;
 use num_traits::Zero;
 
-use super::TxManagedTypes;
+use super::{ManagedValueSize, TxManagedTypes};
 
 impl TxManagedTypes {
     pub fn bi_new_from_big_int(&mut self, value: num_bigint::BigInt) -> RawHandle {
@@ -15,6 +15,10 @@ impl TxManagedTypes {
         self.big_int_map.insert(destination, value);
     }
 
+    pub fn bi_drop(&mut self, handle: RawHandle) {
+        self.big_int_map.remove_handle(handle);
+    }
+
     pub fn bi_get(&self, handle: RawHandle) -> num_bigint::BigInt {
         self.big_int_map.get(handle).clone()
     }
@@ -60,6 +64,12 @@ impl TxManagedTypes {
     }
 }
 
+impl ManagedValueSize for num_bigint::BigInt {
+    fn managed_size(&self) -> usize {
+        ((self.bits() + 7) / 8) as usize
+    }
+}
+
 pub fn big_int_to_i64(bi: &num_bigint::BigInt) -> Option<i64> {
     let (sign, digits) = bi.to_u64_digits();
     match sign {
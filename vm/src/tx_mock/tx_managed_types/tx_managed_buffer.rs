@@ -3,7 +3,7 @@ use crate::{
     types::{RawHandle, VMAddress, VMCodeMetadata},
 };
 
-use super::TxManagedTypes;
+use super::{ManagedValueSize, TxManagedTypes};
 
 /// Returned if load/copy slice could not be performed.
 /// No further data needed.
@@ -69,8 +69,11 @@ impl TxManagedTypes {
     }
 
     pub fn mb_update<R, F: FnOnce(&mut Vec<u8>) -> R>(&mut self, handle: RawHandle, f: F) -> R {
-        let value = self.managed_buffer_map.get_mut(handle);
-        f(value)
+        self.managed_buffer_map.update(handle, f)
+    }
+
+    pub fn mb_drop(&mut self, handle: RawHandle) {
+        self.managed_buffer_map.remove_handle(handle);
     }
 
     pub fn mb_set_slice(
@@ -167,6 +170,12 @@ impl TxManagedTypes {
     }
 }
 
+impl ManagedValueSize for Vec<u8> {
+    fn managed_size(&self) -> usize {
+        self.len()
+    }
+}
+
 pub fn handle_to_be_bytes(handle: RawHandle) -> [u8; 4] {
     handle.to_be_bytes()
 }
@@ -1,6 +1,58 @@
+use std::collections::HashMap;
+
 use crate::types::RawHandle;
 
-use super::{ManagedMapImpl, TxManagedTypes};
+use super::{ManagedValueSize, TxManagedTypes};
+
+/// Managed map contents.
+///
+/// Empty values are not stored, putting an empty value is equivalent to removing the key.
+#[derive(Debug, Default)]
+pub(crate) struct ManagedMapImpl {
+    values: HashMap<Vec<u8>, Vec<u8>>,
+
+    /// Total length of all keys and values.
+    size: usize,
+}
+
+impl ManagedMapImpl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<&Vec<u8>> {
+        self.values.get(key)
+    }
+
+    pub fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        if value.is_empty() {
+            self.remove(key.as_slice());
+            return;
+        }
+
+        self.size += value.len();
+        if !self.values.contains_key(&key) {
+            self.size += key.len();
+        }
+        if let Some(old_value) = self.values.insert(key, value) {
+            self.size -= old_value.len();
+        }
+    }
+
+    pub fn remove(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+        let removed = self.values.remove(key);
+        if let Some(removed_value) = &removed {
+            self.size -= key.len() + removed_value.len();
+        }
+        removed
+    }
+}
+
+impl ManagedValueSize for ManagedMapImpl {
+    fn managed_size(&self) -> usize {
+        self.size
+    }
+}
 
 impl TxManagedTypes {
     pub fn mm_new(&mut self) -> RawHandle {
@@ -9,8 +61,8 @@ impl TxManagedTypes {
     }
 
     pub fn mm_values_insert(&mut self, map_handle: RawHandle, key: Vec<u8>, value: Vec<u8>) {
-        let mmap = self.managed_map_map.get_mut(map_handle);
-        mmap.insert(key, value);
+        self.managed_map_map
+            .update(map_handle, |mmap| mmap.insert(key, value));
     }
 
     pub fn mm_values_get(&self, map_handle: RawHandle, key: &[u8]) -> Vec<u8> {
@@ -28,7 +80,12 @@ impl TxManagedTypes {
     }
 
     pub fn mm_values_remove(&mut self, map_handle: RawHandle, key: &[u8]) -> Vec<u8> {
-        let mmap = self.managed_map_map.get_mut(map_handle);
-        mmap.remove(key).unwrap_or_default()
+        self.managed_map_map
+            .update(map_handle, |mmap| mmap.remove(key))
+            .unwrap_or_default()
+    }
+
+    pub fn mm_drop(&mut self, map_handle: RawHandle) {
+        self.managed_map_map.remove_handle(map_handle);
     }
 }
@@ -0,0 +1,98 @@
+use crate::vm_err_msg;
+
+use super::TxManagedTypes;
+
+/// Limits on the managed types a single call can keep alive at the same time.
+///
+/// Both are unlimited by default.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ManagedTypesLimits {
+    /// Maximum number of live handles, of all types combined.
+    pub max_handles: Option<usize>,
+
+    /// Maximum number of bytes held by all live managed types combined.
+    pub max_bytes: Option<usize>,
+}
+
+impl ManagedTypesLimits {
+    pub fn unlimited() -> Self {
+        ManagedTypesLimits::default()
+    }
+
+    pub fn max_handles(mut self, max_handles: usize) -> Self {
+        self.max_handles = Some(max_handles);
+        self
+    }
+
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+}
+
+/// Managed memory usage of a call, for profiling.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ManagedTypesStats {
+    /// Live handles at the end of the call.
+    pub handles: usize,
+
+    /// Bytes held by managed types at the end of the call.
+    pub bytes: usize,
+
+    /// Maximum number of handles alive at the same time.
+    pub peak_handles: usize,
+
+    /// Maximum number of bytes held at the same time.
+    pub peak_bytes: usize,
+}
+
+impl TxManagedTypes {
+    /// Number of live handles, of all types.
+    pub fn live_handles(&self) -> usize {
+        self.big_int_map.len()
+            + self.big_float_map.len()
+            + self.managed_buffer_map.len()
+            + self.managed_map_map.len()
+    }
+
+    /// Bytes held by all live managed types.
+    pub fn live_bytes(&self) -> usize {
+        self.big_int_map.total_size()
+            + self.big_float_map.total_size()
+            + self.managed_buffer_map.total_size()
+            + self.managed_map_map.total_size()
+    }
+
+    /// Records the peak usage, then checks the current usage against the limits.
+    ///
+    /// The debugger calls it after each VM hook.
+    pub fn check_limits(&mut self) -> Result<(), &'static str> {
+        let handles = self.live_handles();
+        let bytes = self.live_bytes();
+        self.stats.peak_handles = self.stats.peak_handles.max(handles);
+        self.stats.peak_bytes = self.stats.peak_bytes.max(bytes);
+
+        if let Some(max_handles) = self.limits.max_handles {
+            if handles > max_handles {
+                return Err(vm_err_msg::MANAGED_TYPES_TOO_MANY_HANDLES);
+            }
+        }
+        if let Some(max_bytes) = self.limits.max_bytes {
+            if bytes > max_bytes {
+                return Err(vm_err_msg::MANAGED_TYPES_MEMORY_LIMIT_EXCEEDED);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn stats(&self) -> ManagedTypesStats {
+        let handles = self.live_handles();
+        let bytes = self.live_bytes();
+        ManagedTypesStats {
+            handles,
+            bytes,
+            peak_handles: self.stats.peak_handles.max(handles),
+            peak_bytes: self.stats.peak_bytes.max(bytes),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
+use super::{
+    AsyncCallNode, AsyncCallTxData, ManagedTypesStats, StorageAccessStats, StorageReport, TxLog,
+    TxPanic, TxResultCalls,
+};
 
 #[derive(Clone, Debug)]
 #[must_use]
@@ -19,6 +22,21 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// The async calls and promises executed after the tx, in execution order,
+    /// each with the calls it launched in turn and its callback.
+    pub async_call_tree: Vec<AsyncCallNode>,
+
+    /// Storage size changes caused by the tx, including its async calls and callbacks.
+    ///
+    /// Only contains changes that were actually committed.
+    pub storage_report: StorageReport,
+
+    /// Managed memory usage of the called contract, not including the sync calls it made.
+    pub managed_types_stats: ManagedTypesStats,
+
+    /// Storage loads and stores of the called contract, not including the sync calls it made.
+    pub storage_access_stats: StorageAccessStats,
 }
 
 impl Default for TxResult {
@@ -30,6 +48,10 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            async_call_tree: Vec::new(),
+            storage_report: StorageReport::default(),
+            managed_types_stats: ManagedTypesStats::default(),
+            storage_access_stats: StorageAccessStats::default(),
         }
     }
 }
@@ -90,6 +112,10 @@ impl TxResult {
             );
             self.pending_calls.async_call = Some(sync_result_async.clone());
         }
+        // promises stay with the contract that registered them, which also receives their callbacks
+        self.pending_calls
+            .promises
+            .extend_from_slice(sync_call_result.pending_calls.promises.as_slice());
     }
 
     pub fn assert_ok(&self) {
@@ -0,0 +1,8 @@
+/// Number of storage hooks called by a contract, for profiling.
+///
+/// Only counts accesses to the storage of the contract itself, through managed buffers.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StorageAccessStats {
+    pub loads: usize,
+    pub stores: usize,
+}
@@ -0,0 +1,288 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    ops::AddAssign,
+};
+
+use crate::{
+    display_util::{address_hex, bytes_to_string},
+    types::VMAddress,
+    world_mock::AccountStorage,
+};
+
+/// Storage size changes, in bytes.
+///
+/// Keys and values both count towards the storage footprint.
+/// An empty value is equivalent to the key being absent.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StorageDelta {
+    /// Bytes of newly created entries (key + value),
+    /// as well as the growth of existing values.
+    pub bytes_added: u64,
+
+    /// Bytes of cleared entries (key + value),
+    /// as well as the shrinking of existing values.
+    pub bytes_removed: u64,
+
+    /// Total length of the new values written over existing ones.
+    pub bytes_modified: u64,
+}
+
+impl StorageDelta {
+    /// Computes the change caused by overwriting a single storage entry.
+    pub fn from_entry_change(key: &[u8], old_value: &[u8], new_value: &[u8]) -> Self {
+        let key_len = key.len() as u64;
+        let old_len = old_value.len() as u64;
+        let new_len = new_value.len() as u64;
+
+        let mut delta = StorageDelta::default();
+        if old_value == new_value {
+            return delta;
+        }
+        if old_value.is_empty() {
+            delta.bytes_added = key_len + new_len;
+        } else if new_value.is_empty() {
+            delta.bytes_removed = key_len + old_len;
+        } else {
+            delta.bytes_modified = new_len;
+            if new_len > old_len {
+                delta.bytes_added = new_len - old_len;
+            } else {
+                delta.bytes_removed = old_len - new_len;
+            }
+        }
+        delta
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == StorageDelta::default()
+    }
+
+    /// How much the storage footprint grew (positive) or shrank (negative).
+    pub fn net_bytes(&self) -> i64 {
+        self.bytes_added as i64 - self.bytes_removed as i64
+    }
+}
+
+impl AddAssign for StorageDelta {
+    fn add_assign(&mut self, rhs: Self) {
+        self.bytes_added += rhs.bytes_added;
+        self.bytes_removed += rhs.bytes_removed;
+        self.bytes_modified += rhs.bytes_modified;
+    }
+}
+
+impl fmt::Display for StorageDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "+{} -{} ~{} (net {})",
+            self.bytes_added,
+            self.bytes_removed,
+            self.bytes_modified,
+            self.net_bytes()
+        )
+    }
+}
+
+/// Storage size changes of a single account.
+#[derive(Default, Clone, Debug)]
+pub struct AccountStorageReport {
+    pub total: StorageDelta,
+
+    /// Changes of each storage key.
+    pub per_key: BTreeMap<Vec<u8>, StorageDelta>,
+
+    /// Changes grouped by the storage mapper base key guessed by `storage_base_key`.
+    ///
+    /// Only meant for display, use `base_key_delta` for the changes of a known base key.
+    pub per_base_key: BTreeMap<Vec<u8>, StorageDelta>,
+}
+
+impl AccountStorageReport {
+    pub fn add_entry_change(&mut self, key: &[u8], old_value: &[u8], new_value: &[u8]) {
+        let delta = StorageDelta::from_entry_change(key, old_value, new_value);
+        if delta.is_empty() {
+            return;
+        }
+        self.total += delta;
+        *self.per_key.entry(key.to_vec()).or_default() += delta;
+        *self
+            .per_base_key
+            .entry(storage_base_key(key).to_vec())
+            .or_default() += delta;
+    }
+
+    pub fn merge(&mut self, other: &AccountStorageReport) {
+        self.total += other.total;
+        for (key, delta) in &other.per_key {
+            *self.per_key.entry(key.clone()).or_default() += *delta;
+        }
+        for (base_key, delta) in &other.per_base_key {
+            *self.per_base_key.entry(base_key.clone()).or_default() += *delta;
+        }
+    }
+
+    /// Changes of all keys starting with the given storage mapper base key.
+    ///
+    /// Keys of other mappers whose base key starts with the given one are also included,
+    /// e.g. `user_count` is counted as part of `user`.
+    pub fn base_key_delta(&self, base_key: &[u8]) -> StorageDelta {
+        let mut result = StorageDelta::default();
+        for (key, delta) in self.per_key.range(base_key.to_vec()..) {
+            if !key.starts_with(base_key) {
+                break;
+            }
+            result += *delta;
+        }
+        result
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total.is_empty()
+    }
+}
+
+/// Storage size changes of all accounts touched by one or more transactions.
+#[derive(Default, Clone, Debug)]
+pub struct StorageReport {
+    pub accounts: HashMap<VMAddress, AccountStorageReport>,
+}
+
+impl StorageReport {
+    /// Diffs the storage of the updated accounts against their previous storage.
+    pub fn from_storage_changes<'a, I>(changes: I) -> Self
+    where
+        I: Iterator<
+            Item = (
+                &'a VMAddress,
+                Option<&'a AccountStorage>,
+                &'a AccountStorage,
+            ),
+        >,
+    {
+        let mut report = StorageReport::default();
+        for (address, old_storage, new_storage) in changes {
+            let mut account_report = AccountStorageReport::default();
+            for (key, new_value) in new_storage {
+                let old_value = old_storage
+                    .and_then(|storage| storage.get(key))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                account_report.add_entry_change(key, old_value, new_value);
+            }
+            if let Some(old_storage) = old_storage {
+                for (key, old_value) in old_storage {
+                    if !new_storage.contains_key(key) {
+                        account_report.add_entry_change(key, old_value, &[]);
+                    }
+                }
+            }
+            if !account_report.is_empty() {
+                report.accounts.insert(address.clone(), account_report);
+            }
+        }
+        report
+    }
+
+    pub fn merge(&mut self, other: &StorageReport) {
+        for (address, account_report) in &other.accounts {
+            self.accounts
+                .entry(address.clone())
+                .or_default()
+                .merge(account_report);
+        }
+    }
+
+    /// The changes of one account. Empty if the account storage was not changed.
+    pub fn account(&self, address: &VMAddress) -> AccountStorageReport {
+        self.accounts.get(address).cloned().unwrap_or_default()
+    }
+
+    /// Sum of the changes of all accounts.
+    pub fn total(&self) -> StorageDelta {
+        let mut total = StorageDelta::default();
+        for account_report in self.accounts.values() {
+            total += account_report.total;
+        }
+        total
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
+
+impl fmt::Display for StorageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut addresses: Vec<&VMAddress> = self.accounts.keys().collect();
+        addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        for address in addresses {
+            let account_report = &self.accounts[address];
+            writeln!(f, "{}: {}", address_hex(address), account_report.total)?;
+            for (base_key, delta) in &account_report.per_base_key {
+                writeln!(f, "\t{}: {delta}", bytes_to_string(base_key))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Guesses the storage mapper base key of a storage key:
+/// the longest prefix made up of identifier characters.
+///
+/// Mappers append their sub-keys to the base key after a `.` (e.g. `.len`, `.item`, `.node_links`),
+/// while encoded arguments of `#[storage_mapper("key")]` usually start with non-printable bytes,
+/// such as the length prefix of a buffer, or the leading zeros of a number.
+///
+/// The storage keys do not record where the base key ends, so the guess is wrong
+/// when an argument starts with identifier characters. This is the case for addresses,
+/// which are not length-prefixed, e.g. `address:user` is `user` padded with `_`.
+/// Such keys are grouped as separate base keys.
+pub fn storage_base_key(key: &[u8]) -> &[u8] {
+    let end = key
+        .iter()
+        .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_' || b == b'-' || b == b':'))
+        .unwrap_or(key.len());
+    &key[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address_key(base_key: &[u8], name: &[u8]) -> Vec<u8> {
+        let mut address = [b'_'; 32];
+        address[..name.len()].copy_from_slice(name);
+        [base_key, address.as_slice()].concat()
+    }
+
+    #[test]
+    fn test_storage_base_key() {
+        assert_eq!(storage_base_key(b"counter"), b"counter");
+        assert_eq!(storage_base_key(b"queue.node_links"), b"queue");
+        assert_eq!(storage_base_key(b"by_name\0\0\0\x03abc"), b"by_name");
+        assert_eq!(storage_base_key(b"by_id\0\0\0\x05"), b"by_id");
+    }
+
+    #[test]
+    fn test_storage_base_key_address_suffix() {
+        let alice_key = address_key(b"balance", b"alice");
+        let bob_key = address_key(b"balance", b"bob");
+
+        // the address is absorbed into the guessed base key
+        assert_eq!(storage_base_key(&alice_key), alice_key.as_slice());
+
+        let mut report = AccountStorageReport::default();
+        report.add_entry_change(&alice_key, b"", b"\x01");
+        report.add_entry_change(&bob_key, b"", b"\x02");
+        report.add_entry_change(b"balance_total", b"", b"\x03");
+        assert_eq!(report.per_base_key.len(), 3);
+
+        // known base keys are still reported correctly, other mappers with the same prefix included
+        let alice_delta = StorageDelta::from_entry_change(&alice_key, b"", b"\x01");
+        assert_eq!(report.base_key_delta(b"balancealice"), alice_delta);
+        assert_eq!(report.base_key_delta(b"balance"), report.total);
+        assert!(report.base_key_delta(b"other").is_empty());
+    }
+}
@@ -11,3 +11,6 @@ pub const NUMBER_IS_NOT_NORMAL: &str =
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
 
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
+
+pub const MANAGED_TYPES_TOO_MANY_HANDLES: &str = "too many managed type handles";
+pub const MANAGED_TYPES_MEMORY_LIMIT_EXCEEDED: &str = "managed types memory limit exceeded";
@@ -18,6 +18,33 @@ impl VMHooksDispatcher {
     }
 }
 
+/// Hooks not (yet) declared in the executor `VMHooks` interface.
+///
+/// They are only reachable from the Rust debugger, which calls the dispatcher directly.
+/// On the real VM the framework does without them.
+impl VMHooksDispatcher {
+    pub fn managed_buffer_drop(&self, handle: i32) {
+        self.handler.mb_drop(handle);
+    }
+
+    pub fn big_int_drop(&self, handle: i32) {
+        self.handler.bi_drop(handle);
+    }
+
+    pub fn big_float_drop(&self, handle: i32) {
+        self.handler.bf_drop(handle);
+    }
+
+    pub fn managed_map_drop(&self, handle: i32) {
+        self.handler.mm_drop(handle);
+    }
+
+    /// Called by the debugger after each hook, fails the execution if the limits are exceeded.
+    pub fn check_managed_types_limits(&self) {
+        self.handler.check_managed_types_limits();
+    }
+}
+
 fn bool_to_i32(b: bool) -> i32 {
     if b {
         1
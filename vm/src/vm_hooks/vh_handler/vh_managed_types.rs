@@ -52,4 +52,12 @@ pub trait VMHooksManagedTypes:
         let bytes = self.random_next_bytes(length);
         self.mb_set(dest_handle, bytes.as_slice());
     }
+
+    /// Fails the execution if the managed types exceed the configured limits.
+    fn check_managed_types_limits(&self) {
+        let result = self.m_types_lock().check_limits();
+        if let Err(message) = result {
+            self.vm_error(message);
+        }
+    }
 }
@@ -205,8 +205,8 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         arg_buffer_handle: RawHandle,
         success_callback: &[u8],
         error_callback: &[u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
         let contract_address = self.current_address().clone();
@@ -233,6 +233,8 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             success_callback: success_callback.into(),
             error_callback: error_callback.into(),
             callback_closure_data,
+            gas_limit: gas,
+            extra_gas_for_callback,
         };
 
         let mut tx_result = self.result_lock();
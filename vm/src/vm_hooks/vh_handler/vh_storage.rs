@@ -9,6 +9,7 @@ pub trait VMHooksStorageRead: VMHooksHandlerSource {
     fn storage_load_managed_buffer_raw(&self, key_handle: RawHandle, dest: RawHandle) {
         let value = self.storage_read(self.m_types_lock().mb_get(key_handle));
         self.m_types_lock().mb_set(dest, value);
+        self.result_lock().storage_access_stats.loads += 1;
     }
 
     fn storage_load_from_address(
@@ -29,5 +30,6 @@ pub trait VMHooksStorageWrite: VMHooksHandlerSource + VMHooksManagedTypes {
         let key_bytes = types.mb_get(key_handle);
         let value_bytes = types.mb_get(value_handle);
         self.storage_write(key_bytes, value_bytes);
+        self.result_lock().storage_access_stats.stores += 1;
     }
 }
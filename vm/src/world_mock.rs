@@ -1,9 +1,11 @@
 mod account_data;
+mod account_guardians;
 mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
 mod blockchain_state;
 mod esdt_data;
+mod esdt_global_metadata;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
@@ -12,10 +14,12 @@ mod failing_executor;
 pub mod reserved;
 
 pub use account_data::*;
+pub use account_guardians::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_state::BlockchainState;
 pub use esdt_data::*;
+pub use esdt_global_metadata::*;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
@@ -1,7 +1,7 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use super::AccountEsdt;
+use super::{AccountEsdt, AccountGuardians};
 use crate::{display_util::key_hex, types::VMAddress};
 use std::{collections::HashMap, fmt, fmt::Write};
 
@@ -18,6 +18,7 @@ pub struct AccountData {
     pub contract_path: Option<Vec<u8>>,
     pub contract_owner: Option<VMAddress>,
     pub developer_rewards: BigUint,
+    pub guardians: AccountGuardians,
 }
 
 impl AccountData {
@@ -32,6 +33,7 @@ impl AccountData {
             contract_path: None,
             contract_owner: None,
             developer_rewards: BigUint::zero(),
+            guardians: AccountGuardians::default(),
         }
     }
 }
@@ -0,0 +1,59 @@
+use crate::types::VMAddress;
+
+/// A guardian of a user account, either active or waiting for its activation epoch.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Guardian {
+    pub address: VMAddress,
+    pub activation_epoch: u64,
+    pub service_uid: Vec<u8>,
+}
+
+/// The guardian configuration of a user account,
+/// as managed by the `SetGuardian`, `GuardAccount` and `UnGuardAccount` builtin functions.
+#[derive(Clone, Default, Debug)]
+pub struct AccountGuardians {
+    pub active: Option<Guardian>,
+    pub pending: Option<Guardian>,
+    pub guarded: bool,
+}
+
+impl AccountGuardians {
+    pub fn is_empty(&self) -> bool {
+        self.active.is_none() && self.pending.is_none() && !self.guarded
+    }
+
+    /// A pending guardian takes over once its activation epoch is reached.
+    pub fn active_guardian(&self, current_epoch: u64) -> Option<&Guardian> {
+        match &self.pending {
+            Some(pending) if pending.activation_epoch <= current_epoch => Some(pending),
+            _ => self.active.as_ref(),
+        }
+    }
+
+    /// Checks the guardian co-signature of a transaction sent from this account.
+    pub fn check_co_signature(
+        &self,
+        co_signer: Option<&VMAddress>,
+        current_epoch: u64,
+    ) -> Result<(), &'static str> {
+        if !self.guarded {
+            return match co_signer {
+                Some(_) => Err("guarded transaction not expected"),
+                None => Ok(()),
+            };
+        }
+
+        match (co_signer, self.active_guardian(current_epoch)) {
+            (None, _) => Err("guarded account needs guardian co-signature"),
+            (Some(co_signer), Some(guardian)) if *co_signer == guardian.address => Ok(()),
+            _ => Err("invalid guardian"),
+        }
+    }
+
+    /// The new guardian only becomes active at its activation epoch,
+    /// until then the previously active guardian (if any) stays in place.
+    pub fn set_pending_guardian(&mut self, guardian: Guardian, current_epoch: u64) {
+        self.active = self.active_guardian(current_epoch).cloned();
+        self.pending = Some(guardian);
+    }
+}
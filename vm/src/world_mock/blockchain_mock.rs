@@ -1,4 +1,9 @@
-use crate::{tx_execution::BlockchainVMRef, with_shared::Shareable};
+use crate::{
+    tx_execution::{BlockchainVMRef, MockTxHandler},
+    tx_mock::ManagedTypesLimits,
+    types::VMAddress,
+    with_shared::Shareable,
+};
 use multiversx_chain_vm_executor::Executor;
 use std::{fmt::Debug, ops::Deref};
 
@@ -16,6 +21,31 @@ impl BlockchainMock {
             state: Shareable::default(),
         }
     }
+
+    /// Adds a builtin function implemented in Rust, or replaces a protocol builtin function.
+    pub fn register_builtin_function<H>(&self, function_name: &str, handler: H)
+    where
+        H: MockTxHandler + 'static,
+    {
+        self.vm
+            .builtin_functions
+            .register_custom_function(function_name, handler);
+    }
+
+    /// Limits the managed types each call can keep alive, similar to the real VM.
+    ///
+    /// Exceeding them fails the call with an execution error.
+    pub fn set_managed_types_limits(&self, limits: ManagedTypesLimits) {
+        self.vm.set_managed_types_limits(limits);
+    }
+
+    /// All calls to the given address are handled in Rust, instead of by a deployed contract.
+    pub fn register_mock_contract<H>(&self, address: VMAddress, handler: H)
+    where
+        H: MockTxHandler + 'static,
+    {
+        self.vm.mock_contracts.register(address, handler);
+    }
 }
 
 impl Default for BlockchainMock {
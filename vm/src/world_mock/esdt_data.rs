@@ -146,6 +146,11 @@ impl AccountEsdt {
             .attributes = new_attribute_bytes;
     }
 
+    pub fn set_frozen(&mut self, token_identifier: &[u8], frozen: bool) {
+        let esdt_data = self.0.entry(token_identifier.to_vec()).or_default();
+        esdt_data.frozen = frozen;
+    }
+
     pub fn iter(&self) -> Iter<Vec<u8>, EsdtData> {
         self.0.iter()
     }
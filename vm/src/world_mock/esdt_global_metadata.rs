@@ -0,0 +1,53 @@
+use super::reserved::STORAGE_RESERVED_PREFIX;
+
+/// Address of the system account, in whose storage the node keeps the global settings of all ESDT tokens.
+pub const ESDT_SYSTEM_ACCOUNT_ADDRESS_ARRAY: [u8; 32] = [0xff; 32];
+
+const ESDT_KEY_IDENTIFIER: &[u8] = b"esdt";
+
+const PAUSED_FLAG: u8 = 0b0000_0001;
+const LIMITED_TRANSFER_FLAG: u8 = 0b0000_0010;
+const BURN_ROLE_FOR_ALL_FLAG: u8 = 0b0000_0100;
+
+/// Global settings of an ESDT token, same encoding as in the node.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct EsdtGlobalMetadata {
+    pub paused: bool,
+    pub limited_transfer: bool,
+    pub burn_role_for_all: bool,
+}
+
+impl EsdtGlobalMetadata {
+    /// Key under which the metadata is saved in the system account storage.
+    pub fn storage_key(token_identifier: &[u8]) -> Vec<u8> {
+        [
+            STORAGE_RESERVED_PREFIX,
+            ESDT_KEY_IDENTIFIER,
+            token_identifier,
+        ]
+        .concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let flags = bytes.first().cloned().unwrap_or_default();
+        EsdtGlobalMetadata {
+            paused: flags & PAUSED_FLAG != 0,
+            limited_transfer: flags & LIMITED_TRANSFER_FLAG != 0,
+            burn_role_for_all: flags & BURN_ROLE_FOR_ALL_FLAG != 0,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut flags = 0u8;
+        if self.paused {
+            flags |= PAUSED_FLAG;
+        }
+        if self.limited_transfer {
+            flags |= LIMITED_TRANSFER_FLAG;
+        }
+        if self.burn_role_for_all {
+            flags |= BURN_ROLE_FOR_ALL_FLAG;
+        }
+        vec![flags, 0]
+    }
+}